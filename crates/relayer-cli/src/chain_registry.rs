@@ -148,6 +148,7 @@ where
         clock_drift: default::clock_drift(),
         max_block_time: default::max_block_time(),
         trusting_period: None,
+        trusting_period_margin: None,
         ccv_consumer_chain: false,
         memo_prefix: Memo::default(),
         proof_specs: Default::default(),
@@ -157,8 +158,19 @@ where
             denom: asset.base.to_owned(),
         },
         packet_filter: packet_filter.unwrap_or_default(),
+        proxy: None,
+        rate_limit: Default::default(),
+        retry: Default::default(),
+        packet_memo: Default::default(),
+        height_rollback: Default::default(),
+        cache: Default::default(),
+        dynamic_gas_price: Default::default(),
+        adaptive_batch: Default::default(),
+        channel_batch: Vec::new(),
+        submitter: None,
         address_type: AddressType::default(),
         sequential_batch_tx: false,
+        aggregation_window: None,
         extension_options: Vec::new(),
     })
 }
@@ -10,7 +10,9 @@ mod keys;
 mod listen;
 mod misbehaviour;
 mod query;
+mod simulate;
 mod start;
+mod submitter;
 mod tx;
 mod update;
 mod upgrade;
@@ -19,8 +21,9 @@ mod version;
 use self::{
     clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd, create::CreateCmds,
     fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
-    misbehaviour::MisbehaviourCmd, query::QueryCmd, start::StartCmd, tx::TxCmd, update::UpdateCmds,
-    upgrade::UpgradeCmds, version::VersionCmd,
+    misbehaviour::MisbehaviourCmd, query::QueryCmd, simulate::SimulatePathsCmd, start::StartCmd,
+    submitter::SubmitterCmd, tx::TxCmd, update::UpdateCmds, upgrade::UpgradeCmds,
+    version::VersionCmd,
 };
 
 use core::time::Duration;
@@ -70,6 +73,10 @@ pub enum CliCmd {
     /// Relays packets and open handshake messages between all chains in the config.
     Start(StartCmd),
 
+    /// Sign and submit to the chains in the config the messages
+    /// sent by relayers configured to use a remote submitter
+    Submitter(SubmitterCmd),
+
     /// Query objects from the chain
     #[clap(subcommand)]
     Query(QueryCmd),
@@ -94,6 +101,10 @@ pub enum CliCmd {
     /// Performs a health check of all chains in the the config
     HealthCheck(HealthCheckCmd),
 
+    /// Simulate, without broadcasting them, the messages relayed on the paths
+    /// of the chains in the config, to validate the configuration and the keys
+    SimulatePaths(SimulatePathsCmd),
+
     /// Generate auto-complete scripts for different shells.
     #[clap(display_order = 1000)]
     Completions(CompletionsCmd),
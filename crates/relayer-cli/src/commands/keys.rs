@@ -6,6 +6,7 @@ mod add;
 mod balance;
 mod delete;
 mod list;
+mod reload;
 
 /// `keys` subcommand
 #[derive(Command, Debug, Parser, Runnable)]
@@ -21,4 +22,7 @@ pub enum KeysCmd {
 
     /// Query balance for a key from a configured chain. If no key is given, the key is retrieved from the configuration file.
     Balance(balance::KeyBalanceCmd),
+
+    /// Reload the key of a chain in the running relayer, optionally switching to another key
+    Reload(reload::KeysReloadCmd),
 }
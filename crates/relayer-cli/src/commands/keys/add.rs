@@ -1,6 +1,6 @@
-use core::str::FromStr;
 use std::{
     fs,
+    io::{self, Read},
     path::{Path, PathBuf},
 };
 
@@ -8,12 +8,12 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
 use eyre::eyre;
-use hdpath::StandardHDPath;
 use ibc_relayer::{
     chain::ChainType,
     config::{ChainConfig, Config},
     keyring::{
-        AnySigningKeyPair, KeyRing, Secp256k1KeyPair, SigningKeyPair, SigningKeyPairSized, Store,
+        parse_derivation_path, AnySigningKeyPair, DerivationPath, KeyRing, Secp256k1KeyPair,
+        SigningKeyPairSized, Store,
     },
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -24,7 +24,7 @@ use crate::conclude::Output;
 
 /// The data structure that represents the arguments when invoking the `keys add` CLI command.
 ///
-/// The command has one argument and two exclusive flags:
+/// The command has one argument and five exclusive flags:
 ///
 /// The command to add a key from a file:
 ///
@@ -34,13 +34,34 @@ use crate::conclude::Output;
 ///
 /// `keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-file <MNEMONIC_FILE>`
 ///
-/// The key-file and mnemonic-file flags can't be given at the same time, this will cause a terminating error.
+/// The command to restore a key from a mnemonic read from the standard input:
+///
+/// `keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-stdin`
+///
+/// The command to restore a key from a mnemonic read from an open file descriptor:
+///
+/// `keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-fd <FD>`
+///
+/// The command to import a watch-only key from a public key:
+///
+/// `keys add [OPTIONS] --chain <CHAIN_ID> --public-key <PUBLIC_KEY>`
+///
+/// Only one of these flags can be given at a time, otherwise this will cause a terminating error.
 /// If successful the key will be created or restored, depending on which flag was given.
+///
+/// The mnemonic is never accepted as a command-line argument, so that it does not
+/// end up in the shell history or in the process list.
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 #[clap(
     override_usage = "hermes keys add [OPTIONS] --chain <CHAIN_ID> --key-file <KEY_FILE>
 
-    hermes keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-file <MNEMONIC_FILE>"
+    hermes keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-file <MNEMONIC_FILE>
+
+    hermes keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-stdin
+
+    hermes keys add [OPTIONS] --chain <CHAIN_ID> --mnemonic-fd <FD>
+
+    hermes keys add [OPTIONS] --chain <CHAIN_ID> --public-key <PUBLIC_KEY>"
 )]
 pub struct KeysAddCmd {
     #[clap(
@@ -71,6 +92,36 @@ pub struct KeysAddCmd {
     )]
     mnemonic_file: Option<PathBuf>,
 
+    #[clap(
+        long = "mnemonic-stdin",
+        required = true,
+        help_heading = "FLAGS",
+        help = "Read the mnemonic to restore the key from the standard input",
+        group = "add-restore"
+    )]
+    mnemonic_stdin: bool,
+
+    #[clap(
+        long = "mnemonic-fd",
+        required = true,
+        value_name = "FD",
+        help_heading = "FLAGS",
+        help = "Read the mnemonic to restore the key from the given open file descriptor",
+        group = "add-restore"
+    )]
+    mnemonic_fd: Option<u32>,
+
+    #[clap(
+        long = "public-key",
+        required = true,
+        value_name = "PUBLIC_KEY",
+        help_heading = "FLAGS",
+        help = "Import a watch-only key, which can be used for queries but cannot sign transactions, \
+                from its public key (either the JSON output of `<chain-binary> keys show --pubkey` or Bech32-encoded)",
+        group = "add-restore"
+    )]
+    public_key: Option<String>,
+
     #[clap(
         long = "key-name",
         value_name = "KEY_NAME",
@@ -81,7 +132,7 @@ pub struct KeysAddCmd {
     #[clap(
         long = "hd-path",
         value_name = "HD_PATH",
-        help = "Derivation path for this key",
+        help = "Derivation path for this key, any BIP-32 path is accepted",
         default_value = "m/44'/118'/0'/0/0"
     )]
     hd_path: String,
@@ -104,7 +155,7 @@ impl KeysAddCmd {
             .clone()
             .unwrap_or_else(|| chain_config.key_name.clone());
 
-        let hd_path = StandardHDPath::from_str(&self.hd_path)
+        let hd_path = parse_derivation_path(&self.hd_path)
             .map_err(|_| eyre!("invalid derivation path: {}", self.hd_path))?;
 
         Ok(KeysAddOptions {
@@ -119,7 +170,49 @@ impl KeysAddCmd {
 pub struct KeysAddOptions {
     pub name: String,
     pub config: ChainConfig,
-    pub hd_path: StandardHDPath,
+    pub hd_path: DerivationPath,
+}
+
+/// Where to read the mnemonic from when restoring a key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MnemonicSource {
+    File(PathBuf),
+    Stdin,
+    FileDescriptor(u32),
+}
+
+impl MnemonicSource {
+    /// Read the mnemonic from this source.
+    pub fn read(&self) -> eyre::Result<String> {
+        match self {
+            Self::File(path) => {
+                fs::read_to_string(path).map_err(|_| eyre!("error reading the mnemonic file"))
+            }
+            Self::Stdin => {
+                let mut mnemonic = String::new();
+                io::stdin()
+                    .read_to_string(&mut mnemonic)
+                    .map_err(|_| eyre!("error reading the mnemonic from the standard input"))?;
+                Ok(mnemonic)
+            }
+            Self::FileDescriptor(fd) => {
+                // The file descriptor is opened through its `/dev/fd` entry,
+                // which is available on both Linux and macOS.
+                fs::read_to_string(format!("/dev/fd/{fd}"))
+                    .map_err(|_| eyre!("error reading the mnemonic from file descriptor {}", fd))
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for MnemonicSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file {}", path.display()),
+            Self::Stdin => write!(f, "standard input"),
+            Self::FileDescriptor(fd) => write!(f, "file descriptor {fd}"),
+        }
+    }
 }
 
 impl Runnable for KeysAddCmd {
@@ -131,13 +224,22 @@ impl Runnable for KeysAddCmd {
             Ok(result) => result,
         };
 
-        // Check if --key-file or --mnemonic-file was given as input.
-        match (self.key_file.clone(), self.mnemonic_file.clone()) {
-            (Some(key_file), _) => {
+        let mnemonic_source = if let Some(mnemonic_file) = &self.mnemonic_file {
+            Some(MnemonicSource::File(mnemonic_file.clone()))
+        } else if self.mnemonic_stdin {
+            Some(MnemonicSource::Stdin)
+        } else {
+            self.mnemonic_fd.map(MnemonicSource::FileDescriptor)
+        };
+
+        // Check which of --key-file, --mnemonic-file, --mnemonic-stdin, --mnemonic-fd
+        // or --public-key was given as input.
+        match (&self.key_file, mnemonic_source, &self.public_key) {
+            (Some(key_file), _, _) => {
                 let key = add_key(
                     &opts.config,
                     &opts.name,
-                    &key_file,
+                    key_file,
                     &opts.hd_path,
                     self.overwrite,
                 );
@@ -156,9 +258,9 @@ impl Runnable for KeysAddCmd {
                     .exit(),
                 }
             }
-            (_, Some(mnemonic_file)) => {
+            (_, Some(mnemonic_source), _) => {
                 let key = restore_key(
-                    &mnemonic_file,
+                    &mnemonic_source,
                     &opts.name,
                     &opts.hd_path,
                     &opts.config,
@@ -174,17 +276,36 @@ impl Runnable for KeysAddCmd {
                     ))
                     .exit(),
                     Err(e) => Output::error(format!(
-                        "An error occurred restoring the key on chain {} from file {:?}: {}",
-                        self.chain_id, mnemonic_file, e
+                        "An error occurred restoring the key on chain {} from {}: {}",
+                        self.chain_id, mnemonic_source, e
+                    ))
+                    .exit(),
+                }
+            }
+            (_, _, Some(public_key)) => {
+                let key =
+                    add_watch_only_key(&opts.config, &opts.name, public_key, self.overwrite);
+
+                match key {
+                    Ok(key) => Output::success_msg(format!(
+                        "Added watch-only key '{}' ({}) on chain {}",
+                        opts.name,
+                        key.account(),
+                        opts.config.id
+                    ))
+                    .exit(),
+                    Err(e) => Output::error(format!(
+                        "An error occurred adding the watch-only key on chain {}: {}",
+                        self.chain_id, e
                     ))
                     .exit(),
                 }
             }
             // This case should never trigger.
-            // The 'required' parameter for the flags will trigger an error if both flags have not been given.
-            // And the 'group' parameter for the flags will trigger an error if both flags are given.
+            // The 'required' parameter for the flags will trigger an error if none of the flags have been given.
+            // And the 'group' parameter for the flags will trigger an error if more than one flag is given.
             _ => Output::error(
-                "--mnemonic-file and --key-file can't both be set or both None".to_string(),
+                "exactly one of --key-file, --mnemonic-file, --mnemonic-stdin, --mnemonic-fd or --public-key must be set".to_string(),
             )
             .exit(),
         }
@@ -195,7 +316,7 @@ pub fn add_key(
     config: &ChainConfig,
     key_name: &str,
     file: &Path,
-    hd_path: &StandardHDPath,
+    hd_path: &DerivationPath,
     overwrite: bool,
 ) -> eyre::Result<AnySigningKeyPair> {
     let key_pair = match config.r#type {
@@ -211,7 +332,8 @@ pub fn add_key(
 
             let key_contents =
                 fs::read_to_string(file).map_err(|_| eyre!("error reading the key file"))?;
-            let key_pair = Secp256k1KeyPair::from_seed_file(&key_contents, hd_path)?;
+            let key_pair =
+                Secp256k1KeyPair::from_seed_file_with_derivation_path(&key_contents, hd_path)?;
 
             keyring.add_key(key_name, key_pair.clone())?;
             key_pair.into()
@@ -222,14 +344,13 @@ pub fn add_key(
 }
 
 pub fn restore_key(
-    mnemonic: &Path,
+    mnemonic: &MnemonicSource,
     key_name: &str,
-    hdpath: &StandardHDPath,
+    hdpath: &DerivationPath,
     config: &ChainConfig,
     overwrite: bool,
 ) -> eyre::Result<AnySigningKeyPair> {
-    let mnemonic_content =
-        fs::read_to_string(mnemonic).map_err(|_| eyre!("error reading the mnemonic file"))?;
+    let mnemonic_content = mnemonic.read()?;
 
     let key_pair = match config.r#type {
         ChainType::CosmosSdk => {
@@ -242,8 +363,8 @@ pub fn restore_key(
 
             check_key_exists(&keyring, key_name, overwrite);
 
-            let key_pair = Secp256k1KeyPair::from_mnemonic(
-                &mnemonic_content,
+            let key_pair = Secp256k1KeyPair::from_mnemonic_with_derivation_path(
+                mnemonic_content.trim(),
                 hdpath,
                 &config.address_type,
                 keyring.account_prefix(),
@@ -257,6 +378,37 @@ pub fn restore_key(
     Ok(key_pair)
 }
 
+pub fn add_watch_only_key(
+    config: &ChainConfig,
+    key_name: &str,
+    public_key: &str,
+    overwrite: bool,
+) -> eyre::Result<AnySigningKeyPair> {
+    let key_pair = match config.r#type {
+        ChainType::CosmosSdk => {
+            let mut keyring = KeyRing::new_secp256k1(
+                Store::Test,
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+            )?;
+
+            check_key_exists(&keyring, key_name, overwrite);
+
+            let key_pair = Secp256k1KeyPair::from_public_key(
+                public_key,
+                &config.address_type,
+                keyring.account_prefix(),
+            )?;
+
+            keyring.add_key(key_name, key_pair.clone())?;
+            key_pair.into()
+        }
+    };
+
+    Ok(key_pair)
+}
+
 /// Check if the key with the given key name already exists.
 /// If it already exists and overwrite is false, abort the command with an error.
 /// If overwrite is true, output a warning message informing the key will be overwritten.
@@ -286,6 +438,9 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 key_file: Some(PathBuf::from("key_file")),
                 mnemonic_file: None,
+                mnemonic_stdin: false,
+                mnemonic_fd: None,
+                public_key: None,
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
                 overwrite: false,
@@ -301,6 +456,9 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 key_file: None,
                 mnemonic_file: Some(PathBuf::from("mnemonic_file")),
+                mnemonic_stdin: false,
+                mnemonic_fd: None,
+                public_key: None,
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
                 overwrite: false
@@ -322,6 +480,9 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 key_file: Some(PathBuf::from("key_file")),
                 mnemonic_file: None,
+                mnemonic_stdin: false,
+                mnemonic_fd: None,
+                public_key: None,
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
                 overwrite: true,
@@ -344,6 +505,9 @@ mod tests {
                 chain_id: ChainId::from_string("chain_id"),
                 key_file: None,
                 mnemonic_file: Some(PathBuf::from("mnemonic_file")),
+                mnemonic_stdin: false,
+                mnemonic_fd: None,
+                public_key: None,
                 key_name: None,
                 hd_path: "m/44'/118'/0'/0/0".to_string(),
                 overwrite: true,
@@ -359,6 +523,81 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_keys_add_mnemonic_stdin() {
+        assert_eq!(
+            KeysAddCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_file: None,
+                mnemonic_file: None,
+                mnemonic_stdin: true,
+                mnemonic_fd: None,
+                public_key: None,
+                key_name: None,
+                hd_path: "m/44'/118'/0'/0/0".to_string(),
+                overwrite: false,
+            },
+            KeysAddCmd::parse_from(["test", "--chain", "chain_id", "--mnemonic-stdin"])
+        )
+    }
+
+    #[test]
+    fn test_keys_add_mnemonic_fd_custom_hd_path() {
+        assert_eq!(
+            KeysAddCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_file: None,
+                mnemonic_file: None,
+                mnemonic_stdin: false,
+                mnemonic_fd: Some(3),
+                public_key: None,
+                key_name: None,
+                hd_path: "m/44'/60'/0'/0/0/1".to_string(),
+                overwrite: false,
+            },
+            KeysAddCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--mnemonic-fd",
+                "3",
+                "--hd-path",
+                "m/44'/60'/0'/0/0/1"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_add_public_key() {
+        assert_eq!(
+            KeysAddCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_file: None,
+                mnemonic_file: None,
+                mnemonic_stdin: false,
+                mnemonic_fd: None,
+                public_key: Some("public_key".to_string()),
+                key_name: None,
+                hd_path: "m/44'/118'/0'/0/0".to_string(),
+                overwrite: false,
+            },
+            KeysAddCmd::parse_from(["test", "--chain", "chain_id", "--public-key", "public_key"])
+        )
+    }
+
+    #[test]
+    fn test_keys_add_mnemonic_file_and_stdin() {
+        assert!(KeysAddCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--mnemonic-file",
+            "mnemonic_file",
+            "--mnemonic-stdin"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn test_keys_add_no_file_nor_mnemonic() {
         assert!(KeysAddCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err());
@@ -0,0 +1,175 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use eyre::eyre;
+use serde::{Deserialize, Serialize};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer::config::RestConfig;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+
+/// The data structure that represents the arguments when invoking the `keys reload` CLI command.
+///
+/// The command has the following format:
+///
+/// `keys reload --chain <CHAIN_ID> [--key-name <KEY_NAME>]`
+///
+/// The key is reloaded by the relayer running with the configuration, through its REST server,
+/// so that a key added or rotated in the keyring is used without restarting the relayer.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct KeysReloadCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Name of the key to switch to. Defaults to the key in use by the relayer"
+    )]
+    key_name: Option<String>,
+}
+
+impl Runnable for KeysReloadCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        if config.find_chain(&self.chain_id).is_none() {
+            Output::error(format!(
+                "chain '{}' not found in configuration file",
+                self.chain_id
+            ))
+            .exit()
+        }
+
+        if !config.rest.enabled {
+            Output::error(
+                "the REST server must be enabled in the `[rest]` section of the configuration \
+                 to reload the keys of a running relayer",
+            )
+            .exit()
+        }
+
+        if !config.rest.allow_key_reload {
+            Output::error(
+                "reloading the keys must be allowed with `allow_key_reload = true` \
+                 in the `[rest]` section of the configuration",
+            )
+            .exit()
+        }
+
+        let rt = TokioRuntime::new().unwrap_or_else(exit_with_unrecoverable_error);
+
+        match rt.block_on(reload_key(
+            &config.rest,
+            &self.chain_id,
+            self.key_name.clone(),
+        )) {
+            Ok(signer) => Output::success_msg(format!(
+                "reloaded the key of chain '{}', the transactions are now signed by {}",
+                self.chain_id, signer
+            ))
+            .exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ReloadKeyParams {
+    key_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "status", content = "result")]
+#[serde(rename_all = "lowercase")]
+enum ReloadKeyResponse {
+    Success(Signer),
+    Error(ReloadKeyError),
+}
+
+#[derive(Debug, Deserialize)]
+struct ReloadKeyError {
+    msg: String,
+}
+
+async fn reload_key(
+    rest: &RestConfig,
+    chain_id: &ChainId,
+    key_name: Option<String>,
+) -> eyre::Result<Signer> {
+    let url = format!(
+        "http://{}:{}/chain/{}/keys/reload",
+        rest.host, rest.port, chain_id
+    );
+
+    let mut request = reqwest::Client::new()
+        .post(&url)
+        .json(&ReloadKeyParams { key_name });
+
+    if let Some(token_file) = &rest.token_file {
+        let token = std::fs::read_to_string(token_file).map_err(|e| {
+            eyre!(
+                "failed to read the REST token from {}: {e}",
+                token_file.display()
+            )
+        })?;
+
+        request = request.bearer_auth(token.trim());
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| eyre!("failed to reach the REST server of the relayer at {url}: {e}"))?
+        .json()
+        .await?;
+
+    match response {
+        ReloadKeyResponse::Success(signer) => Ok(signer),
+        ReloadKeyResponse::Error(e) => Err(eyre!("{}", e.msg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeysReloadCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_keys_reload() {
+        assert_eq!(
+            KeysReloadCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_name: None,
+            },
+            KeysReloadCmd::parse_from(["test", "--chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_keys_reload_key_name() {
+        assert_eq!(
+            KeysReloadCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                key_name: Some("rotated".to_owned()),
+            },
+            KeysReloadCmd::parse_from(["test", "--chain", "chain_id", "--key-name", "rotated"])
+        )
+    }
+
+    #[test]
+    fn test_keys_reload_no_chain() {
+        assert!(KeysReloadCmd::try_parse_from(["test"]).is_err())
+    }
+}
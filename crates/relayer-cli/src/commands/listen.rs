@@ -1,25 +1,40 @@
+use alloc::borrow::Cow;
 use alloc::sync::Arc;
 use core::{
     fmt::{Display, Error as FmtError, Formatter},
     ops::Deref,
     str::FromStr,
 };
-use std::thread;
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+    path::PathBuf,
+};
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{application::fatal_error, Runnable};
 use eyre::eyre;
 use itertools::Itertools;
+use serde::Serialize;
 use tendermint_rpc::{client::CompatMode, Client, HttpClient};
 use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, info, instrument};
 
 use ibc_relayer::{
     chain::handle::Subscription,
-    config::{ChainConfig, EventSourceMode},
-    event::source::websocket::EventSource,
+    config::{
+        packet_memo::{self, PacketMemoConfig, PacketMemoPolicy},
+        ChainConfig, EventSourceMode,
+    },
+    event::{source::websocket::EventSource, IbcEventWithHeight},
+    util::profile,
+};
+use ibc_relayer_types::{
+    applications::transfer::acknowledgement::Acknowledgement as TransferAcknowledgement,
+    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    events::{IbcEvent, IbcEventType},
+    Height,
 };
-use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent};
 
 use crate::prelude::*;
 
@@ -59,6 +74,220 @@ impl FromStr for EventFilter {
     }
 }
 
+/// Filter on the type of an IBC event, eg. `send_packet` or `write_acknowledgement`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventTypeFilter(IbcEventType);
+
+impl EventTypeFilter {
+    pub fn matches(&self, event: &IbcEvent) -> bool {
+        event.event_type() == self.0
+    }
+}
+
+impl FromStr for EventTypeFilter {
+    type Err = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IbcEventType::from_str(s)
+            .map(Self)
+            .map_err(|_| format!("unrecognized IBC event type: {s}").into())
+    }
+}
+
+/// Filters applied to the events received from the chain.
+/// An event is only output if it matches all the non-empty filters.
+#[derive(Clone, Debug, Default)]
+pub struct ListenFilters {
+    pub events: Vec<EventFilter>,
+    pub event_types: Vec<EventTypeFilter>,
+    pub ports: Vec<PortId>,
+    pub channels: Vec<ChannelId>,
+}
+
+impl ListenFilters {
+    pub fn matches(&self, event: &IbcEvent) -> bool {
+        if !self.events.iter().any(|f| f.matches(event)) {
+            return false;
+        }
+
+        if !self.event_types.is_empty() && !self.event_types.iter().any(|f| f.matches(event)) {
+            return false;
+        }
+
+        if self.ports.is_empty() && self.channels.is_empty() {
+            return true;
+        }
+
+        let ends = event_channel_ends(event);
+
+        let port_matches =
+            self.ports.is_empty() || ends.iter().any(|(port_id, _)| self.ports.contains(port_id));
+
+        let channel_matches = self.channels.is_empty()
+            || ends.iter().any(|(_, channel_id)| {
+                channel_id
+                    .as_ref()
+                    .map_or(false, |channel_id| self.channels.contains(channel_id))
+            });
+
+        port_matches && channel_matches
+    }
+}
+
+/// The port and channel identifiers, on both ends, that an event pertains to.
+fn event_channel_ends(event: &IbcEvent) -> Vec<(PortId, Option<ChannelId>)> {
+    if let Some(packet) = event.packet() {
+        return vec![
+            (
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+            (
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+        ];
+    }
+
+    match event {
+        IbcEvent::CloseInitChannel(ev) => vec![
+            (ev.port_id().clone(), Some(ev.channel_id().clone())),
+            (
+                ev.counterparty_port_id().clone(),
+                ev.counterparty_channel_id().cloned(),
+            ),
+        ],
+        IbcEvent::CloseConfirmChannel(ev) => vec![
+            (ev.port_id.clone(), ev.channel_id.clone()),
+            (
+                ev.counterparty_port_id.clone(),
+                ev.counterparty_channel_id.clone(),
+            ),
+        ],
+        _ => match event.clone().channel_attributes() {
+            Some(attributes) => vec![
+                (attributes.port_id, attributes.channel_id),
+                (
+                    attributes.counterparty_port_id,
+                    attributes.counterparty_channel_id,
+                ),
+            ],
+            None => vec![],
+        },
+    }
+}
+
+/// A single line of output when listening with `--ndjson`.
+///
+/// The field names make up the output format consumed by external tools
+/// and must therefore be kept stable.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum ListenRecord<'a> {
+    Event {
+        chain_id: &'a ChainId,
+        height: Height,
+        event_type: &'static str,
+        event: &'a IbcEvent,
+        /// The decoded acknowledgement, for `write_acknowledgement` events of ICS-20 transfers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        transfer_ack: Option<TransferAcknowledgement>,
+    },
+    Error {
+        chain_id: &'a ChainId,
+        error: String,
+    },
+}
+
+impl<'a> ListenRecord<'a> {
+    pub fn event(chain_id: &'a ChainId, event: &'a IbcEventWithHeight) -> Self {
+        Self::Event {
+            chain_id,
+            height: event.height,
+            event_type: event.event.event_type().as_str(),
+            event: &event.event,
+            transfer_ack: transfer_ack(&event.event),
+        }
+    }
+}
+
+/// Decodes the acknowledgement of a `WriteAcknowledgement` event for an ICS-20 transfer.
+fn transfer_ack(event: &IbcEvent) -> Option<TransferAcknowledgement> {
+    match event {
+        IbcEvent::WriteAcknowledgement(write_ack) => write_ack.transfer_ack(),
+        _ => None,
+    }
+}
+
+/// Strips the memo from the packet data of the given event if it exceeds
+/// the maximum size and the configured policy is to strip it.
+fn strip_oversized_memo<'a>(
+    config: &PacketMemoConfig,
+    event: &'a IbcEventWithHeight,
+) -> Cow<'a, IbcEventWithHeight> {
+    let oversized = config.policy == PacketMemoPolicy::Strip
+        && event
+            .event
+            .packet()
+            .and_then(|packet| config.oversized_memo(&packet.data))
+            .is_some();
+
+    if !oversized {
+        return Cow::Borrowed(event);
+    }
+
+    let mut event = event.clone();
+
+    if let Some(packet) = event.event.packet_mut() {
+        packet_memo::strip_memo(&mut packet.data);
+    }
+
+    Cow::Owned(event)
+}
+
+/// Where to output the events.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListenOutput {
+    /// Log the events, using the configured log format
+    Log,
+    /// Write newline-delimited JSON to the standard output
+    Stdout,
+    /// Append newline-delimited JSON to the given file
+    File(PathBuf),
+    /// Write newline-delimited JSON to the UNIX socket at the given path
+    Socket(PathBuf),
+}
+
+impl ListenOutput {
+    fn writer(&self) -> eyre::Result<Option<Box<dyn Write>>> {
+        match self {
+            Self::Log => Ok(None),
+            Self::Stdout => Ok(Some(Box::new(io::stdout()))),
+            Self::File(path) => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|e| eyre!("could not open output file {}: {}", path.display(), e))?;
+
+                Ok(Some(Box::new(file)))
+            }
+            #[cfg(unix)]
+            Self::Socket(path) => {
+                let stream = std::os::unix::net::UnixStream::connect(path).map_err(|e| {
+                    eyre!("could not connect to UNIX socket {}: {}", path.display(), e)
+                })?;
+
+                Ok(Some(Box::new(stream)))
+            }
+            #[cfg(not(unix))]
+            Self::Socket(_) => Err(eyre!(
+                "writing events to a UNIX socket is not supported on this platform"
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Parser, PartialEq, Eq)]
 pub struct ListenCmd {
     /// Identifier of the chain to listen for events from
@@ -74,6 +303,35 @@ pub struct ListenCmd {
     /// Listen for all events by default (available: Tx, NewBlock).
     #[clap(long = "events", value_name = "EVENT", multiple_values = true)]
     events: Vec<EventFilter>,
+
+    /// Only output IBC events of the given type (eg. send_packet, write_acknowledgement), can be repeated.
+    #[clap(long = "event-type", value_name = "EVENT_TYPE", multiple_values = true)]
+    event_types: Vec<EventTypeFilter>,
+
+    /// Only output events pertaining to the given port, on either end, can be repeated.
+    #[clap(long = "port", value_name = "PORT_ID", multiple_values = true)]
+    ports: Vec<PortId>,
+
+    /// Only output events pertaining to the given channel, on either end, can be repeated.
+    #[clap(long = "channel", value_name = "CHANNEL_ID", multiple_values = true)]
+    channels: Vec<ChannelId>,
+
+    /// Output the events as newline-delimited JSON, one event per line, instead of logging them.
+    #[clap(long = "ndjson")]
+    ndjson: bool,
+
+    /// Append the newline-delimited JSON events to the given file instead of the standard output.
+    #[clap(
+        long = "output-file",
+        value_name = "PATH",
+        requires = "ndjson",
+        conflicts_with = "output-socket"
+    )]
+    output_file: Option<PathBuf>,
+
+    /// Write the newline-delimited JSON events to the UNIX socket at the given path instead of the standard output.
+    #[clap(long = "output-socket", value_name = "PATH", requires = "ndjson")]
+    output_socket: Option<PathBuf>,
 }
 
 impl ListenCmd {
@@ -85,12 +343,28 @@ impl ListenCmd {
             .ok_or_else(|| eyre!("chain '{}' not found in configuration", self.chain_id))?;
 
         let events = if self.events.is_empty() {
-            &[EventFilter::Tx, EventFilter::NewBlock]
+            vec![EventFilter::Tx, EventFilter::NewBlock]
         } else {
-            self.events.as_slice()
+            self.events.clone()
+        };
+
+        let filters = ListenFilters {
+            events,
+            event_types: self.event_types.clone(),
+            ports: self.ports.clone(),
+            channels: self.channels.clone(),
         };
 
-        listen(chain_config, events)
+        listen(chain_config, &filters, &self.output())
+    }
+
+    fn output(&self) -> ListenOutput {
+        match (self.ndjson, &self.output_file, &self.output_socket) {
+            (false, _, _) => ListenOutput::Log,
+            (true, Some(path), _) => ListenOutput::File(path.clone()),
+            (true, None, Some(path)) => ListenOutput::Socket(path.clone()),
+            (true, None, None) => ListenOutput::Stdout,
+        }
     }
 }
 
@@ -103,7 +377,13 @@ impl Runnable for ListenCmd {
 
 /// Listen to events
 #[instrument(skip_all, level = "error", fields(chain = %config.id))]
-pub fn listen(config: &ChainConfig, filters: &[EventFilter]) -> eyre::Result<()> {
+pub fn listen(
+    config: &ChainConfig,
+    filters: &ListenFilters,
+    output: &ListenOutput,
+) -> eyre::Result<()> {
+    let mut writer = output.writer()?;
+
     let rt = Arc::new(TokioRuntime::new()?);
     let compat_mode = detect_compatibility_mode(config, rt.clone())?;
     let rx = subscribe(config, compat_mode, rt)?;
@@ -117,7 +397,7 @@ pub fn listen(config: &ChainConfig, filters: &[EventFilter]) -> eyre::Result<()>
                 let matching_events = batch
                     .events
                     .iter()
-                    .filter(|e| event_match(&e.event, filters))
+                    .filter(|e| filters.matches(&e.event))
                     .collect_vec();
 
                 if matching_events.is_empty() {
@@ -125,18 +405,49 @@ pub fn listen(config: &ChainConfig, filters: &[EventFilter]) -> eyre::Result<()>
                 }
 
                 for event in matching_events {
-                    info!("{}", event);
+                    let event = strip_oversized_memo(&config.packet_memo, event);
+                    let event = event.as_ref();
+
+                    match writer.as_mut() {
+                        Some(writer) => {
+                            write_record(writer.as_mut(), &ListenRecord::event(&config.id, event))?
+                        }
+                        None => match transfer_ack(&event.event) {
+                            Some(TransferAcknowledgement::Success(_)) => {
+                                info!("{} (transfer succeeded)", event)
+                            }
+                            Some(TransferAcknowledgement::Error(e)) => {
+                                info!("{} (transfer failed: {})", event, e)
+                            }
+                            None => info!("{}", event),
+                        },
+                    }
                 }
             }
-            Err(e) => error!("- error: {}", e),
+            Err(e) => match writer.as_mut() {
+                Some(writer) => write_record(
+                    writer.as_mut(),
+                    &ListenRecord::Error {
+                        chain_id: &config.id,
+                        error: e.to_string(),
+                    },
+                )?,
+                None => error!("- error: {}", e),
+            },
         }
     }
 
     Ok(())
 }
 
-fn event_match(event: &IbcEvent, filters: &[EventFilter]) -> bool {
-    filters.iter().any(|f| f.matches(event))
+/// Write the given record as a single line of JSON, and flush the writer
+/// so that consumers see the event as soon as it is received.
+fn write_record(writer: &mut dyn Write, record: &ListenRecord<'_>) -> eyre::Result<()> {
+    serde_json::to_writer(&mut *writer, record)?;
+    writer.write_all(b"\n")?;
+    writer.flush()?;
+
+    Ok(())
 }
 
 fn subscribe(
@@ -164,7 +475,7 @@ fn subscribe(
     let queries = event_source.queries();
     info!("listening for queries: {}", queries.iter().format(", "),);
 
-    thread::spawn(|| event_source.run());
+    profile::spawn_thread(|| event_source.run());
 
     let subscription = tx_cmd.subscribe()?;
     Ok(subscription)
@@ -185,19 +496,25 @@ fn detect_compatibility_mode(
 
 #[cfg(test)]
 mod tests {
-    use super::{EventFilter, ListenCmd};
+    use super::{EventFilter, EventTypeFilter, ListenCmd};
 
-    use std::str::FromStr;
+    use std::{path::PathBuf, str::FromStr};
 
     use abscissa_core::clap::Parser;
-    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
     #[test]
     fn test_listen_required_only() {
         assert_eq!(
             ListenCmd {
                 chain_id: ChainId::from_string("chain_id"),
-                events: vec!()
+                events: vec!(),
+                event_types: vec!(),
+                ports: vec!(),
+                channels: vec!(),
+                ndjson: false,
+                output_file: None,
+                output_socket: None,
             },
             ListenCmd::parse_from(["test", "--chain", "chain_id"])
         )
@@ -208,7 +525,13 @@ mod tests {
         assert_eq!(
             ListenCmd {
                 chain_id: ChainId::from_string("chain_id"),
-                events: vec!(EventFilter::from_str("Tx").unwrap())
+                events: vec!(EventFilter::from_str("Tx").unwrap()),
+                event_types: vec!(),
+                ports: vec!(),
+                channels: vec!(),
+                ndjson: false,
+                output_file: None,
+                output_socket: None,
             },
             ListenCmd::parse_from(["test", "--chain", "chain_id", "--events", "Tx"])
         )
@@ -222,7 +545,13 @@ mod tests {
                 events: vec!(
                     EventFilter::from_str("Tx").unwrap(),
                     EventFilter::from_str("NewBlock").unwrap()
-                )
+                ),
+                event_types: vec!(),
+                ports: vec!(),
+                channels: vec!(),
+                ndjson: false,
+                output_file: None,
+                output_socket: None,
             },
             ListenCmd::parse_from([
                 "test", "--chain", "chain_id", "--events", "Tx", "--events", "NewBlock"
@@ -238,12 +567,91 @@ mod tests {
                 events: vec!(
                     EventFilter::from_str("Tx").unwrap(),
                     EventFilter::from_str("NewBlock").unwrap()
-                )
+                ),
+                event_types: vec!(),
+                ports: vec!(),
+                channels: vec!(),
+                ndjson: false,
+                output_file: None,
+                output_socket: None,
             },
             ListenCmd::parse_from(["test", "--chain", "chain_id", "--events", "Tx", "NewBlock"])
         )
     }
 
+    #[test]
+    fn test_listen_ndjson_filters() {
+        assert_eq!(
+            ListenCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                events: vec!(),
+                event_types: vec!(
+                    EventTypeFilter::from_str("send_packet").unwrap(),
+                    EventTypeFilter::from_str("write_acknowledgement").unwrap()
+                ),
+                ports: vec!(PortId::from_str("transfer").unwrap()),
+                channels: vec!(ChannelId::from_str("channel-1").unwrap()),
+                ndjson: true,
+                output_file: Some(PathBuf::from("events.ndjson")),
+                output_socket: None,
+            },
+            ListenCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--event-type",
+                "send_packet",
+                "write_acknowledgement",
+                "--port",
+                "transfer",
+                "--channel",
+                "channel-1",
+                "--ndjson",
+                "--output-file",
+                "events.ndjson"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_listen_output_requires_ndjson() {
+        assert!(ListenCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--output-file",
+            "events.ndjson"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_listen_output_file_and_socket() {
+        assert!(ListenCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--ndjson",
+            "--output-file",
+            "events.ndjson",
+            "--output-socket",
+            "events.sock"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_listen_unknown_event_type() {
+        assert!(ListenCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--event-type",
+            "not_an_event"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_listen_unknown_event_filter() {
         assert!(ListenCmd::try_parse_from([
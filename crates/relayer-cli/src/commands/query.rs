@@ -8,6 +8,7 @@ use crate::commands::query::channel_ends::QueryChannelEndsCmd;
 use crate::commands::query::channels::QueryChannelsCmd;
 use crate::commands::query::packet::QueryPacketCmds;
 
+pub(crate) mod cache;
 mod channel;
 mod channel_client;
 mod channel_ends;
@@ -16,6 +17,7 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod fees;
 mod packet;
 mod transfer;
 mod tx;
@@ -55,6 +57,10 @@ pub enum QueryCmd {
     /// Query information about token transfers
     #[clap(subcommand)]
     Transfer(transfer::TransferCmd),
+
+    /// Query information about the fees paid to relayers
+    #[clap(subcommand)]
+    Fees(fees::FeesCmd),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
@@ -0,0 +1,179 @@
+//! Caching of the queries issued by the commands iterating over many IBC objects,
+//! which look up the same connections, clients and chains over and over again.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{
+    IncludeProof, QueryClientStateRequest, QueryConnectionRequest, QueryHeight,
+};
+use ibc_relayer::client_state::AnyClientState;
+use ibc_relayer::registry::SharedRegistry;
+use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use ibc_relayer_types::Height;
+
+/// The maximum number of objects queried at the same time.
+///
+/// As the queries to a chain are handled one at a time by its runtime,
+/// this mostly speeds up the queries spread over many counterparty chains.
+pub const MAX_CONCURRENT_QUERIES: usize = 16;
+
+/// Caches the results of the queries issued during a single command invocation.
+///
+/// The objects are queried at the height of the chain when it was first queried,
+/// so that all the objects of a chain are seen at the same height, and can
+/// therefore be cached for the whole invocation.
+pub struct QueryCache<Chain: ChainHandle> {
+    registry: SharedRegistry<Chain>,
+    heights: Mutex<HashMap<ChainId, Height>>,
+    connections: Mutex<HashMap<(ChainId, ConnectionId), ConnectionEnd>>,
+    client_states: Mutex<HashMap<(ChainId, ClientId), AnyClientState>>,
+}
+
+impl<Chain: ChainHandle> QueryCache<Chain> {
+    pub fn new(registry: SharedRegistry<Chain>) -> Self {
+        Self {
+            registry,
+            heights: Mutex::new(HashMap::new()),
+            connections: Mutex::new(HashMap::new()),
+            client_states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The handle of the given chain, whose runtime is spawned on first use.
+    pub fn chain(&self, chain_id: &ChainId) -> eyre::Result<Chain> {
+        Ok(self.registry.get_or_spawn(chain_id)?)
+    }
+
+    /// The height at which the objects of the given chain are queried.
+    pub fn height(&self, chain: &Chain) -> eyre::Result<Height> {
+        get_or_query(&self.heights, chain.id(), || {
+            Ok(chain.query_latest_height()?)
+        })
+    }
+
+    pub fn query_connection(
+        &self,
+        chain: &Chain,
+        connection_id: &ConnectionId,
+    ) -> eyre::Result<ConnectionEnd> {
+        let height = self.height(chain)?;
+
+        get_or_query(
+            &self.connections,
+            (chain.id(), connection_id.clone()),
+            || {
+                let (connection_end, _) = chain.query_connection(
+                    QueryConnectionRequest {
+                        connection_id: connection_id.clone(),
+                        height: QueryHeight::Specific(height),
+                    },
+                    IncludeProof::No,
+                )?;
+
+                Ok(connection_end)
+            },
+        )
+    }
+
+    pub fn query_client_state(
+        &self,
+        chain: &Chain,
+        client_id: &ClientId,
+    ) -> eyre::Result<AnyClientState> {
+        let height = self.height(chain)?;
+
+        get_or_query(&self.client_states, (chain.id(), client_id.clone()), || {
+            let (client_state, _) = chain.query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::No,
+            )?;
+
+            Ok(client_state)
+        })
+    }
+}
+
+/// Looks up the cached value for the given key, or queries and caches it.
+///
+/// The lock is not held during the query, so that the queries for different
+/// keys run concurrently, at the cost of querying the same key more than once
+/// if it is first looked up concurrently.
+fn get_or_query<K, V>(
+    cache: &Mutex<HashMap<K, V>>,
+    key: K,
+    query: impl FnOnce() -> eyre::Result<V>,
+) -> eyre::Result<V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    if let Some(value) = cache.lock().unwrap().get(&key) {
+        return Ok(value.clone());
+    }
+
+    let value = query()?;
+
+    cache.lock().unwrap().insert(key, value.clone());
+
+    Ok(value)
+}
+
+/// Applies the given function to all the given items, running at most
+/// [`MAX_CONCURRENT_QUERIES`] of them at the same time, and returns
+/// the results in the order of the items.
+pub fn map_concurrently<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+    let workers = items.len().min(MAX_CONCURRENT_QUERIES);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                let result = f(item);
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::map_concurrently;
+
+    #[test]
+    fn map_concurrently_preserves_order() {
+        let items: Vec<u64> = (0..100).collect();
+
+        let results = map_concurrently(items.clone(), |item| item * 2);
+
+        assert_eq!(
+            results,
+            items.iter().map(|item| item * 2).collect::<Vec<_>>()
+        );
+        assert!(map_concurrently(Vec::<u64>::new(), |item| *item).is_empty());
+    }
+}
@@ -7,16 +7,17 @@ use serde::Serialize;
 use eyre::eyre;
 use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
 use ibc_relayer::chain::requests::{
-    IncludeProof, PageRequest, QueryChannelRequest, QueryChannelsRequest, QueryClientStateRequest,
-    QueryConnectionRequest, QueryHeight,
+    IncludeProof, PageRequest, QueryChannelRequest, QueryChannelsRequest, QueryHeight,
 };
-use ibc_relayer::registry::Registry;
+use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
-use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, State};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, State};
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ConnectionId, PortChannelId, PortId,
 };
+use ibc_relayer_types::Height;
 
+use crate::commands::query::cache::{map_concurrently, QueryCache};
 use crate::commands::query::channel_ends::ChannelEnds;
 use crate::conclude::Output;
 use crate::prelude::*;
@@ -62,149 +63,138 @@ fn run_query_channels<Chain: ChainHandle>(
     };
 
     let config = app_config();
-    let chain_id = cmd.chain_id.clone();
 
-    let mut registry = <Registry<Chain>>::new((*config).clone());
-    let chain = registry.get_or_spawn(&cmd.chain_id)?;
-    let chain_height = chain.query_latest_height()?;
+    let cache = QueryCache::new(<SharedRegistry<Chain>>::new((*config).clone()));
+    let chain = cache.chain(&cmd.chain_id)?;
+    let chain_height = cache.height(&chain)?;
 
     let identified_channels = chain.query_channels(QueryChannelsRequest {
         pagination: Some(PageRequest::all()),
     })?;
 
-    for identified_channel in identified_channels {
-        let port_id = identified_channel.port_id;
-        let channel_id = identified_channel.channel_id;
-        let chain_id = chain_id.clone();
-        let channel_end = identified_channel.channel_end;
+    // The channels are queried concurrently, while the connections and clients
+    // they share are only queried once
+    let channels = map_concurrently(identified_channels, |identified_channel| {
+        query_channel(cmd, &cache, &chain, chain_height, identified_channel)
+    });
+
+    for channel in channels {
+        if let Some(channel) = channel? {
+            output.push(channel);
+        }
+    }
+
+    Ok(output)
+}
+
+/// Query what is displayed about the given channel, if it is not filtered out.
+fn query_channel<Chain: ChainHandle>(
+    cmd: &QueryChannelsCmd,
+    cache: &QueryCache<Chain>,
+    chain: &Chain,
+    chain_height: Height,
+    identified_channel: &IdentifiedChannelEnd,
+) -> eyre::Result<Option<ChannelOutput>> {
+    let port_id = identified_channel.port_id.clone();
+    let channel_id = identified_channel.channel_id.clone();
+    let chain_id = cmd.chain_id.clone();
+    let channel_end = identified_channel.channel_end.clone();
 
-        if channel_end.state_matches(&State::Uninitialized) {
-            return Err(eyre!(
-                "{}/{} on chain {} @ {:?} is uninitialized",
+    if channel_end.state_matches(&State::Uninitialized) {
+        return Err(eyre!(
+            "{}/{} on chain {} @ {:?} is uninitialized",
+            port_id,
+            channel_id,
+            chain_id,
+            chain_height
+        ));
+    }
+
+    let connection_id = channel_end
+        .connection_hops
+        .first()
+        .ok_or_else(|| {
+            eyre!(
+                "missing connection_hops for {}/{} on chain {} @ {:?}",
                 port_id,
                 channel_id,
                 chain_id,
                 chain_height
-            ));
-        }
-
-        let connection_id = channel_end
-            .connection_hops
-            .first()
-            .ok_or_else(|| {
-                eyre!(
-                    "missing connection_hops for {}/{} on chain {} @ {:?}",
-                    port_id,
-                    channel_id,
-                    chain_id,
-                    chain_height
-                )
-            })?
-            .clone();
-
-        let mut counterparty_chain_id = None;
-
-        // If a counterparty chain is specified as a filter, check and skip the
-        // channel if required.
-        if cmd.show_counterparty || cmd.dst_chain_id.is_some() {
-            let (connection_end, _) = chain.query_connection(
-                QueryConnectionRequest {
-                    connection_id: connection_id.clone(),
-                    height: QueryHeight::Specific(chain_height),
-                },
-                IncludeProof::No,
-            )?;
-
-            let client_id = connection_end.client_id().clone();
-            let (client_state, _) = chain.query_client_state(
-                QueryClientStateRequest {
-                    client_id,
-                    height: QueryHeight::Specific(chain_height),
-                },
-                IncludeProof::No,
-            )?;
-            let cid = client_state.chain_id().clone();
-
-            if let Some(dst_chain_id) = &cmd.dst_chain_id {
-                if cid != *dst_chain_id {
-                    continue;
-                }
+            )
+        })?
+        .clone();
+
+    let mut counterparty_chain_id = None;
+
+    // If a counterparty chain is specified as a filter, check and skip the
+    // channel if required.
+    if cmd.show_counterparty || cmd.dst_chain_id.is_some() {
+        let connection_end = cache.query_connection(chain, &connection_id)?;
+        let client_state = cache.query_client_state(chain, connection_end.client_id())?;
+        let cid = client_state.chain_id();
+
+        if let Some(dst_chain_id) = &cmd.dst_chain_id {
+            if cid != *dst_chain_id {
+                return Ok(None);
             }
-
-            counterparty_chain_id = Some(cid);
         }
 
-        match output {
-            QueryChannelsOutput::Verbose(_) => {
-                match query_channel_ends(
-                    &mut registry,
-                    &chain,
-                    channel_end,
-                    connection_id,
-                    chain_id,
-                    port_id,
-                    channel_id,
-                    QueryHeight::Specific(chain_height),
-                ) {
-                    Ok(channel_ends) => output.push_verbose(channel_ends),
-                    Err(e) => error!("failed to query channel ends: {}", e),
-                }
-            }
-            QueryChannelsOutput::Pretty(_) => {
-                // Get counterparty channel_id and port_id
-                let counterparty_channel = channel_end.counterparty().clone();
-                let counterparty_channel_id = counterparty_channel.channel_id;
-                let counterparty_channel_port = counterparty_channel.port_id;
-
-                let pretty = PrettyOutput {
-                    channel_a: channel_id,
-                    port_a: port_id,
-                    chain_id_a: chain_id,
-                    channel_b: counterparty_channel_id,
-                    port_b: counterparty_channel_port,
-                    chain_id_b: counterparty_chain_id,
-                };
-
-                output.push_pretty(pretty)
-            }
-            QueryChannelsOutput::Summary(_) => {
-                output.push_summary(PortChannelId {
-                    channel_id,
-                    port_id,
-                });
+        counterparty_chain_id = Some(cid);
+    }
+
+    if cmd.verbose {
+        return match query_channel_ends(
+            cache,
+            chain,
+            channel_end,
+            connection_id,
+            chain_id,
+            port_id,
+            channel_id,
+        ) {
+            Ok(channel_ends) => Ok(Some(ChannelOutput::Verbose(Box::new(channel_ends)))),
+            Err(e) => {
+                error!("failed to query channel ends: {}", e);
+                Ok(None)
             }
-        }
+        };
     }
 
-    Ok(output)
+    if cmd.show_counterparty {
+        // Get counterparty channel_id and port_id
+        let counterparty_channel = channel_end.counterparty().clone();
+        let counterparty_channel_id = counterparty_channel.channel_id;
+        let counterparty_channel_port = counterparty_channel.port_id;
+
+        return Ok(Some(ChannelOutput::Pretty(PrettyOutput {
+            channel_a: channel_id,
+            port_a: port_id,
+            chain_id_a: chain_id,
+            channel_b: counterparty_channel_id,
+            port_b: counterparty_channel_port,
+            chain_id_b: counterparty_chain_id,
+        })));
+    }
+
+    Ok(Some(ChannelOutput::Summary(PortChannelId {
+        channel_id,
+        port_id,
+    })))
 }
 
-#[allow(clippy::too_many_arguments)]
 fn query_channel_ends<Chain: ChainHandle>(
-    registry: &mut Registry<Chain>,
+    cache: &QueryCache<Chain>,
     chain: &Chain,
     channel_end: ChannelEnd,
     connection_id: ConnectionId,
     chain_id: ChainId,
     port_id: PortId,
     channel_id: ChannelId,
-    chain_height_query: QueryHeight,
 ) -> eyre::Result<ChannelEnds> {
-    let (connection_end, _) = chain.query_connection(
-        QueryConnectionRequest {
-            connection_id: connection_id.clone(),
-            height: chain_height_query,
-        },
-        IncludeProof::No,
-    )?;
-    let client_id = connection_end.client_id().clone();
-    let (client_state, _) = chain.query_client_state(
-        QueryClientStateRequest {
-            client_id,
-            height: chain_height_query,
-        },
-        IncludeProof::No,
-    )?;
+    let chain_height = cache.height(chain)?;
+
+    let connection_end = cache.query_connection(chain, &connection_id)?;
+    let client_state = cache.query_client_state(chain, connection_end.client_id())?;
     let counterparty_chain_id = client_state.chain_id();
 
     let channel_counterparty = channel_end.counterparty().clone();
@@ -216,7 +206,7 @@ fn query_channel_ends<Chain: ChainHandle>(
             "connection end for {} on chain {} @ {:?} does not have counterparty connection id: {:?}",
             connection_id,
             chain_id,
-            chain_height_query,
+            chain_height,
             connection_end
         )
     })?;
@@ -227,35 +217,24 @@ fn query_channel_ends<Chain: ChainHandle>(
         channel_counterparty.channel_id.ok_or_else(|| {
             eyre!(
             "channel end for {}/{} on chain {} @ {:?} does not have counterparty channel id: {:?}",
-            port_id, channel_id, chain_id, chain_height_query, channel_end
+            port_id, channel_id, chain_id, chain_height, channel_end
         )
         })?;
 
-    let counterparty_chain = registry.get_or_spawn(&counterparty_chain_id)?;
-    let counterparty_chain_height_query =
-        QueryHeight::Specific(counterparty_chain.query_latest_height()?);
+    let counterparty_chain = cache.chain(&counterparty_chain_id)?;
+    let counterparty_chain_height = cache.height(&counterparty_chain)?;
 
-    let (counterparty_connection_end, _) = counterparty_chain.query_connection(
-        QueryConnectionRequest {
-            connection_id: counterparty_connection_id,
-            height: counterparty_chain_height_query,
-        },
-        IncludeProof::No,
-    )?;
+    let counterparty_connection_end =
+        cache.query_connection(&counterparty_chain, &counterparty_connection_id)?;
 
-    let (counterparty_client_state, _) = counterparty_chain.query_client_state(
-        QueryClientStateRequest {
-            client_id: counterparty_client_id,
-            height: counterparty_chain_height_query,
-        },
-        IncludeProof::No,
-    )?;
+    let counterparty_client_state =
+        cache.query_client_state(&counterparty_chain, &counterparty_client_id)?;
 
     let (counterparty_channel_end, _) = counterparty_chain.query_channel(
         QueryChannelRequest {
             port_id: counterparty_port_id,
             channel_id: counterparty_channel_id,
-            height: counterparty_chain_height_query,
+            height: QueryHeight::Specific(counterparty_chain_height),
         },
         IncludeProof::No,
     )?;
@@ -289,6 +268,13 @@ struct PrettyOutput {
     chain_id_b: Option<ChainId>,
 }
 
+/// What is displayed about a single channel.
+enum ChannelOutput {
+    Verbose(Box<ChannelEnds>),
+    Pretty(PrettyOutput),
+    Summary(PortChannelId),
+}
+
 #[derive(Serialize)]
 #[serde(untagged)]
 enum QueryChannelsOutput {
@@ -310,6 +296,14 @@ impl QueryChannelsOutput {
         Self::Pretty(Vec::new())
     }
 
+    fn push(&mut self, channel: ChannelOutput) {
+        match channel {
+            ChannelOutput::Verbose(ce) => self.push_verbose(*ce),
+            ChannelOutput::Pretty(pe) => self.push_pretty(pe),
+            ChannelOutput::Summary(pc) => self.push_summary(pc),
+        }
+    }
+
     fn push_pretty(&mut self, pe: PrettyOutput) {
         match self {
             Self::Pretty(pes) => pes.push(pe),
@@ -1,3 +1,5 @@
+use core::time::Duration;
+
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use color_eyre::eyre::eyre;
@@ -8,6 +10,7 @@ use ibc_relayer::chain::requests::{
     QueryClientStateRequest, QueryConsensusStateHeightsRequest, QueryConsensusStateRequest,
     QueryHeight, QueryTxRequest,
 };
+use ibc_relayer::client_state::AnyClientState;
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -295,13 +298,26 @@ impl Runnable for QueryClientStatusCmd {
     }
 }
 
-#[derive(Debug, serde::Serialize)]
-enum Status {
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub enum Status {
     Frozen,
     Expired,
     Active,
 }
 
+impl Status {
+    /// The status of a client whose latest consensus state was installed `elapsed` ago
+    pub fn of(client_state: &AnyClientState, elapsed: Duration) -> Self {
+        if client_state.is_frozen() {
+            Status::Frozen
+        } else if client_state.expired(elapsed) {
+            Status::Expired
+        } else {
+            Status::Active
+        }
+    }
+}
+
 fn client_status(
     chain: &impl ChainHandle,
     client_id: &ClientId,
@@ -350,11 +366,7 @@ fn client_status(
         .duration_since(&latest_consensus_state.timestamp())
         .unwrap_or_default();
 
-    if client_state.expired(elapsed) {
-        Ok(Status::Expired)
-    } else {
-        Ok(Status::Active)
-    }
+    Ok(Status::of(&client_state, elapsed))
 }
 
 /// Query client connections command
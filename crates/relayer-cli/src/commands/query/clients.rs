@@ -12,8 +12,11 @@ use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
+mod status;
+
 /// Query clients command
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct QueryAllClientsCmd {
     #[clap(
         long = "host-chain",
@@ -22,7 +25,7 @@ pub struct QueryAllClientsCmd {
         help_heading = "REQUIRED",
         help = "Identifier of the chain to query"
     )]
-    chain_id: ChainId,
+    chain_id: Option<ChainId>,
 
     #[clap(
         long = "reference-chain",
@@ -36,6 +39,15 @@ pub struct QueryAllClientsCmd {
         help = "Omit printing the reference (or target) chain for each client"
     )]
     omit_chain_ids: bool,
+
+    #[clap(subcommand)]
+    cmd: Option<QueryClientsCmds>,
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq, Runnable)]
+pub enum QueryClientsCmds {
+    /// Query the status of all the clients on a chain, or on all the configured chains
+    Status(status::QueryClientsStatusCmd),
 }
 
 #[derive(Debug, Serialize)]
@@ -48,10 +60,20 @@ struct ClientChain {
 /// hermes --config cfg.toml query clients --chain ibc-1
 impl Runnable for QueryAllClientsCmd {
     fn run(&self) {
+        if let Some(cmd) = &self.cmd {
+            cmd.run();
+            return;
+        }
+
+        // The host chain is only optional when a subcommand is given
+        let Some(chain_id) = &self.chain_id else {
+            Output::error("the '--host-chain <HOST_CHAIN_ID>' option is required").exit()
+        };
+
         let config = app_config();
 
-        let chain = spawn_chain_runtime(&config, &self.chain_id)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let chain =
+            spawn_chain_runtime(&config, chain_id).unwrap_or_else(exit_with_unrecoverable_error);
 
         let res: Result<_, Error> = chain
             .query_clients(QueryClientStatesRequest {
@@ -68,7 +90,7 @@ impl Runnable for QueryAllClientsCmd {
                                 // Omit chain identifiers
                                 debug!(
                                     "printing identifiers of all clients hosted on chain {}",
-                                    self.chain_id
+                                    chain_id
                                 );
                                 let out: Vec<ClientId> =
                                     clients.into_iter().map(|cs| cs.client_id).collect();
@@ -76,7 +98,7 @@ impl Runnable for QueryAllClientsCmd {
                             }
                             false => {
                                 // Include chain identifiers
-                                debug!("printing identifiers (and target chain identifiers) of all clients hosted on chain {}", self.chain_id);
+                                debug!("printing identifiers (and target chain identifiers) of all clients hosted on chain {}", chain_id);
                                 let out: Vec<ClientChain> = clients
                                     .into_iter()
                                     .map(|cs| ClientChain {
@@ -91,7 +113,7 @@ impl Runnable for QueryAllClientsCmd {
                     Some(source_chain_id) => {
                         debug!(
                             "printing identifiers of all clients hosted on chain {} which target chain {}",
-                            self.chain_id, source_chain_id
+                            chain_id, source_chain_id
                         );
                         // Filter and omit chain ids
                         let out: Vec<ClientId> = clients
@@ -119,9 +141,10 @@ mod tests {
     fn test_query_clients_required_only() {
         assert_eq!(
             QueryAllClientsCmd {
-                chain_id: ChainId::from_string("chain_host_id"),
+                chain_id: Some(ChainId::from_string("chain_host_id")),
                 src_chain_id: None,
-                omit_chain_ids: false
+                omit_chain_ids: false,
+                cmd: None,
             },
             QueryAllClientsCmd::parse_from(["test", "--host-chain", "chain_host_id"])
         )
@@ -131,9 +154,10 @@ mod tests {
     fn test_query_clients_omit_chain_ids() {
         assert_eq!(
             QueryAllClientsCmd {
-                chain_id: ChainId::from_string("chain_host_id"),
+                chain_id: Some(ChainId::from_string("chain_host_id")),
                 src_chain_id: None,
-                omit_chain_ids: true
+                omit_chain_ids: true,
+                cmd: None,
             },
             QueryAllClientsCmd::parse_from([
                 "test",
@@ -148,9 +172,10 @@ mod tests {
     fn test_query_clients_reference_chain() {
         assert_eq!(
             QueryAllClientsCmd {
-                chain_id: ChainId::from_string("chain_host_id"),
+                chain_id: Some(ChainId::from_string("chain_host_id")),
                 src_chain_id: Some(ChainId::from_string("reference_chain_id")),
-                omit_chain_ids: false
+                omit_chain_ids: false,
+                cmd: None,
             },
             QueryAllClientsCmd::parse_from([
                 "test",
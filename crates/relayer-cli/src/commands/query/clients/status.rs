@@ -0,0 +1,262 @@
+use core::fmt::Write;
+use core::time::Duration;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+
+use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
+use ibc_relayer::chain::requests::{
+    IncludeProof, PageRequest, QueryClientStatesRequest, QueryConsensusStateRequest, QueryHeight,
+};
+use ibc_relayer::client_state::{AnyClientState, IdentifiedAnyClientState};
+use ibc_relayer::registry::SharedRegistry;
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::application::app_config;
+use crate::commands::query::cache::{map_concurrently, QueryCache};
+use crate::commands::query::client::Status;
+use crate::conclude::{json, Output};
+
+/// The data structure that represents the arguments when invoking the `query clients status` CLI command.
+///
+/// The command has the following format:
+///
+/// `query clients status [--chain <CHAIN_ID>]`
+///
+/// If successful, the status of each client hosted on the chain, or on all the configured chains
+/// if none is given, is displayed, flagging the clients which need the attention of the operator.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryClientsStatusCmd {
+    #[clap(
+        long = "chain",
+        value_name = "CHAIN_ID",
+        help = "Identifier of the chain hosting the clients. Defaults to all the configured chains"
+    )]
+    chain_id: Option<ChainId>,
+}
+
+/// The status of a client, along with what is needed to assess whether it needs attention.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ClientStatus {
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+    pub counterparty_chain_id: ChainId,
+    pub status: Status,
+    pub latest_height: Height,
+    /// The timestamp of the latest consensus state of the client
+    pub last_update: Timestamp,
+    /// Whether the client is frozen or expired, or was not updated
+    /// within its refresh period and is therefore at risk of expiring
+    pub needs_attention: bool,
+}
+
+impl ClientStatus {
+    fn new(
+        chain_id: ChainId,
+        client: &IdentifiedAnyClientState,
+        last_update: Timestamp,
+        elapsed: Duration,
+    ) -> Self {
+        let status = Status::of(&client.client_state, elapsed);
+
+        Self {
+            chain_id,
+            client_id: client.client_id.clone(),
+            counterparty_chain_id: client.client_state.chain_id(),
+            status,
+            latest_height: client.client_state.latest_height(),
+            last_update,
+            needs_attention: needs_attention(&client.client_state, status, elapsed),
+        }
+    }
+}
+
+fn needs_attention(client_state: &AnyClientState, status: Status, elapsed: Duration) -> bool {
+    status != Status::Active
+        || client_state
+            .refresh_period()
+            .map_or(false, |refresh_period| elapsed > refresh_period)
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ClientsStatus {
+    pub clients: Vec<ClientStatus>,
+    /// The errors raised when querying the chains or the clients
+    pub errors: Vec<String>,
+}
+
+impl ClientsStatus {
+    fn summary(&self) -> String {
+        let mut out = String::new();
+
+        for client in &self.clients {
+            let _ = writeln!(
+                out,
+                "{} {} (counterparty {}): {:?}, latest height {}, last update {}{}",
+                client.chain_id,
+                client.client_id,
+                client.counterparty_chain_id,
+                client.status,
+                client.latest_height,
+                client.last_update,
+                if client.needs_attention {
+                    " [needs attention]"
+                } else {
+                    ""
+                }
+            );
+        }
+
+        for error in &self.errors {
+            let _ = writeln!(out, "error: {error}");
+        }
+
+        out
+    }
+}
+
+impl Runnable for QueryClientsStatusCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain_ids = match &self.chain_id {
+            Some(chain_id) => {
+                if config.find_chain(chain_id).is_none() {
+                    Output::error(format!(
+                        "chain '{chain_id}' not found in configuration file"
+                    ))
+                    .exit()
+                }
+
+                vec![chain_id.clone()]
+            }
+            None => config.chains.iter().map(|chain| chain.id.clone()).collect(),
+        };
+
+        let cache = QueryCache::new(<SharedRegistry<BaseChainHandle>>::new((*config).clone()));
+
+        let mut output = ClientsStatus::default();
+
+        for chain_id in chain_ids {
+            match query_clients_status(&cache, &chain_id) {
+                Ok(results) => {
+                    for result in results {
+                        match result {
+                            Ok(client) => output.clients.push(client),
+                            Err(e) => output.errors.push(e.to_string()),
+                        }
+                    }
+                }
+                Err(e) => output.errors.push(format!(
+                    "failed to query the clients on chain {chain_id}: {e}"
+                )),
+            }
+        }
+
+        if json() {
+            Output::success(output).exit()
+        } else {
+            Output::success_msg(output.summary()).exit()
+        }
+    }
+}
+
+fn query_clients_status<Chain: ChainHandle>(
+    cache: &QueryCache<Chain>,
+    chain_id: &ChainId,
+) -> eyre::Result<Vec<eyre::Result<ClientStatus>>> {
+    let chain = cache.chain(chain_id)?;
+    let height = cache.height(&chain)?;
+
+    // The network time, to assess how long ago each client was updated
+    let now = chain.query_application_status()?.timestamp;
+
+    let clients = chain.query_clients(QueryClientStatesRequest {
+        pagination: Some(PageRequest::all()),
+    })?;
+
+    Ok(map_concurrently(clients, |client| {
+        query_client_status(&chain, height, now, client).map_err(|e| {
+            eyre::eyre!(
+                "failed to query the status of client {} on chain {}: {}",
+                client.client_id,
+                chain_id,
+                e
+            )
+        })
+    }))
+}
+
+fn query_client_status<Chain: ChainHandle>(
+    chain: &Chain,
+    height: Height,
+    now: Timestamp,
+    client: &IdentifiedAnyClientState,
+) -> eyre::Result<ClientStatus> {
+    // The latest consensus state of a client is the one at its latest height
+    let (consensus_state, _) = chain.query_consensus_state(
+        QueryConsensusStateRequest {
+            client_id: client.client_id.clone(),
+            consensus_height: client.client_state.latest_height(),
+            query_height: QueryHeight::Specific(height),
+        },
+        IncludeProof::No,
+    )?;
+
+    let last_update = consensus_state.timestamp();
+    let elapsed = now.duration_since(&last_update).unwrap_or_default();
+
+    Ok(ClientStatus::new(chain.id(), client, last_update, elapsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+
+    #[test]
+    fn test_query_clients_status() {
+        assert_eq!(
+            QueryClientsStatusCmd { chain_id: None },
+            QueryClientsStatusCmd::parse_from(["test"])
+        );
+
+        assert_eq!(
+            QueryClientsStatusCmd {
+                chain_id: Some(ChainId::from_string("chain_id"))
+            },
+            QueryClientsStatusCmd::parse_from(["test", "--chain", "chain_id"])
+        );
+    }
+
+    #[test]
+    fn clients_needing_attention() {
+        let client_state: AnyClientState =
+            get_dummy_tendermint_client_state(get_dummy_tendermint_header()).into();
+
+        let refresh_period = client_state.refresh_period().unwrap();
+
+        assert!(!needs_attention(
+            &client_state,
+            Status::Active,
+            refresh_period / 2
+        ));
+        assert!(needs_attention(
+            &client_state,
+            Status::Active,
+            refresh_period * 2
+        ));
+        assert!(needs_attention(
+            &client_state,
+            Status::Frozen,
+            Duration::ZERO
+        ));
+    }
+}
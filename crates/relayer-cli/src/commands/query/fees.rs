@@ -0,0 +1,13 @@
+//! `query fees` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+mod pending;
+
+/// `query fees` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum FeesCmd {
+    /// Query the fees escrowed on the packets sent from a chain which have not been relayed yet
+    Pending(pending::QueryPendingFeesCmd),
+}
@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter, Write};
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+use tracing::warn;
+
+use ibc_relayer::chain::counterparty::channel_connection_client;
+use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
+use ibc_relayer::chain::requests::{PageRequest, QueryIncentivizedPacketsRequest};
+use ibc_relayer::config::Config;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
+use ibc_relayer_types::applications::transfer::coin::RawCoin;
+use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::cli_utils::{spawn_chain_runtime, spawn_chain_runtime_generic};
+use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `query fees pending` CLI command.
+///
+/// The command has the following format:
+///
+/// `query fees pending --chain <CHAIN_ID>`
+///
+/// If successful, the fees escrowed on the packets sent from the chain which have not been
+/// relayed yet are displayed, per channel and in total, for each denomination, along with
+/// the payees registered by the relayer for each channel. The channels for which the relayer
+/// registered no counterparty payee are flagged, as it is not paid their receive fees.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryPendingFeesCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain on which the fees are escrowed"
+    )]
+    chain_id: ChainId,
+}
+
+impl Runnable for QueryPendingFeesCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let relayer = chain
+            .get_signer()
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let packets = chain
+            .query_incentivized_packets(QueryIncentivizedPacketsRequest {
+                pagination: Some(PageRequest::all()),
+            })
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let mut pending_fees = PendingFees::new(relayer, &packets);
+
+        let mut counterparty_chains = BTreeMap::new();
+
+        for channel in &mut pending_fees.channels {
+            channel.payee = query_payee(&chain, &pending_fees.relayer, &channel.channel_id);
+            channel.counterparty_payee = query_counterparty_payee(
+                &config,
+                &chain,
+                &mut counterparty_chains,
+                &channel.port_id,
+                &channel.channel_id,
+            );
+        }
+
+        if json() {
+            Output::success(pending_fees).exit()
+        } else {
+            Output::success_msg(pending_fees.summary()).exit()
+        }
+    }
+}
+
+/// The payee registered by the relayer for a channel, if it could be queried.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegisteredPayee {
+    Registered(Signer),
+    NotRegistered,
+    Unknown,
+}
+
+impl Display for RegisteredPayee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Registered(payee) => write!(f, "{payee}"),
+            Self::NotRegistered => write!(f, "none"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl From<Option<Signer>> for RegisteredPayee {
+    fn from(payee: Option<Signer>) -> Self {
+        payee.map_or(Self::NotRegistered, Self::Registered)
+    }
+}
+
+/// The payee registered on the chain by the relayer for the acknowledgement
+/// and timeout fees of the channel, which are otherwise paid to the relayer.
+fn query_payee(
+    chain: &impl ChainHandle,
+    relayer: &Signer,
+    channel_id: &ChannelId,
+) -> RegisteredPayee {
+    chain
+        .query_payee(channel_id, relayer)
+        .map(RegisteredPayee::from)
+        .unwrap_or_else(|e| {
+            warn!("failed to query the payee of channel {channel_id}: {e}");
+            RegisteredPayee::Unknown
+        })
+}
+
+/// The counterparty payee registered on the counterparty chain by the relayer for the
+/// receive fees of the channel, which are not paid to the relayer if there is none.
+fn query_counterparty_payee(
+    config: &Config,
+    chain: &impl ChainHandle,
+    counterparty_chains: &mut BTreeMap<ChainId, BaseChainHandle>,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> RegisteredPayee {
+    let result = (|| -> Result<Option<Signer>, Error> {
+        let channel_connection_client =
+            channel_connection_client(chain, port_id, channel_id).map_err(Error::supervisor)?;
+
+        let counterparty_chain_id = channel_connection_client.client.client_state.chain_id();
+
+        let counterparty_channel_id = channel_connection_client
+            .channel
+            .channel_end
+            .counterparty()
+            .channel_id()
+            .cloned()
+            .ok_or_else(|| {
+                Error::missing_counterparty_channel_id(channel_connection_client.channel.clone())
+            })?;
+
+        let counterparty_chain = match counterparty_chains.get(&counterparty_chain_id) {
+            Some(counterparty_chain) => counterparty_chain.clone(),
+            None => {
+                let counterparty_chain =
+                    spawn_chain_runtime_generic::<BaseChainHandle>(config, &counterparty_chain_id)?;
+                counterparty_chains.insert(counterparty_chain_id, counterparty_chain.clone());
+                counterparty_chain
+            }
+        };
+
+        let counterparty_relayer = counterparty_chain.get_signer().map_err(Error::relayer)?;
+
+        counterparty_chain
+            .query_counterparty_payee(&counterparty_channel_id, &counterparty_relayer)
+            .map_err(Error::relayer)
+    })();
+
+    result.map(RegisteredPayee::from).unwrap_or_else(|e| {
+        warn!("failed to query the counterparty payee of channel {port_id}/{channel_id}: {e}");
+        RegisteredPayee::Unknown
+    })
+}
+
+/// The fees of each kind pending for a denomination.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DenomFees {
+    /// Paid to the relayer relaying the packets to the counterparty chain
+    pub recv_fee: Amount,
+    /// Paid to the relayer relaying the acknowledgements back to the chain
+    pub ack_fee: Amount,
+    /// Paid to the relayer relaying the timeouts back to the chain, instead of the two fees above
+    pub timeout_fee: Amount,
+}
+
+impl Default for DenomFees {
+    fn default() -> Self {
+        Self {
+            recv_fee: Amount::from(0u64),
+            ack_fee: Amount::from(0u64),
+            timeout_fee: Amount::from(0u64),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ChannelFees {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub packets: usize,
+    pub fees: BTreeMap<String, DenomFees>,
+    /// Paid the acknowledgement and timeout fees instead of the relayer, if registered
+    pub payee: RegisteredPayee,
+    /// Paid the receive fees, which are not paid to the relayer if none is registered
+    pub counterparty_payee: RegisteredPayee,
+}
+
+/// The fees a relayer can claim by relaying the pending packets sent from a chain.
+///
+/// The acknowledgement and timeout fees are paid to the address of the relayer on the chain,
+/// or to the payee it registered for the channel, while the receive fees are paid to the
+/// counterparty payee registered by the relayer on the counterparty chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PendingFees {
+    pub relayer: Signer,
+    pub channels: Vec<ChannelFees>,
+    pub total: BTreeMap<String, DenomFees>,
+}
+
+impl PendingFees {
+    pub fn new(relayer: Signer, packets: &[IdentifiedPacketFees]) -> Self {
+        let mut channels: BTreeMap<(PortId, ChannelId), ChannelFees> = BTreeMap::new();
+        let mut total = BTreeMap::new();
+
+        for packet in packets {
+            let packet_id = &packet.packet_id;
+
+            let channel = channels
+                .entry((packet_id.port_id.clone(), packet_id.channel_id.clone()))
+                .or_insert_with(|| ChannelFees {
+                    port_id: packet_id.port_id.clone(),
+                    channel_id: packet_id.channel_id.clone(),
+                    packets: 0,
+                    fees: BTreeMap::new(),
+                    payee: RegisteredPayee::Unknown,
+                    counterparty_payee: RegisteredPayee::Unknown,
+                });
+
+            channel.packets += 1;
+
+            for packet_fee in &packet.packet_fees {
+                let fee = &packet_fee.fee;
+
+                for fees in [&mut channel.fees, &mut total] {
+                    add_fees(fees, &fee.recv_fee, |fees| &mut fees.recv_fee);
+                    add_fees(fees, &fee.ack_fee, |fees| &mut fees.ack_fee);
+                    add_fees(fees, &fee.timeout_fee, |fees| &mut fees.timeout_fee);
+                }
+            }
+        }
+
+        Self {
+            relayer,
+            channels: channels.into_values().collect(),
+            total,
+        }
+    }
+
+    fn summary(&self) -> String {
+        fn write_fees(out: &mut String, fees: &BTreeMap<String, DenomFees>) {
+            for (denom, fees) in fees {
+                let _ = writeln!(
+                    out,
+                    "    {denom}: recv {}, ack {}, timeout {}",
+                    fees.recv_fee, fees.ack_fee, fees.timeout_fee
+                );
+            }
+        }
+
+        let mut out = format!("relayer: {}\n", self.relayer);
+
+        for channel in &self.channels {
+            let _ = writeln!(
+                out,
+                "{}/{}: {} pending packet(s), payee: {}, counterparty payee: {}",
+                channel.port_id,
+                channel.channel_id,
+                channel.packets,
+                channel.payee,
+                channel.counterparty_payee
+            );
+            if channel.counterparty_payee == RegisteredPayee::NotRegistered {
+                out.push_str("    no counterparty payee registered, the receive fees are not paid to the relayer\n");
+            }
+            write_fees(&mut out, &channel.fees);
+        }
+
+        out.push_str("total:\n");
+        write_fees(&mut out, &self.total);
+
+        out
+    }
+}
+
+fn add_fees(
+    fees: &mut BTreeMap<String, DenomFees>,
+    coins: &[RawCoin],
+    kind: impl Fn(&mut DenomFees) -> &mut Amount,
+) {
+    for coin in coins {
+        let amount = kind(fees.entry(coin.denom.clone()).or_default());
+        *amount = *amount + coin.amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::applications::ics29_fee::packet_fee::{Fee, PacketFee};
+    use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+    use ibc_relayer_types::core::ics04_channel::packet_id::PacketId;
+
+    #[test]
+    fn test_query_fees_pending() {
+        assert_eq!(
+            QueryPendingFeesCmd {
+                chain_id: ChainId::from_string("chain_id"),
+            },
+            QueryPendingFeesCmd::parse_from(["test", "--chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_query_fees_pending_no_chain() {
+        assert!(QueryPendingFeesCmd::try_parse_from(["test"]).is_err())
+    }
+
+    #[test]
+    fn aggregate_pending_fees() {
+        fn coins(amount: u64) -> Vec<RawCoin> {
+            vec![RawCoin::new("stake".to_string(), amount)]
+        }
+
+        fn packet(channel: u64, sequence: u64) -> IdentifiedPacketFees {
+            let packet_fee = PacketFee {
+                fee: Fee {
+                    recv_fee: coins(10),
+                    ack_fee: coins(5),
+                    timeout_fee: coins(3),
+                },
+                refund_address: Signer::from_str("refund").unwrap(),
+            };
+
+            IdentifiedPacketFees {
+                packet_id: PacketId {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::new(channel),
+                    sequence: Sequence::from(sequence),
+                },
+                packet_fees: vec![packet_fee.clone(), packet_fee],
+            }
+        }
+
+        let relayer = Signer::from_str("relayer").unwrap();
+        let fees = PendingFees::new(relayer, &[packet(0, 1), packet(0, 2), packet(1, 1)]);
+
+        assert_eq!(fees.channels.len(), 2);
+        assert_eq!(fees.channels[0].packets, 2);
+        assert_eq!(fees.channels[0].fees["stake"].recv_fee, Amount::from(40u64));
+        assert_eq!(fees.channels[1].fees["stake"].ack_fee, Amount::from(10u64));
+        assert_eq!(
+            fees.total["stake"],
+            DenomFees {
+                recv_fee: Amount::from(60u64),
+                ack_fee: Amount::from(30u64),
+                timeout_fee: Amount::from(18u64),
+            }
+        );
+    }
+
+    #[test]
+    fn flag_channels_without_counterparty_payee() {
+        let packet = IdentifiedPacketFees {
+            packet_id: PacketId {
+                port_id: PortId::transfer(),
+                channel_id: ChannelId::new(0),
+                sequence: Sequence::from(1),
+            },
+            packet_fees: vec![],
+        };
+
+        let relayer = Signer::from_str("relayer").unwrap();
+        let mut fees = PendingFees::new(relayer, &[packet]);
+
+        fees.channels[0].payee = RegisteredPayee::NotRegistered;
+        fees.channels[0].counterparty_payee =
+            RegisteredPayee::Registered(Signer::from_str("payee").unwrap());
+        assert!(!fees.summary().contains("no counterparty payee registered"));
+
+        fees.channels[0].counterparty_payee = RegisteredPayee::NotRegistered;
+        assert!(fees.summary().contains("no counterparty payee registered"));
+    }
+}
@@ -0,0 +1,299 @@
+use core::fmt::Write;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+
+use ibc_relayer::chain::counterparty::counterparty_chain_from_channel;
+use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
+use ibc_relayer::chain::requests::{PageRequest, QueryChannelsRequest};
+use ibc_relayer::config::Config;
+use ibc_relayer::link::cli::SimulatedMsgs;
+use ibc_relayer::link::{Link, LinkParameters};
+use ibc_relayer::registry::SharedRegistry;
+use ibc_relayer_types::core::ics04_channel::channel::{IdentifiedChannelEnd, State};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::application::app_config;
+use crate::commands::query::cache::map_concurrently;
+use crate::conclude::{json, Output};
+
+/// The data structure that represents the arguments when invoking the `simulate-paths` CLI command.
+///
+/// The command has the following format:
+///
+/// `simulate-paths [--chain <CHAIN_ID>]`
+///
+/// For each open channel allowed by the packet filter of the chains, the messages relaying
+/// a pending packet or acknowledgement, or else a client update, are built with their current
+/// proofs and simulated on the destination chain, without broadcasting anything.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct SimulatePathsCmd {
+    #[clap(
+        long = "chain",
+        value_name = "CHAIN_ID",
+        help = "Identifier of the source chain of the paths to simulate. Defaults to all the configured chains"
+    )]
+    chain_id: Option<ChainId>,
+}
+
+/// The outcome of the simulation of a transaction relaying messages on a path.
+#[derive(Debug, Serialize)]
+pub struct TxSimulation {
+    pub chain_id: ChainId,
+    pub msg_types: Vec<String>,
+    pub gas_used: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl From<SimulatedMsgs> for TxSimulation {
+    fn from(simulated: SimulatedMsgs) -> Self {
+        let (gas_used, error) = match simulated.result {
+            Ok(gas_used) => (gas_used, None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        Self {
+            chain_id: simulated.chain_id,
+            msg_types: simulated.msg_types,
+            gas_used,
+            error,
+        }
+    }
+}
+
+/// The outcome of the simulations on a path, from a channel on the source chain.
+#[derive(Debug, Serialize)]
+pub struct PathSimulation {
+    pub src_chain_id: ChainId,
+    pub src_port_id: PortId,
+    pub src_channel_id: ChannelId,
+    pub dst_chain_id: Option<ChainId>,
+    pub simulations: Vec<TxSimulation>,
+    /// The error raised when building the messages of the path, if any
+    pub error: Option<String>,
+}
+
+impl PathSimulation {
+    fn succeeded(&self) -> bool {
+        self.error.is_none() && self.simulations.iter().all(|tx| tx.error.is_none())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PathsSimulation {
+    pub paths: Vec<PathSimulation>,
+    /// The errors raised when querying the channels of the chains
+    pub errors: Vec<String>,
+}
+
+impl PathsSimulation {
+    fn succeeded(&self) -> bool {
+        self.errors.is_empty() && self.paths.iter().all(PathSimulation::succeeded)
+    }
+
+    fn summary(&self) -> String {
+        let mut out = String::new();
+
+        for path in &self.paths {
+            let dst_chain_id = path
+                .dst_chain_id
+                .as_ref()
+                .map_or_else(|| "?".to_owned(), ToString::to_string);
+
+            let _ = writeln!(
+                out,
+                "{}/{}/{} -> {}: {}",
+                path.src_chain_id,
+                path.src_port_id,
+                path.src_channel_id,
+                dst_chain_id,
+                if path.succeeded() { "ok" } else { "FAILED" }
+            );
+
+            if let Some(error) = &path.error {
+                let _ = writeln!(out, "    error: {error}");
+            }
+
+            if path.error.is_none() && path.simulations.is_empty() {
+                let _ = writeln!(out, "    nothing to simulate, the path is up to date");
+            }
+
+            for tx in &path.simulations {
+                let outcome = match (&tx.error, tx.gas_used) {
+                    (Some(error), _) => format!("error: {error}"),
+                    (None, Some(gas_used)) => format!("estimated gas {gas_used}"),
+                    (None, None) => "succeeded, no gas reported".to_owned(),
+                };
+
+                let _ = writeln!(
+                    out,
+                    "    on {} [{}]: {}",
+                    tx.chain_id,
+                    tx.msg_types.join(", "),
+                    outcome
+                );
+            }
+        }
+
+        for error in &self.errors {
+            let _ = writeln!(out, "error: {error}");
+        }
+
+        out
+    }
+}
+
+impl Runnable for SimulatePathsCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain_ids = match &self.chain_id {
+            Some(chain_id) => {
+                if config.find_chain(chain_id).is_none() {
+                    Output::error(format!(
+                        "chain '{chain_id}' not found in configuration file"
+                    ))
+                    .exit()
+                }
+
+                vec![chain_id.clone()]
+            }
+            None => config.chains.iter().map(|chain| chain.id.clone()).collect(),
+        };
+
+        let registry = <SharedRegistry<BaseChainHandle>>::new((*config).clone());
+
+        let mut output = PathsSimulation::default();
+
+        for chain_id in chain_ids {
+            match simulate_chain_paths(&config, &registry, &chain_id) {
+                Ok(paths) => output.paths.extend(paths),
+                Err(e) => output.errors.push(format!(
+                    "failed to query the channels on chain {chain_id}: {e}"
+                )),
+            }
+        }
+
+        let succeeded = output.succeeded();
+
+        let out = match (json(), succeeded) {
+            (true, true) => Output::success(output),
+            (true, false) => Output::with_error().with_result(output),
+            (false, true) => Output::success_msg(output.summary()),
+            (false, false) => Output::error(output.summary()),
+        };
+
+        out.exit()
+    }
+}
+
+fn simulate_chain_paths<Chain: ChainHandle>(
+    config: &Config,
+    registry: &SharedRegistry<Chain>,
+    chain_id: &ChainId,
+) -> eyre::Result<Vec<PathSimulation>> {
+    let chain_config = config
+        .find_chain(chain_id)
+        .ok_or_else(|| eyre::eyre!("chain '{chain_id}' not found in configuration file"))?;
+
+    let chain = registry.get_or_spawn(chain_id)?;
+
+    let channels: Vec<IdentifiedChannelEnd> = chain
+        .query_channels(QueryChannelsRequest {
+            pagination: Some(PageRequest::all()),
+        })?
+        .into_iter()
+        .filter(|channel| {
+            channel.channel_end.state_matches(&State::Open)
+                && chain_config
+                    .packet_filter
+                    .is_allowed(&channel.port_id, &channel.channel_id)
+        })
+        .collect();
+
+    Ok(map_concurrently(channels, |channel| {
+        simulate_path(config, registry, &chain, channel)
+    }))
+}
+
+fn simulate_path<Chain: ChainHandle>(
+    config: &Config,
+    registry: &SharedRegistry<Chain>,
+    chain: &Chain,
+    channel: &IdentifiedChannelEnd,
+) -> PathSimulation {
+    let mut path = PathSimulation {
+        src_chain_id: chain.id(),
+        src_port_id: channel.port_id.clone(),
+        src_channel_id: channel.channel_id.clone(),
+        dst_chain_id: None,
+        simulations: vec![],
+        error: None,
+    };
+
+    let dst_chain_id =
+        match counterparty_chain_from_channel(chain, &channel.channel_id, &channel.port_id) {
+            Ok(dst_chain_id) => dst_chain_id,
+            Err(e) => {
+                path.error = Some(e.to_string());
+                return path;
+            }
+        };
+
+    path.dst_chain_id = Some(dst_chain_id.clone());
+
+    match simulate_link(config, registry, chain, channel, &dst_chain_id) {
+        Ok(simulations) => path.simulations = simulations.into_iter().map(Into::into).collect(),
+        Err(e) => path.error = Some(e.to_string()),
+    }
+
+    path
+}
+
+fn simulate_link<Chain: ChainHandle>(
+    config: &Config,
+    registry: &SharedRegistry<Chain>,
+    chain: &Chain,
+    channel: &IdentifiedChannelEnd,
+    dst_chain_id: &ChainId,
+) -> eyre::Result<Vec<SimulatedMsgs>> {
+    if config.find_chain(dst_chain_id).is_none() {
+        return Err(eyre::eyre!(
+            "counterparty chain '{dst_chain_id}' not found in configuration file"
+        ));
+    }
+
+    let dst_chain = registry.get_or_spawn(dst_chain_id)?;
+
+    let opts = LinkParameters {
+        src_port_id: channel.port_id.clone(),
+        src_channel_id: channel.channel_id.clone(),
+    };
+
+    let link = Link::new_from_opts(chain.clone(), dst_chain, opts, false, false)?;
+
+    Ok(link.simulate_messages()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use abscissa_core::clap::Parser;
+
+    #[test]
+    fn test_simulate_paths() {
+        assert_eq!(
+            SimulatePathsCmd { chain_id: None },
+            SimulatePathsCmd::parse_from(["test"])
+        );
+
+        assert_eq!(
+            SimulatePathsCmd {
+                chain_id: Some(ChainId::from_string("chain_id"))
+            },
+            SimulatePathsCmd::parse_from(["test", "--chain", "chain_id"])
+        );
+    }
+}
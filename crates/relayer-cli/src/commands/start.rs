@@ -8,10 +8,14 @@ use abscissa_core::{Command, Runnable};
 use crossbeam_channel::Sender;
 
 use ibc_relayer::chain::handle::{CachingChainHandle, ChainHandle};
+use ibc_relayer::config::profile::ProfileConfig;
 use ibc_relayer::config::Config;
+use ibc_relayer::event_sink::EventSink;
+use ibc_relayer::hooks::Hooks;
 use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer::rest;
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, spawn_supervisor, SupervisorHandle};
+use ibc_relayer::util::profile::{self, Profile};
 
 use crate::conclude::json;
 use crate::conclude::Output;
@@ -56,21 +60,58 @@ impl Runnable for StartCmd {
 
         let config = (*app_config()).clone();
 
-        let options = SupervisorOptions {
+        let options = |profile| SupervisorOptions {
             force_full_scan: self.full_scan,
             health_check: true,
+            profile,
         };
 
-        let supervisor_handle = make_supervisor::<CachingChainHandle>(config, options)
-            .unwrap_or_else(|e| {
-                Output::error(format!("Hermes failed to start, last error: {e}")).exit()
-            });
+        let profiles = config.profiles.clone();
+
+        // The supervisor of the main config is only needed if it has chains of its own,
+        // while its telemetry server is shared with all the profiles, along with its
+        // event sink and hooks for the profiles which do not have their own
+        let relay_main_config = !config.chains.is_empty() || profiles.is_empty();
+
+        let mut supervisor_handles = Vec::with_capacity(profiles.len() + 1);
+
+        spawn_telemetry_server(&config);
+
+        let main = Profile::default()
+            .with_event_sink(spawn_event_sink(&config))
+            .with_hooks(spawn_hooks(&config));
+
+        if relay_main_config {
+            let supervisor_handle =
+                make_supervisor::<CachingChainHandle>(config, options(main.clone()))
+                    .unwrap_or_else(|e| {
+                        Output::error(format!("Hermes failed to start, last error: {e}")).exit()
+                    });
+
+            supervisor_handles.push(supervisor_handle);
+        }
+
+        for profile in &profiles {
+            let supervisor_handle =
+                make_profile_supervisor::<CachingChainHandle>(profile, options(main.clone()))
+                    .unwrap_or_else(|e| {
+                        Output::error(format!(
+                            "Hermes failed to start profile '{}', last error: {e}",
+                            profile.name
+                        ))
+                        .exit()
+                    });
+
+            supervisor_handles.push(supervisor_handle);
+        }
 
         match crate::config::config_path() {
             Some(_) => {
-                register_signals(supervisor_handle.sender.clone()).unwrap_or_else(|e| {
-                    warn!("failed to install signal handler: {}", e);
-                });
+                for supervisor_handle in &supervisor_handles {
+                    register_signals(supervisor_handle.sender.clone()).unwrap_or_else(|e| {
+                        warn!("failed to install signal handler: {}", e);
+                    });
+                }
             }
             None => {
                 warn!("cannot figure out configuration path, skipping registration of signal handlers");
@@ -79,7 +120,9 @@ impl Runnable for StartCmd {
 
         info!("Hermes has started");
 
-        supervisor_handle.wait();
+        for supervisor_handle in supervisor_handles {
+            supervisor_handle.wait();
+        }
     }
 }
 
@@ -96,7 +139,7 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
 
     let mut signals = Signals::new(sigs)?;
 
-    std::thread::spawn(move || {
+    profile::spawn_thread(move || {
         for signal in &mut signals {
             match signal {
                 SIGHUP => warn!(
@@ -109,7 +152,7 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
                     let (tx, rx) = crossbeam_channel::bounded(1);
                     tx_cmd.try_send(SupervisorCmd::DumpState(tx)).unwrap();
 
-                    std::thread::spawn(move || {
+                    profile::spawn_thread(move || {
                         if let Ok(state) = rx.recv() {
                             if json() {
                                 match serde_json::to_string(&state) {
@@ -232,16 +275,71 @@ fn spawn_telemetry_server(config: &Config) {
     }
 }
 
+fn spawn_event_sink(config: &Config) -> Option<EventSink> {
+    let _span = tracing::error_span!("event_sink").entered();
+
+    let event_sink = config.event_sink.clone();
+
+    if !event_sink.enabled {
+        info!("event sink disabled");
+        return None;
+    }
+
+    ibc_relayer::event_sink::spawn(event_sink)
+        .map_err(|e| error!("event sink failed to start: {e}"))
+        .ok()
+}
+
+fn spawn_hooks(config: &Config) -> Option<Hooks> {
+    let _span = tracing::error_span!("hooks").entered();
+
+    let hooks = config.hooks.clone();
+
+    if !hooks.enabled {
+        info!("hooks disabled");
+        return None;
+    }
+
+    Some(ibc_relayer::hooks::spawn(hooks))
+}
+
 fn make_supervisor<Chain: ChainHandle>(
     config: Config,
     options: SupervisorOptions,
 ) -> Result<SupervisorHandle, Box<dyn Error + Send + Sync>> {
     let registry = SharedRegistry::<Chain>::new(config.clone());
 
-    spawn_telemetry_server(&config);
+    let rest_rx = spawn_rest_server(&config);
+
+    Ok(spawn_supervisor(config, registry, rest_rx, options)?)
+}
+
+/// Spawns the supervisor of the given profile, with its own chain runtimes, REST server,
+/// event sink, hooks and channel leases, relying on the telemetry server of the main config.
+///
+/// The profile of the given options holds the services of the main config, which are
+/// used in place of the event sink and hooks the profile does not set up itself.
+fn make_profile_supervisor<Chain: ChainHandle>(
+    profile: &ProfileConfig,
+    mut options: SupervisorOptions,
+) -> Result<SupervisorHandle, Box<dyn Error + Send + Sync>> {
+    // The tasks of the supervisor, and the workers they spawn, are labeled with the profile
+    let _span = tracing::error_span!("profile", name = %profile.name).entered();
+
+    let config = profile.load()?;
+
+    let main = options.profile;
+
+    options.profile = Profile::named(&profile.name)
+        .with_event_sink(spawn_event_sink(&config).or_else(|| main.event_sink().cloned()))
+        .with_hooks(spawn_hooks(&config).or_else(|| main.hooks().cloned()));
 
     let rest_rx = spawn_rest_server(&config);
 
+    let registry = SharedRegistry::<Chain>::new(config.clone());
+
+    info!("starting the supervisor of the profile");
+
     Ok(spawn_supervisor(config, registry, rest_rx, options)?)
 }
 
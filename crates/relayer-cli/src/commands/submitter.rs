@@ -0,0 +1,111 @@
+use alloc::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer::submitter::{read_identity, read_token, SubmitterServer};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::prelude::*;
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct SubmitterCmd {
+    #[clap(
+        long = "host",
+        value_name = "HOST",
+        default_value = "127.0.0.1",
+        help = "Address on which to serve the submitter"
+    )]
+    host: IpAddr,
+
+    #[clap(
+        long = "port",
+        value_name = "PORT",
+        default_value = "7071",
+        help = "Port on which to serve the submitter"
+    )]
+    port: u16,
+
+    #[clap(
+        long = "token-file",
+        required = true,
+        value_name = "TOKEN_FILE",
+        help_heading = "REQUIRED",
+        help = "File holding the token shared with the relayers, which they must present to submit messages"
+    )]
+    token_file: PathBuf,
+
+    #[clap(
+        long = "tls-cert",
+        required = true,
+        value_name = "TLS_CERT",
+        help_heading = "REQUIRED",
+        help = "PEM file holding the TLS certificate served to the relayers, so that the token is not sent in the clear"
+    )]
+    tls_cert: PathBuf,
+
+    #[clap(
+        long = "tls-key",
+        required = true,
+        value_name = "TLS_KEY",
+        help_heading = "REQUIRED",
+        help = "PEM file holding the private key of the TLS certificate"
+    )]
+    tls_key: PathBuf,
+
+    #[clap(
+        long = "chain",
+        value_name = "CHAIN_ID",
+        help = "Identifier of a chain to sign and submit the messages to, can be repeated (default: all the chains in the config)"
+    )]
+    chain_ids: Vec<ChainId>,
+}
+
+impl Runnable for SubmitterCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let token = read_token(&self.token_file).unwrap_or_else(exit_with_unrecoverable_error);
+        let identity = read_identity(&self.tls_cert, &self.tls_key)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let mut chains = BTreeMap::new();
+
+        for chain_config in &config.chains {
+            if !self.chain_ids.is_empty() && !self.chain_ids.contains(&chain_config.id) {
+                continue;
+            }
+
+            if chain_config.submitter.is_some() {
+                warn!(
+                    "skipping chain {} which is configured to use a remote submitter itself",
+                    chain_config.id
+                );
+
+                continue;
+            }
+
+            let chain = spawn_chain_runtime(&config, &chain_config.id)
+                .unwrap_or_else(exit_with_unrecoverable_error);
+
+            chains.insert(chain_config.id.clone(), chain);
+        }
+
+        if chains.is_empty() {
+            Output::error("no chain to sign and submit the messages to").exit();
+        }
+
+        let addr = SocketAddr::new(self.host, self.port);
+        let rt = TokioRuntime::new().unwrap_or_else(exit_with_unrecoverable_error);
+
+        match rt.block_on(SubmitterServer::new(chains, token).serve(addr, identity)) {
+            Ok(()) => Output::success_msg("submitter stopped").exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
@@ -8,6 +8,7 @@ pub(crate) mod client;
 mod connection;
 mod packet;
 mod transfer;
+mod unwind;
 mod upgrade;
 
 /// `tx` subcommand
@@ -47,14 +48,23 @@ pub enum TxCmd {
     /// Send a fungible token transfer test transaction (ICS20 MsgTransfer)
     FtTransfer(transfer::TxIcs20MsgTransferCmd),
 
+    /// Send an IBC voucher back to its origin chain, one hop at a time
+    FtUnwind(unwind::TxIcs20UnwindCmd),
+
     /// Relay receive or timeout packets
     PacketRecv(packet::TxPacketRecvCmd),
 
     /// Relay acknowledgment packets
     PacketAck(packet::TxPacketAckCmd),
 
+    /// Relay a single packet, or its acknowledgment if it was already received
+    RelayPacket(packet::TxRelayPacketCmd),
+
     /// Send an IBC upgrade plan
     UpgradeChain(upgrade::TxIbcUpgradeChainCmd),
+
+    /// Submit an IBC upgrade proposal, track its vote and upgrade the clients once it passes
+    UpgradeProposal(upgrade::TxUpgradeProposalCmd),
 }
 
 impl Override<Config> for TxCmd {
@@ -458,6 +458,18 @@ impl Runnable for TxUpgradeClientsCmd {
 }
 
 impl TxUpgradeClientsCmd {
+    pub(crate) fn new(
+        reference_chain_id: ChainId,
+        reference_upgrade_height: u64,
+        host_chain_id: Option<ChainId>,
+    ) -> Self {
+        Self {
+            reference_chain_id,
+            reference_upgrade_height,
+            host_chain_id,
+        }
+    }
+
     fn upgrade_clients_for_chain<Chain: ChainHandle>(
         &self,
         config: &Config,
@@ -1,14 +1,19 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
+use eyre::eyre;
 use ibc_relayer_types::core::ics02_client::height::Height;
 
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::link::{Link, LinkParameters};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::cli_utils::ChainHandlePair;
-use crate::conclude::Output;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
@@ -172,13 +177,150 @@ impl Runnable for TxPacketAckCmd {
     }
 }
 
+/// The data structure that represents the arguments when invoking the `tx relay-packet` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx relay-packet --dst-chain <DST_CHAIN_ID> --src-chain <SRC_CHAIN_ID> --src-port <SRC_PORT_ID>
+///  --src-channel <SRC_CHANNEL_ID> (--sequence <SEQUENCE> | --packet-file <PACKET_FILE>)`
+///
+/// Relays a single packet sent from the source chain, by submitting a receive or timeout message
+/// for it, or the acknowledgement written on the destination chain if it was already received,
+/// without clearing the other pending packets of the channel.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxRelayPacketCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel"
+    )]
+    src_channel_id: ChannelId,
+
+    #[clap(
+        long = "sequence",
+        visible_alias = "seq",
+        required = true,
+        value_name = "SEQUENCE",
+        help_heading = "PACKET",
+        help = "Sequence of the packet, whose data is queried from the events of the source chain",
+        group = "packet"
+    )]
+    sequence: Option<Sequence>,
+
+    #[clap(
+        long = "packet-file",
+        required = true,
+        value_name = "PACKET_FILE",
+        help_heading = "PACKET",
+        help = "Path to a JSON file describing the packet, as output by the `--json` queries, \
+                for the packets whose events can no longer be queried",
+        group = "packet"
+    )]
+    packet_file: Option<PathBuf>,
+}
+
+impl Runnable for TxRelayPacketCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let packet = self
+            .packet_file
+            .as_deref()
+            .map(read_packet)
+            .transpose()
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let opts = LinkParameters {
+            src_port_id: self.src_port_id.clone(),
+            src_channel_id: self.src_channel_id.clone(),
+        };
+        let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
+            Ok(link) => link,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let (sequence, res) = match (packet, self.sequence) {
+            (Some(packet), _) => (packet.sequence, link.relay_given_packet(packet)),
+            (None, Some(sequence)) => (sequence, link.relay_packet(sequence)),
+            (None, None) => unreachable!("clap requires either a sequence or a packet file"),
+        };
+
+        let mut events = res
+            .map_err(Error::link)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        // Nothing to relay to the destination chain, the packet was already received:
+        // relay its acknowledgement back to the source chain, if it was not already
+        if events.is_empty() {
+            let res: Result<Vec<IbcEvent>, Error> = link
+                .reverse(false, false)
+                .and_then(|reverse_link| reverse_link.relay_packet_ack(sequence))
+                .map_err(Error::link);
+
+            events = res.unwrap_or_else(exit_with_unrecoverable_error);
+        }
+
+        Output::success(events).exit()
+    }
+}
+
+fn read_packet(path: &Path) -> eyre::Result<Packet> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| eyre!("failed to read the packet file '{}': {}", path.display(), e))?;
+
+    serde_json::from_str(&content).map_err(|e| {
+        eyre!(
+            "failed to parse the packet file '{}': {}",
+            path.display(),
+            e
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{TxPacketAckCmd, TxPacketRecvCmd};
+    use super::{TxPacketAckCmd, TxPacketRecvCmd, TxRelayPacketCmd};
 
+    use std::path::PathBuf;
     use std::str::FromStr;
 
     use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics04_channel::packet::Sequence;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
     #[test]
@@ -413,4 +555,94 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_relay_packet_sequence() {
+        assert_eq!(
+            TxRelayPacketCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                sequence: Some(Sequence::from(42)),
+                packet_file: None,
+            },
+            TxRelayPacketCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--sequence",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_relay_packet_file() {
+        assert_eq!(
+            TxRelayPacketCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                sequence: None,
+                packet_file: Some(PathBuf::from("packet.json")),
+            },
+            TxRelayPacketCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-chan",
+                "channel_sender",
+                "--packet-file",
+                "packet.json"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_relay_packet_no_packet() {
+        assert!(TxRelayPacketCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_relay_packet_sequence_and_file() {
+        assert!(TxRelayPacketCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender",
+            "--sequence",
+            "42",
+            "--packet-file",
+            "packet.json"
+        ])
+        .is_err())
+    }
 }
@@ -0,0 +1,141 @@
+use core::time::Duration;
+use std::sync::Arc;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer::chain::handle::BaseChainHandle;
+use ibc_relayer::spawn::spawn_chain_runtime;
+use ibc_relayer::unwind::{resolve_denom, UnwindRoute};
+use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::cli_utils::spawn_chain_runtime_generic;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::prelude::*;
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcs20UnwindCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain holding the voucher"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "denom",
+        required = true,
+        value_name = "DENOM",
+        help_heading = "REQUIRED",
+        help = "Denomination of the voucher, either hashed (e.g. `ibc/27394F...`) or with its full trace (e.g. `transfer/channel-0/uatom`)"
+    )]
+    denom: String,
+
+    #[clap(
+        long = "amount",
+        required = true,
+        value_name = "AMOUNT",
+        help_heading = "REQUIRED",
+        help = "Amount of the voucher to send back to its origin chain"
+    )]
+    amount: Amount,
+
+    #[clap(
+        long = "receiver",
+        value_name = "RECEIVER",
+        help = "The account address on the origin chain which will receive the tokens. If omitted, the relayer's wallet on the origin chain will be used"
+    )]
+    receiver: Option<String>,
+
+    #[clap(
+        long = "timeout-seconds",
+        default_value = "600",
+        value_name = "TIMEOUT_SECONDS",
+        help = "Timeout of each hop, in seconds"
+    )]
+    timeout_seconds: u64,
+
+    #[clap(
+        long = "dry-run",
+        help = "Only compute and print the route, without sending any transfer"
+    )]
+    dry_run: bool,
+}
+
+impl Runnable for TxIcs20UnwindCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime_generic::<BaseChainHandle>(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let denom =
+            resolve_denom(&chain, &self.denom).unwrap_or_else(exit_with_unrecoverable_error);
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let route = UnwindRoute::compute(chain, &denom, |chain_id| {
+            spawn_chain_runtime(&config, chain_id, rt.clone())
+        })
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        if self.dry_run {
+            Output::success(route.hops.clone()).exit();
+        }
+
+        info!(
+            "unwinding {} {} from chain {} in {} hop(s), the packets must be relayed by a running Hermes instance",
+            self.amount,
+            denom,
+            self.chain_id,
+            route.hops.len()
+        );
+
+        match route.execute(
+            self.amount,
+            self.receiver.clone(),
+            Duration::from_secs(self.timeout_seconds),
+        ) {
+            Ok(()) => Output::success(route.hops.clone()).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TxIcs20UnwindCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::{
+        applications::transfer::Amount, core::ics24_host::identifier::ChainId,
+    };
+
+    #[test]
+    fn test_ft_unwind() {
+        assert_eq!(
+            TxIcs20UnwindCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                denom: "transfer/channel-0/uatom".to_owned(),
+                amount: Amount::from(42u64),
+                receiver: None,
+                timeout_seconds: 600,
+                dry_run: true,
+            },
+            TxIcs20UnwindCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--denom",
+                "transfer/channel-0/uatom",
+                "--amount",
+                "42",
+                "--dry-run"
+            ])
+        )
+    }
+}
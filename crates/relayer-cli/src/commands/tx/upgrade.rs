@@ -1,16 +1,28 @@
 use core::time::Duration;
+use std::thread;
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
-use ibc_relayer::upgrade_chain::{build_and_send_ibc_upgrade_proposal, UpgradePlanOptions};
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::upgrade_chain::{
+    build_and_send_ibc_upgrade_proposal, find_ibc_upgrade_proposal, query_ibc_upgrade_proposal,
+    UpgradeChainError, UpgradePlanOptions, UpgradeProposalState, UpgradeProposalStatus,
+};
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 
+use super::client::TxUpgradeClientsCmd;
 use crate::cli_utils::spawn_chain_runtime;
 use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
 
+/// How often to query the status of a submitted upgrade proposal
+const PROPOSAL_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How many times to look for a submitted upgrade proposal before giving up
+const PROPOSAL_LOOKUP_ATTEMPTS: usize = 30;
+
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 pub struct TxIbcUpgradeChainCmd {
     #[clap(
@@ -134,9 +146,126 @@ impl Runnable for TxIbcUpgradeChainCmd {
     }
 }
 
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxUpgradeProposalCmd {
+    #[clap(flatten)]
+    plan: TxIbcUpgradeChainCmd,
+
+    #[clap(
+        long = "upgrade-clients",
+        help = "Once the proposal passes, wait for the upgrade height and upgrade the clients of the upgraded chain on all the other chains"
+    )]
+    upgrade_clients: bool,
+}
+
+impl Runnable for TxUpgradeProposalCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let opts = match self.plan.validate_options() {
+            Err(err) => Output::error(err).exit(),
+            Ok(result) => result,
+        };
+
+        let host_chain = spawn_chain_runtime(&config, &self.plan.host_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let reference_chain = spawn_chain_runtime(&config, &self.plan.reference_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        // Remember the latest proposal with the same plan name, if any,
+        // to tell it apart from the proposal submitted below
+        let previous = find_ibc_upgrade_proposal(&reference_chain, &opts.upgrade_plan_name)
+            .map_err(Error::upgrade_chain)
+            .unwrap_or_else(exit_with_unrecoverable_error)
+            .map(|proposal| proposal.proposal_id);
+
+        let tx_hash =
+            build_and_send_ibc_upgrade_proposal(reference_chain.clone(), host_chain, &opts)
+                .map_err(Error::upgrade_chain)
+                .unwrap_or_else(exit_with_unrecoverable_error);
+
+        info!("submitted upgrade proposal in transaction {tx_hash}");
+
+        let mut proposal = wait_for_proposal(&reference_chain, &opts.upgrade_plan_name, previous)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        info!(
+            "upgrade proposal {} is in status {:?}",
+            proposal.proposal_id, proposal.status
+        );
+
+        while !proposal.status.is_final() {
+            thread::sleep(PROPOSAL_POLL_INTERVAL);
+
+            let status = proposal.status;
+
+            proposal = query_ibc_upgrade_proposal(&reference_chain, proposal.proposal_id)
+                .map_err(Error::upgrade_chain)
+                .unwrap_or_else(exit_with_unrecoverable_error);
+
+            if proposal.status != status {
+                info!(
+                    "upgrade proposal {} is now in status {:?}",
+                    proposal.proposal_id, proposal.status
+                );
+            }
+        }
+
+        if proposal.status != UpgradeProposalStatus::Passed {
+            Output::error(format!(
+                "upgrade proposal {} did not pass: {:?}",
+                proposal.proposal_id, proposal.status
+            ))
+            .exit()
+        }
+
+        if self.upgrade_clients {
+            TxUpgradeClientsCmd::new(
+                self.plan.reference_chain_id.clone(),
+                proposal.plan_height,
+                None,
+            )
+            .run();
+        } else {
+            Output::success_msg(format!(
+                "upgrade proposal {} passed, once chain {} halts at height {} upgrade its clients with \
+                `hermes upgrade clients --reference-chain {} --upgrade-height {}`",
+                proposal.proposal_id,
+                self.plan.reference_chain_id,
+                proposal.plan_height,
+                self.plan.reference_chain_id,
+                proposal.plan_height,
+            ))
+            .exit()
+        }
+    }
+}
+
+/// Waits for the upgrade proposal submitted with the given plan name to show up on the chain.
+fn wait_for_proposal(
+    chain: &impl ChainHandle,
+    plan_name: &str,
+    previous: Option<u64>,
+) -> Result<UpgradeProposalState, Error> {
+    for _ in 0..PROPOSAL_LOOKUP_ATTEMPTS {
+        let proposal = find_ibc_upgrade_proposal(chain, plan_name).map_err(Error::upgrade_chain)?;
+
+        match proposal {
+            Some(proposal) if Some(proposal.proposal_id) != previous => return Ok(proposal),
+            _ => thread::sleep(Duration::from_secs(2)),
+        }
+    }
+
+    Err(Error::upgrade_chain(UpgradeChainError::plan_not_found(
+        chain.id(),
+        plan_name.to_owned(),
+    )))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TxIbcUpgradeChainCmd;
+    use super::{TxIbcUpgradeChainCmd, TxUpgradeProposalCmd};
 
     use abscissa_core::clap::Parser;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
@@ -379,4 +508,38 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_upgrade_proposal_upgrade_clients() {
+        assert_eq!(
+            TxUpgradeProposalCmd {
+                plan: TxIbcUpgradeChainCmd {
+                    reference_chain_id: ChainId::from_string("chain_receiver"),
+                    host_chain_id: ChainId::from_string("chain_sender"),
+                    host_client_id: ClientId::from_str("client_sender").unwrap(),
+                    amount: 42,
+                    height_offset: 21,
+                    new_chain_id: None,
+                    new_unbonding: None,
+                    upgrade_name: None,
+                    denom: None
+                },
+                upgrade_clients: true,
+            },
+            TxUpgradeProposalCmd::parse_from([
+                "test",
+                "--reference-chain",
+                "chain_receiver",
+                "--host-chain",
+                "chain_sender",
+                "--host-client",
+                "client_sender",
+                "--amount",
+                "42",
+                "--height-offset",
+                "21",
+                "--upgrade-clients"
+            ])
+        )
+    }
 }
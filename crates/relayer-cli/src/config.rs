@@ -8,7 +8,12 @@ use alloc::collections::BTreeSet;
 use std::path::PathBuf;
 
 use flex_error::{define_error, TraceError};
-use ibc_relayer::config::{ChainConfig, Config, ModeConfig};
+use ibc_relayer::config::adaptive_batch::AdaptiveBatchConfig;
+use ibc_relayer::config::coordination::CoordinationConfig;
+use ibc_relayer::config::event_sink::EventSinkConfig;
+use ibc_relayer::config::hooks::HooksConfig;
+use ibc_relayer::config::profile::ProfileConfig;
+use ibc_relayer::config::{ChainConfig, Config, ModeConfig, RestConfig};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tracing_subscriber::filter::ParseError;
@@ -73,6 +78,95 @@ define_error! {
                     e.chain_id, e.gas_adjustment, e.gas_multiplier
                 )
             },
+
+        DynamicGasPriceMaxTooLow
+            {
+                chain_id: ChainId,
+                max: f64,
+                gas_price: f64,
+            }
+            |e| {
+                format!("config file specifies a `dynamic_gas_price.max` ({0}) smaller than the price of `gas_price` ({1}) for the chain '{2}'",
+                    e.max, e.gas_price, e.chain_id)
+            },
+
+        InvalidEventSink
+            { reason: String }
+            |e| {
+                format!("config file specifies an invalid `event_sink` config, caused by: {0}",
+                    e.reason)
+            },
+
+        InvalidRest
+            { reason: String }
+            |e| {
+                format!("config file specifies an invalid `rest` config, caused by: {0}",
+                    e.reason)
+            },
+
+        InvalidHooks
+            { reason: String }
+            |e| {
+                format!("config file specifies an invalid `hooks` config, caused by: {0}",
+                    e.reason)
+            },
+
+        InvalidCoordination
+            { reason: String }
+            |e| {
+                format!("config file specifies an invalid `coordination` config, caused by: {0}",
+                    e.reason)
+            },
+
+        InvalidProfile
+            {
+                name: String,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid profile '{0}', caused by: {1}",
+                    e.name, e.reason)
+            },
+
+        InvalidChannelBatch
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid `channel_batch` for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
+
+        InvalidAdaptiveBatch
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid `adaptive_batch` for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
+
+        InvalidTrustingPeriodMargin
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid `trusting_period_margin` for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
+
+        InvalidProofSpecs
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies invalid `proof_specs` for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
     }
 }
 
@@ -94,13 +188,40 @@ pub fn validate_config(config: &Config) -> Result<(), Diagnostic<Error>> {
 
         validate_trust_threshold(&c.id, c.trust_threshold)?;
 
+        // Validate the safety margin before the end of the trusting period
+        validate_trusting_period_margin(&c.id, c)?;
+
         // Validate gas-related settings
         validate_gas_settings(&c.id, c)?;
+
+        // Validate the custom proof specs, if any
+        validate_proof_specs(&c.id, c)?;
+
+        // Validate the per-channel batching overrides
+        validate_channel_batch(&c.id, c)?;
+
+        // Validate the adaptation of the batch limits to the block capacity
+        validate_adaptive_batch(&c.id, &c.adaptive_batch)?;
     }
 
     // Check for invalid mode config
     validate_mode(&config.mode)?;
 
+    // Check for invalid event sink config
+    validate_event_sink(&config.event_sink)?;
+
+    // Check for invalid REST config
+    validate_rest(&config.rest)?;
+
+    // Check for invalid hooks config
+    validate_hooks(&config.hooks)?;
+
+    // Check for invalid coordination config
+    validate_coordination(&config.coordination)?;
+
+    // Check for invalid profiles, along with their own config
+    validate_profiles(&config.profiles)?;
+
     Ok(())
 }
 
@@ -156,6 +277,31 @@ fn validate_trust_threshold(
     Ok(())
 }
 
+/// Check that the safety margin is shorter than the trusting period, if both are configured.
+/// Otherwise, the margin is checked against the trusting period derived from the unbonding
+/// period of the chain, when the chain is validated.
+fn validate_trusting_period_margin(
+    id: &ChainId,
+    config: &ChainConfig,
+) -> Result<(), Diagnostic<Error>> {
+    if let (Some(margin), Some(trusting_period)) =
+        (config.trusting_period_margin, config.trusting_period)
+    {
+        if margin >= trusting_period {
+            return Err(Diagnostic::Error(Error::invalid_trusting_period_margin(
+                id.clone(),
+                format!(
+                    "the margin ({}) must be shorter than the `trusting_period` ({})",
+                    humantime::format_duration(margin),
+                    humantime::format_duration(trusting_period)
+                ),
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_gas_settings(id: &ChainId, config: &ChainConfig) -> Result<(), Diagnostic<Error>> {
     // Check that the gas_adjustment option is not set
     if let Some(gas_adjustment) = config.gas_adjustment {
@@ -168,5 +314,221 @@ fn validate_gas_settings(id: &ChainId, config: &ChainConfig) -> Result<(), Diagn
         )));
     }
 
+    // Check that the maximum dynamic gas price, if any, is not below the static gas price
+    if let Some(max) = config.dynamic_gas_price.max {
+        if max < config.gas_price.price {
+            return Err(Diagnostic::Error(Error::dynamic_gas_price_max_too_low(
+                id.clone(),
+                max,
+                config.gas_price.price,
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the batching overrides of each channel are given once,
+/// and that their limits do not exceed those of the chain.
+fn validate_channel_batch(id: &ChainId, config: &ChainConfig) -> Result<(), Diagnostic<Error>> {
+    let mut channels = BTreeSet::new();
+
+    for channel_batch in &config.channel_batch {
+        let invalid = |reason: String| {
+            Diagnostic::Error(Error::invalid_channel_batch(
+                id.clone(),
+                format!(
+                    "{reason} for the channel {}/{}",
+                    channel_batch.port_id, channel_batch.channel_id
+                ),
+            ))
+        };
+
+        if !channels.insert((&channel_batch.port_id, &channel_batch.channel_id)) {
+            return Err(invalid("duplicate overrides".to_string()));
+        }
+
+        if let Some(max_msg_num) = channel_batch.max_msg_num {
+            if max_msg_num > config.max_msg_num {
+                return Err(invalid(format!(
+                    "`max_msg_num` ({}) exceeds the `max_msg_num` of the chain ({})",
+                    max_msg_num.to_usize(),
+                    config.max_msg_num.to_usize()
+                )));
+            }
+        }
+
+        if let Some(max_tx_size) = channel_batch.max_tx_size {
+            if max_tx_size > config.max_tx_size {
+                return Err(invalid(format!(
+                    "`max_tx_size` ({}) exceeds the `max_tx_size` of the chain ({})",
+                    max_tx_size.to_usize(),
+                    config.max_tx_size.to_usize()
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_event_sink(event_sink: &EventSinkConfig) -> Result<(), Diagnostic<Error>> {
+    if event_sink.enabled && event_sink.url.is_empty() {
+        return Err(Diagnostic::Error(Error::invalid_event_sink(format!(
+            "the `url` of the {} server must be set when the event sink is enabled",
+            event_sink.backend.as_str()
+        ))));
+    }
+
+    Ok(())
+}
+
+/// Check that the key reloads are authenticated with a token whenever they are allowed,
+/// as they change the key signing the transactions of Hermes.
+fn validate_rest(rest: &RestConfig) -> Result<(), Diagnostic<Error>> {
+    if rest.allow_key_reload && rest.token_file.is_none() {
+        return Err(Diagnostic::Error(Error::invalid_rest(
+            "a `token_file` must be set when `allow_key_reload` is enabled".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_hooks(hooks: &HooksConfig) -> Result<(), Diagnostic<Error>> {
+    if !hooks.enabled {
+        return Ok(());
+    }
+
+    let reason = if hooks.max_concurrency == 0 {
+        "the `max_concurrency` must be greater than zero".to_string()
+    } else if let Some(hook) = hooks.webhooks.iter().find(|hook| hook.triggers.is_empty()) {
+        format!("the webhook '{}' has no `triggers`", hook.url)
+    } else if let Some(hook) = hooks
+        .webhooks
+        .iter()
+        .find(|hook| !hook.url.starts_with("http://") && !hook.url.starts_with("https://"))
+    {
+        format!(
+            "the `url` of the webhook '{}' must be an HTTP(S) URL",
+            hook.url
+        )
+    } else if hooks.exec.iter().any(|hook| hook.command.is_empty()) {
+        "the `command` of an exec hook must not be empty".to_string()
+    } else if let Some(hook) = hooks.exec.iter().find(|hook| hook.triggers.is_empty()) {
+        format!(
+            "the exec hook '{}' has no `triggers`",
+            hook.command.join(" ")
+        )
+    } else {
+        return Ok(());
+    };
+
+    Err(Diagnostic::Error(Error::invalid_hooks(reason)))
+}
+
+fn validate_coordination(coordination: &CoordinationConfig) -> Result<(), Diagnostic<Error>> {
+    if !coordination.enabled {
+        return Ok(());
+    }
+
+    let reason = if coordination.instance_id.is_empty() {
+        "the `instance_id` must be set when the coordination is enabled"
+    } else if coordination.database_url.is_empty() {
+        "the `database_url` must be set when the coordination is enabled"
+    } else if !coordination.has_valid_table() {
+        "the `table` must start with a letter or an underscore, \
+            and only contain alphanumeric characters and underscores"
+    } else if coordination.renew_interval >= coordination.lease_duration {
+        "the `renew_interval` must be shorter than the `lease_duration`"
+    } else {
+        return Ok(());
+    };
+
+    Err(Diagnostic::Error(Error::invalid_coordination(
+        reason.to_string(),
+    )))
+}
+
+/// Check that the utilization thresholds of the adaptive batching satisfy
+/// `0 < low_utilization < high_utilization <= 1`, and that the `target_latency`
+/// is non-zero, as the batch limits would otherwise never settle.
+fn validate_adaptive_batch(
+    id: &ChainId,
+    adaptive_batch: &AdaptiveBatchConfig,
+) -> Result<(), Diagnostic<Error>> {
+    if !adaptive_batch.enabled {
+        return Ok(());
+    }
+
+    let low = adaptive_batch.low_utilization;
+    let high = adaptive_batch.high_utilization;
+
+    let reason = if !(low > 0.0 && low <= 1.0) {
+        format!("the `low_utilization` ({low}) must be greater than 0 and at most 1")
+    } else if !(high > 0.0 && high <= 1.0) {
+        format!("the `high_utilization` ({high}) must be greater than 0 and at most 1")
+    } else if low >= high {
+        format!("the `low_utilization` ({low}) must be lower than the `high_utilization` ({high})")
+    } else if adaptive_batch.target_latency.is_zero() {
+        "the `target_latency` must be greater than zero".to_string()
+    } else {
+        return Ok(());
+    };
+
+    Err(Diagnostic::Error(Error::invalid_adaptive_batch(
+        id.clone(),
+        reason,
+    )))
+}
+
+fn validate_profiles(profiles: &[ProfileConfig]) -> Result<(), Diagnostic<Error>> {
+    let mut unique_names = BTreeSet::new();
+
+    for profile in profiles {
+        let invalid = |reason: String| {
+            Diagnostic::Error(Error::invalid_profile(profile.name.clone(), reason))
+        };
+
+        if profile.name.is_empty() {
+            return Err(invalid("the `name` of the profile must be set".to_string()));
+        }
+
+        if !unique_names.insert(profile.name.as_str()) {
+            return Err(invalid("the name of the profile is not unique".to_string()));
+        }
+
+        let config = profile.load().map_err(|e| {
+            invalid(format!(
+                "failed to load the config file '{}': {}",
+                profile.config.display(),
+                e
+            ))
+        })?;
+
+        if !config.profiles.is_empty() {
+            return Err(invalid(
+                "the config of a profile cannot have profiles of its own".to_string(),
+            ));
+        }
+
+        validate_config(&config).map_err(|diagnostic| match diagnostic {
+            Diagnostic::Warning(e) => {
+                Diagnostic::Warning(Error::invalid_profile(profile.name.clone(), e.to_string()))
+            }
+            Diagnostic::Error(e) => invalid(e.to_string()),
+        })?;
+    }
+
+    Ok(())
+}
+
+fn validate_proof_specs(id: &ChainId, config: &ChainConfig) -> Result<(), Diagnostic<Error>> {
+    if let Some(proof_specs) = &config.proof_specs {
+        proof_specs.validate().map_err(|e| {
+            Diagnostic::Error(Error::invalid_proof_specs(id.clone(), e.to_string()))
+        })?;
+    }
+
     Ok(())
 }
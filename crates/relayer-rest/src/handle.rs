@@ -1,4 +1,5 @@
 use core::fmt::Debug;
+use core::str::FromStr;
 
 use tracing::error;
 
@@ -7,12 +8,15 @@ use crossbeam_channel as channel;
 use ibc_relayer::supervisor::dump_state::SupervisorState;
 use ibc_relayer::{
     config::ChainConfig,
+    object::Client,
     rest::{
+        dashboard::DashboardState,
         request::{reply_channel, ReplySender, Request, VersionInfo},
         RestApiError,
     },
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::signer::Signer;
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -58,12 +62,47 @@ pub fn chain_config(
     })
 }
 
+pub fn reload_key(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    key_name: Option<String>,
+    token: Option<String>,
+) -> Result<Signer, RestApiError> {
+    submit_request(sender, |reply_to| Request::ReloadKey {
+        chain_id: ChainId::from_string(chain_id),
+        key_name,
+        token,
+        reply_to,
+    })
+}
+
+pub fn acknowledge_client(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    client_id: &str,
+    token: Option<String>,
+) -> Result<Client, RestApiError> {
+    let client_id = ClientId::from_str(client_id)
+        .map_err(|e| RestApiError::InvalidClientId(client_id.to_string(), e.0))?;
+
+    submit_request(sender, |reply_to| Request::AcknowledgeClient {
+        chain_id: ChainId::from_string(chain_id),
+        client_id,
+        token,
+        reply_to,
+    })
+}
+
 pub fn supervisor_state(
     sender: &channel::Sender<Request>,
 ) -> Result<SupervisorState, RestApiError> {
     submit_request(sender, |reply_to| Request::State { reply_to })
 }
 
+pub fn dashboard_state(sender: &channel::Sender<Request>) -> Result<DashboardState, RestApiError> {
+    submit_request(sender, |reply_to| Request::Dashboard { reply_to })
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
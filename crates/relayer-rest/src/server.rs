@@ -3,17 +3,31 @@ use std::{
     net::{SocketAddr, ToSocketAddrs},
 };
 
-use axum::{extract::Path, response::IntoResponse, routing::get, Extension, Json, Router, Server};
+use axum::{
+    extract::Path,
+    http::{header::AUTHORIZATION, HeaderMap},
+    response::{Html, IntoResponse},
+    routing::{get, post},
+    Extension, Json, Router, Server,
+};
 use crossbeam_channel as channel;
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
 
 use ibc_relayer::{
-    rest::{request::Request, RestApiError},
+    object::Client,
+    rest::{dashboard::DashboardState, request::Request, RestApiError},
     supervisor::dump_state::SupervisorState,
 };
+use ibc_relayer_types::signer::Signer;
+
+use crate::handle::{
+    acknowledge_client, all_chain_ids, assemble_version_info, chain_config, dashboard_state,
+    reload_key, supervisor_state,
+};
 
-use crate::handle::{all_chain_ids, assemble_version_info, chain_config, supervisor_state};
+/// The page of the dashboard, which polls `/dashboard/state` to render the state of the relayer
+const DASHBOARD_PAGE: &str = include_str!("dashboard.html");
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
 
@@ -68,6 +82,52 @@ async fn get_state(
     Json(JsonResult::from(state))
 }
 
+async fn get_dashboard() -> Html<&'static str> {
+    Html(DASHBOARD_PAGE)
+}
+
+async fn get_dashboard_state(
+    Extension(sender): Extension<Sender>,
+) -> Json<JsonResult<DashboardState, RestApiError>> {
+    let state = dashboard_state(&sender);
+    Json(JsonResult::from(state))
+}
+
+/// The body of a request reloading the key of a chain, which is optional
+#[derive(Debug, Default, Deserialize)]
+struct ReloadKeyParams {
+    /// The name of the key to switch to, instead of the key configured for the chain
+    key_name: Option<String>,
+}
+
+/// The token carried by the `Authorization: Bearer <token>` header of a request, if any
+fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    let authorization = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let token = authorization.strip_prefix("Bearer ")?;
+
+    Some(token.trim().to_owned())
+}
+
+async fn post_reload_key(
+    Path(id): Path<String>,
+    Extension(sender): Extension<Sender>,
+    headers: HeaderMap,
+    params: Option<Json<ReloadKeyParams>>,
+) -> Json<JsonResult<Signer, RestApiError>> {
+    let key_name = params.and_then(|Json(params)| params.key_name);
+    let signer = reload_key(&sender, &id, key_name, bearer_token(&headers));
+    Json(JsonResult::from(signer))
+}
+
+async fn post_acknowledge_client(
+    Path((id, client_id)): Path<(String, String)>,
+    Extension(sender): Extension<Sender>,
+    headers: HeaderMap,
+) -> Json<JsonResult<Client, RestApiError>> {
+    let client = acknowledge_client(&sender, &id, &client_id, bearer_token(&headers));
+    Json(JsonResult::from(client))
+}
+
 type Sender = channel::Sender<Request>;
 
 async fn run(addr: SocketAddr, sender: Sender) {
@@ -75,7 +135,14 @@ async fn run(addr: SocketAddr, sender: Sender) {
         .route("/version", get(get_version))
         .route("/chains", get(get_chains))
         .route("/chain/:id", get(get_chain))
+        .route("/chain/:id/keys/reload", post(post_reload_key))
+        .route(
+            "/chain/:id/client/:client_id/acknowledge",
+            post(post_acknowledge_client),
+        )
         .route("/state", get(get_state))
+        .route("/dashboard", get(get_dashboard))
+        .route("/dashboard/state", get(get_dashboard_state))
         .layer(Extension(sender));
 
     Server::bind(&addr)
@@ -4,10 +4,15 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use ibc_relayer::{
     config::ChainConfig,
-    rest::request::{Request, VersionInfo},
+    object::Client,
+    rest::{
+        dashboard::{ChainHealth, DashboardState},
+        request::{Request, VersionInfo},
+    },
     supervisor::dump_state::SupervisorState,
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::signer::Signer;
 
 use ibc_relayer_rest::spawn;
 
@@ -28,6 +33,19 @@ async fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize + DeserializeOwned + Debug + PartialEq,
     F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_request_test(port, |client, url| client.get(url), path, expected, handler).await
+}
+
+async fn run_request_test<R, F>(
+    port: u16,
+    build: impl FnOnce(reqwest::Client, String) -> reqwest::RequestBuilder,
+    path: &str,
+    expected: R,
+    handler: F,
+) where
+    R: Serialize + DeserializeOwned + Debug + PartialEq,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
 {
     let (tx, rx) = crossbeam_channel::unbounded();
 
@@ -43,12 +61,16 @@ where
 
     tokio::time::sleep(Duration::from_millis(500)).await;
 
-    let response = reqwest::get(&format!("http://127.0.0.1:{port}{path}"))
-        .await
-        .unwrap()
-        .json::<R>()
-        .await
-        .unwrap();
+    let response = build(
+        reqwest::Client::new(),
+        format!("http://127.0.0.1:{port}{path}"),
+    )
+    .send()
+    .await
+    .unwrap()
+    .json::<R>()
+    .await
+    .unwrap();
 
     assert_eq!(response, expected);
 
@@ -130,7 +152,11 @@ async fn get_chain() {
 
 #[tokio::test]
 async fn state() {
-    let state = SupervisorState::new(vec!["mock-0".parse().unwrap()], std::iter::empty());
+    let state = SupervisorState::new(
+        vec!["mock-0".parse().unwrap()],
+        std::iter::empty(),
+        std::iter::empty(),
+    );
     let result: JsonResult<_, ()> = JsonResult::Success(state.clone());
 
     run_test(19104, "/state", result, |req| match req {
@@ -142,3 +168,93 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn dashboard_state() {
+    let state = DashboardState {
+        chains: vec![ChainHealth {
+            chain_id: "mock-0".parse().unwrap(),
+            latest_height: None,
+            error: Some("connection refused".to_string()),
+            workers: 2,
+        }],
+        backlogs: vec![],
+        wallets: vec![],
+        recent_errors: vec![],
+    };
+    let result: JsonResult<_, ()> = JsonResult::Success(state.clone());
+
+    run_test(19105, "/dashboard/state", result, |req| match req {
+        Request::Dashboard { reply_to } => {
+            reply_to.send(Ok(state)).unwrap();
+            TestResult::Success
+        }
+        req => TestResult::WrongRequest(req),
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn reload_key() {
+    let signer = Signer::from_str("cosmos1rotated").unwrap();
+    let result: JsonResult<_, ()> = JsonResult::Success(signer.clone());
+
+    run_request_test(
+        19106,
+        |client, url| {
+            client
+                .post(url)
+                .header("content-type", "application/json")
+                .bearer_auth("s3cr3t")
+                .body(r#"{"key_name":"rotated"}"#)
+        },
+        "/chain/mock-0/keys/reload",
+        result,
+        |req| match req {
+            Request::ReloadKey {
+                chain_id,
+                key_name: Some(key_name),
+                token: Some(token),
+                reply_to,
+            } if chain_id.as_str() == "mock-0" && key_name == "rotated" && token == "s3cr3t" => {
+                reply_to.send(Ok(signer)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn acknowledge_client() {
+    let suspect = Client {
+        dst_chain_id: "mock-1".parse().unwrap(),
+        dst_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+        src_chain_id: "mock-0".parse().unwrap(),
+    };
+    let result: JsonResult<_, ()> = JsonResult::Success(suspect.clone());
+
+    run_request_test(
+        19107,
+        |client, url| client.post(url).bearer_auth("s3cr3t"),
+        "/chain/mock-1/client/07-tendermint-0/acknowledge",
+        result,
+        |req| match req {
+            Request::AcknowledgeClient {
+                chain_id,
+                client_id,
+                token: Some(token),
+                reply_to,
+            } if chain_id.as_str() == "mock-1"
+                && client_id.as_str() == "07-tendermint-0"
+                && token == "s3cr3t" =>
+            {
+                reply_to.send(Ok(suspect)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
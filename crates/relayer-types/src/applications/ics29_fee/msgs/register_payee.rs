@@ -7,14 +7,16 @@ use crate::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::signer::Signer;
 use crate::tx_msg::encode_message;
 
+pub const REGISTER_PAYEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterPayee";
+pub const REGISTER_COUNTERPARTY_PAYEE_TYPE_URL: &str =
+    "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
 pub fn build_register_counterparty_payee_message(
     address: &Signer,
     counterparty_payee: &Signer,
     channel_id: &ChannelId,
     port_id: &PortId,
 ) -> Result<Any, Error> {
-    const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
-
     let message = MsgRegisterCounterpartyPayee {
         relayer: address.to_string(),
         counterparty_payee: counterparty_payee.to_string(),
@@ -25,7 +27,7 @@ pub fn build_register_counterparty_payee_message(
     let encoded = encode_message(&message).map_err(Error::encode)?;
 
     Ok(Any {
-        type_url: TYPE_URL.to_string(),
+        type_url: REGISTER_COUNTERPARTY_PAYEE_TYPE_URL.to_string(),
         value: encoded,
     })
 }
@@ -36,8 +38,6 @@ pub fn build_register_payee_message(
     channel_id: &ChannelId,
     port_id: &PortId,
 ) -> Result<Any, Error> {
-    const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterPayee";
-
     let message = MsgRegisterPayee {
         relayer: address.to_string(),
         payee: payee.to_string(),
@@ -48,7 +48,7 @@ pub fn build_register_payee_message(
     let encoded = encode_message(&message).map_err(Error::encode)?;
 
     Ok(Any {
-        type_url: TYPE_URL.to_string(),
+        type_url: REGISTER_PAYEE_TYPE_URL.to_string(),
         value: encoded,
     })
 }
@@ -37,6 +37,17 @@ impl Acknowledgement {
     pub fn from_error(err: Error) -> Self {
         Self::Error(format!("{ACK_ERR_STR}: {err}"))
     }
+
+    /// Decodes the JSON-encoded acknowledgement written on chain by the ICS-20 module,
+    /// e.g. `{"result":"AQ=="}`. Returns `None` if the bytes are not a valid ICS-20
+    /// acknowledgement, as is the case for acknowledgements written by other applications.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success(_))
+    }
 }
 
 impl AsRef<[u8]> for Acknowledgement {
@@ -91,4 +102,17 @@ mod test {
         assert!(serde_json::from_str::<Acknowledgement>(r#"{"result":"AQ="}"#).is_err());
         assert!(serde_json::from_str::<Acknowledgement>(r#"{"success":"AQ=="}"#).is_err());
     }
+
+    #[test]
+    fn test_ack_decode() {
+        let ack = Acknowledgement::decode(br#"{"result":"AQ=="}"#).unwrap();
+        assert!(ack.is_success());
+
+        let ack = Acknowledgement::decode(br#"{"error":"insufficient funds"}"#).unwrap();
+        assert_eq!(ack, Acknowledgement::Error("insufficient funds".to_owned()));
+        assert!(!ack.is_success());
+
+        assert_eq!(Acknowledgement::decode(br#"{"result":"eyJ9"}"#), None);
+        assert_eq!(Acknowledgement::decode(&[0x01]), None);
+    }
 }
@@ -39,6 +39,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -152,6 +160,17 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// The prefix of the channel over which the token was last received, if any,
+    /// ie. the first prefix of the trace path.
+    pub fn leading_trace_prefix(&self) -> Option<&TracePrefix> {
+        self.trace_path.0.last()
+    }
+
+    /// Returns true if the token is native to the chain, ie. if its trace path is empty.
+    pub fn is_native(&self) -> bool {
+        self.trace_path.is_empty()
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -346,6 +365,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_leading_trace_prefix() -> Result<(), Error> {
+        let mut denom = PrefixedDenom::from_str("transfer/channel-0/transfer/channel-1/uatom")?;
+
+        let leading = TracePrefix::new("transfer".parse().unwrap(), "channel-0".parse().unwrap());
+        assert_eq!(denom.leading_trace_prefix(), Some(&leading));
+
+        denom.remove_trace_prefix(&leading);
+        assert_eq!(denom.to_string(), "transfer/channel-1/uatom");
+
+        let leading = TracePrefix::new("transfer".parse().unwrap(), "channel-1".parse().unwrap());
+        assert_eq!(denom.leading_trace_prefix(), Some(&leading));
+
+        denom.remove_trace_prefix(&leading);
+        assert!(denom.is_native());
+        assert_eq!(denom.leading_trace_prefix(), None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_trace_path() -> Result<(), Error> {
         assert!(TracePath::from_str("").is_ok(), "empty trace path");
@@ -95,13 +95,19 @@ impl ClientState {
             ));
         }
 
-        // Disallow empty proof-specs
+        // Disallow empty or malformed proof-specs
         if proof_specs.is_empty() {
             return Err(Error::validation(
                 "ClientState proof specs cannot be empty".to_string(),
             ));
         }
 
+        if let Err(e) = proof_specs.validate() {
+            return Err(Error::validation(format!(
+                "ClientState proof specs are invalid: {e}"
+            )));
+        }
+
         Ok(Self {
             chain_id,
             trust_threshold,
@@ -1,15 +1,20 @@
+use serde::Deserializer;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt::{Display, Error as FmtError, Formatter};
 
 use super::error::Error;
 
 /// Type of the client, depending on the specific consensus algorithm.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 pub enum ClientType {
-    Tendermint = 1,
+    Tendermint,
 
     #[cfg(any(test, feature = "mocks"))]
-    Mock = 9999,
+    Mock,
+
+    /// A client type unknown to this crate, identified by its client type string,
+    /// eg. one handled by a plugin of the relayer. Cannot be deserialized.
+    Other(&'static str),
 }
 
 impl ClientType {
@@ -25,6 +30,28 @@ impl ClientType {
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock => Self::MOCK_STR,
+
+            Self::Other(client_type) => client_type,
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ClientType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The client types known to this crate, serialized like those of `ClientType`
+        #[derive(Deserialize)]
+        enum KnownClientType {
+            Tendermint,
+
+            #[cfg(any(test, feature = "mocks"))]
+            Mock,
+        }
+
+        match KnownClientType::deserialize(deserializer)? {
+            KnownClientType::Tendermint => Ok(Self::Tendermint),
+
+            #[cfg(any(test, feature = "mocks"))]
+            KnownClientType::Mock => Ok(Self::Mock),
         }
     }
 }
@@ -101,6 +128,13 @@ mod tests {
         assert_eq!(client_type_from_str, client_type);
     }
 
+    #[test]
+    fn other_client_type_is_not_parsed() {
+        let client_type = ClientType::Other("08-wasm");
+        assert_eq!(client_type.as_str(), "08-wasm");
+        assert!(ClientType::from_str(client_type.as_str()).is_err());
+    }
+
     #[test]
     fn parse_tendermint_as_string_result() {
         let client_type = ClientType::Tendermint;
@@ -62,6 +62,8 @@ impl PartialEq for dyn ConsensusState {
     }
 }
 
+impl Eq for dyn ConsensusState {}
+
 // see https://github.com/rust-lang/rust/issues/31740
 impl PartialEq<&Self> for Box<dyn ConsensusState> {
     fn eq(&self, other: &&Self) -> bool {
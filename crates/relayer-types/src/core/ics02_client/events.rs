@@ -1,10 +1,12 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the client module.
 
+use serde::de::{Deserialize as _, Deserializer, IgnoredAny};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt::{Display, Error as FmtError, Formatter};
 use tendermint::abci;
 
 use super::header::Header;
+use crate::clients::ics07_tendermint::header::Header as TmHeader;
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics02_client::height::Height;
 use crate::core::ics24_host::identifier::ClientId;
@@ -24,7 +26,7 @@ pub const HEADER_ATTRIBUTE_KEY: &str = "header";
 
 /// NewBlock event signals the committing & execution of a new block.
 // TODO - find a better place for NewBlock
-#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub struct NewBlock {
     pub height: Height,
 }
@@ -95,7 +97,7 @@ impl From<Attributes> for Vec<abci::EventAttribute> {
 }
 
 /// CreateClient event signals the creation of a new on-chain client (IBC client).
-#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct CreateClient(pub Attributes);
 
 impl CreateClient {
@@ -132,12 +134,32 @@ impl From<CreateClient> for abci::Event {
 }
 
 /// UpdateClient event signals a recent update of an on-chain client (IBC Client).
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct UpdateClient {
     pub common: Attributes,
+    /// The header is serialized without its concrete type, which is erased, so only
+    /// the Tendermint headers are deserialized back, the others being dropped.
+    #[serde(default, deserialize_with = "deserialize_header")]
     pub header: Option<Box<dyn Header>>,
 }
 
+fn deserialize_header<'de, D>(deserializer: D) -> Result<Option<Box<dyn Header>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SerializedHeader {
+        Tendermint(TmHeader),
+        Other(IgnoredAny),
+    }
+
+    match Option::<SerializedHeader>::deserialize(deserializer)? {
+        Some(SerializedHeader::Tendermint(header)) => Ok(Some(Box::new(header))),
+        Some(SerializedHeader::Other(_)) | None => Ok(None),
+    }
+}
+
 impl UpdateClient {
     pub fn client_id(&self) -> &ClientId {
         &self.common.client_id
@@ -194,7 +216,7 @@ impl From<UpdateClient> for abci::Event {
 
 /// ClientMisbehaviour event signals the update of an on-chain client (IBC Client) with evidence of
 /// misbehaviour.
-#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct ClientMisbehaviour(pub Attributes);
 
 impl ClientMisbehaviour {
@@ -231,7 +253,7 @@ impl From<ClientMisbehaviour> for abci::Event {
 }
 
 /// Signals a recent upgrade of an on-chain client (IBC Client).
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct UpgradeClient(pub Attributes);
 
 impl UpgradeClient {
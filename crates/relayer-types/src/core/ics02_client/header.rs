@@ -59,6 +59,8 @@ impl PartialEq for dyn Header {
     }
 }
 
+impl Eq for dyn Header {}
+
 mod sealed {
     use super::*;
 
@@ -1,12 +1,23 @@
 use dyn_clone::DynClone;
+use erased_serde::Serialize as ErasedSerialize;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf as ErasedProtobuf;
 
 use crate::dynamic_typing::AsAny;
 
+use crate::core::ics02_client::error::Error;
 use crate::core::ics24_host::identifier::ClientId;
 use crate::Height;
 
 pub trait Misbehaviour:
-    AsAny + sealed::ErasedPartialEqMisbehaviour + DynClone + core::fmt::Debug + Send + Sync
+    AsAny
+    + sealed::ErasedPartialEqMisbehaviour
+    + DynClone
+    + ErasedSerialize
+    + ErasedProtobuf<Any, Error = Error>
+    + core::fmt::Debug
+    + Send
+    + Sync
 {
     /// The type of client (eg. Tendermint)
     fn client_id(&self) -> &ClientId;
@@ -18,11 +29,16 @@ pub trait Misbehaviour:
 // Implements `Clone` for `Box<dyn Misbehaviour>`
 dyn_clone::clone_trait_object!(Misbehaviour);
 
+// Implements `serde::Serialize` for all types that have Misbehaviour as supertrait
+erased_serde::serialize_trait_object!(Misbehaviour);
+
 impl PartialEq for dyn Misbehaviour {
     fn eq(&self, other: &Self) -> bool {
         self.eq_misbehaviour(other)
     }
 }
+
+impl Eq for dyn Misbehaviour {}
 mod sealed {
     use super::*;
 
@@ -56,7 +56,7 @@ impl From<Attributes> for Vec<abci::EventAttribute> {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenInit(pub Attributes);
 
 impl OpenInit {
@@ -95,7 +95,7 @@ impl From<OpenInit> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenTry(pub Attributes);
 
 impl OpenTry {
@@ -134,7 +134,7 @@ impl From<OpenTry> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenAck(pub Attributes);
 
 impl OpenAck {
@@ -173,7 +173,7 @@ impl From<OpenAck> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenConfirm(pub Attributes);
 
 impl OpenConfirm {
@@ -5,6 +5,7 @@ use std::fmt::{Display, Error as FmtError, Formatter};
 use std::str;
 use tendermint::abci;
 
+use crate::applications::transfer::acknowledgement::Acknowledgement as TransferAcknowledgement;
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::Packet;
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
@@ -129,7 +130,7 @@ pub trait EventType {
     fn event_type() -> IbcEventType;
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenInit {
     pub port_id: PortId,
     pub channel_id: Option<ChannelId>,
@@ -182,7 +183,7 @@ impl EventType for OpenInit {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenTry {
     pub port_id: PortId,
     pub channel_id: Option<ChannelId>,
@@ -234,7 +235,7 @@ impl EventType for OpenTry {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenAck {
     pub port_id: PortId,
     pub channel_id: Option<ChannelId>,
@@ -291,7 +292,7 @@ impl EventType for OpenAck {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OpenConfirm {
     pub port_id: PortId,
     pub channel_id: Option<ChannelId>,
@@ -344,7 +345,7 @@ impl EventType for OpenConfirm {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CloseInit {
     pub port_id: PortId,
     pub channel_id: ChannelId,
@@ -421,7 +422,7 @@ impl EventType for CloseInit {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CloseConfirm {
     pub channel_id: Option<ChannelId>,
     pub port_id: PortId,
@@ -514,7 +515,7 @@ impl_from_ibc_to_abci_event!(
     CloseConfirm
 );
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct SendPacket {
     pub packet: Packet,
 }
@@ -557,7 +558,7 @@ impl TryFrom<SendPacket> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ReceivePacket {
     pub packet: Packet,
 }
@@ -600,10 +601,13 @@ impl TryFrom<ReceivePacket> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct WriteAcknowledgement {
     pub packet: Packet,
-    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::de_hex_upper"
+    )]
     pub ack: Vec<u8>,
 }
 
@@ -620,6 +624,19 @@ impl WriteAcknowledgement {
     pub fn dst_channel_id(&self) -> &ChannelId {
         &self.packet.destination_channel
     }
+
+    /// Decodes the acknowledgement as an ICS-20 acknowledgement, which tells whether the
+    /// transfer succeeded or the error it failed with.
+    ///
+    /// Returns `None` if the packet was not received on the `transfer` port, as other
+    /// applications may write acknowledgements which also happen to decode as ICS-20 ones.
+    pub fn transfer_ack(&self) -> Option<TransferAcknowledgement> {
+        if self.packet.destination_port != PortId::transfer() {
+            return None;
+        }
+
+        TransferAcknowledgement::decode(&self.ack)
+    }
 }
 
 impl Display for WriteAcknowledgement {
@@ -654,7 +671,7 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct AcknowledgePacket {
     pub packet: Packet,
 }
@@ -691,7 +708,7 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct TimeoutPacket {
     pub packet: Packet,
 }
@@ -734,7 +751,7 @@ impl TryFrom<TimeoutPacket> for abci::Event {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct TimeoutOnClosePacket {
     pub packet: Packet,
 }
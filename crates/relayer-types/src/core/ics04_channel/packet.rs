@@ -110,7 +110,10 @@ pub struct Packet {
     pub source_channel: ChannelId,
     pub destination_port: PortId,
     pub destination_channel: ChannelId,
-    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::de_hex_upper"
+    )]
     pub data: Vec<u8>,
     pub timeout_height: TimeoutHeight,
     pub timeout_timestamp: Timestamp,
@@ -34,6 +34,24 @@ define_error! {
             |_| { "invalid merkle proof" },
 
         VerificationFailure
-            |_| { "proof verification failed" }
+            |_| { "proof verification failed" },
+
+        EmptyProofSpecs
+            |_| { "empty proof specs" },
+
+        MissingLeafSpec
+            { index: usize }
+            |e| { format_args!("proof spec #{} is missing its leaf spec", e.index) },
+
+        MissingInnerSpec
+            { index: usize }
+            |e| { format_args!("proof spec #{} is missing its inner spec", e.index) },
+
+        InvalidProofSpecDepth
+            { index: usize, min_depth: i32, max_depth: i32 }
+            |e| {
+                format_args!("proof spec #{} has an invalid depth range: min depth {} and max depth {}",
+                    e.index, e.min_depth, e.max_depth)
+            },
     }
 }
@@ -1,6 +1,8 @@
 use ics23::ProofSpec;
 use serde::{Deserialize, Serialize};
 
+use crate::core::ics23_commitment::error::Error;
+
 /// An array of proof specifications.
 ///
 /// This type encapsulates different types of proof specifications, mostly predefined, e.g., for
@@ -24,6 +26,34 @@ impl ProofSpecs {
     pub fn into_vec(self) -> Vec<ProofSpec> {
         self.0
     }
+
+    /// Checks that there is at least one proof spec, and that every spec
+    /// has a leaf and an inner spec, and a valid depth range.
+    ///
+    /// A depth of zero means that the depth is not constrained.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.is_empty() {
+            return Err(Error::empty_proof_specs());
+        }
+
+        for (index, spec) in self.0.iter().enumerate() {
+            if spec.leaf_spec.is_none() {
+                return Err(Error::missing_leaf_spec(index));
+            }
+
+            if spec.inner_spec.is_none() {
+                return Err(Error::missing_inner_spec(index));
+            }
+
+            let (min_depth, max_depth) = (spec.min_depth, spec.max_depth);
+
+            if min_depth < 0 || max_depth < 0 || (max_depth > 0 && min_depth > max_depth) {
+                return Err(Error::invalid_proof_spec_depth(index, min_depth, max_depth));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl From<ProofSpecs> for Vec<ProofSpec> {
@@ -43,3 +73,23 @@ impl Default for ProofSpecs {
         Self::cosmos()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_proof_specs() {
+        assert!(ProofSpecs::cosmos().validate().is_ok());
+        assert!(ProofSpecs::from(Vec::new()).validate().is_err());
+
+        let mut spec = ics23::iavl_spec();
+        spec.min_depth = 10;
+        spec.max_depth = 5;
+        assert!(ProofSpecs::from(vec![spec]).validate().is_err());
+
+        let mut spec = ics23::tendermint_spec();
+        spec.inner_spec = None;
+        assert!(ProofSpecs::from(vec![spec]).validate().is_err());
+    }
+}
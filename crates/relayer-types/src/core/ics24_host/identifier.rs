@@ -86,6 +86,28 @@ impl ChainId {
             .unwrap_or(0)
     }
 
+    /// Checks whether the given chain identifier is the one of this chain after an upgrade
+    /// which bumped its revision number, ie. with the same name and a higher version.
+    /// ```
+    /// use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+    ///
+    /// let id = ChainId::from_string("cosmoshub-3");
+    /// assert!(id.is_upgraded_to(&ChainId::from_string("cosmoshub-4")));
+    /// assert!(!id.is_upgraded_to(&ChainId::from_string("cosmoshub-2")));
+    /// assert!(!id.is_upgraded_to(&ChainId::from_string("osmosis-4")));
+    /// assert!(ChainId::from_string("ibc-0").is_upgraded_to(&ChainId::from_string("ibc-1")));
+    /// ```
+    pub fn is_upgraded_to(&self, other: &ChainId) -> bool {
+        fn name(id: &str) -> Option<&str> {
+            id.rsplit_once('-').map(|(name, _)| name)
+        }
+
+        other.version > self.version
+            && ChainId::is_epoch_format(other.as_str())
+            && name(self.as_str()).is_some()
+            && name(self.as_str()) == name(other.as_str())
+    }
+
     /// is_epoch_format() checks if a chain_id is in the format required for parsing epochs
     /// The chainID must be in the form: `{chainID}-{version}`
     /// ```
@@ -168,12 +190,7 @@ impl ClientId {
     /// The prefix is deterministic for a given chain type, hence all clients for a Tendermint-type
     /// chain, for example, will have the prefix '07-tendermint'.
     pub fn prefix(client_type: ClientType) -> &'static str {
-        match client_type {
-            ClientType::Tendermint => ClientType::Tendermint.as_str(),
-
-            #[cfg(any(test, feature = "mocks"))]
-            ClientType::Mock => ClientType::Mock.as_str(),
-        }
+        client_type.as_str()
     }
 
     /// Get this identifier as a borrowed byte slice
@@ -429,3 +446,30 @@ impl Display for PortChannelId {
         write!(f, "{}/{}", self.port_id, self.channel_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ChainId;
+
+    #[test]
+    fn chain_id_is_upgraded_to_higher_revision() {
+        let id = ChainId::from_string("cosmoshub-3");
+
+        assert!(id.is_upgraded_to(&ChainId::from_string("cosmoshub-4")));
+        assert!(id.is_upgraded_to(&ChainId::from_string("cosmoshub-10")));
+
+        // Same or lower revision number
+        assert!(!id.is_upgraded_to(&id));
+        assert!(!id.is_upgraded_to(&ChainId::from_string("cosmoshub-2")));
+
+        // Another chain
+        assert!(!id.is_upgraded_to(&ChainId::from_string("osmosis-4")));
+        assert!(!id.is_upgraded_to(&ChainId::from_string("hub-4")));
+
+        // Chain identifiers without a revision number are never upgraded
+        assert!(
+            !ChainId::from_string("cosmoshub").is_upgraded_to(&ChainId::from_string("cosmoshub-1"))
+        );
+        assert!(!id.is_upgraded_to(&ChainId::from_string("cosmoshub")));
+    }
+}
@@ -246,7 +246,7 @@ impl FromStr for IbcEventType {
 }
 
 /// Events created by the IBC component of a chain, destined for a relayer.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum IbcEvent {
     NewBlock(NewBlock),
 
@@ -432,6 +432,18 @@ impl IbcEvent {
         }
     }
 
+    pub fn packet_mut(&mut self) -> Option<&mut Packet> {
+        match self {
+            IbcEvent::SendPacket(ev) => Some(&mut ev.packet),
+            IbcEvent::ReceivePacket(ev) => Some(&mut ev.packet),
+            IbcEvent::WriteAcknowledgement(ev) => Some(&mut ev.packet),
+            IbcEvent::AcknowledgePacket(ev) => Some(&mut ev.packet),
+            IbcEvent::TimeoutPacket(ev) => Some(&mut ev.packet),
+            IbcEvent::TimeoutOnClosePacket(ev) => Some(&mut ev.packet),
+            _ => None,
+        }
+    }
+
     pub fn cross_chain_query_packet(&self) -> Option<&CrossChainQueryPacket> {
         match self {
             IbcEvent::CrossChainQueryPacket(ev) => Some(ev),
@@ -477,7 +489,7 @@ impl FromStr for ModuleId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ModuleEvent {
     pub kind: String,
     pub module_name: ModuleId,
@@ -548,3 +560,54 @@ impl From<ModuleEventAttribute> for abci::EventAttribute {
         (attr.key, attr.value).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+    use crate::core::ics02_client::client_type::ClientType;
+    use crate::core::ics02_client::events::{Attributes, UpdateClient};
+    use crate::core::ics04_channel::packet::Sequence;
+    use crate::core::ics04_channel::timeout::TimeoutHeight;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn ibc_event_serde_roundtrip() {
+        let packet = Packet {
+            sequence: Sequence::from(1),
+            data: b"{\"amount\":\"100\"}".to_vec(),
+            timeout_height: TimeoutHeight::At(Height::new(0, 42).unwrap()),
+            timeout_timestamp: Timestamp::from_nanoseconds(1_000_000_000).unwrap(),
+            ..Packet::default()
+        };
+
+        let events = vec![
+            IbcEvent::NewBlock(NewBlock::new(Height::new(0, 10).unwrap())),
+            IbcEvent::SendPacket(ChannelEvents::SendPacket {
+                packet: packet.clone(),
+            }),
+            IbcEvent::WriteAcknowledgement(ChannelEvents::WriteAcknowledgement {
+                packet,
+                ack: b"{\"result\":\"AQ==\"}".to_vec(),
+            }),
+            IbcEvent::UpdateClient(UpdateClient {
+                common: Attributes {
+                    client_id: "07-tendermint-0".parse().unwrap(),
+                    client_type: ClientType::Tendermint,
+                    consensus_height: Height::new(0, 10).unwrap(),
+                },
+                header: Some(Box::new(get_dummy_ics07_header())),
+            }),
+            IbcEvent::ChainError("out of gas".to_string()),
+        ];
+
+        for event in events {
+            let json = serde_json::to_value(&event).unwrap();
+            let decoded: IbcEvent = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::mock::Misbehaviour as RawMisbehaviour;
 use ibc_proto::protobuf::Protobuf;
 use serde::{Deserialize, Serialize};
@@ -46,6 +47,29 @@ impl TryFrom<RawMisbehaviour> for Misbehaviour {
     }
 }
 
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Error> {
+        match raw.type_url.as_str() {
+            MOCK_MISBEHAVIOUR_TYPE_URL => Protobuf::<RawMisbehaviour>::decode_vec(&raw.value)
+                .map_err(Error::decode_raw_misbehaviour),
+            _ => Err(Error::unknown_misbehaviour_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(&misbehaviour),
+        }
+    }
+}
+
 impl From<Misbehaviour> for RawMisbehaviour {
     fn from(value: Misbehaviour) -> Self {
         RawMisbehaviour {
@@ -1,3 +1,4 @@
+use serde::de::{self, Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
 use subtle_encoding::{Encoding, Hex};
 
@@ -10,6 +11,18 @@ where
     hex.serialize(serializer)
 }
 
+/// Deserializes bytes from the upper-case hex string output by [`ser_hex_upper`].
+pub fn de_hex_upper<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = String::deserialize(deserializer)?;
+
+    Hex::upper_case()
+        .decode(hex.to_uppercase())
+        .map_err(de::Error::custom)
+}
+
 pub mod serde_string {
 
     use core::fmt::Display;
@@ -4,7 +4,7 @@
 //! concurrency of retrievals and a high expected concurrency for updates.
 use core::fmt::Formatter;
 use std::fmt;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use moka::sync::Cache as MokaCache;
 
@@ -13,6 +13,7 @@ use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
 use ibc_relayer_types::core::ics24_host::identifier::{ClientId, ConnectionId, PortChannelId};
 
+use crate::chain::endpoint::ChainStatus;
 use crate::client_state::AnyClientState;
 
 const CHANNEL_CACHE_TTL: Duration = Duration::from_secs(60);
@@ -48,6 +49,9 @@ pub struct Cache {
     client_states: MokaCache<ClientId, AnyClientState>,
     /// The latest `Height` associated with the chain runtime this `Cache` is associated with.
     latest_height: MokaCache<(), Height>,
+    /// The latest [`ChainStatus`] of the chain runtime this `Cache` is associated with,
+    /// along with the time at which it was fetched, as its time-to-live is configurable.
+    application_status: MokaCache<(), (Instant, ChainStatus)>,
 }
 
 impl Default for Cache {
@@ -79,11 +83,14 @@ impl Cache {
             .max_capacity(1)
             .build();
 
+        let application_status = MokaCache::builder().max_capacity(1).build();
+
         Cache {
             channels,
             connections,
             client_states,
             latest_height,
+            application_status,
         }
     }
 
@@ -175,6 +182,35 @@ impl Cache {
             Ok((height, CacheStatus::Miss))
         }
     }
+
+    /// Returns the latest [`ChainStatus`] if it exists in the cache and was fetched
+    /// less than `ttl` ago. Otherwise, attempts to fetch it via the supplied fetcher
+    /// function `F`. If `F` returns successfully with the chain status, a copy of it
+    /// is stored in the cache before it is returned.
+    ///
+    /// Like the latest height, this value is cached with a small time-to-live, so that
+    /// the workers sharing the chain, which query its status eg. to compute the timeouts
+    /// of packets, do not all query the full node within a small time frame.
+    pub fn get_or_try_update_application_status_with<F, E>(
+        &self,
+        ttl: Duration,
+        f: F,
+    ) -> CacheResult<ChainStatus, E>
+    where
+        F: FnOnce() -> Result<ChainStatus, E>,
+    {
+        match self.application_status.get(&()) {
+            Some((fetched_at, status)) if fetched_at.elapsed() < ttl => {
+                Ok((status, CacheStatus::Hit))
+            }
+            _ => {
+                let status = f()?;
+                self.application_status
+                    .insert((), (Instant::now(), status.clone()));
+                Ok((status, CacheStatus::Miss))
+            }
+        }
+    }
 }
 
 impl fmt::Debug for Cache {
@@ -182,3 +218,54 @@ impl fmt::Debug for Cache {
         f.debug_struct("Cache").finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::convert::Infallible;
+    use std::thread;
+
+    use ibc_relayer_types::timestamp::Timestamp;
+
+    fn status(height: u64) -> Result<ChainStatus, Infallible> {
+        Ok(ChainStatus {
+            height: Height::new(0, height).unwrap(),
+            timestamp: Timestamp::none(),
+        })
+    }
+
+    #[test]
+    fn application_status_is_cached_for_its_time_to_live() {
+        let cache = Cache::new();
+        let ttl = Duration::from_millis(100);
+
+        let (fetched, cache_status) = cache
+            .get_or_try_update_application_status_with(ttl, || status(1))
+            .unwrap();
+        assert_eq!(
+            (fetched.height.revision_height(), cache_status),
+            (1, CacheStatus::Miss)
+        );
+
+        // Within the time-to-live, the status fetched first is returned
+        let (cached, cache_status) = cache
+            .get_or_try_update_application_status_with(ttl, || status(2))
+            .unwrap();
+        assert_eq!(
+            (cached.height.revision_height(), cache_status),
+            (1, CacheStatus::Hit)
+        );
+
+        // Once it passed, the status is fetched again
+        thread::sleep(ttl);
+
+        let (refetched, cache_status) = cache
+            .get_or_try_update_application_status_with(ttl, || status(3))
+            .unwrap();
+        assert_eq!(
+            (refetched.height.revision_height(), cache_status),
+            (3, CacheStatus::Miss)
+        );
+    }
+}
@@ -7,13 +7,18 @@ use core::{
     time::Duration,
 };
 use futures::future::join_all;
+use moka::sync::Cache;
 use num_bigint::BigInt;
-use std::{cmp::Ordering, thread};
+use once_cell::sync::OnceCell;
+use std::{
+    cmp::Ordering,
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
 
 use tokio::runtime::Runtime as TokioRuntime;
-use tonic::codegen::http::Uri;
 use tonic::metadata::AsciiMetadataValue;
-use tracing::{error, instrument, trace, warn};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use ibc_proto::cosmos::{
     base::node::v1beta1::ConfigResponse, staking::v1beta1::Params as StakingParams,
@@ -25,6 +30,7 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_proto::protobuf::Protobuf;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     AllowUpdate, ClientState as TmClientState,
@@ -51,44 +57,57 @@ use ibc_relayer_types::core::ics24_host::path::{
 use ibc_relayer_types::core::ics24_host::{
     ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
 };
+use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height as ICSHeight;
 
 use tendermint::block::Height as TmHeight;
 use tendermint::node::{self, info::TxIndexStatus};
 use tendermint::time::Time as TmTime;
+use tendermint::Hash as TxHash;
 use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
 use tendermint_rpc::client::CompatMode;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::endpoint::status;
-use tendermint_rpc::{Client, HttpClient, Order};
+use tendermint_rpc::{Client, Order, WebSocketClientUrl};
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::adaptive_batch::{AdaptiveBatch, BatchOutcome};
 use crate::chain::cosmos::batch::{
-    send_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_commit,
-    sequential_send_batched_messages_and_wait_commit,
+    events_of_tx_sync_results, send_batched_messages_and_wait_check_tx,
+    send_batched_messages_and_wait_commit_results,
+    sequential_send_batched_messages_and_wait_commit_results,
 };
 use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::features::detect_features;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
-use crate::chain::cosmos::query::account::get_or_fetch_account;
+use crate::chain::cosmos::query::account::{get_or_fetch_account, query_account};
 use crate::chain::cosmos::query::balance::{query_all_balances, query_balance};
 use crate::chain::cosmos::query::consensus_state::query_consensus_state_heights;
 use crate::chain::cosmos::query::custom::cross_chain_query_via_rpc;
 use crate::chain::cosmos::query::denom_trace::query_denom_trace;
-use crate::chain::cosmos::query::fee::query_incentivized_packet;
+use crate::chain::cosmos::query::fee::{
+    query_all_incentivized_packets, query_counterparty_payee, query_incentivized_packet,
+    query_payee,
+};
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
-    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
+    all_ibc_events_from_tx_search_response, filter_matching_event, query_packets_from_block,
+    query_packets_from_txs, query_tx_response, query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
+use crate::chain::cosmos::simulate::simulate_messages;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
 };
-use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::cosmos::types::grpc::GrpcAddress;
+use crate::chain::cosmos::types::rpc::RpcClient;
+use crate::chain::cosmos::types::tx::TxSyncResult;
+use crate::chain::endpoint::{ChainEndpoint, ChainFeatures, ChainStatus, HealthCheck};
 use crate::chain::handle::Subscription;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
@@ -103,15 +122,21 @@ use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::{LightClient, Verified};
 use crate::misbehaviour::MisbehaviourEvidence;
+use crate::submitter::RemoteSubmitter;
 use crate::util::pretty::{
     PrettyIdentifiedChannel, PrettyIdentifiedClientState, PrettyIdentifiedConnection,
 };
+use crate::util::profile;
+use crate::util::proxy::{self, ProxyBridge, WebSocketBridge};
+use crate::util::rate_limit::RateLimiter;
 
+pub mod adaptive_batch;
 pub mod batch;
 pub mod client;
 pub mod compatibility;
 pub mod encode;
 pub mod estimate;
+pub mod features;
 pub mod fee;
 pub mod gas;
 pub mod query;
@@ -137,12 +162,24 @@ pub mod wait;
 ///
 /// [tm-37-max]: https://github.com/tendermint/tendermint/blob/v0.37.0-rc1/types/params.go#L79
 pub const BLOCK_MAX_BYTES_MAX_FRACTION: f64 = 0.9;
+
+/// How often to check whether the chain was upgraded to a new revision
+/// number, ie. whether its node now reports a new chain identifier.
+const CHAIN_ID_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long the transactions broadcast without waiting for their commit are kept
+/// track of, waiting for their confirmation to record how long they took to be committed.
+const PENDING_TX_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// The maximum number of transactions kept track of until their confirmation.
+const PENDING_TX_CAPACITY: u64 = 10_000;
+
 pub struct CosmosSdkChain {
     config: ChainConfig,
     tx_config: TxConfig,
-    rpc_client: HttpClient,
+    rpc_client: RpcClient,
     compat_mode: CompatMode,
-    grpc_addr: Uri,
+    grpc_addr: GrpcAddress,
     light_client: TmLightClient,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing<Secp256k1KeyPair>,
@@ -150,6 +187,25 @@ pub struct CosmosSdkChain {
     /// A cached copy of the account information
     account: Option<Account>,
 
+    /// The remote submitter signing and submitting the messages, if any
+    submitter: Option<RemoteSubmitter>,
+
+    /// The batch limits adapted to the block capacity of the chain, if enabled.
+    /// Guarded by a mutex as they are also adapted when the pending transactions
+    /// are confirmed, which is done with a shared reference to the chain.
+    adaptive_batch: Option<Mutex<AdaptiveBatch>>,
+
+    /// The transactions broadcast without waiting for their commit, along with
+    /// when they were broadcast, until they are confirmed. Only tracked if
+    /// adaptive batching is enabled, to adapt the limits to their outcome.
+    pending_txs: Cache<TxHash, Instant>,
+
+    /// When the chain identifier reported by the node was last checked
+    last_chain_id_check: Option<Instant>,
+
+    /// The optional features of IBC supported by the chain, once detected
+    features: OnceCell<ChainFeatures>,
+
     tx_monitor_cmd: Option<TxEventSourceCmd>,
 }
 
@@ -170,6 +226,18 @@ impl CosmosSdkChain {
             .map_err(Error::key_base)
     }
 
+    /// The address of the account of the given key, or else of the account
+    /// signing the messages, which is held by the remote submitter if any.
+    fn account_address(&self, key_name: Option<&str>) -> Result<String, Error> {
+        let key = match (key_name, &self.config.submitter) {
+            (Some(key_name), _) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
+            (None, Some(submitter)) => return Ok(submitter.signer.clone()),
+            (None, None) => self.key()?,
+        };
+
+        Ok(key.account())
+    }
+
     /// Fetches the trusting period as a `Duration` from the chain config.
     /// If no trusting period exists in the config, the trusting period is calculated
     /// as two-thirds of the `unbonding_period`.
@@ -186,6 +254,7 @@ impl CosmosSdkChain {
     ///     - the configured `max_tx_size` is appropriate
     ///     - the trusting period is greater than zero
     ///     - the trusting period is smaller than the unbonding period
+    ///     - the trusting period margin, if any, is smaller than the trusting period
     ///     - the default gas is smaller than the max gas
     ///
     /// Emits a log warning in case any error is encountered and
@@ -213,6 +282,17 @@ impl CosmosSdkChain {
             );
         }
 
+        // Check that the safety margin leaves some of the trusting period to use the consensus states
+        if let Some(margin) = self.config.trusting_period_margin {
+            if margin >= trusting_period {
+                return Err(Error::config_validation_trusting_period_margin_too_large(
+                    self.id().clone(),
+                    margin,
+                    trusting_period,
+                ));
+            }
+        }
+
         let max_gas = max_gas_from_config(&self.config);
         let default_gas = default_gas_from_config(&self.config);
 
@@ -286,6 +366,21 @@ impl CosmosSdkChain {
         Ok(())
     }
 
+    /// The URL the WebSocket client connects to, which is the one of a local bridge
+    /// forwarding the connections to the given endpoint if the chain has a proxy.
+    fn websocket_url(&self, url: &WebSocketClientUrl) -> Result<WebSocketClientUrl, Error> {
+        let Some(proxy) = &self.config.proxy else {
+            return Ok(url.clone());
+        };
+
+        let bridge = self
+            .rt
+            .block_on(WebSocketBridge::spawn(proxy.clone(), url))
+            .map_err(|e| Error::proxy_bridge(proxy.url.to_string(), e))?;
+
+        Ok(bridge.url().clone())
+    }
+
     fn init_event_source(&mut self) -> Result<TxEventSourceCmd, Error> {
         crate::time!(
             "init_event_source",
@@ -299,7 +394,7 @@ impl CosmosSdkChain {
         let (event_source, monitor_tx) = match &self.config.event_source {
             Mode::Push { url, batch_delay } => EventSource::websocket(
                 self.config.id.clone(),
-                url.clone(),
+                self.websocket_url(url)?,
                 self.compat_mode,
                 *batch_delay,
                 self.rt.clone(),
@@ -313,7 +408,7 @@ impl CosmosSdkChain {
         }
         .map_err(Error::event_source)?;
 
-        thread::spawn(move || event_source.run());
+        profile::spawn_thread(move || event_source.run());
 
         Ok(monitor_tx)
     }
@@ -329,11 +424,9 @@ impl CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_ccv_consumer_chain_params");
 
         let mut client = self
-            .block_on(
-                ibc_proto::interchain_security::ccv::consumer::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone()
-                ),
-            )
+            .block_on(self.grpc_addr.connect(
+                ibc_proto::interchain_security::ccv::consumer::v1::query_client::QueryClient::new,
+            ))
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -367,9 +460,8 @@ impl CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -421,11 +513,9 @@ impl CosmosSdkChain {
         }
 
         let mut client = self
-            .block_on(
-                ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
+            .block_on(self.grpc_addr.connect(
+                ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient::new,
+            ))
             .map_err(Error::grpc_transport)?;
 
         client = client
@@ -654,6 +744,16 @@ impl CosmosSdkChain {
             }
         );
 
+        if let Some(submitter) = &mut self.submitter {
+            return submitter
+                .submit_and_wait_commit(&self.config.id, tracked_msgs)
+                .await;
+        }
+
+        self.follow_chain_upgrade().await;
+        self.apply_batch_limits();
+
+        let limits = tracked_msgs.limits;
         let proto_msgs = tracked_msgs.msgs;
 
         let key_pair = self.key()?;
@@ -662,10 +762,12 @@ impl CosmosSdkChain {
         let account =
             get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
 
-        if self.config.sequential_batch_tx {
-            sequential_send_batched_messages_and_wait_commit(
+        let tx_config = self.tx_config.within(limits);
+
+        let result = if self.config.sequential_batch_tx {
+            sequential_send_batched_messages_and_wait_commit_results(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
@@ -673,18 +775,221 @@ impl CosmosSdkChain {
             )
             .await
         } else {
-            send_batched_messages_and_wait_commit(
+            send_batched_messages_and_wait_commit_results(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
                 proto_msgs,
             )
             .await
+        };
+
+        // Release the borrow of the tx config before adapting it
+        drop(tx_config);
+
+        self.adapt_batch_limits(&result).await;
+
+        result.map(events_of_tx_sync_results)
+    }
+
+    /// Follows an upgrade of the chain which bumped the revision number of its identifier,
+    /// eg. from `cosmoshub-3` to `cosmoshub-4`, so that the transactions are signed for,
+    /// and the heights built with, the new revision number.
+    ///
+    /// The node is queried at most once every [`CHAIN_ID_CHECK_INTERVAL`].
+    async fn follow_chain_upgrade(&mut self) {
+        if self
+            .last_chain_id_check
+            .map_or(false, |at| at.elapsed() < CHAIN_ID_CHECK_INTERVAL)
+        {
+            return;
+        }
+
+        self.last_chain_id_check = Some(Instant::now());
+
+        let network = match self.rpc_client.status().await {
+            Ok(status) => status.node_info.network,
+            Err(e) => {
+                debug!("failed to query the chain identifier reported by the node: {e}");
+                return;
+            }
+        };
+
+        let chain_id = ChainId::from_string(network.as_str());
+
+        if !self.config.id.is_upgraded_to(&chain_id) {
+            return;
+        }
+
+        warn!(
+            "chain was upgraded from {} to {}, now using revision number {}",
+            self.config.id,
+            chain_id,
+            chain_id.version()
+        );
+
+        self.light_client.set_chain_id(chain_id.clone());
+        self.tx_config.chain_id = chain_id.clone();
+        self.config.id = chain_id;
+    }
+
+    /// Sets the batch limits adapted to the outcome of the previous
+    /// transactions as the ones of the next transactions, if enabled.
+    fn apply_batch_limits(&mut self) {
+        if let Some(adaptive_batch) = &mut self.adaptive_batch {
+            adaptive_batch
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .apply(&mut self.tx_config);
+        }
+    }
+
+    /// Records the outcome of each of the transactions sent while waiting
+    /// for their commit, if adaptive batching is enabled.
+    async fn adapt_batch_limits(&mut self, result: &Result<Vec<TxSyncResult>, Error>) {
+        let Some(adaptive_batch) = &mut self.adaptive_batch else {
+            return;
+        };
+
+        let adaptive_batch = adaptive_batch
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let tx_sync_results = match result {
+            Ok(tx_sync_results) => tx_sync_results,
+            Err(e) => {
+                adaptive_batch.record(&BatchOutcome {
+                    congestion_error: BatchOutcome::is_congestion_error(e.category()),
+                    ..Default::default()
+                });
+
+                return;
+            }
+        };
+
+        for tx_sync_result in tx_sync_results {
+            let congestion_error = tx_sync_result.events.iter().any(|ev| {
+                matches!(&ev.event, IbcEvent::ChainError(log) if BatchOutcome::is_congestion_log(log))
+            });
+
+            // The transactions rejected by `check_tx` were not included in any block
+            let Some(latency) = tx_sync_result.latency else {
+                adaptive_batch.record(&BatchOutcome {
+                    congestion_error,
+                    ..Default::default()
+                });
+
+                continue;
+            };
+
+            match tx_sync_result.events.iter().map(|ev| ev.height).max() {
+                Some(height) => {
+                    adaptive_batch
+                        .record_delivered_tx(
+                            &self.rpc_client,
+                            height.revision_height(),
+                            latency,
+                            congestion_error,
+                        )
+                        .await
+                }
+                None => adaptive_batch.record(&BatchOutcome {
+                    latency,
+                    congestion_error,
+                    ..Default::default()
+                }),
+            }
+        }
+    }
+
+    /// Keeps track of the transactions broadcast without waiting for their commit,
+    /// so that their outcome is recorded once [`Self::query_txs`] confirms them,
+    /// if adaptive batching is enabled.
+    fn track_pending_txs(&mut self, result: Result<&[Response], &Error>) {
+        let Some(adaptive_batch) = &mut self.adaptive_batch else {
+            return;
+        };
+
+        let adaptive_batch = adaptive_batch
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let responses = match result {
+            Ok(responses) => responses,
+            Err(e) => {
+                adaptive_batch.record(&BatchOutcome {
+                    congestion_error: BatchOutcome::is_congestion_error(e.category()),
+                    ..Default::default()
+                });
+
+                return;
+            }
+        };
+
+        for response in responses {
+            if response.code.is_ok() {
+                self.pending_txs.insert(response.hash, Instant::now());
+            } else {
+                adaptive_batch.record(&BatchOutcome {
+                    congestion_error: BatchOutcome::is_congestion_log(&response.log),
+                    ..Default::default()
+                });
+            }
         }
     }
 
+    /// Queries a transaction broadcast without waiting for its commit, and records
+    /// its outcome, in the telemetry and the adaptive batch limits, if it was
+    /// included in a block.
+    ///
+    /// Its latency is measured until it is found to be included, so it is bounded
+    /// below by the interval at which the pending transactions are checked.
+    fn query_pending_tx(
+        &self,
+        hash: &TxHash,
+        sent_at: Instant,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let response = self.block_on(query_tx_response(
+            &self.rpc_client,
+            &self.config.rpc_addr,
+            hash,
+        ))?;
+
+        let Some(response) = response else {
+            return Ok(vec![]);
+        };
+
+        self.pending_txs.invalidate(hash);
+
+        crate::telemetry!(
+            tx_delivered,
+            self.id(),
+            response.tx_result.code.is_err(),
+            response.tx_result.gas_used,
+            response.tx_result.gas_wanted
+        );
+
+        if let Some(adaptive_batch) = &self.adaptive_batch {
+            let mut adaptive_batch = adaptive_batch
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            let congestion_error = response.tx_result.code.is_err()
+                && BatchOutcome::is_congestion_log(&response.tx_result.log);
+
+            self.block_on(adaptive_batch.record_delivered_tx(
+                &self.rpc_client,
+                response.height.value(),
+                sent_at.elapsed(),
+                congestion_error,
+            ));
+        }
+
+        Ok(all_ibc_events_from_tx_search_response(self.id(), response))
+    }
+
     #[instrument(
         name = "send_messages_and_wait_check_tx",
         level = "error",
@@ -697,7 +1002,7 @@ impl CosmosSdkChain {
     async fn do_send_messages_and_wait_check_tx(
         &mut self,
         tracked_msgs: TrackedMsgs,
-    ) -> Result<Vec<Response>, Error> {
+    ) -> Result<(Vec<Response>, Option<Vec<usize>>), Error> {
         crate::time!(
             "send_messages_and_wait_check_tx",
             {
@@ -705,6 +1010,18 @@ impl CosmosSdkChain {
             }
         );
 
+        if let Some(submitter) = &mut self.submitter {
+            // The submitter does not tell how the messages were batched
+            return submitter
+                .submit_and_wait_check_tx(&self.config.id, tracked_msgs)
+                .await
+                .map(|responses| (responses, None));
+        }
+
+        self.follow_chain_upgrade().await;
+        self.apply_batch_limits();
+
+        let limits = tracked_msgs.limits;
         let proto_msgs = tracked_msgs.msgs;
 
         let key_pair = self.key()?;
@@ -713,15 +1030,23 @@ impl CosmosSdkChain {
         let account =
             get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
 
-        send_batched_messages_and_wait_check_tx(
+        let result = send_batched_messages_and_wait_check_tx(
             &self.rpc_client,
-            &self.tx_config,
+            &self.tx_config.within(limits),
             &key_pair,
             account,
             &self.config.memo_prefix,
             proto_msgs,
         )
         .await
+        .map(|batches| {
+            let (message_counts, responses): (Vec<usize>, Vec<_>) = batches.into_iter().unzip();
+            (responses, Some(message_counts))
+        });
+
+        self.track_pending_txs(result.as_ref().map(|(responses, _)| responses.as_slice()));
+
+        result
     }
 
     fn query_packet_from_block(
@@ -845,9 +1170,32 @@ impl ChainEndpoint for CosmosSdkChain {
     type SigningKeyPair = Secp256k1KeyPair;
 
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        let mut rpc_client = HttpClient::new(config.rpc_addr.clone())
+        // The RPC client only supports unauthenticated HTTP proxies, so it connects
+        // to the configured proxy through a local bridge, which only forwards the
+        // connections to the RPC endpoints of the chain.
+        let mut rpc_endpoints = vec![&config.rpc_addr];
+        rpc_endpoints.extend(
+            config
+                .genesis_restart
+                .as_ref()
+                .map(|genesis_restart| &genesis_restart.archive_addr),
+        );
+
+        let rpc_proxy = config
+            .proxy
+            .as_ref()
+            .map(|proxy| {
+                rt.block_on(ProxyBridge::spawn(proxy.clone(), &rpc_endpoints))
+                    .map_err(|e| Error::proxy_bridge(proxy.url.to_string(), e))
+            })
+            .transpose()?;
+
+        let rpc_client = proxy::rpc_client(&config.rpc_addr, rpc_proxy.as_ref())
             .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
 
+        let mut rpc_client =
+            RpcClient::new(rpc_client, config.rate_limit.rpc.map(RateLimiter::new));
+
         let node_info = rt.block_on(fetch_node_info(&rpc_client, &config))?;
 
         let compat_mode = CompatMode::from_version(node_info.version).unwrap_or_else(|e| {
@@ -856,7 +1204,12 @@ impl ChainEndpoint for CosmosSdkChain {
         });
         rpc_client.set_compat_mode(compat_mode);
 
-        let light_client = TmLightClient::from_config(&config, node_info.id)?;
+        let light_client = TmLightClient::from_config(
+            &config,
+            node_info.id,
+            rpc_client.clone(),
+            rpc_proxy.as_ref(),
+        )?;
 
         // Initialize key store and load key
         let keybase = KeyRing::new_secp256k1(
@@ -867,11 +1220,30 @@ impl ChainEndpoint for CosmosSdkChain {
         )
         .map_err(Error::key_base)?;
 
-        let grpc_addr = Uri::from_str(&config.grpc_addr.to_string())
-            .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
-
         let tx_config = TxConfig::try_from(&config)?;
 
+        // Share the gRPC rate limiter between the queries and the transactions
+        let grpc_addr = tx_config.grpc_address.clone();
+
+        let submitter = config
+            .submitter
+            .as_ref()
+            .map(RemoteSubmitter::new)
+            .transpose()?;
+
+        let adaptive_batch = config.adaptive_batch.enabled.then(|| {
+            Mutex::new(AdaptiveBatch::new(
+                config.adaptive_batch,
+                config.max_msg_num,
+                config.max_tx_size,
+            ))
+        });
+
+        let pending_txs = Cache::builder()
+            .time_to_live(PENDING_TX_TTL)
+            .max_capacity(PENDING_TX_CAPACITY)
+            .build();
+
         // Retrieve the version specification of this chain
 
         let chain = Self {
@@ -884,6 +1256,11 @@ impl ChainEndpoint for CosmosSdkChain {
             keybase,
             tx_config,
             account: None,
+            submitter,
+            adaptive_batch,
+            pending_txs,
+            last_chain_id_check: None,
+            features: OnceCell::new(),
             tx_monitor_cmd: None,
         };
 
@@ -1014,13 +1391,47 @@ impl ChainEndpoint for CosmosSdkChain {
         &mut self,
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<Response>, Error> {
+        self.send_messages_and_wait_check_tx_batches(tracked_msgs)
+            .map(|(responses, _)| responses)
+    }
+
+    fn send_messages_and_wait_check_tx_batches(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<(Vec<Response>, Option<Vec<usize>>), Error> {
         let runtime = self.rt.clone();
 
         runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs))
     }
 
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error> {
+        let key_pair = self.key()?;
+
+        // Fetch the account rather than using the cached one, which is
+        // only refreshed when sending the transactions
+        let account: Account = self
+            .block_on(query_account(&self.grpc_addr, &key_pair.account()))?
+            .into();
+
+        self.block_on(simulate_messages(
+            &self.tx_config,
+            &key_pair,
+            &account,
+            &self.config.memo_prefix,
+            &tracked_msgs.msgs,
+        ))
+    }
+
     /// Get the account for the signer
     fn get_signer(&self) -> Result<Signer, Error> {
+        // The messages are signed by the account of the remote submitter, if any
+        if let Some(submitter) = &self.config.submitter {
+            return submitter
+                .signer
+                .parse()
+                .map_err(|e| Error::ics02(ClientError::signer(e)));
+        }
+
         // Get the key from key seed file
         let key_pair = self.key()?;
 
@@ -1029,6 +1440,32 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(signer)
     }
 
+    fn reload_key(&mut self, key_name: Option<String>) -> Result<Signer, Error> {
+        let key_name = key_name.unwrap_or_else(|| self.config.key_name.clone());
+
+        // Check that the key can be loaded before switching to it
+        let key_pair = self
+            .keybase()
+            .get_key(&key_name)
+            .map_err(|e| Error::key_not_found(key_name.clone(), e))?;
+
+        let signer = key_pair_to_signer(&key_pair)?;
+
+        self.config.key_name = key_name;
+
+        // The cached account belongs to the previous key, so the number and sequence
+        // of the account of the reloaded key are fetched before the next transaction
+        self.account = None;
+
+        info!(
+            key_name = %self.config.key_name,
+            %signer,
+            "reloaded the key signing the transactions"
+        );
+
+        Ok(signer)
+    }
+
     /// Get the chain configuration
     fn config(&self) -> &ChainConfig {
         &self.config
@@ -1039,14 +1476,24 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(version_specs.ibc_go)
     }
 
+    fn query_features(&self) -> Result<ChainFeatures, Error> {
+        self.features
+            .get_or_try_init(|| {
+                let version_specs =
+                    self.block_on(fetch_version_specs(self.id(), &self.grpc_addr))?;
+
+                self.block_on(detect_features(
+                    &self.grpc_addr,
+                    version_specs.ibc_go.as_ref(),
+                ))
+            })
+            .copied()
+    }
+
     fn query_balance(&self, key_name: Option<&str>, denom: Option<&str>) -> Result<Balance, Error> {
         // If a key_name is given, extract the account hash.
         // Else retrieve the account from the configuration file.
-        let key = match key_name {
-            Some(key_name) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
-            None => self.key()?,
-        };
-        let account = key.account();
+        let account = self.account_address(key_name)?;
 
         let denom = denom.unwrap_or(&self.config.gas_price.denom);
         let balance = self.block_on(query_balance(&self.grpc_addr, &account, denom))?;
@@ -1057,11 +1504,7 @@ impl ChainEndpoint for CosmosSdkChain {
     fn query_all_balances(&self, key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
         // If a key_name is given, extract the account hash.
         // Else retrieve the account from the configuration file.
-        let key = match key_name {
-            Some(key_name) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
-            None => self.key()?,
-        };
-        let account = key.account();
+        let account = self.account_address(key_name)?;
 
         let balance = self.block_on(query_all_balances(&self.grpc_addr, &account))?;
 
@@ -1134,9 +1577,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::client::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1185,11 +1627,22 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_client_state");
 
+        #[cfg(feature = "telemetry")]
+        let start = Instant::now();
+
         let res = self.query(
             ClientStatePath(request.client_id.clone()),
             request.height,
             matches!(include_proof, IncludeProof::Yes),
         )?;
+
+        crate::telemetry!(
+            query_latency,
+            self.id(),
+            "query_client_state",
+            start.elapsed()
+        );
+
         let client_state = AnyClientState::decode_vec(&res.value).map_err(Error::decode)?;
 
         match include_proof {
@@ -1283,6 +1736,9 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_consensus_state");
 
+        #[cfg(feature = "telemetry")]
+        let start = Instant::now();
+
         let res = self.query(
             ClientConsensusStatePath {
                 client_id: request.client_id.clone(),
@@ -1293,6 +1749,13 @@ impl ChainEndpoint for CosmosSdkChain {
             matches!(include_proof, IncludeProof::Yes),
         )?;
 
+        crate::telemetry!(
+            query_latency,
+            self.id(),
+            "query_consensus_state",
+            start.elapsed()
+        );
+
         let consensus_state = AnyConsensusState::decode_vec(&res.value).map_err(Error::decode)?;
 
         if !matches!(consensus_state, AnyConsensusState::Tendermint(_)) {
@@ -1325,9 +1788,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::connection::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1369,9 +1831,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::connection::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1425,10 +1886,11 @@ impl ChainEndpoint for CosmosSdkChain {
             use ibc_proto::ibc::core::connection::v1 as connection;
             use tonic::IntoRequest;
 
-            let mut client =
-                connection::query_client::QueryClient::connect(chain.grpc_addr.clone())
-                    .await
-                    .map_err(Error::grpc_transport)?;
+            let mut client = chain
+                .grpc_addr
+                .connect(connection::query_client::QueryClient::new)
+                .await
+                .map_err(Error::grpc_transport)?;
 
             client = client.max_decoding_message_size(
                 chain.config().max_grpc_decoding_size.get_bytes() as usize,
@@ -1506,9 +1968,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1554,9 +2015,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1633,9 +2093,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1661,6 +2120,11 @@ impl ChainEndpoint for CosmosSdkChain {
         request: QueryPacketCommitmentRequest,
         include_proof: IncludeProof,
     ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        crate::telemetry!(query, self.id(), "query_packet_commitment");
+
+        #[cfg(feature = "telemetry")]
+        let start = Instant::now();
+
         let res = self.query(
             CommitmentsPath {
                 port_id: request.port_id,
@@ -1671,6 +2135,13 @@ impl ChainEndpoint for CosmosSdkChain {
             matches!(include_proof, IncludeProof::Yes),
         )?;
 
+        crate::telemetry!(
+            query_latency,
+            self.id(),
+            "query_packet_commitment",
+            start.elapsed()
+        );
+
         match include_proof {
             IncludeProof::Yes => {
                 let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
@@ -1696,9 +2167,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1767,9 +2237,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1835,9 +2304,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1880,9 +2348,8 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let mut client = self
             .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
+                self.grpc_addr
+                    .connect(ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new),
             )
             .map_err(Error::grpc_transport)?;
 
@@ -1937,12 +2404,10 @@ impl ChainEndpoint for CosmosSdkChain {
                 Ok((seq, Some(proof)))
             }
             IncludeProof::No => {
-                let mut client = self
-                    .block_on(
-                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                            self.grpc_addr.clone(),
-                        ),
-                    )
+                let mut client =
+                    self.block_on(self.grpc_addr.connect(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::new,
+                    ))
                     .map_err(Error::grpc_transport)?;
 
                 client = client.max_decoding_message_size(
@@ -1971,6 +2436,12 @@ impl ChainEndpoint for CosmosSdkChain {
         });
         crate::telemetry!(query, self.id(), "query_txs");
 
+        if let QueryTxRequest::Transaction(QueryTxHash(hash)) = &request {
+            if let Some(sent_at) = self.pending_txs.get(hash) {
+                return self.query_pending_tx(hash, sent_at);
+            }
+        }
+
         self.block_on(query_txs(
             self.id(),
             &self.rpc_client,
@@ -2209,6 +2680,49 @@ impl ChainEndpoint for CosmosSdkChain {
             self.block_on(query_incentivized_packet(&self.grpc_addr, request))?;
         Ok(incentivized_response)
     }
+
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        crate::time!(
+            "query_incentivized_packets",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_incentivized_packets");
+
+        self.block_on(query_all_incentivized_packets(&self.grpc_addr, request))
+    }
+
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        crate::telemetry!(query, self.id(), "query_payee");
+
+        let payee = self.block_on(query_payee(&self.grpc_addr, channel_id, relayer))?;
+
+        Ok(payee.and_then(|payee| payee.parse().ok()))
+    }
+
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        crate::telemetry!(query, self.id(), "query_counterparty_payee");
+
+        let payee = self.block_on(query_counterparty_payee(
+            &self.grpc_addr,
+            channel_id,
+            relayer,
+        ))?;
+
+        Ok(payee.and_then(|payee| payee.parse().ok()))
+    }
 }
 
 fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
@@ -2222,7 +2736,7 @@ fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
 }
 
 async fn fetch_node_info(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &ChainConfig,
 ) -> Result<node::Info, Error> {
     crate::time!("fetch_node_info",
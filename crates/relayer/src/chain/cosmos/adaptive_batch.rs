@@ -0,0 +1,300 @@
+//! Adapts the batch limits of a chain to its block capacity, by shrinking the batches
+//! while the chain is congested, and growing them back while its blocks have room to spare.
+
+use core::time::Duration;
+
+use tendermint::block::Height as TmHeight;
+use tendermint_rpc::Client;
+use tracing::debug;
+
+use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::rpc::RpcClient;
+use crate::config::adaptive_batch::AdaptiveBatchConfig;
+use crate::config::types::{MaxMsgNum, MaxTxSize};
+use crate::error::ErrorCategory;
+
+/// What was observed when sending a transaction to the chain.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct BatchOutcome {
+    /// The time it took for the transaction to be included in a block
+    pub latency: Duration,
+
+    /// The fraction of the gas of the block which included the transaction, if known
+    pub block_utilization: Option<f64>,
+
+    /// Whether the transaction ran out of gas or was rejected by a full mempool
+    pub congestion_error: bool,
+}
+
+impl BatchOutcome {
+    pub fn is_congestion_error(category: ErrorCategory) -> bool {
+        matches!(
+            category,
+            ErrorCategory::OutOfGas | ErrorCategory::MempoolFull
+        )
+    }
+
+    /// Whether the error logged by the chain for a transaction is a sign of congestion.
+    pub fn is_congestion_log(log: &str) -> bool {
+        Self::is_congestion_error(ErrorCategory::from_tx_log(log))
+    }
+}
+
+/// The current batch limits of a chain, between the configured minima and maxima.
+#[derive(Clone, Debug)]
+pub struct AdaptiveBatch {
+    config: AdaptiveBatchConfig,
+    max_msg_num: usize,
+    max_tx_size: usize,
+    msg_num: usize,
+    tx_size: usize,
+
+    /// The maximum gas of a block, as set by the consensus parameters
+    /// of the chain, which is fetched on the first outcome recorded
+    block_max_gas: Option<Option<u64>>,
+
+    /// The gas utilization of the last block queried, so that it is
+    /// only queried once for all the transactions it included
+    last_block_utilization: Option<(u64, Option<f64>)>,
+}
+
+impl AdaptiveBatch {
+    /// Starts from the configured limits, which are the largest the batches can grow to.
+    pub fn new(
+        config: AdaptiveBatchConfig,
+        max_msg_num: MaxMsgNum,
+        max_tx_size: MaxTxSize,
+    ) -> Self {
+        let max_msg_num = max_msg_num.to_usize();
+        let max_tx_size = max_tx_size.to_usize();
+
+        Self {
+            config,
+            max_msg_num,
+            max_tx_size,
+            msg_num: max_msg_num,
+            tx_size: max_tx_size,
+            block_max_gas: None,
+            last_block_utilization: None,
+        }
+    }
+
+    pub fn msg_num(&self) -> usize {
+        self.msg_num
+    }
+
+    pub fn tx_size(&self) -> usize {
+        self.tx_size
+    }
+
+    /// Shrinks the limits by half when the chain is congested, and grows
+    /// them by a quarter when the block had plenty of room to spare.
+    pub fn record(&mut self, outcome: &BatchOutcome) {
+        let min_msg_num = self.config.min_msg_num.clamp(1, self.max_msg_num);
+        let min_tx_size = self.config.min_tx_size.min(self.max_tx_size);
+
+        let slow = outcome.latency > self.config.target_latency;
+
+        let congested = outcome.congestion_error
+            || slow
+            || outcome
+                .block_utilization
+                .map_or(false, |u| u > self.config.high_utilization);
+
+        let idle = !slow
+            && outcome
+                .block_utilization
+                .map_or(false, |u| u < self.config.low_utilization);
+
+        let (msg_num, tx_size) = if congested {
+            (
+                (self.msg_num / 2).max(min_msg_num),
+                (self.tx_size / 2).max(min_tx_size),
+            )
+        } else if idle {
+            (
+                (self.msg_num + (self.msg_num / 4).max(1)).min(self.max_msg_num),
+                (self.tx_size + (self.tx_size / 4).max(1)).min(self.max_tx_size),
+            )
+        } else {
+            return;
+        };
+
+        if (msg_num, tx_size) != (self.msg_num, self.tx_size) {
+            debug!(
+                ?outcome,
+                "adapting batch limits from {} messages and {} bytes to {} messages and {} bytes",
+                self.msg_num,
+                self.tx_size,
+                msg_num,
+                tx_size,
+            );
+        }
+
+        self.msg_num = msg_num;
+        self.tx_size = tx_size;
+    }
+
+    /// Records the outcome of a transaction which was included in the block
+    /// at the given height, the given time after it was broadcast.
+    pub async fn record_delivered_tx(
+        &mut self,
+        rpc_client: &RpcClient,
+        height: u64,
+        latency: Duration,
+        congestion_error: bool,
+    ) {
+        let block_utilization = self.query_block_utilization(rpc_client, height).await;
+
+        self.record(&BatchOutcome {
+            latency,
+            block_utilization,
+            congestion_error,
+        });
+    }
+
+    /// Sets the current limits as the ones of the transactions sent to the chain.
+    ///
+    /// The size is only the one the messages are grouped into, so that a single message
+    /// larger than it can still be sent, within the configured `max_tx_size`.
+    pub fn apply(&self, tx_config: &mut TxConfig) {
+        if let Ok(max_msg_num) = MaxMsgNum::new(self.msg_num) {
+            tx_config.max_msg_num = max_msg_num;
+        }
+
+        tx_config.target_tx_size = MaxTxSize::new(self.tx_size).ok();
+    }
+
+    /// The fraction of the maximum gas of the block at the given height used by its
+    /// transactions, or `None` if it cannot be queried or the block gas is unlimited.
+    pub async fn query_block_utilization(
+        &mut self,
+        rpc_client: &RpcClient,
+        height: u64,
+    ) -> Option<f64> {
+        if let Some((last_height, utilization)) = self.last_block_utilization {
+            if last_height == height {
+                return utilization;
+            }
+        }
+
+        let utilization = self.fetch_block_utilization(rpc_client, height).await;
+        self.last_block_utilization = Some((height, utilization));
+        utilization
+    }
+
+    async fn fetch_block_utilization(
+        &mut self,
+        rpc_client: &RpcClient,
+        height: u64,
+    ) -> Option<f64> {
+        let height = TmHeight::try_from(height).ok()?;
+
+        let block_max_gas = match self.block_max_gas {
+            Some(block_max_gas) => block_max_gas,
+            None => {
+                let params = rpc_client.consensus_params(height).await.ok()?;
+
+                // A negative maximum gas means that the gas of the blocks is unlimited
+                let block_max_gas = u64::try_from(params.consensus_params.block.max_gas).ok();
+                self.block_max_gas = Some(block_max_gas);
+                block_max_gas
+            }
+        };
+
+        let block_max_gas = block_max_gas.filter(|gas| *gas > 0)?;
+
+        let results = rpc_client.block_results(height).await.ok()?;
+
+        let gas_used: i64 = results
+            .txs_results
+            .unwrap_or_default()
+            .iter()
+            .map(|tx| tx.gas_used)
+            .sum();
+
+        Some(gas_used.max(0) as f64 / block_max_gas as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adaptive_batch() -> AdaptiveBatch {
+        let config = AdaptiveBatchConfig {
+            enabled: true,
+            min_msg_num: 2,
+            min_tx_size: 1000,
+            ..Default::default()
+        };
+
+        AdaptiveBatch::new(
+            config,
+            MaxMsgNum::new(16).unwrap(),
+            MaxTxSize::new(8000).unwrap(),
+        )
+    }
+
+    #[test]
+    fn shrink_on_congestion_and_grow_back() {
+        let mut batch = adaptive_batch();
+
+        for _ in 0..10 {
+            batch.record(&BatchOutcome {
+                congestion_error: true,
+                ..Default::default()
+            });
+        }
+
+        assert_eq!((batch.msg_num(), batch.tx_size()), (2, 1000));
+
+        batch.record(&BatchOutcome {
+            latency: Duration::from_secs(60),
+            block_utilization: Some(0.1),
+            ..Default::default()
+        });
+
+        assert_eq!((batch.msg_num(), batch.tx_size()), (2, 1000));
+
+        for _ in 0..20 {
+            batch.record(&BatchOutcome {
+                latency: Duration::from_secs(1),
+                block_utilization: Some(0.1),
+                ..Default::default()
+            });
+        }
+
+        assert_eq!((batch.msg_num(), batch.tx_size()), (16, 8000));
+    }
+
+    #[test]
+    fn detect_congestion_logs() {
+        assert!(BatchOutcome::is_congestion_log(
+            "out of gas in location: WriteFlat; gasWanted: 100, gasUsed: 120"
+        ));
+        assert!(BatchOutcome::is_congestion_log("mempool is full"));
+        assert!(!BatchOutcome::is_congestion_log(
+            "account sequence mismatch, expected 2, got 1"
+        ));
+    }
+
+    #[test]
+    fn keep_limits_when_utilization_is_moderate() {
+        let mut batch = adaptive_batch();
+
+        batch.record(&BatchOutcome {
+            block_utilization: Some(0.95),
+            ..Default::default()
+        });
+
+        assert_eq!((batch.msg_num(), batch.tx_size()), (8, 4000));
+
+        batch.record(&BatchOutcome {
+            block_utilization: Some(0.7),
+            ..Default::default()
+        });
+
+        assert_eq!((batch.msg_num(), batch.tx_size()), (8, 4000));
+    }
+}
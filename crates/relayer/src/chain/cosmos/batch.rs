@@ -1,4 +1,5 @@
 use core::mem;
+use std::time::Instant;
 
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -6,7 +7,6 @@ use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
 use prost::Message;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
-use tendermint_rpc::HttpClient;
 use tracing::debug;
 
 use crate::chain::cosmos::encode::encoded_tx_metrics;
@@ -14,6 +14,7 @@ use crate::chain::cosmos::gas::gas_amount_to_fee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
 use crate::chain::cosmos::wait::wait_for_block_commits;
 use crate::config::types::Memo;
@@ -29,13 +30,31 @@ use crate::keyring::Secp256k1KeyPair;
    priority mempool is enabled.
 */
 pub async fn send_batched_messages_and_wait_commit(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let tx_sync_results = send_batched_messages_and_wait_commit_results(
+        rpc_client, config, key_pair, account, tx_memo, messages,
+    )
+    .await?;
+
+    Ok(events_of_tx_sync_results(tx_sync_results))
+}
+
+/// Same as [`send_batched_messages_and_wait_commit`], but returns the result
+/// of each transaction, eg. to find out how long it took to be committed.
+pub async fn send_batched_messages_and_wait_commit_results(
+    rpc_client: &RpcClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<TxSyncResult>, Error> {
     if messages.is_empty() {
         return Ok(Vec::new());
     }
@@ -52,12 +71,7 @@ pub async fn send_batched_messages_and_wait_commit(
     )
     .await?;
 
-    let events = tx_sync_results
-        .into_iter()
-        .flat_map(|el| el.events)
-        .collect();
-
-    Ok(events)
+    Ok(tx_sync_results)
 }
 
 /**
@@ -66,38 +80,57 @@ pub async fn send_batched_messages_and_wait_commit(
    are committed in the wrong order due to interference from priority mempool.
 */
 pub async fn sequential_send_batched_messages_and_wait_commit(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let tx_sync_results = sequential_send_batched_messages_and_wait_commit_results(
+        rpc_client, config, key_pair, account, tx_memo, messages,
+    )
+    .await?;
+
+    Ok(events_of_tx_sync_results(tx_sync_results))
+}
+
+/// Same as [`sequential_send_batched_messages_and_wait_commit`], but returns the result
+/// of each transaction, eg. to find out how long it took to be committed.
+pub async fn sequential_send_batched_messages_and_wait_commit_results(
+    rpc_client: &RpcClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<TxSyncResult>, Error> {
     if messages.is_empty() {
         return Ok(Vec::new());
     }
 
-    let tx_sync_results = sequential_send_messages_as_batches(
-        rpc_client, config, key_pair, account, tx_memo, messages,
-    )
-    .await?;
+    sequential_send_messages_as_batches(rpc_client, config, key_pair, account, tx_memo, messages)
+        .await
+}
 
-    let events = tx_sync_results
+pub fn events_of_tx_sync_results(tx_sync_results: Vec<TxSyncResult>) -> Vec<IbcEventWithHeight> {
+    tx_sync_results
         .into_iter()
         .flat_map(|el| el.events)
-        .collect();
-
-    Ok(events)
+        .collect()
 }
 
+/// Broadcasts messages as multiple batched transactions, and returns the
+/// `CheckTx` response to each transaction along with how many of the
+/// messages, in order, it carries.
 pub async fn send_batched_messages_and_wait_check_tx(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
-) -> Result<Vec<Response>, Error> {
+) -> Result<Vec<(usize, Response)>, Error> {
     if messages.is_empty() {
         return Ok(Vec::new());
     }
@@ -112,14 +145,14 @@ pub async fn send_batched_messages_and_wait_check_tx(
         )
         .await?;
 
-        responses.push(response);
+        responses.push((batch.len(), response));
     }
 
     Ok(responses)
 }
 
 async fn send_messages_as_batches(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
@@ -160,7 +193,7 @@ async fn send_messages_as_batches(
 }
 
 async fn sequential_send_messages_as_batches(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
@@ -228,12 +261,16 @@ fn response_to_tx_sync_result(
             response,
             events: events_per_tx,
             status: TxStatus::ReceivedResponse,
+            sent_at: Instant::now(),
+            latency: None,
         }
     } else {
         TxSyncResult {
             response,
             events: Vec::new(),
             status: TxStatus::Pending { message_count },
+            sent_at: Instant::now(),
+            latency: None,
         }
     }
 }
@@ -246,7 +283,10 @@ fn batch_messages(
     messages: Vec<Any>,
 ) -> Result<Vec<Vec<Any>>, Error> {
     let max_message_count = config.max_msg_num.to_usize();
-    let max_tx_size = config.max_tx_size.into();
+    let max_tx_size: usize = config.max_tx_size.into();
+    let target_tx_size = config
+        .target_tx_size
+        .map_or(max_tx_size, |size| size.to_usize().min(max_tx_size));
 
     let mut batches = vec![];
 
@@ -279,18 +319,23 @@ fn batch_messages(
         let tagged_len = 1 + prost::length_delimiter_len(message_len) + message_len;
 
         if current_count >= max_message_count
-            || tx_len(tx_envelope_len, current_len + tagged_len) > max_tx_size
+            || tx_len(tx_envelope_len, current_len + tagged_len) > target_tx_size
         {
             let insert_batch = mem::take(&mut current_batch);
 
             if insert_batch.is_empty() {
                 assert!(max_message_count != 0);
-                return Err(Error::message_too_big_for_tx(message_len));
-            }
 
-            batches.push(insert_batch);
-            current_count = 0;
-            current_len = empty_body_len;
+                // A message larger than the target size is sent in a batch of its own,
+                // as long as it fits within the maximum size of a transaction
+                if tx_len(tx_envelope_len, current_len + tagged_len) > max_tx_size {
+                    return Err(Error::message_too_big_for_tx(message_len));
+                }
+            } else {
+                batches.push(insert_batch);
+                current_count = 0;
+                current_len = empty_body_len;
+            }
         }
 
         current_count += 1;
@@ -455,6 +500,50 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn batch_within_target_tx_size() {
+        const MAX_TX_SIZE: usize = 203;
+
+        let (config, key_pair, account) = test_fixture();
+        let message = Any {
+            type_url: "/example.Foo".into(),
+            value: vec![0; 6],
+        };
+        let memo = Memo::new("example").unwrap();
+
+        let mut limited_config = config;
+        limited_config.max_msg_num = MaxMsgNum::default();
+        limited_config.max_tx_size = MaxTxSize::new(MAX_TX_SIZE * 2).unwrap();
+        limited_config.target_tx_size = Some(MaxTxSize::new(MAX_TX_SIZE).unwrap());
+
+        let batches = batch_messages(
+            &limited_config,
+            &key_pair,
+            &account,
+            &memo,
+            vec![message.clone(), message.clone()],
+        )
+        .unwrap();
+
+        assert_eq!(batches.len(), 2);
+
+        // A message larger than the target size is sent on its own,
+        // as long as it fits within the maximum size of a transaction
+        limited_config.target_tx_size = Some(MaxTxSize::new(MAX_TX_SIZE - 1).unwrap());
+
+        let batches = batch_messages(
+            &limited_config,
+            &key_pair,
+            &account,
+            &memo,
+            vec![message.clone(), message],
+        )
+        .unwrap();
+
+        assert_eq!(batches.len(), 2);
+        assert!(batches.iter().all(|batch| batch.len() == 1));
+    }
+
     #[test]
     fn test_batches_are_structured_appropriately_per_max_msg_num() {
         let (config, key_pair, account) = test_fixture();
@@ -1,21 +1,25 @@
+use alloc::borrow::Cow;
 use ibc_proto::cosmos::tx::v1beta1::{Fee, Tx};
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
-use tonic::codegen::http::Uri;
 use tracing::{debug, error, span, warn, Level};
 
 use crate::chain::cosmos::encode::sign_tx;
-use crate::chain::cosmos::gas::gas_amount_to_fee;
+use crate::chain::cosmos::gas::{dynamic_gas_price, gas_amount_to_fee};
+use crate::chain::cosmos::query::fee_market::query_eip_base_fee;
 use crate::chain::cosmos::simulate::send_tx_simulate;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::GasConfig;
+use crate::chain::cosmos::types::grpc::GrpcAddress;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::keyring::Secp256k1KeyPair;
 use crate::util::pretty::PrettyFee;
 
 pub async fn estimate_tx_fees(
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &Account,
@@ -44,15 +48,63 @@ pub async fn estimate_tx_fees(
         signatures: signed_tx.signatures,
     };
 
+    let gas_config = gas_config_with_dynamic_price(rpc_client, config).await;
+
     let estimated_fee =
-        estimate_fee_with_tx(gas_config, &config.grpc_address, &config.chain_id, tx).await?;
+        estimate_fee_with_tx(&gas_config, &config.grpc_address, &config.chain_id, tx).await?;
 
     Ok(estimated_fee)
 }
 
+/// The gas config of the transaction, whose gas price is derived from the current base fee
+/// of the chain if the dynamic gas price is enabled, or else is the static gas price.
+async fn gas_config_with_dynamic_price<'a>(
+    rpc_client: &RpcClient,
+    config: &'a TxConfig,
+) -> Cow<'a, GasConfig> {
+    let gas_config = &config.gas_config;
+
+    if !gas_config.dynamic_gas_price.enabled {
+        return Cow::Borrowed(gas_config);
+    }
+
+    match query_eip_base_fee(rpc_client, &config.rpc_address).await {
+        Ok(base_fee) => {
+            let gas_price = dynamic_gas_price(
+                &gas_config.dynamic_gas_price,
+                &gas_config.gas_price,
+                base_fee,
+            );
+
+            debug!(
+                id = %config.chain_id,
+                "using dynamic gas price {} derived from base fee {}",
+                gas_price,
+                base_fee
+            );
+
+            Cow::Owned(GasConfig {
+                gas_price,
+                ..gas_config.clone()
+            })
+        }
+
+        Err(e) => {
+            warn!(
+                id = %config.chain_id,
+                "failed to query the base fee, falling back on the static gas price {}: {}",
+                gas_config.gas_price,
+                e
+            );
+
+            Cow::Borrowed(gas_config)
+        }
+    }
+}
+
 async fn estimate_fee_with_tx(
     gas_config: &GasConfig,
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     chain_id: &ChainId,
     tx: Tx,
 ) -> Result<Fee, Error> {
@@ -102,7 +154,7 @@ async fn estimate_fee_with_tx(
 /// In this case we use the `default_gas` param.
 async fn estimate_gas_with_tx(
     gas_config: &GasConfig,
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     tx: Tx,
 ) -> Result<u64, Error> {
     let simulated_gas = send_tx_simulate(grpc_address, tx)
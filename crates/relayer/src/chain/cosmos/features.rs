@@ -0,0 +1,145 @@
+//! Detection of the optional features of IBC supported by a Cosmos SDK chain.
+
+use ibc_proto::ibc::applications::fee::v1::query_client::QueryClient as FeeQueryClient;
+use ibc_proto::ibc::applications::fee::v1::QueryFeeEnabledChannelsRequest;
+use tonic::client::Grpc;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::{Code, Request, Status};
+use tracing::warn;
+
+use crate::chain::cosmos::types::grpc::GrpcAddress;
+use crate::chain::endpoint::ChainFeatures;
+use crate::chain::requests::PageRequest;
+use crate::error::Error;
+
+/// The first version of ibc-go supporting channel upgrades
+const CHANNEL_UPGRADES_IBC_GO_VERSION: semver::Version = semver::Version::new(8, 1, 0);
+
+/// The query of the packet forward middleware, which writes the acknowledgements of the
+/// packets it forwards once the next hop acknowledges them
+const PACKET_FORWARD_PARAMS_PATH: &str = "/packetforward.v1.Query/Params";
+
+/// The empty request and response of the parameters query of the packet forward middleware,
+/// whose parameters are ignored as only whether the query is served matters
+#[derive(Clone, PartialEq, prost::Message)]
+struct EmptyMessage {}
+
+/// Detects the optional features of IBC supported by the chain, by probing
+/// its gRPC services and looking at the version of its ibc-go module.
+pub async fn detect_features(
+    grpc_address: &GrpcAddress,
+    ibc_go_version: Option<&semver::Version>,
+) -> Result<ChainFeatures, Error> {
+    let fee_middleware = has_fee_middleware(grpc_address).await?;
+    let async_acknowledgements = has_packet_forward_middleware(grpc_address).await?;
+
+    Ok(ChainFeatures {
+        fee_middleware,
+        channel_upgrades: supports_channel_upgrades(ibc_go_version),
+        async_acknowledgements,
+    })
+}
+
+fn supports_channel_upgrades(ibc_go_version: Option<&semver::Version>) -> bool {
+    ibc_go_version.map_or(false, |version| *version >= CHANNEL_UPGRADES_IBC_GO_VERSION)
+}
+
+/// Whether the chain serves the queries of the fee middleware, which is
+/// the case if and only if the application wires the middleware.
+async fn has_fee_middleware(grpc_address: &GrpcAddress) -> Result<bool, Error> {
+    let mut client = grpc_address
+        .connect(FeeQueryClient::new)
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    let request = QueryFeeEnabledChannelsRequest {
+        pagination: Some(
+            PageRequest {
+                limit: 1,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        query_height: 0,
+    };
+
+    let probe = client.fee_enabled_channels(request).await.map(|_| ());
+
+    Ok(middleware_from_probe("fee", probe))
+}
+
+/// Whether the chain serves the queries of the packet forward middleware, the middleware
+/// acknowledging packets asynchronously. Its queries are not part of the IBC protos,
+/// so the probe is sent as a raw gRPC request.
+async fn has_packet_forward_middleware(grpc_address: &GrpcAddress) -> Result<bool, Error> {
+    let mut client = grpc_address
+        .connect(Grpc::new)
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    let probe = async {
+        client
+            .ready()
+            .await
+            .map_err(|e| Status::unknown(format!("service was not ready: {e}")))?;
+
+        client
+            .unary::<_, EmptyMessage, _>(
+                Request::new(EmptyMessage {}),
+                PathAndQuery::from_static(PACKET_FORWARD_PARAMS_PATH),
+                ProstCodec::default(),
+            )
+            .await
+            .map(|_| ())
+    };
+
+    Ok(middleware_from_probe("packet forward", probe.await))
+}
+
+/// Only a chain which does not serve the queries of a middleware at all lacks it,
+/// any other failure of the probe is assumed to be transient.
+fn middleware_from_probe(middleware: &str, probe: Result<(), Status>) -> bool {
+    match probe {
+        Ok(()) => true,
+        Err(e) if e.code() == Code::Unimplemented => false,
+        Err(e) => {
+            warn!("failed to probe the {middleware} middleware, assuming it is available: {e}");
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_channel_upgrades() {
+        assert!(!supports_channel_upgrades(None));
+        assert!(!supports_channel_upgrades(Some(&semver::Version::new(
+            7, 3, 0
+        ))));
+        assert!(supports_channel_upgrades(Some(&semver::Version::new(
+            8, 1, 0
+        ))));
+        assert!(supports_channel_upgrades(Some(&semver::Version::new(
+            9, 0, 0
+        ))));
+    }
+
+    #[test]
+    fn detect_fee_middleware() {
+        assert!(middleware_from_probe("fee", Ok(())));
+        assert!(!middleware_from_probe(
+            "fee",
+            Err(Status::unimplemented(
+                "unknown service ibc.applications.fee.v1.Query"
+            ))
+        ));
+        assert!(middleware_from_probe(
+            "fee",
+            Err(Status::unavailable("connection refused"))
+        ));
+    }
+}
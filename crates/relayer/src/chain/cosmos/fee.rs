@@ -1,13 +1,13 @@
 use ibc_relayer_types::applications::ics29_fee::msgs::register_payee::build_register_counterparty_payee_message;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::signer::Signer;
-use tendermint_rpc::HttpClient;
 
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::fee::query_counterparty_payee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
@@ -15,7 +15,7 @@ use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
 
 // FIXME: monster function, refactor
 pub async fn maybe_register_counterparty_payee(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     tx_config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     m_account: &mut Option<Account>,
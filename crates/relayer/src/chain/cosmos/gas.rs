@@ -5,6 +5,7 @@ use num_bigint::BigInt;
 use num_rational::BigRational;
 
 use crate::chain::cosmos::types::gas::GasConfig;
+use crate::config::dynamic_gas::DynamicGasPriceConfig;
 use crate::config::GasPrice;
 
 pub fn gas_amount_to_fee(config: &GasConfig, gas_amount: u64) -> Fee {
@@ -34,6 +35,19 @@ pub fn calculate_fee(adjusted_gas_amount: u64, gas_price: &GasPrice) -> Coin {
     }
 }
 
+/// The gas price paid on a chain with a fee market, given its current base fee:
+/// the base fee multiplied by the configured multiplier, and capped at the configured maximum, if any.
+pub fn dynamic_gas_price(
+    config: &DynamicGasPriceConfig,
+    gas_price: &GasPrice,
+    base_fee: f64,
+) -> GasPrice {
+    let price = base_fee * config.multiplier.to_f64();
+    let price = config.max.map_or(price, |max| price.min(max));
+
+    GasPrice::new(price, gas_price.denom.clone())
+}
+
 /// Multiply `a` with `f` and round the result up to the nearest integer.
 pub fn mul_ceil(a: u64, f: f64) -> BigInt {
     assert!(f.is_finite());
@@ -98,7 +112,11 @@ fn adjust_estimated_gas(
 
 #[cfg(test)]
 mod tests {
-    use super::{adjust_estimated_gas, AdjustGas};
+    use super::{adjust_estimated_gas, dynamic_gas_price, AdjustGas};
+
+    use crate::config::dynamic_gas::DynamicGasPriceConfig;
+    use crate::config::gas_multiplier::GasMultiplier;
+    use crate::config::GasPrice;
 
     #[test]
     fn adjust_zero_gas() {
@@ -154,4 +172,25 @@ mod tests {
 
         assert_eq!(adjusted_gas, u64::MAX);
     }
+
+    #[test]
+    fn dynamic_gas_price_from_base_fee() {
+        let config = DynamicGasPriceConfig {
+            enabled: true,
+            multiplier: GasMultiplier::unsafe_new(1.2),
+            max: Some(0.6),
+        };
+        let gas_price = GasPrice::new(0.0025, "uosmo".to_string());
+
+        let price = dynamic_gas_price(&config, &gas_price, 0.025);
+        assert_eq!(price.denom, "uosmo");
+        assert!((price.price - 0.03).abs() < 1e-12);
+
+        let capped = dynamic_gas_price(&config, &gas_price, 1.0);
+        assert_eq!(capped.price, 0.6);
+
+        let unbounded = DynamicGasPriceConfig { max: None, ..config };
+        let price = dynamic_gas_price(&unbounded, &gas_price, 1.0);
+        assert!((price.price - 1.2).abs() < 1e-12);
+    }
 }
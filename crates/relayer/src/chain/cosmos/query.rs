@@ -1,4 +1,3 @@
-use http::uri::Uri;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient;
 use ibc_proto::cosmos::base::tendermint::v1beta1::GetNodeInfoRequest;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
@@ -8,8 +7,10 @@ use ibc_relayer_types::core::ics23_commitment::merkle::{
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint::block::Height;
 use tendermint_rpc::query::Query;
-use tendermint_rpc::{Client, HttpClient, Url};
+use tendermint_rpc::{Client, Url};
 
+use crate::chain::cosmos::types::grpc::GrpcAddress;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::cosmos::version::Specs;
 use crate::chain::requests::{QueryClientEventRequest, QueryPacketEventDataRequest, QueryTxHash};
 use crate::error::Error;
@@ -20,6 +21,7 @@ pub mod consensus_state;
 pub mod custom;
 pub mod denom_trace;
 pub mod fee;
+pub mod fee_market;
 pub mod status;
 pub mod tx;
 
@@ -75,7 +77,7 @@ pub fn tx_hash_query(request: &QueryTxHash) -> Query {
 
 /// Perform a generic `abci_query`, and return the corresponding deserialized response data.
 pub async fn abci_query(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     path: String,
     data: String,
@@ -120,11 +122,15 @@ pub async fn abci_query(
 }
 
 /// Queries the chain to obtain the version information.
-pub async fn fetch_version_specs(chain_id: &ChainId, grpc_address: &Uri) -> Result<Specs, Error> {
+pub async fn fetch_version_specs(
+    chain_id: &ChainId,
+    grpc_address: &GrpcAddress,
+) -> Result<Specs, Error> {
     let grpc_addr_string = grpc_address.to_string();
 
     // Construct a gRPC client
-    let mut client = ServiceClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(ServiceClient::new)
         .await
         .map_err(|e| {
             Error::fetch_version_grpc_transport(
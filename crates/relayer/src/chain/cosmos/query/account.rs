@@ -1,10 +1,10 @@
-use http::uri::Uri;
 use ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient;
 use ibc_proto::cosmos::auth::v1beta1::{BaseAccount, EthAccount, QueryAccountRequest};
 use prost::Message;
 use tracing::info;
 
 use crate::chain::cosmos::types::account::Account;
+use crate::chain::cosmos::types::grpc::GrpcAddress;
 use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
 
@@ -12,7 +12,7 @@ use crate::error::Error;
 /// Otherwise query for the account information, update the `Option` to `Some`,
 /// and return the underlying `&mut` reference.
 pub async fn get_or_fetch_account<'a>(
-    grpc_address: &'a Uri,
+    grpc_address: &'a GrpcAddress,
     account_address: &'a str,
     m_account: &'a mut Option<Account>,
 ) -> Result<&'a mut Account, Error> {
@@ -32,7 +32,7 @@ pub async fn get_or_fetch_account<'a>(
 /// Refresh the account sequence behind the `&mut Account` by refetching the
 /// account and updating the `&mut` reference.
 pub async fn refresh_account<'a>(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     account_address: &str,
     m_account: &'a mut Account,
 ) -> Result<(), Error> {
@@ -51,10 +51,11 @@ pub async fn refresh_account<'a>(
 
 /// Uses the GRPC client to retrieve the account sequence
 pub async fn query_account(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     account_address: &str,
 ) -> Result<BaseAccount, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(QueryClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
@@ -1,20 +1,20 @@
-use http::uri::Uri;
-
 use ibc_proto::cosmos::bank::v1beta1::{
     query_client::QueryClient, QueryAllBalancesRequest, QueryBalanceRequest,
 };
 
 use crate::account::Balance;
+use crate::chain::cosmos::types::grpc::GrpcAddress;
 use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
 
 /// Uses the GRPC client to retrieve the account balance for a specific denom
 pub async fn query_balance(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     account_address: &str,
     denom: &str,
 ) -> Result<Balance, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(QueryClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
@@ -44,10 +44,11 @@ pub async fn query_balance(
 
 /// Uses the GRPC client to retrieve the account balance for all denom
 pub async fn query_all_balances(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     account_address: &str,
 ) -> Result<Vec<Balance>, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(QueryClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
@@ -1,8 +1,8 @@
-use http::Uri;
 use tracing::{debug, warn};
 
 use ibc_relayer_types::{core::ics24_host::identifier::ChainId, Height};
 
+use crate::chain::cosmos::types::grpc::GrpcAddress;
 use crate::chain::requests::{QueryConsensusStateHeightsRequest, QueryConsensusStatesRequest};
 use crate::config::default::max_grpc_decoding_size;
 use crate::consensus_state::AnyConsensusStateWithHeight;
@@ -11,7 +11,7 @@ use crate::util::pretty::{PrettyConsensusStateWithHeight, PrettyHeight};
 
 pub async fn query_consensus_state_heights(
     chain_id: &ChainId,
-    grpc_addr: &Uri,
+    grpc_addr: &GrpcAddress,
     request: QueryConsensusStateHeightsRequest,
 ) -> Result<Vec<Height>, Error> {
     crate::time!(
@@ -34,10 +34,10 @@ pub async fn query_consensus_state_heights(
             .contains("unknown method ConsensusStateHeights")
     }
 
-    let mut client =
-        ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(grpc_addr.clone())
-            .await
-            .map_err(Error::grpc_transport)?;
+    let mut client = grpc_addr
+        .connect(ibc_proto::ibc::core::client::v1::query_client::QueryClient::new)
+        .await
+        .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
 
@@ -89,7 +89,7 @@ pub async fn query_consensus_state_heights(
 
 pub async fn query_consensus_states(
     chain_id: &ChainId,
-    grpc_addr: &Uri,
+    grpc_addr: &GrpcAddress,
     request: QueryConsensusStatesRequest,
 ) -> Result<Vec<AnyConsensusStateWithHeight>, Error> {
     crate::telemetry!(query, chain_id, "query_consensus_states");
@@ -101,10 +101,10 @@ pub async fn query_consensus_states(
         }
     );
 
-    let mut client =
-        ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(grpc_addr.clone())
-            .await
-            .map_err(Error::grpc_transport)?;
+    let mut client = grpc_addr
+        .connect(ibc_proto::ibc::core::client::v1::query_client::QueryClient::new)
+        .await
+        .map_err(Error::grpc_transport)?;
 
     client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
 
@@ -1,13 +1,14 @@
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::requests::CrossChainQueryRequest;
 use crate::error::Error;
 use hex;
 use ibc_relayer_types::applications::ics31_icq::{
     error::Error as CrossChainQueryError, response::CrossChainQueryResponse,
 };
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::Client;
 
 pub async fn cross_chain_query_via_rpc(
-    client: &HttpClient,
+    client: &RpcClient,
     cross_chain_query_request: CrossChainQueryRequest,
 ) -> Result<CrossChainQueryResponse, Error> {
     let hex_decoded_request = hex::decode(cross_chain_query_request.request)
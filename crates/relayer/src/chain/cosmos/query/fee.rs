@@ -1,7 +1,6 @@
-use http::uri::Uri;
 use ibc_proto::ibc::applications::fee::v1::query_client::QueryClient;
 use ibc_proto::ibc::applications::fee::v1::{
-    QueryCounterpartyPayeeRequest, QueryIncentivizedPacketsForChannelRequest,
+    QueryCounterpartyPayeeRequest, QueryIncentivizedPacketsForChannelRequest, QueryPayeeRequest,
 };
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
@@ -11,15 +10,18 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::signer::Signer;
 use tonic::Code;
 
+use crate::chain::cosmos::types::grpc::GrpcAddress;
+use crate::chain::requests::QueryIncentivizedPacketsRequest;
 use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
 
 pub async fn query_counterparty_payee(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     channel_id: &ChannelId,
     address: &Signer,
 ) -> Result<Option<String>, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(QueryClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
@@ -48,12 +50,48 @@ pub async fn query_counterparty_payee(
     }
 }
 
+pub async fn query_payee(
+    grpc_address: &GrpcAddress,
+    channel_id: &ChannelId,
+    address: &Signer,
+) -> Result<Option<String>, Error> {
+    let mut client = grpc_address
+        .connect(QueryClient::new)
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
+
+    let request = QueryPayeeRequest {
+        channel_id: channel_id.to_string(),
+        relayer: address.to_string(),
+    };
+
+    let result = client.payee(request).await;
+
+    match result {
+        Ok(response) => {
+            let payee = response.into_inner().payee_address;
+
+            Ok(Some(payee))
+        }
+        Err(e) => {
+            if e.code() == Code::NotFound {
+                Ok(None)
+            } else {
+                Err(Error::grpc_status(e, "query_payee".to_owned()))
+            }
+        }
+    }
+}
+
 pub async fn query_incentivized_packets(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     channel_id: &ChannelId,
     port_id: &PortId,
 ) -> Result<Vec<IdentifiedPacketFees>, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(QueryClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
@@ -82,12 +120,41 @@ pub async fn query_incentivized_packets(
     Ok(packets)
 }
 
+/// Query the incentivized packets of all the channels of the chain.
+pub async fn query_all_incentivized_packets(
+    grpc_address: &GrpcAddress,
+    request: QueryIncentivizedPacketsRequest,
+) -> Result<Vec<IdentifiedPacketFees>, Error> {
+    let mut client = grpc_address
+        .connect(QueryClient::new)
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    client = client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
+
+    let response = client
+        .incentivized_packets(tonic::Request::new(request.into()))
+        .await
+        .map_err(|e| Error::grpc_status(e, "query_all_incentivized_packets".to_owned()))?;
+
+    let raw_packets = response.into_inner().incentivized_packets;
+
+    let packets = raw_packets
+        .into_iter()
+        .map(IdentifiedPacketFees::try_from)
+        .collect::<Result<_, _>>()
+        .map_err(Error::ics29)?;
+
+    Ok(packets)
+}
+
 /// Query the incentivized packet for a specific packet at a specific height.
 pub async fn query_incentivized_packet(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     request: QueryIncentivizedPacketRequest,
 ) -> Result<QueryIncentivizedPacketResponse, Error> {
-    let mut client = QueryClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(QueryClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
@@ -0,0 +1,59 @@
+use tendermint::block::Height as TmHeight;
+use tendermint_rpc::Url;
+
+use crate::chain::cosmos::query::abci_query;
+use crate::chain::cosmos::types::rpc::RpcClient;
+use crate::error::Error;
+
+/// The gRPC method querying the base fee of the EIP-1559 fee market of Osmosis.
+const EIP_BASE_FEE_QUERY_PATH: &str = "/osmosis.txfees.v1beta1.Query/GetEipBaseFee";
+
+/// The number of fractional digits of the decimals of the Cosmos SDK,
+/// which are encoded as integers in their protobuf representation.
+const SDK_DEC_PRECISION: i32 = 18;
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct QueryEipBaseFeeResponse {
+    #[prost(string, tag = "1")]
+    base_fee: String,
+}
+
+/// Query the current base fee of the fee market of the chain, per unit of gas.
+pub async fn query_eip_base_fee(rpc_client: &RpcClient, rpc_address: &Url) -> Result<f64, Error> {
+    let response = abci_query(
+        rpc_client,
+        rpc_address,
+        EIP_BASE_FEE_QUERY_PATH.to_string(),
+        String::new(),
+        TmHeight::from(0u32),
+        false,
+    )
+    .await?;
+
+    let response: QueryEipBaseFeeResponse = prost::Message::decode(response.value.as_ref())
+        .map_err(|e| Error::protobuf_decode("QueryEipBaseFeeResponse".to_string(), e))?;
+
+    parse_sdk_dec(&response.base_fee)
+        .ok_or_else(|| Error::invalid_base_fee(response.base_fee.clone()))
+}
+
+/// Parse a decimal of the Cosmos SDK from its protobuf representation,
+/// which is the integer obtained by shifting the decimal point by [`SDK_DEC_PRECISION`] digits.
+fn parse_sdk_dec(value: &str) -> Option<f64> {
+    let value = value.parse::<u128>().ok()?;
+
+    Some(value as f64 / 10f64.powi(SDK_DEC_PRECISION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_sdk_dec;
+
+    #[test]
+    fn parse_base_fee() {
+        assert_eq!(parse_sdk_dec("25000000000000000"), Some(0.025));
+        assert_eq!(parse_sdk_dec("1500000000000000000"), Some(1.5));
+        assert_eq!(parse_sdk_dec("0"), Some(0.0));
+        assert_eq!(parse_sdk_dec("0.025"), None);
+    }
+}
@@ -1,7 +1,8 @@
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::Height;
-use tendermint_rpc::{Client, HttpClient, Url};
+use tendermint_rpc::{Client, Url};
 
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::endpoint::ChainStatus;
 use crate::error::Error;
 
@@ -11,7 +12,7 @@ use crate::error::Error;
 /// ie. if `sync_info.catching_up` is `true`.
 pub async fn query_status(
     chain_id: &ChainId,
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
 ) -> Result<ChainStatus, Error> {
     let response = rpc_client
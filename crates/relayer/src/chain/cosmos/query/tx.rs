@@ -6,11 +6,12 @@ use ibc_relayer_types::Height as ICSHeight;
 use tendermint::abci::Event;
 use tendermint::Hash as TxHash;
 use tendermint_rpc::endpoint::tx::Response as TxResponse;
-use tendermint_rpc::{Client, HttpClient, Order, Url};
+use tendermint_rpc::{Client, Order, Url};
 use tracing::warn;
 
 use crate::chain::cosmos::query::{header_query, packet_query, tx_hash_query};
 use crate::chain::cosmos::types::events;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::requests::{
     QueryClientEventRequest, QueryHeight, QueryPacketEventDataRequest, QueryTxHash, QueryTxRequest,
 };
@@ -22,7 +23,7 @@ use crate::event::{ibc_event_try_from_abci_event, IbcEventWithHeight};
 /// 2. Transaction event request - returns all IBC events resulted from a Tx execution
 pub async fn query_txs(
     chain_id: &ChainId,
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     request: QueryTxRequest,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
@@ -108,7 +109,7 @@ pub async fn query_txs(
 ///    packets ever sent.
 pub async fn query_packets_from_txs(
     chain_id: &ChainId,
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     request: &QueryPacketEventDataRequest,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
@@ -171,7 +172,7 @@ pub async fn query_packets_from_txs(
 /// It returns at most one packet event for each sequence specified in the request.
 pub async fn query_packets_from_block(
     chain_id: &ChainId,
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     request: &QueryPacketEventDataRequest,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
@@ -353,7 +354,7 @@ pub fn filter_matching_event(
 }
 
 pub async fn query_tx_response(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     tx_hash: &TxHash,
 ) -> Result<Option<TxResponse>, Error> {
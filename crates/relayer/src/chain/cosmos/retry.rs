@@ -6,12 +6,12 @@ use tracing::{debug, error, instrument, warn};
 use ibc_proto::google::protobuf::Any;
 use tendermint::abci::Code;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
-use tendermint_rpc::HttpClient;
 
 use crate::chain::cosmos::query::account::refresh_account;
 use crate::chain::cosmos::tx::estimate_fee_and_send_tx;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
@@ -46,7 +46,7 @@ const INCORRECT_ACCOUNT_SEQUENCE_ERR: u32 = 32;
     ),
 )]
 pub async fn send_tx_with_account_sequence_retry(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
@@ -75,7 +75,7 @@ pub async fn send_tx_with_account_sequence_retry(
 }
 
 async fn do_send_tx_with_account_sequence_retry(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
@@ -162,7 +162,7 @@ async fn do_send_tx_with_account_sequence_retry(
 }
 
 async fn refresh_account_and_retry_send_tx_with_account_sequence(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
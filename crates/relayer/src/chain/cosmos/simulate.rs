@@ -1,11 +1,52 @@
 use ibc_proto::cosmos::tx::v1beta1::service_client::ServiceClient;
 use ibc_proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse, Tx};
-use tonic::codegen::http::Uri;
+use ibc_proto::google::protobuf::Any;
 
+use crate::chain::cosmos::encode::sign_tx;
+use crate::chain::cosmos::types::account::Account;
+use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::grpc::GrpcAddress;
 use crate::config::default::max_grpc_decoding_size;
+use crate::config::types::Memo;
 use crate::error::Error;
+use crate::keyring::Secp256k1KeyPair;
 
-pub async fn send_tx_simulate(grpc_address: &Uri, tx: Tx) -> Result<SimulateResponse, Error> {
+/// Simulate a transaction made of the given messages, signed with the maximum fee,
+/// and return the gas it used, if reported by the chain.
+///
+/// Unlike the gas estimation, a failed simulation is reported as an error
+/// instead of falling back on the default gas.
+pub async fn simulate_messages(
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &Account,
+    tx_memo: &Memo,
+    messages: &[Any],
+) -> Result<Option<u64>, Error> {
+    let signed_tx = sign_tx(
+        config,
+        key_pair,
+        account,
+        tx_memo,
+        messages,
+        &config.gas_config.max_fee,
+    )?;
+
+    let tx = Tx {
+        body: Some(signed_tx.body),
+        auth_info: Some(signed_tx.auth_info),
+        signatures: signed_tx.signatures,
+    };
+
+    let response = send_tx_simulate(&config.grpc_address, tx).await?;
+
+    Ok(response.gas_info.map(|gas_info| gas_info.gas_used))
+}
+
+pub async fn send_tx_simulate(
+    grpc_address: &GrpcAddress,
+    tx: Tx,
+) -> Result<SimulateResponse, Error> {
     let mut tx_bytes = vec![];
     prost::Message::encode(&tx, &mut tx_bytes)
         .map_err(|e| Error::protobuf_encode(String::from("Transaction"), e))?;
@@ -15,7 +56,8 @@ pub async fn send_tx_simulate(grpc_address: &Uri, tx: Tx) -> Result<SimulateResp
         ..Default::default()
     };
 
-    let mut client = ServiceClient::connect(grpc_address.clone())
+    let mut client = grpc_address
+        .connect(ServiceClient::new)
         .await
         .map_err(Error::grpc_transport)?;
 
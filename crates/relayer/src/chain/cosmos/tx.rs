@@ -2,7 +2,7 @@ use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::events::IbcEvent;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
-use tendermint_rpc::{Client, HttpClient, Url};
+use tendermint_rpc::{Client, Url};
 
 use crate::chain::cosmos::encode::sign_and_encode_tx;
 use crate::chain::cosmos::estimate::estimate_tx_fees;
@@ -10,6 +10,7 @@ use crate::chain::cosmos::query::account::query_account;
 use crate::chain::cosmos::query::tx::all_ibc_events_from_tx_search_response;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
@@ -19,14 +20,14 @@ use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
 use super::batch::send_batched_messages_and_wait_commit;
 
 pub async fn estimate_fee_and_send_tx(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
-    let fee = estimate_tx_fees(config, key_pair, account, tx_memo, messages).await?;
+    let fee = estimate_tx_fees(rpc_client, config, key_pair, account, tx_memo, messages).await?;
 
     send_tx_with_fee(
         rpc_client, config, key_pair, account, tx_memo, messages, &fee,
@@ -35,7 +36,7 @@ pub async fn estimate_fee_and_send_tx(
 }
 
 async fn send_tx_with_fee(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &Account,
@@ -45,14 +46,20 @@ async fn send_tx_with_fee(
 ) -> Result<Response, Error> {
     let tx_bytes = sign_and_encode_tx(config, key_pair, account, tx_memo, messages, fee)?;
 
-    let response = broadcast_tx_sync(rpc_client, &config.rpc_address, tx_bytes).await?;
+    let response = broadcast_tx_sync(rpc_client, &config.rpc_address, tx_bytes).await;
 
-    Ok(response)
+    crate::telemetry!(
+        tx_broadcast,
+        &config.chain_id,
+        matches!(&response, Ok(response) if response.code.is_err())
+    );
+
+    response
 }
 
 /// Perform a `broadcast_tx_sync`, and return the corresponding deserialized response data.
 pub async fn broadcast_tx_sync(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     data: Vec<u8>,
 ) -> Result<Response, Error> {
@@ -77,7 +84,7 @@ pub async fn broadcast_tx_sync(
    error event.
 */
 pub async fn simple_send_tx(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     messages: Vec<Any>,
@@ -115,7 +122,7 @@ pub async fn simple_send_tx(
 }
 
 pub async fn batched_send_tx(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     messages: Vec<Any>,
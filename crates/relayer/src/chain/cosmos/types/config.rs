@@ -1,11 +1,12 @@
-use core::str::FromStr;
+use alloc::borrow::Cow;
 use core::time::Duration;
-use http::Uri;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::Url;
 
 use crate::chain::cosmos::types::gas::GasConfig;
+use crate::chain::cosmos::types::grpc::GrpcAddress;
+use crate::config::channel_batch::BatchLimits;
 use crate::config::types::{MaxMsgNum, MaxTxSize};
 use crate::config::{AddressType, ChainConfig};
 use crate::error::Error;
@@ -15,11 +16,17 @@ pub struct TxConfig {
     pub chain_id: ChainId,
     pub gas_config: GasConfig,
     pub rpc_address: Url,
-    pub grpc_address: Uri,
+    pub grpc_address: GrpcAddress,
     pub rpc_timeout: Duration,
     pub address_type: AddressType,
     pub max_msg_num: MaxMsgNum,
     pub max_tx_size: MaxTxSize,
+
+    /// The size the batches of messages are grouped into, if lower than `max_tx_size`,
+    /// eg. while the chain is congested. A message larger than this size is still sent
+    /// on its own, as long as it fits within `max_tx_size`.
+    pub target_tx_size: Option<MaxTxSize>,
+
     pub extension_options: Vec<Any>,
 }
 
@@ -27,8 +34,7 @@ impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
     type Error = Error;
 
     fn try_from(config: &'a ChainConfig) -> Result<Self, Error> {
-        let grpc_address = Uri::from_str(&config.grpc_addr.to_string())
-            .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
+        let grpc_address = GrpcAddress::from_config(config)?;
 
         let gas_config = GasConfig::from(config);
 
@@ -47,7 +53,30 @@ impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
             address_type: config.address_type.clone(),
             max_msg_num: config.max_msg_num,
             max_tx_size: config.max_tx_size,
+            target_tx_size: None,
             extension_options,
         })
     }
 }
+
+impl TxConfig {
+    /// The config of the transactions sending messages within the given limits,
+    /// which can only lower the limits of the chain.
+    pub fn within(&self, limits: BatchLimits) -> Cow<'_, Self> {
+        if limits.is_unlimited() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut config = self.clone();
+
+        if let Some(max_msg_num) = limits.max_msg_num {
+            config.max_msg_num = config.max_msg_num.min(max_msg_num);
+        }
+
+        if let Some(max_tx_size) = limits.max_tx_size {
+            config.max_tx_size = config.max_tx_size.min(max_tx_size);
+        }
+
+        Cow::Owned(config)
+    }
+}
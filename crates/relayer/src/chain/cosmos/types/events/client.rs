@@ -8,6 +8,7 @@ use ibc_relayer_types::core::ics02_client::header::Header;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType};
 use tendermint::abci::Event as AbciEvent;
 
+use crate::light_client::registry::client_registry;
 use crate::light_client::AnyHeader;
 
 pub fn try_from_tx(event: &AbciEvent) -> Option<IbcEvent> {
@@ -51,9 +52,9 @@ fn extract_attributes_from_tx(event: &AbciEvent) -> Result<Attributes, Error> {
                 attr.client_id = value.parse().map_err(Error::invalid_client_identifier)?
             }
             CLIENT_TYPE_ATTRIBUTE_KEY => {
-                attr.client_type = value
-                    .parse()
-                    .map_err(|_| Error::unknown_client_type(value.to_string()))?
+                attr.client_type = client_registry()
+                    .client_type(value)
+                    .ok_or_else(|| Error::unknown_client_type(value.to_string()))?
             }
             CONSENSUS_HEIGHT_ATTRIBUTE_KEY => {
                 attr.consensus_height = value
@@ -1,6 +1,7 @@
 use ibc_proto::cosmos::tx::v1beta1::Fee;
 
 use crate::chain::cosmos::calculate_fee;
+use crate::config::dynamic_gas::DynamicGasPriceConfig;
 use crate::config::{ChainConfig, GasPrice};
 
 /// Default gas limit when submitting a transaction.
@@ -16,6 +17,7 @@ pub struct GasConfig {
     pub gas_price: GasPrice,
     pub max_fee: Fee,
     pub fee_granter: String,
+    pub dynamic_gas_price: DynamicGasPriceConfig,
 }
 
 impl<'a> From<&'a ChainConfig> for GasConfig {
@@ -27,6 +29,7 @@ impl<'a> From<&'a ChainConfig> for GasConfig {
             gas_price: config.gas_price.clone(),
             max_fee: max_fee_from_config(config),
             fee_granter: fee_granter_from_config(config),
+            dynamic_gas_price: config.dynamic_gas_price,
         }
     }
 }
@@ -0,0 +1,118 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::str::FromStr;
+use core::task::{Context, Poll};
+
+use http::Uri;
+use tonic::body::BoxBody;
+use tonic::codegen::Service;
+use tonic::transport::{Body, Channel, Endpoint};
+
+use crate::config::proxy::ProxyConfig;
+use crate::config::ChainConfig;
+use crate::error::Error;
+use crate::util::proxy::ProxyConnector;
+use crate::util::rate_limit::RateLimiter;
+
+/// The address of the gRPC endpoint of a chain, along with the proxy
+/// through which to connect to it and the limit on the rate of requests, if any.
+#[derive(Clone, Debug)]
+pub struct GrpcAddress {
+    uri: Uri,
+    proxy: Option<ProxyConfig>,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl GrpcAddress {
+    pub fn new(uri: Uri, proxy: Option<ProxyConfig>) -> Self {
+        Self {
+            uri,
+            proxy,
+            rate_limiter: None,
+        }
+    }
+
+    pub fn from_config(config: &ChainConfig) -> Result<Self, Error> {
+        let uri = Uri::from_str(&config.grpc_addr.to_string())
+            .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
+
+        Ok(Self {
+            uri,
+            proxy: config.proxy.clone(),
+            rate_limiter: config.rate_limit.grpc.map(RateLimiter::new),
+        })
+    }
+
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// Connects to the gRPC endpoint, through the proxy if any, and builds a client
+    /// on top of the connection with the given constructor, eg. `QueryClient::new`.
+    pub async fn connect<C>(
+        &self,
+        client: impl FnOnce(GrpcChannel) -> C,
+    ) -> Result<C, tonic::transport::Error> {
+        let endpoint = Endpoint::from(self.uri.clone());
+
+        let channel = match &self.proxy {
+            Some(proxy) => {
+                endpoint
+                    .connect_with_connector(ProxyConnector::new(proxy.clone()))
+                    .await?
+            }
+            None => endpoint.connect().await?,
+        };
+
+        Ok(client(GrpcChannel {
+            inner: channel,
+            rate_limiter: self.rate_limiter.clone(),
+        }))
+    }
+}
+
+impl From<Uri> for GrpcAddress {
+    fn from(uri: Uri) -> Self {
+        Self::new(uri, None)
+    }
+}
+
+impl Display for GrpcAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.uri)
+    }
+}
+
+/// A connection to a gRPC endpoint, which holds back the requests
+/// in excess of the rate limit of the endpoint, if any.
+#[derive(Clone, Debug)]
+pub struct GrpcChannel {
+    inner: Channel,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl Service<http::Request<BoxBody>> for GrpcChannel {
+    type Response = http::Response<Body>;
+    type Error = tonic::transport::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        // Take the channel which was driven to readiness, leaving a fresh clone in its place
+        let clone = self.inner.clone();
+        let mut inner = core::mem::replace(&mut self.inner, clone);
+        let rate_limiter = self.rate_limiter.clone();
+
+        Box::pin(async move {
+            if let Some(rate_limiter) = rate_limiter {
+                rate_limiter.acquire().await;
+            }
+
+            inner.call(request).await
+        })
+    }
+}
@@ -2,4 +2,6 @@ pub mod account;
 pub mod config;
 pub mod events;
 pub mod gas;
+pub mod grpc;
+pub mod rpc;
 pub mod tx;
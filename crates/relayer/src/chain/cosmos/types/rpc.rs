@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use tendermint::block::Height;
+use tendermint::evidence::Evidence;
+use tendermint::Hash;
+use tendermint_rpc::client::CompatMode;
+use tendermint_rpc::endpoint::{
+    block_results, broadcast, evidence, header, header_by_hash, tx, tx_search,
+};
+use tendermint_rpc::query::Query;
+use tendermint_rpc::{Client, Error, HttpClient, Order, SimpleRequest};
+
+use crate::util::rate_limit::RateLimiter;
+
+/// An RPC client, which holds back the requests in excess
+/// of the rate limit of the endpoint, if any.
+///
+/// Clones of the client share the same rate limiter.
+#[derive(Clone, Debug)]
+pub struct RpcClient {
+    inner: HttpClient,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl RpcClient {
+    pub fn new(inner: HttpClient, rate_limiter: Option<RateLimiter>) -> Self {
+        Self {
+            inner,
+            rate_limiter,
+        }
+    }
+
+    /// The underlying HTTP client, whose requests are not rate limited.
+    pub fn inner(&self) -> &HttpClient {
+        &self.inner
+    }
+
+    pub fn set_compat_mode(&mut self, compat_mode: CompatMode) {
+        self.inner.set_compat_mode(compat_mode);
+    }
+
+    async fn throttle(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+}
+
+impl From<HttpClient> for RpcClient {
+    fn from(inner: HttpClient) -> Self {
+        Self::new(inner, None)
+    }
+}
+
+// The methods which `HttpClient` overrides to adapt the requests to the
+// compatibility mode are forwarded as is, all the other ones go through `perform`.
+#[async_trait]
+impl Client for RpcClient {
+    async fn block_results<H>(&self, height: H) -> Result<block_results::Response, Error>
+    where
+        H: Into<Height> + Send,
+    {
+        self.throttle().await;
+        self.inner.block_results(height).await
+    }
+
+    async fn header<H>(&self, height: H) -> Result<header::Response, Error>
+    where
+        H: Into<Height> + Send,
+    {
+        self.throttle().await;
+        self.inner.header(height).await
+    }
+
+    async fn header_by_hash(&self, hash: Hash) -> Result<header_by_hash::Response, Error> {
+        self.throttle().await;
+        self.inner.header_by_hash(hash).await
+    }
+
+    async fn broadcast_tx_commit<T>(&self, tx: T) -> Result<broadcast::tx_commit::Response, Error>
+    where
+        T: Into<Vec<u8>> + Send,
+    {
+        self.throttle().await;
+        self.inner.broadcast_tx_commit(tx).await
+    }
+
+    async fn broadcast_evidence(&self, e: Evidence) -> Result<evidence::Response, Error> {
+        self.throttle().await;
+        self.inner.broadcast_evidence(e).await
+    }
+
+    async fn tx(&self, hash: Hash, prove: bool) -> Result<tx::Response, Error> {
+        self.throttle().await;
+        self.inner.tx(hash, prove).await
+    }
+
+    async fn tx_search(
+        &self,
+        query: Query,
+        prove: bool,
+        page: u32,
+        per_page: u8,
+        order: Order,
+    ) -> Result<tx_search::Response, Error> {
+        self.throttle().await;
+        self.inner
+            .tx_search(query, prove, page, per_page, order)
+            .await
+    }
+
+    async fn perform<R>(&self, request: R) -> Result<R::Output, Error>
+    where
+        R: SimpleRequest,
+    {
+        self.throttle().await;
+        self.inner.perform(request).await
+    }
+}
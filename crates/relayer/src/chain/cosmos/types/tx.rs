@@ -1,3 +1,6 @@
+use core::time::Duration;
+use std::time::Instant;
+
 use ibc_proto::cosmos::tx::v1beta1::{AuthInfo, TxBody};
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 
@@ -22,4 +25,8 @@ pub struct TxSyncResult {
     // the events generated by a Tx once executed
     pub events: Vec<IbcEventWithHeight>,
     pub status: TxStatus,
+    // when the Tx was broadcast
+    pub sent_at: Instant,
+    // the time it took for the Tx to be included in a block, once it was
+    pub latency: Option<Duration>,
 }
@@ -7,12 +7,13 @@ use std::thread;
 use std::time::Instant;
 use tendermint::Hash as TxHash;
 use tendermint_rpc::endpoint::tx::Response as TxResponse;
-use tendermint_rpc::{HttpClient, Url};
+use tendermint_rpc::Url;
 use tokio::time::sleep;
 use tracing::{debug, debug_span, trace};
 
 use crate::chain::cosmos::query::tx::query_tx_response;
 use crate::chain::cosmos::types::events::from_tx_response_event;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
@@ -24,7 +25,7 @@ const WAIT_BACKOFF: Duration = Duration::from_millis(300);
 /// with the transaction hashes to get the list of IbcEvents included in those transactions.
 pub async fn wait_for_block_commits(
     chain_id: &ChainId,
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     rpc_timeout: &Duration,
     tx_sync_results: &mut [TxSyncResult],
@@ -80,7 +81,7 @@ pub async fn wait_for_block_commits(
 
 async fn update_tx_sync_result(
     chain_id: &ChainId,
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     tx_sync_result: &mut TxSyncResult,
 ) -> Result<(), Error> {
@@ -90,6 +91,15 @@ async fn update_tx_sync_result(
 
         if let Some(response) = response {
             tx_sync_result.status = TxStatus::ReceivedResponse;
+            tx_sync_result.latency = Some(tx_sync_result.sent_at.elapsed());
+
+            crate::telemetry!(
+                tx_delivered,
+                chain_id,
+                response.tx_result.code.is_err(),
+                response.tx_result.gas_used,
+                response.tx_result.gas_wanted
+            );
 
             let height = Height::new(chain_id.version(), u64::from(response.height)).unwrap();
             if response.tx_result.code.is_err() {
@@ -124,7 +134,7 @@ fn all_tx_results_found(tx_sync_results: &[TxSyncResult]) -> bool {
 }
 
 pub async fn wait_tx_succeed(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     timeout: &Duration,
     tx_hash: &TxHash,
@@ -140,7 +150,7 @@ pub async fn wait_tx_succeed(
 }
 
 pub async fn wait_tx_hash(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     rpc_address: &Url,
     timeout: &Duration,
     tx_hash: &TxHash,
@@ -2,10 +2,12 @@ use alloc::sync::Arc;
 use core::convert::TryFrom;
 
 use tokio::runtime::Runtime as TokioRuntime;
+use tracing::warn;
 
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
@@ -61,6 +63,56 @@ pub struct ChainStatus {
     pub timestamp: Timestamp,
 }
 
+/// The optional features of IBC supported by a chain, which gate
+/// the workers and the messages relying on them.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChainFeatures {
+    /// Whether the chain runs the ICS 29 fee middleware
+    pub fee_middleware: bool,
+
+    /// Whether the chain supports upgrading its channels, in which case its open
+    /// channel ends may change and are thus not cached
+    pub channel_upgrades: bool,
+
+    /// Whether the chain runs a middleware writing the acknowledgements of the packets
+    /// it receives asynchronously, ie. not in the transaction receiving them
+    pub async_acknowledgements: bool,
+}
+
+impl ChainFeatures {
+    /// All the optional features, which are assumed to be supported by a chain
+    /// whose features could not be detected, so as not to gate anything needlessly.
+    pub fn all() -> Self {
+        Self {
+            fee_middleware: true,
+            channel_upgrades: true,
+            async_acknowledgements: true,
+        }
+    }
+
+    /// The features detected on the given chain, or all the features if the detection failed.
+    pub fn detected_or_all(chain_id: &ChainId, detected: Result<Self, Error>) -> Self {
+        detected.unwrap_or_else(|e| {
+            warn!(
+                chain = %chain_id,
+                "failed to detect the features of the chain, assuming all of them are supported: {e}"
+            );
+
+            Self::all()
+        })
+    }
+}
+
+impl core::fmt::Display for ChainFeatures {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "fee middleware: {}, channel upgrades: {}, async acknowledgements: {}",
+            self.fee_middleware, self.channel_upgrades, self.async_acknowledgements
+        )
+    }
+}
+
 /// Defines a blockchain as understood by the relayer
 pub trait ChainEndpoint: Sized {
     /// Type of light blocks for this chain
@@ -132,11 +184,22 @@ pub trait ChainEndpoint: Sized {
         Ok(())
     }
 
+    /// Reload the key signing the transactions from the keyring, optionally switching
+    /// to the key with the given name, and return the signer of the reloaded key.
+    ///
+    /// Any state derived from the previous key, such as the cached account
+    /// sequence, must be discarded.
+    fn reload_key(&mut self, key_name: Option<String>) -> Result<Signer, Error>;
+
     // Versioning
 
     /// Return the version of the IBC protocol that this chain is running, if known.
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error>;
 
+    /// Return the optional features of IBC supported by this chain,
+    /// which are detected on the first call and cached afterwards.
+    fn query_features(&self) -> Result<ChainFeatures, Error>;
+
     // Send transactions
 
     /// Sends one or more transactions with `msgs` to chain and
@@ -153,6 +216,21 @@ pub trait ChainEndpoint: Sized {
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<TxResponse>, Error>;
 
+    /// Same as `send_messages_and_wait_check_tx`, but also returns how many of the
+    /// messages, in order, were sent in each of the transactions, if known, so that
+    /// the responses can be matched with the messages they are for.
+    fn send_messages_and_wait_check_tx_batches(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<(Vec<TxResponse>, Option<Vec<usize>>), Error> {
+        self.send_messages_and_wait_check_tx(tracked_msgs)
+            .map(|responses| (responses, None))
+    }
+
+    /// Simulate a transaction made of the given messages, without broadcasting it,
+    /// and return the gas used by the transaction, if reported by the chain.
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error>;
+
     /// Fetch a header from the chain at the given height and verify it.
     fn verify_header(
         &mut self,
@@ -686,4 +764,52 @@ pub trait ChainEndpoint: Sized {
         &self,
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error>;
+
+    /// Query the fees escrowed for all the packets sent from the chain
+    /// which have not been relayed yet, across all channels.
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error>;
+
+    /// Query the payee registered by the given relayer for the acknowledgement and
+    /// timeout fees of the packets sent on the given channel, if any.
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error>;
+
+    /// Query the counterparty payee registered by the given relayer for the receive
+    /// fees of the packets received on the given channel, if any.
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_fall_back_to_all_when_undetected() {
+        let chain_id = ChainId::from_string("chain_id");
+
+        let detected = ChainFeatures {
+            fee_middleware: false,
+            channel_upgrades: true,
+            async_acknowledgements: false,
+        };
+
+        assert_eq!(
+            ChainFeatures::detected_or_all(&chain_id, Ok(detected)),
+            detected
+        );
+        assert_eq!(
+            ChainFeatures::detected_or_all(&chain_id, Err(Error::empty_response_value())),
+            ChainFeatures::all()
+        );
+    }
 }
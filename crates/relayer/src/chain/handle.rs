@@ -8,6 +8,7 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::{
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     core::{
         ics02_client::events::UpdateClient,
@@ -46,7 +47,7 @@ use crate::{
 
 use super::{
     client::ClientSettings,
-    endpoint::{ChainStatus, HealthCheck},
+    endpoint::{ChainFeatures, ChainStatus, HealthCheck},
     requests::*,
     tracking::TrackedMsgs,
 };
@@ -123,6 +124,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
     },
 
+    SimulateMessages {
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Option<u64>>,
+    },
+
     Config {
         reply_to: ReplyTo<ChainConfig>,
     },
@@ -141,10 +147,19 @@ pub enum ChainRequest {
         reply_to: ReplyTo<()>,
     },
 
+    ReloadKey {
+        key_name: Option<String>,
+        reply_to: ReplyTo<Signer>,
+    },
+
     IbcVersion {
         reply_to: ReplyTo<Option<semver::Version>>,
     },
 
+    QueryFeatures {
+        reply_to: ReplyTo<ChainFeatures>,
+    },
+
     QueryBalance {
         key_name: Option<String>,
         denom: Option<String>,
@@ -367,6 +382,23 @@ pub enum ChainRequest {
         request: QueryIncentivizedPacketRequest,
         reply_to: ReplyTo<QueryIncentivizedPacketResponse>,
     },
+
+    QueryIncentivizedPackets {
+        request: QueryIncentivizedPacketsRequest,
+        reply_to: ReplyTo<Vec<IdentifiedPacketFees>>,
+    },
+
+    QueryPayee {
+        channel_id: ChannelId,
+        relayer: Signer,
+        reply_to: ReplyTo<Option<Signer>>,
+    },
+
+    QueryCounterpartyPayee {
+        channel_id: ChannelId,
+        relayer: Signer,
+        reply_to: ReplyTo<Option<Signer>>,
+    },
 }
 
 pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
@@ -400,6 +432,10 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>, Error>;
 
+    /// Simulate a transaction made of the given messages, without broadcasting it,
+    /// and return the gas used by the transaction, if reported by the chain.
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error>;
+
     fn get_signer(&self) -> Result<Signer, Error>;
 
     fn config(&self) -> Result<ChainConfig, Error>;
@@ -408,9 +444,19 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
 
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error>;
 
+    /// Reload the key signing the transactions from the keyring, optionally switching
+    /// to the key with the given name, and return the signer of the reloaded key.
+    ///
+    /// As the requests to a chain are handled one at a time, the transactions submitted
+    /// after the reload are signed with the reloaded key, without restarting the relayer.
+    fn reload_key(&self, key_name: Option<String>) -> Result<Signer, Error>;
+
     /// Return the version of the IBC protocol that this chain is running, if known.
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error>;
 
+    /// Return the optional features of IBC supported by this chain.
+    fn query_features(&self) -> Result<ChainFeatures, Error>;
+
     /// Query the balance of the given account for the given denom.
     /// If no account is given, behavior must be specified, e.g. retrieve it from configuration file.
     /// If no denom is given, behavior must be specified, e.g. using the denom used to pay tx fees
@@ -678,4 +724,27 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         &self,
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error>;
+
+    /// Query the fees escrowed for all the packets sent from the chain
+    /// which have not been relayed yet, across all channels.
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error>;
+
+    /// Query the payee registered by the given relayer for the acknowledgement and
+    /// timeout fees of the packets sent on the given channel, if any.
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error>;
+
+    /// Query the counterparty payee registered by the given relayer for the receive
+    /// fees of the packets received on the given channel, if any.
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error>;
 }
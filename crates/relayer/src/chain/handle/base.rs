@@ -7,6 +7,7 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::{
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     core::{
         ics02_client::events::UpdateClient,
@@ -26,7 +27,12 @@ use ibc_relayer_types::{
 
 use crate::{
     account::Balance,
-    chain::{client::ClientSettings, endpoint::ChainStatus, requests::*, tracking::TrackedMsgs},
+    chain::{
+        client::ClientSettings,
+        endpoint::{ChainFeatures, ChainStatus},
+        requests::*,
+        tracking::TrackedMsgs,
+    },
     client_state::{AnyClientState, IdentifiedAnyClientState},
     config::ChainConfig,
     connection::ConnectionMsgType,
@@ -125,6 +131,13 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error> {
+        self.send(|reply_to| ChainRequest::SimulateMessages {
+            tracked_msgs,
+            reply_to,
+        })
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.send(|reply_to| ChainRequest::Signer { reply_to })
     }
@@ -145,10 +158,18 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn reload_key(&self, key_name: Option<String>) -> Result<Signer, Error> {
+        self.send(|reply_to| ChainRequest::ReloadKey { key_name, reply_to })
+    }
+
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
         self.send(|reply_to| ChainRequest::IbcVersion { reply_to })
     }
 
+    fn query_features(&self) -> Result<ChainFeatures, Error> {
+        self.send(|reply_to| ChainRequest::QueryFeatures { reply_to })
+    }
+
     fn query_balance(
         &self,
         key_name: Option<String>,
@@ -515,4 +536,35 @@ impl ChainHandle for BaseChainHandle {
     ) -> Result<QueryIncentivizedPacketResponse, Error> {
         self.send(|reply_to| ChainRequest::QueryIncentivizedPacket { request, reply_to })
     }
+
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.send(|reply_to| ChainRequest::QueryIncentivizedPackets { request, reply_to })
+    }
+
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.send(|reply_to| ChainRequest::QueryPayee {
+            channel_id: channel_id.clone(),
+            relayer: relayer.clone(),
+            reply_to,
+        })
+    }
+
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.send(|reply_to| ChainRequest::QueryCounterpartyPayee {
+            channel_id: channel_id.clone(),
+            relayer: relayer.clone(),
+            reply_to,
+        })
+    }
 }
@@ -1,9 +1,12 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
 use crossbeam_channel as channel;
+use once_cell::sync::OnceCell;
 use tracing::Span;
 
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketRequest;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketResponse;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -24,7 +27,7 @@ use ibc_relayer_types::Height;
 use crate::account::Balance;
 use crate::cache::{Cache, CacheStatus};
 use crate::chain::client::ClientSettings;
-use crate::chain::endpoint::{ChainStatus, HealthCheck};
+use crate::chain::endpoint::{ChainFeatures, ChainStatus, HealthCheck};
 use crate::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
@@ -46,6 +49,11 @@ use crate::telemetry;
 pub struct CachingChainHandle<Handle> {
     inner: Handle,
     cache: Cache,
+    /// The time-to-live of the cached status of the chain, read from its configuration
+    /// on first use, as the runtime of the chain is not running yet when the handle is built
+    application_status_ttl: OnceCell<Duration>,
+    /// Whether the chain supports upgrading its channels, once detected
+    channel_upgrades: OnceCell<bool>,
 }
 
 impl<Handle> CachingChainHandle<Handle> {
@@ -53,6 +61,8 @@ impl<Handle> CachingChainHandle<Handle> {
         Self {
             inner: handle,
             cache: Cache::new(),
+            application_status_ttl: OnceCell::new(),
+            channel_upgrades: OnceCell::new(),
         }
     }
 
@@ -61,6 +71,32 @@ impl<Handle> CachingChainHandle<Handle> {
     }
 }
 
+impl<Handle: ChainHandle> CachingChainHandle<Handle> {
+    fn application_status_ttl(&self) -> Duration {
+        *self.application_status_ttl.get_or_init(|| {
+            self.inner()
+                .config()
+                .map(|config| config.cache)
+                .unwrap_or_default()
+                .application_status_ttl
+        })
+    }
+
+    /// Whether the channel ends of the chain can change while they are open, as it supports
+    /// upgrading its channels, in which case they are not cached. The detection is retried
+    /// until it succeeds, the channel upgrades being assumed to be supported until then.
+    fn supports_channel_upgrades(&self) -> bool {
+        if let Some(channel_upgrades) = self.channel_upgrades.get() {
+            return *channel_upgrades;
+        }
+
+        match self.inner().query_features() {
+            Ok(features) => *self.channel_upgrades.get_or_init(|| features.channel_upgrades),
+            Err(_) => ChainFeatures::all().channel_upgrades,
+        }
+    }
+}
+
 impl<Handle: ChainHandle> Display for CachingChainHandle<Handle> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
@@ -106,6 +142,10 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error> {
+        self.inner().simulate_messages(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.inner().get_signer()
     }
@@ -122,10 +162,18 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().add_key(key_name, key)
     }
 
+    fn reload_key(&self, key_name: Option<String>) -> Result<Signer, Error> {
+        self.inner().reload_key(key_name)
+    }
+
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
         self.inner().ibc_version()
     }
 
+    fn query_features(&self) -> Result<ChainFeatures, Error> {
+        self.inner().query_features()
+    }
+
     fn query_balance(
         &self,
         key_name: Option<String>,
@@ -143,7 +191,18 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
     }
 
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
-        self.inner().query_application_status()
+        let handle = self.inner();
+        let (result, in_cache) = self
+            .cache
+            .get_or_try_update_application_status_with(self.application_status_ttl(), || {
+                handle.query_application_status()
+            })?;
+
+        if in_cache == CacheStatus::Hit {
+            telemetry!(queries_cache_hits, &self.id(), "query_application_status");
+        }
+
+        Ok(result)
     }
 
     fn query_latest_height(&self) -> Result<Height, Error> {
@@ -312,7 +371,9 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         match include_proof {
             IncludeProof::Yes => handle.query_channel(request, IncludeProof::Yes),
             IncludeProof::No => {
-                if matches!(request.height, QueryHeight::Latest) {
+                if matches!(request.height, QueryHeight::Latest)
+                    && !self.supports_channel_upgrades()
+                {
                     let (result, in_cache) = self.cache.get_or_try_insert_channel_with(
                         &PortChannelId::new(request.channel_id.clone(), request.port_id.clone()),
                         || {
@@ -510,4 +571,27 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
     ) -> Result<QueryIncentivizedPacketResponse, Error> {
         self.inner.query_incentivized_packet(request)
     }
+
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.inner.query_incentivized_packets(request)
+    }
+
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.inner.query_payee(channel_id, relayer)
+    }
+
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.inner.query_counterparty_payee(channel_id, relayer)
+    }
 }
@@ -8,6 +8,7 @@ use tracing::{debug, Span};
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -27,7 +28,7 @@ use ibc_relayer_types::Height;
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
-use crate::chain::endpoint::{ChainStatus, HealthCheck};
+use crate::chain::endpoint::{ChainFeatures, ChainStatus, HealthCheck};
 use crate::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
@@ -130,6 +131,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error> {
+        self.inc_metric("simulate_messages");
+        self.inner().simulate_messages(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.inc_metric("get_signer");
         self.inner().get_signer()
@@ -150,11 +156,21 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().add_key(key_name, key)
     }
 
+    fn reload_key(&self, key_name: Option<String>) -> Result<Signer, Error> {
+        self.inc_metric("reload_key");
+        self.inner().reload_key(key_name)
+    }
+
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
         self.inc_metric("ibc_version");
         self.inner().ibc_version()
     }
 
+    fn query_features(&self) -> Result<ChainFeatures, Error> {
+        self.inc_metric("query_features");
+        self.inner().query_features()
+    }
+
     fn query_balance(
         &self,
         key_name: Option<String>,
@@ -503,4 +519,30 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inc_metric("query_incentivized_packet");
         self.inner.query_incentivized_packet(request)
     }
+
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.inc_metric("query_incentivized_packets");
+        self.inner.query_incentivized_packets(request)
+    }
+
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.inc_metric("query_payee");
+        self.inner.query_payee(channel_id, relayer)
+    }
+
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.inc_metric("query_counterparty_payee");
+        self.inner.query_counterparty_payee(channel_id, relayer)
+    }
 }
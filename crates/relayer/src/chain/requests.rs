@@ -3,6 +3,7 @@ use core::fmt::{self, Display};
 use crate::error::Error;
 
 use ibc_proto::cosmos::base::query::v1beta1::PageRequest as RawPageRequest;
+use ibc_proto::ibc::applications::fee::v1::QueryIncentivizedPacketsRequest as RawQueryIncentivizedPacketsRequest;
 use ibc_proto::ibc::core::channel::v1::{
     QueryChannelClientStateRequest as RawQueryChannelClientStateRequest,
     QueryChannelsRequest as RawQueryChannelsRequest,
@@ -492,3 +493,17 @@ pub struct CrossChainQueryRequest {
     pub request: String,
     pub height: TMBlockHeight,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryIncentivizedPacketsRequest {
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<QueryIncentivizedPacketsRequest> for RawQueryIncentivizedPacketsRequest {
+    fn from(request: QueryIncentivizedPacketsRequest) -> Self {
+        RawQueryIncentivizedPacketsRequest {
+            pagination: request.pagination.map(|pagination| pagination.into()),
+            query_height: 0,
+        }
+    }
+}
@@ -1,17 +1,27 @@
 use alloc::sync::Arc;
+use core::time::Duration;
+use std::collections::HashMap;
 use std::thread;
+use std::time::Instant;
 
 use crossbeam_channel as channel;
 use tokio::runtime::Runtime as TokioRuntime;
-use tracing::{error, Span};
+use tracing::{debug, error, Span};
 
+use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
+use ibc_proto::protobuf::Protobuf;
 use ibc_relayer_types::{
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     core::{
-        ics02_client::events::UpdateClient,
+        ics02_client::{
+            events::UpdateClient,
+            header::Header,
+            msgs::update_client::{MsgUpdateClient, TYPE_URL as UPDATE_CLIENT_TYPE_URL},
+        },
         ics03_connection::{
             connection::{ConnectionEnd, IdentifiedConnectionEnd},
             version::Version,
@@ -32,25 +42,32 @@ use crate::{
     account::Balance,
     chain::requests::QueryPacketEventDataRequest,
     client_state::{AnyClientState, IdentifiedAnyClientState},
-    config::ChainConfig,
+    config::{channel_batch::BatchLimits, ChainConfig},
     connection::ConnectionMsgType,
     consensus_state::AnyConsensusState,
     denom::DenomTrace,
     error::Error,
     event::IbcEventWithHeight,
     keyring::AnySigningKeyPair,
-    light_client::AnyHeader,
+    light_client::{registry::client_registry, AnyHeader},
     misbehaviour::MisbehaviourEvidence,
+    util::profile,
 };
 
 use super::{
     client::ClientSettings,
-    endpoint::{ChainEndpoint, ChainStatus, HealthCheck},
+    endpoint::{ChainEndpoint, ChainFeatures, ChainStatus, HealthCheck},
     handle::{ChainHandle, ChainRequest, ReplyTo, Subscription},
     requests::*,
-    tracking::TrackedMsgs,
+    tracking::{TrackedMsgs, TrackingId},
 };
 
+/// A request to send messages and wait for `CheckTx`, along with where to send the responses.
+type CheckTxRequest = (
+    TrackedMsgs,
+    ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
+);
+
 pub struct Threads {
     pub chain_runtime: thread::JoinHandle<()>,
     pub event_source: Option<thread::JoinHandle<()>>,
@@ -102,7 +119,8 @@ where
 
         // Spawn the runtime & return
         let id = handle.id();
-        let thread = thread::spawn(move || {
+
+        let thread = profile::spawn_thread(move || {
             if let Err(e) = chain_runtime.run() {
                 error!("failed to start runtime for chain '{}': {}", id, e);
             }
@@ -144,217 +162,305 @@ where
 
                     let _span = span.entered();
 
-                    match event {
-                        ChainRequest::Shutdown { reply_to } => {
-                            let res = self.chain.shutdown();
-
-                            reply_to.send(res)
-                                .map_err(Error::send)?;
-
-                            break;
-                        },
+                    if let Some(reply_to) = self.handle_request(event)? {
+                        let res = self.chain.shutdown();
 
-                        ChainRequest::HealthCheck { reply_to } => {
-                            self.health_check(reply_to)?
-                        },
+                        reply_to.send(res)
+                            .map_err(Error::send)?;
 
-                        ChainRequest::Subscribe { reply_to } => {
-                            self.subscribe(reply_to)?
-                        },
-
-                        ChainRequest::SendMessagesAndWaitCommit { tracked_msgs, reply_to } => {
-                            self.send_messages_and_wait_commit(tracked_msgs, reply_to)?
-                        },
-
-                        ChainRequest::SendMessagesAndWaitCheckTx { tracked_msgs, reply_to } => {
-                            self.send_messages_and_wait_check_tx(tracked_msgs, reply_to)?
-                        },
-
-                        ChainRequest::Signer { reply_to } => {
-                            self.get_signer(reply_to)?
-                        },
-
-                        ChainRequest::Config { reply_to } => {
-                            self.get_config(reply_to)?
-                        },
+                        break;
+                    }
+                },
+            }
+        }
 
-                        ChainRequest::GetKey { reply_to } => {
-                            self.get_key(reply_to)?
-                        },
+        Ok(())
+    }
 
-                        ChainRequest::AddKey { key_name, key, reply_to } => {
-                            self.add_key(key_name, key, reply_to)?
-                        },
+    /// Handles a request, except for a request to shut down which is handed back
+    /// to the caller, as the chain endpoint is consumed when shut down.
+    fn handle_request(&mut self, request: ChainRequest) -> Result<Option<ReplyTo<()>>, Error> {
+        match request {
+            ChainRequest::Shutdown { reply_to } => return Ok(Some(reply_to)),
 
-                        ChainRequest::IbcVersion { reply_to } => {
-                            self.ibc_version(reply_to)?
-                        },
+            ChainRequest::HealthCheck { reply_to } => self.health_check(reply_to)?,
 
-                        ChainRequest::BuildHeader { trusted_height, target_height, client_state, reply_to } => {
-                            self.build_header(trusted_height, target_height, client_state, reply_to)?
-                        },
+            ChainRequest::Subscribe { reply_to } => self.subscribe(reply_to)?,
 
-                        ChainRequest::BuildClientState { height, settings, reply_to } => {
-                            self.build_client_state(height, settings, reply_to)?
-                        },
+            ChainRequest::SendMessagesAndWaitCommit {
+                tracked_msgs,
+                reply_to,
+            } => self.send_messages_and_wait_commit(tracked_msgs, reply_to)?,
 
-                        ChainRequest::BuildConsensusState { trusted, target, client_state, reply_to } => {
-                            self.build_consensus_state(trusted, target, client_state, reply_to)?
-                        },
+            ChainRequest::SendMessagesAndWaitCheckTx {
+                tracked_msgs,
+                reply_to,
+            } => self.send_messages_and_wait_check_tx(tracked_msgs, reply_to)?,
 
-                        ChainRequest::BuildMisbehaviour { client_state, update_event, reply_to } => {
-                            self.check_misbehaviour(update_event, client_state, reply_to)?
-                        },
+            ChainRequest::SimulateMessages {
+                tracked_msgs,
+                reply_to,
+            } => self.simulate_messages(tracked_msgs, reply_to)?,
 
-                        ChainRequest::BuildConnectionProofsAndClientState { message_type, connection_id, client_id, height, reply_to } => {
-                            self.build_connection_proofs_and_client_state(message_type, connection_id, client_id, height, reply_to)?
-                        },
+            ChainRequest::Signer { reply_to } => self.get_signer(reply_to)?,
 
-                        ChainRequest::BuildChannelProofs { port_id, channel_id, height, reply_to } => {
-                            self.build_channel_proofs(port_id, channel_id, height, reply_to)?
-                        },
+            ChainRequest::Config { reply_to } => self.get_config(reply_to)?,
 
-                        ChainRequest::QueryBalance { key_name, denom, reply_to } => {
-                            self.query_balance(key_name, denom, reply_to)?
-                        },
+            ChainRequest::GetKey { reply_to } => self.get_key(reply_to)?,
 
-                        ChainRequest::QueryAllBalances { key_name, reply_to } => {
-                            self.query_all_balances(key_name, reply_to)?
-                        },
+            ChainRequest::AddKey {
+                key_name,
+                key,
+                reply_to,
+            } => self.add_key(key_name, key, reply_to)?,
 
-                        ChainRequest::QueryDenomTrace { hash, reply_to } => {
-                            self.query_denom_trace(hash, reply_to)?
-                        },
+            ChainRequest::ReloadKey { key_name, reply_to } => {
+                self.reload_key(key_name, reply_to)?
+            }
 
-                        ChainRequest::QueryApplicationStatus { reply_to } => {
-                            self.query_application_status(reply_to)?
-                        },
+            ChainRequest::IbcVersion { reply_to } => self.ibc_version(reply_to)?,
+
+            ChainRequest::QueryFeatures { reply_to } => self.query_features(reply_to)?,
+
+            ChainRequest::BuildHeader {
+                trusted_height,
+                target_height,
+                client_state,
+                reply_to,
+            } => self.build_header(trusted_height, target_height, client_state, reply_to)?,
+
+            ChainRequest::BuildClientState {
+                height,
+                settings,
+                reply_to,
+            } => self.build_client_state(height, settings, reply_to)?,
+
+            ChainRequest::BuildConsensusState {
+                trusted,
+                target,
+                client_state,
+                reply_to,
+            } => self.build_consensus_state(trusted, target, client_state, reply_to)?,
+
+            ChainRequest::BuildMisbehaviour {
+                client_state,
+                update_event,
+                reply_to,
+            } => self.check_misbehaviour(update_event, client_state, reply_to)?,
+
+            ChainRequest::BuildConnectionProofsAndClientState {
+                message_type,
+                connection_id,
+                client_id,
+                height,
+                reply_to,
+            } => self.build_connection_proofs_and_client_state(
+                message_type,
+                connection_id,
+                client_id,
+                height,
+                reply_to,
+            )?,
+
+            ChainRequest::BuildChannelProofs {
+                port_id,
+                channel_id,
+                height,
+                reply_to,
+            } => self.build_channel_proofs(port_id, channel_id, height, reply_to)?,
+
+            ChainRequest::QueryBalance {
+                key_name,
+                denom,
+                reply_to,
+            } => self.query_balance(key_name, denom, reply_to)?,
+
+            ChainRequest::QueryAllBalances { key_name, reply_to } => {
+                self.query_all_balances(key_name, reply_to)?
+            }
 
-                        ChainRequest::QueryClients { request, reply_to } => {
-                            self.query_clients(request, reply_to)?
-                        },
+            ChainRequest::QueryDenomTrace { hash, reply_to } => {
+                self.query_denom_trace(hash, reply_to)?
+            }
 
-                        ChainRequest::QueryClientConnections { request, reply_to } => {
-                            self.query_client_connections(request, reply_to)?
-                        },
+            ChainRequest::QueryApplicationStatus { reply_to } => {
+                self.query_application_status(reply_to)?
+            }
 
-                        ChainRequest::QueryClientState { request, include_proof, reply_to } => {
-                            self.query_client_state(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryClients { request, reply_to } => {
+                self.query_clients(request, reply_to)?
+            }
 
-                        ChainRequest::QueryConsensusStateHeights { request, reply_to } => {
-                            self.query_consensus_state_heights(request, reply_to)?
-                        },
+            ChainRequest::QueryClientConnections { request, reply_to } => {
+                self.query_client_connections(request, reply_to)?
+            }
 
-                        ChainRequest::QueryConsensusState { request, include_proof, reply_to } => {
-                            self.query_consensus_state(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryClientState {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_client_state(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryUpgradedClientState { request, reply_to } => {
-                            self.query_upgraded_client_state(request, reply_to)?
-                        },
+            ChainRequest::QueryConsensusStateHeights { request, reply_to } => {
+                self.query_consensus_state_heights(request, reply_to)?
+            }
 
-                        ChainRequest::QueryUpgradedConsensusState { request, reply_to } => {
-                            self.query_upgraded_consensus_state(request, reply_to)?
-                        },
+            ChainRequest::QueryConsensusState {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_consensus_state(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryCommitmentPrefix { reply_to } => {
-                            self.query_commitment_prefix(reply_to)?
-                        },
+            ChainRequest::QueryUpgradedClientState { request, reply_to } => {
+                self.query_upgraded_client_state(request, reply_to)?
+            }
 
-                        ChainRequest::QueryCompatibleVersions { reply_to } => {
-                            self.query_compatible_versions(reply_to)?
-                        },
+            ChainRequest::QueryUpgradedConsensusState { request, reply_to } => {
+                self.query_upgraded_consensus_state(request, reply_to)?
+            }
 
-                        ChainRequest::QueryConnection { request, include_proof, reply_to } => {
-                            self.query_connection(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryCommitmentPrefix { reply_to } => {
+                self.query_commitment_prefix(reply_to)?
+            }
 
-                        ChainRequest::QueryConnections { request, reply_to } => {
-                            self.query_connections(request, reply_to)?
-                        },
+            ChainRequest::QueryCompatibleVersions { reply_to } => {
+                self.query_compatible_versions(reply_to)?
+            }
 
-                        ChainRequest::QueryConnectionChannels { request, reply_to } => {
-                            self.query_connection_channels(request, reply_to)?
-                        },
+            ChainRequest::QueryConnection {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_connection(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryChannels { request, reply_to } => {
-                            self.query_channels(request, reply_to)?
-                        },
+            ChainRequest::QueryConnections { request, reply_to } => {
+                self.query_connections(request, reply_to)?
+            }
 
-                        ChainRequest::QueryChannel { request, include_proof, reply_to } => {
-                            self.query_channel(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryConnectionChannels { request, reply_to } => {
+                self.query_connection_channels(request, reply_to)?
+            }
 
-                        ChainRequest::QueryChannelClientState { request, reply_to } => {
-                            self.query_channel_client_state(request, reply_to)?
-                        },
+            ChainRequest::QueryChannels { request, reply_to } => {
+                self.query_channels(request, reply_to)?
+            }
 
-                        ChainRequest::BuildPacketProofs { packet_type, port_id, channel_id, sequence, height, reply_to } => {
-                            self.build_packet_proofs(packet_type, port_id, channel_id, sequence, height, reply_to)?
-                        },
+            ChainRequest::QueryChannel {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_channel(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryPacketCommitment { request, include_proof, reply_to } => {
-                            self.query_packet_commitment(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryChannelClientState { request, reply_to } => {
+                self.query_channel_client_state(request, reply_to)?
+            }
 
-                        ChainRequest::QueryPacketCommitments { request, reply_to } => {
-                            self.query_packet_commitments(request, reply_to)?
-                        },
+            ChainRequest::BuildPacketProofs {
+                packet_type,
+                port_id,
+                channel_id,
+                sequence,
+                height,
+                reply_to,
+            } => self.build_packet_proofs(
+                packet_type,
+                port_id,
+                channel_id,
+                sequence,
+                height,
+                reply_to,
+            )?,
+
+            ChainRequest::QueryPacketCommitment {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_packet_commitment(request, include_proof, reply_to)?,
+
+            ChainRequest::QueryPacketCommitments { request, reply_to } => {
+                self.query_packet_commitments(request, reply_to)?
+            }
 
-                        ChainRequest::QueryPacketReceipt { request, include_proof, reply_to } => {
-                            self.query_packet_receipt(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryPacketReceipt {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_packet_receipt(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryUnreceivedPackets { request, reply_to } => {
-                            self.query_unreceived_packets(request, reply_to)?
-                        },
+            ChainRequest::QueryUnreceivedPackets { request, reply_to } => {
+                self.query_unreceived_packets(request, reply_to)?
+            }
 
-                        ChainRequest::QueryPacketAcknowledgement { request, include_proof, reply_to } => {
-                            self.query_packet_acknowledgement(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryPacketAcknowledgement {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_packet_acknowledgement(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryPacketAcknowledgements { request, reply_to } => {
-                            self.query_packet_acknowledgements(request, reply_to)?
-                        },
+            ChainRequest::QueryPacketAcknowledgements { request, reply_to } => {
+                self.query_packet_acknowledgements(request, reply_to)?
+            }
 
-                        ChainRequest::QueryUnreceivedAcknowledgement { request, reply_to } => {
-                            self.query_unreceived_acknowledgement(request, reply_to)?
-                        },
+            ChainRequest::QueryUnreceivedAcknowledgement { request, reply_to } => {
+                self.query_unreceived_acknowledgement(request, reply_to)?
+            }
 
-                        ChainRequest::QueryNextSequenceReceive { request, include_proof, reply_to } => {
-                            self.query_next_sequence_receive(request, include_proof, reply_to)?
-                        },
+            ChainRequest::QueryNextSequenceReceive {
+                request,
+                include_proof,
+                reply_to,
+            } => self.query_next_sequence_receive(request, include_proof, reply_to)?,
 
-                        ChainRequest::QueryPacketEventDataFromTxs { request, reply_to } => {
-                            self.query_txs(request, reply_to)?
-                        },
+            ChainRequest::QueryPacketEventDataFromTxs { request, reply_to } => {
+                self.query_txs(request, reply_to)?
+            }
 
-                        ChainRequest::QueryPacketEventData { request, reply_to } => {
-                            self.query_packet_events(request, reply_to)?
-                        },
+            ChainRequest::QueryPacketEventData { request, reply_to } => {
+                self.query_packet_events(request, reply_to)?
+            }
 
-                        ChainRequest::QueryHostConsensusState { request, reply_to } => {
-                            self.query_host_consensus_state(request, reply_to)?
-                        },
+            ChainRequest::QueryHostConsensusState { request, reply_to } => {
+                self.query_host_consensus_state(request, reply_to)?
+            }
 
-                        ChainRequest::MaybeRegisterCounterpartyPayee { channel_id, port_id, counterparty_payee, reply_to } => {
-                            self.maybe_register_counterparty_payee(&channel_id, &port_id, &counterparty_payee, reply_to)?
-                        },
+            ChainRequest::MaybeRegisterCounterpartyPayee {
+                channel_id,
+                port_id,
+                counterparty_payee,
+                reply_to,
+            } => self.maybe_register_counterparty_payee(
+                &channel_id,
+                &port_id,
+                &counterparty_payee,
+                reply_to,
+            )?,
+
+            ChainRequest::CrossChainQuery { request, reply_to } => {
+                self.cross_chain_query(request, reply_to)?
+            }
 
-                        ChainRequest::CrossChainQuery { request, reply_to } => {
-                            self.cross_chain_query(request, reply_to)?
-                        },
+            ChainRequest::QueryIncentivizedPacket { request, reply_to } => {
+                self.query_incentivized_packet(request, reply_to)?
+            }
 
-                        ChainRequest::QueryIncentivizedPacket { request, reply_to } => {
-                            self.query_incentivized_packet(request, reply_to)?
-                        },
-                    }
-                },
+            ChainRequest::QueryIncentivizedPackets { request, reply_to } => {
+                self.query_incentivized_packets(request, reply_to)?
             }
+
+            ChainRequest::QueryPayee {
+                channel_id,
+                relayer,
+                reply_to,
+            } => self.query_payee(channel_id, relayer, reply_to)?,
+
+            ChainRequest::QueryCounterpartyPayee {
+                channel_id,
+                relayer,
+                reply_to,
+            } => self.query_counterparty_payee(channel_id, relayer, reply_to)?,
         }
 
-        Ok(())
+        Ok(None)
     }
 
     fn health_check(&mut self, reply_to: ReplyTo<HealthCheck>) -> Result<(), Error> {
@@ -381,8 +487,114 @@ where
         tracked_msgs: TrackedMsgs,
         reply_to: ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
     ) -> Result<(), Error> {
-        let result = self.chain.send_messages_and_wait_check_tx(tracked_msgs);
-        reply_to.send(result).map_err(Error::send)
+        let Some(window) = self.chain.config().aggregation_window else {
+            let result = self.chain.send_messages_and_wait_check_tx(tracked_msgs);
+            return reply_to.send(result).map_err(Error::send);
+        };
+
+        let requests = self.aggregate_check_tx_requests(tracked_msgs, reply_to, window)?;
+
+        if requests.len() == 1 {
+            let (tracked_msgs, reply_to) = requests.into_iter().next().unwrap();
+            let result = self.chain.send_messages_and_wait_check_tx(tracked_msgs);
+            return reply_to.send(result).map_err(Error::send);
+        }
+
+        // The shared transactions are within the limits of all the requests
+        let limits = requests
+            .iter()
+            .fold(BatchLimits::default(), |limits, (tracked_msgs, _)| {
+                limits.min(tracked_msgs.limits)
+            });
+
+        let (msgs, replies): (Vec<_>, Vec<_>) = requests
+            .into_iter()
+            .map(|(tracked_msgs, reply_to)| (tracked_msgs.msgs, reply_to))
+            .unzip();
+
+        let (msgs, positions) = merge_requests(msgs);
+
+        debug!(
+            "merging {} messages from {} requests into shared transactions",
+            msgs.len(),
+            replies.len()
+        );
+
+        let tracked_msgs =
+            TrackedMsgs::new(msgs, TrackingId::new_static("aggregated")).with_limits(limits);
+
+        // Each requester gets the responses of the shared transactions carrying the messages
+        // it relies on, and thus resubmits its messages if one of those transactions fails.
+        // If it is not known which transactions carry which messages, all the requesters get
+        // all the responses.
+        match self
+            .chain
+            .send_messages_and_wait_check_tx_batches(tracked_msgs)
+        {
+            Ok((responses, message_counts)) => {
+                let message_counts =
+                    message_counts.filter(|counts| counts.len() == responses.len());
+
+                for (reply_to, positions) in replies.into_iter().zip(positions) {
+                    let responses = match &message_counts {
+                        Some(message_counts) => {
+                            responses_for(&responses, message_counts, &positions)
+                        }
+                        None => responses.clone(),
+                    };
+
+                    reply_to.send(Ok(responses)).map_err(Error::send)?;
+                }
+            }
+            Err(e) => {
+                let category = e.category();
+                let reason = e.to_string();
+
+                for reply_to in replies {
+                    reply_to
+                        .send(Err(Error::aggregated_tx(category, reason.clone())))
+                        .map_err(Error::send)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collects the requests to send messages and wait for `CheckTx` received during the
+    /// aggregation window. The other requests received meanwhile are handled right away,
+    /// except for a request to shut down, which ends the window and is put back in the queue.
+    fn aggregate_check_tx_requests(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
+        window: Duration,
+    ) -> Result<Vec<CheckTxRequest>, Error> {
+        let deadline = Instant::now() + window;
+
+        let mut requests = vec![(tracked_msgs, reply_to)];
+
+        while let Ok((span, request)) = self.request_receiver.recv_deadline(deadline) {
+            match request {
+                ChainRequest::SendMessagesAndWaitCheckTx {
+                    tracked_msgs,
+                    reply_to,
+                } => requests.push((tracked_msgs, reply_to)),
+                request @ ChainRequest::Shutdown { .. } => {
+                    self.request_sender
+                        .send((span, request))
+                        .map_err(Error::send)?;
+
+                    break;
+                }
+                request => {
+                    let _span = span.entered();
+                    self.handle_request(request)?;
+                }
+            }
+        }
+
+        Ok(requests)
     }
 
     fn query_balance(
@@ -417,6 +629,15 @@ where
         reply_to.send(latest_timestamp).map_err(Error::send)
     }
 
+    fn simulate_messages(
+        &self,
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Option<u64>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.simulate_messages(tracked_msgs);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn get_signer(&mut self, reply_to: ReplyTo<Signer>) -> Result<(), Error> {
         let result = self.chain.get_signer();
         reply_to.send(result).map_err(Error::send)
@@ -445,11 +666,25 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn reload_key(
+        &mut self,
+        key_name: Option<String>,
+        reply_to: ReplyTo<Signer>,
+    ) -> Result<(), Error> {
+        let result = self.chain.reload_key(key_name);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn ibc_version(&mut self, reply_to: ReplyTo<Option<semver::Version>>) -> Result<(), Error> {
         let result = self.chain.ibc_version();
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_features(&self, reply_to: ReplyTo<ChainFeatures>) -> Result<(), Error> {
+        let result = self.chain.query_features();
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn build_header(
         &mut self,
         trusted_height: Height,
@@ -457,14 +692,17 @@ where
         client_state: AnyClientState,
         reply_to: ReplyTo<(AnyHeader, Vec<AnyHeader>)>,
     ) -> Result<(), Error> {
-        let result = self
-            .chain
-            .build_header(trusted_height, target_height, &client_state)
-            .map(|(header, support)| {
-                let header = header.into();
-                let support = support.into_iter().map(|h| h.into()).collect();
-                (header, support)
-            });
+        // The registry is not locked while the header is built
+        let handler = client_registry().handler_for(&client_state);
+
+        let result = handler.map_err(Error::ics02).and_then(|handler| {
+            handler.build_header(
+                &mut self.chain,
+                trusted_height,
+                target_height,
+                &client_state,
+            )
+        });
 
         reply_to.send(result).map_err(Error::send)
     }
@@ -492,12 +730,11 @@ where
         client_state: AnyClientState,
         reply_to: ReplyTo<AnyConsensusState>,
     ) -> Result<(), Error> {
-        let verified = self.chain.verify_header(trusted, target, &client_state)?;
+        let handler = client_registry().handler_for(&client_state);
 
-        let consensus_state = self
-            .chain
-            .build_consensus_state(verified)
-            .map(|cs| cs.into());
+        let consensus_state = handler.map_err(Error::ics02).and_then(|handler| {
+            handler.build_consensus_state(&mut self.chain, trusted, target, &client_state)
+        });
 
         reply_to.send(consensus_state).map_err(Error::send)
     }
@@ -509,7 +746,11 @@ where
         client_state: AnyClientState,
         reply_to: ReplyTo<Option<MisbehaviourEvidence>>,
     ) -> Result<(), Error> {
-        let misbehaviour = self.chain.check_misbehaviour(&update_event, &client_state);
+        let handler = client_registry().handler_for(&client_state);
+
+        let misbehaviour = handler.map_err(Error::ics02).and_then(|handler| {
+            handler.check_misbehaviour(&mut self.chain, &update_event, &client_state)
+        });
 
         reply_to.send(misbehaviour).map_err(Error::send)
     }
@@ -850,4 +1091,194 @@ where
 
         Ok(())
     }
+
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+        reply_to: ReplyTo<Vec<IdentifiedPacketFees>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_incentivized_packets(request);
+        reply_to.send(result).map_err(Error::send)?;
+
+        Ok(())
+    }
+
+    fn query_payee(
+        &self,
+        channel_id: ChannelId,
+        relayer: Signer,
+        reply_to: ReplyTo<Option<Signer>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_payee(&channel_id, &relayer);
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn query_counterparty_payee(
+        &self,
+        channel_id: ChannelId,
+        relayer: Signer,
+        reply_to: ReplyTo<Option<Signer>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_counterparty_payee(&channel_id, &relayer);
+        reply_to.send(result).map_err(Error::send)
+    }
+}
+
+/// Merges the messages of the given requests into the messages of shared transactions.
+///
+/// The messages of each request are kept together and in order, so as to preserve the
+/// ordering of the packets of each channel. The messages updating a client to a height it
+/// was already updated to by a previous message are removed, as the requests each carry
+/// the update of the client needed to verify their proofs. The first update of each client
+/// and height is kept, so that it still precedes all the messages which rely on it.
+/// The updates whose header cannot be decoded are kept as is.
+///
+/// Returns the merged messages, along with the positions of the messages each request
+/// relies on among them, ie. its own messages and the client updates it needs.
+fn merge_requests(requests: Vec<Vec<Any>>) -> (Vec<Any>, Vec<Vec<usize>>) {
+    let mut merged = Vec::new();
+    let mut updates = HashMap::new();
+    let mut positions = Vec::with_capacity(requests.len());
+
+    for msgs in requests {
+        let mut request_positions = Vec::with_capacity(msgs.len());
+
+        for msg in msgs {
+            let key = client_update_key(&msg);
+
+            if let Some(position) = key.as_ref().and_then(|key| updates.get(key)) {
+                request_positions.push(*position);
+                continue;
+            }
+
+            if let Some(key) = key {
+                updates.insert(key, merged.len());
+            }
+
+            request_positions.push(merged.len());
+            merged.push(msg);
+        }
+
+        positions.push(request_positions);
+    }
+
+    (merged, positions)
+}
+
+/// The responses to the transactions carrying any of the messages at the given positions,
+/// given how many of the messages, in order, were sent in each of the transactions.
+fn responses_for<Response: Clone>(
+    responses: &[Response],
+    message_counts: &[usize],
+    positions: &[usize],
+) -> Vec<Response> {
+    let mut start = 0;
+
+    responses
+        .iter()
+        .zip(message_counts)
+        .filter_map(|(response, count)| {
+            let carried = start..start + count;
+            start += count;
+
+            positions
+                .iter()
+                .any(|position| carried.contains(position))
+                .then(|| response.clone())
+        })
+        .collect()
+}
+
+fn client_update_key(msg: &Any) -> Option<(ClientId, Height)> {
+    if msg.type_url != UPDATE_CLIENT_TYPE_URL {
+        return None;
+    }
+
+    let update = MsgUpdateClient::decode_vec(&msg.value).ok()?;
+    let header = AnyHeader::try_from(update.header).ok()?;
+
+    Some((update.client_id, header.height()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+    use ibc_relayer_types::test_utils::get_dummy_account_id;
+    use ibc_relayer_types::tx_msg::Msg;
+
+    fn update_client(client_id: &str) -> Any {
+        MsgUpdateClient::new(
+            client_id.parse().unwrap(),
+            get_dummy_ics07_header().into(),
+            get_dummy_account_id(),
+        )
+        .to_any()
+    }
+
+    fn recv_packet(sequence: u8) -> Any {
+        Any {
+            type_url: "/ibc.core.channel.v1.MsgRecvPacket".to_string(),
+            value: vec![sequence],
+        }
+    }
+
+    #[test]
+    fn dedup_client_updates_of_merged_requests() {
+        let msgs = [
+            update_client("07-tendermint-0"),
+            recv_packet(1),
+            update_client("07-tendermint-1"),
+            recv_packet(2),
+            update_client("07-tendermint-0"),
+            recv_packet(3),
+        ];
+
+        let expected = vec![
+            msgs[0].clone(),
+            msgs[1].clone(),
+            msgs[2].clone(),
+            msgs[3].clone(),
+            msgs[5].clone(),
+        ];
+
+        let requests = vec![
+            msgs[0..2].to_vec(),
+            msgs[2..4].to_vec(),
+            msgs[4..6].to_vec(),
+        ];
+
+        let (merged, positions) = merge_requests(requests);
+
+        assert_eq!(merged, expected);
+
+        // The last request relies on the update of the client carried by the first one
+        assert_eq!(positions, vec![vec![0, 1], vec![2, 3], vec![0, 4]]);
+    }
+
+    #[test]
+    fn fan_out_responses_to_the_requests_they_carry() {
+        let responses = ["tx-0", "tx-1", "tx-2"];
+
+        // The first transaction carries the first two messages, and so on
+        let message_counts = [2, 2, 1];
+
+        assert_eq!(
+            responses_for(&responses, &message_counts, &[0, 1]),
+            vec!["tx-0"]
+        );
+        assert_eq!(
+            responses_for(&responses, &message_counts, &[2, 3]),
+            vec!["tx-1"]
+        );
+        assert_eq!(
+            responses_for(&responses, &message_counts, &[0, 4]),
+            vec!["tx-0", "tx-2"]
+        );
+        assert_eq!(
+            responses_for(&responses, &message_counts, &[1, 2]),
+            vec!["tx-0", "tx-1"]
+        );
+    }
 }
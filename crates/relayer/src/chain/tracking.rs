@@ -3,6 +3,8 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use ibc_proto::google::protobuf::Any;
 use uuid::Uuid;
 
+use crate::config::channel_batch::BatchLimits;
+
 /// Identifier used to track an `EventBatch` along
 /// the relaying pipeline until the corresponding
 /// transactions are submitted and/or confirmed.
@@ -65,17 +67,24 @@ impl Display for TrackingId {
 pub struct TrackedMsgs {
     pub msgs: Vec<Any>,
     pub tracking_id: TrackingId,
+    /// The limits of the batches of these messages, lowering those of the chain
+    pub limits: BatchLimits,
 }
 
 impl TrackedMsgs {
     pub fn new(msgs: Vec<Any>, tracking_id: TrackingId) -> Self {
-        Self { msgs, tracking_id }
+        Self {
+            msgs,
+            tracking_id,
+            limits: BatchLimits::default(),
+        }
     }
 
     pub fn new_static(msgs: Vec<Any>, tracking_id: &'static str) -> Self {
         Self {
             msgs,
             tracking_id: TrackingId::Static(tracking_id),
+            limits: BatchLimits::default(),
         }
     }
 
@@ -83,6 +92,7 @@ impl TrackedMsgs {
         Self {
             msgs,
             tracking_id: TrackingId::Uuid(tracking_id),
+            limits: BatchLimits::default(),
         }
     }
 
@@ -90,6 +100,7 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Static(tracking_id),
+            limits: BatchLimits::default(),
         }
     }
 
@@ -97,9 +108,16 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Uuid(tracking_id),
+            limits: BatchLimits::default(),
         }
     }
 
+    /// Sends these messages in batches within the given limits, if lower than those of the chain.
+    pub fn with_limits(mut self, limits: BatchLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn messages(&self) -> &Vec<Any> {
         &self.msgs
     }
@@ -5,6 +5,7 @@ use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawClientState;
 #[cfg(test)]
 use ibc_proto::ibc::mock::ClientState as RawMockClientState;
 use ibc_proto::protobuf::Protobuf;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
@@ -27,6 +28,8 @@ use ibc_relayer_types::mock::client_state::MockClientState;
 use ibc_relayer_types::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
 use ibc_relayer_types::Height;
 
+use crate::light_client::registry::client_registry;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AnyUpgradeOptions {
@@ -55,6 +58,11 @@ pub enum AnyClientState {
 
     #[cfg(test)]
     Mock(MockClientState),
+
+    /// A client state of a client type registered with
+    /// [`register_client_handler`](crate::light_client::registry::register_client_handler).
+    #[serde(skip_deserializing)]
+    Other(Box<dyn ClientState>),
 }
 
 impl AnyClientState {
@@ -64,6 +72,8 @@ impl AnyClientState {
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.latest_height(),
+
+            Self::Other(state) => state.latest_height(),
         }
     }
 
@@ -73,6 +83,8 @@ impl AnyClientState {
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.frozen_height(),
+
+            Self::Other(state) => state.frozen_height(),
         }
     }
 
@@ -82,6 +94,8 @@ impl AnyClientState {
 
             #[cfg(test)]
             AnyClientState::Mock(_) => None,
+
+            AnyClientState::Other(_) => None,
         }
     }
 
@@ -91,6 +105,8 @@ impl AnyClientState {
 
             #[cfg(test)]
             AnyClientState::Mock(_) => Duration::new(0, 0),
+
+            AnyClientState::Other(_) => Duration::new(0, 0),
         }
     }
 
@@ -100,6 +116,8 @@ impl AnyClientState {
 
             #[cfg(test)]
             Self::Mock(state) => state.client_type(),
+
+            Self::Other(state) => state.client_type(),
         }
     }
 
@@ -109,6 +127,10 @@ impl AnyClientState {
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.refresh_time(),
+
+            AnyClientState::Other(state) => client_registry()
+                .handler(state.client_type().as_str())
+                .and_then(|handler| handler.refresh_period(state.as_ref())),
         }
     }
 }
@@ -119,22 +141,7 @@ impl TryFrom<Any> for AnyClientState {
     type Error = Error;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        match raw.type_url.as_str() {
-            "" => Err(Error::empty_client_state_response()),
-
-            TENDERMINT_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Tendermint(
-                Protobuf::<RawClientState>::decode_vec(&raw.value)
-                    .map_err(Error::decode_raw_client_state)?,
-            )),
-
-            #[cfg(test)]
-            MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
-                Protobuf::<RawMockClientState>::decode_vec(&raw.value)
-                    .map_err(Error::decode_raw_client_state)?,
-            )),
-
-            _ => Err(Error::unknown_client_state_type(raw.type_url)),
-        }
+        client_registry().decode_client_state(&raw)
     }
 }
 
@@ -150,6 +157,9 @@ impl From<AnyClientState> for Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockClientState>::encode_vec(&value),
             },
+
+            AnyClientState::Other(value) => Any::decode(value.encode_vec().as_slice())
+                .expect("a client state is encoded as an `Any`"),
         }
     }
 }
@@ -161,6 +171,8 @@ impl ClientState for AnyClientState {
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
+
+            AnyClientState::Other(state) => state.chain_id(),
         }
     }
 
@@ -198,6 +210,10 @@ impl ClientState for AnyClientState {
             AnyClientState::Mock(mock_state) => {
                 mock_state.upgrade(upgrade_height, upgrade_options, chain_id)
             }
+
+            AnyClientState::Other(state) => {
+                state.upgrade(upgrade_height, upgrade_options, chain_id)
+            }
         }
     }
 
@@ -207,6 +223,8 @@ impl ClientState for AnyClientState {
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
+
+            AnyClientState::Other(state) => state.expired(elapsed_since_latest),
         }
     }
 }
@@ -234,7 +252,7 @@ impl From<&dyn ClientState> for AnyClientState {
         if let Some(cs) = downcast_client_state::<TmClientState>(client_state) {
             AnyClientState::from(cs.clone())
         } else {
-            unreachable!()
+            AnyClientState::Other(dyn_clone::clone_box(client_state))
         }
     }
 }
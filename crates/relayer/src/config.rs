@@ -1,9 +1,23 @@
 //! Relayer configuration
 
+pub mod adaptive_batch;
+pub mod cache;
+pub mod channel_batch;
+pub mod coordination;
+pub mod dynamic_gas;
 pub mod error;
+pub mod event_sink;
 pub mod filter;
 pub mod gas_multiplier;
+pub mod height_rollback;
+pub mod hooks;
+pub mod packet_memo;
+pub mod profile;
 pub mod proof_specs;
+pub mod proxy;
+pub mod rate_limit;
+pub mod retry;
+pub mod submitter;
 pub mod types;
 
 use alloc::collections::BTreeMap;
@@ -32,7 +46,21 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::ChainType;
+use crate::config::adaptive_batch::AdaptiveBatchConfig;
+use crate::config::cache::CacheConfig;
+use crate::config::channel_batch::ChannelBatchConfig;
+use crate::config::coordination::CoordinationConfig;
+use crate::config::dynamic_gas::DynamicGasPriceConfig;
+use crate::config::event_sink::EventSinkConfig;
 use crate::config::gas_multiplier::GasMultiplier;
+use crate::config::height_rollback::HeightRollbackConfig;
+use crate::config::hooks::HooksConfig;
+use crate::config::packet_memo::PacketMemoConfig;
+use crate::config::profile::ProfileConfig;
+use crate::config::proxy::ProxyConfig;
+use crate::config::rate_limit::RateLimitConfig;
+use crate::config::retry::RetryConfig;
+use crate::config::submitter::SubmitterConfig;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
 use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
@@ -238,6 +266,15 @@ pub struct Config {
     pub rest: RestConfig,
     #[serde(default)]
     pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub event_sink: EventSinkConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub coordination: CoordinationConfig,
+    /// The other relaying profiles hosted by this process, each with its own supervisor
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<ProfileConfig>,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub chains: Vec<ChainConfig>,
 }
@@ -255,6 +292,25 @@ impl Config {
         self.chains.iter_mut().find(|c| c.id == *id)
     }
 
+    /// Follows an upgrade of the given chain to the given identifier, with a higher
+    /// revision number, by renaming the chain so that its runtime and workers are
+    /// spawned for the new identifier from now on.
+    ///
+    /// Returns whether the chain was renamed.
+    pub fn follow_chain_upgrade(&mut self, id: &ChainId, upgraded_id: &ChainId) -> bool {
+        if !id.is_upgraded_to(upgraded_id) || self.has_chain(upgraded_id) {
+            return false;
+        }
+
+        match self.find_chain_mut(id) {
+            Some(chain_config) => {
+                chain_config.id = upgraded_id.clone();
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Returns true if filtering is disabled or if packets are allowed on
     /// the channel [`PortId`] [`ChannelId`] on [`ChainId`].
     /// Returns false otherwise.
@@ -265,10 +321,7 @@ impl Config {
         channel_id: &ChannelId,
     ) -> bool {
         match self.find_chain(chain_id) {
-            Some(chain_config) => chain_config
-                .packet_filter
-                .channel_policy
-                .is_allowed(port_id, channel_id),
+            Some(chain_config) => chain_config.packet_filter.is_allowed(port_id, channel_id),
             None => false,
         }
     }
@@ -482,6 +535,13 @@ pub struct RestConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    /// Whether the keys of the chains can be reloaded through the REST API
+    #[serde(default)]
+    pub allow_key_reload: bool,
+    /// The file holding the token which the requests reloading a key,
+    /// or acknowledging a suspect client, must carry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_file: Option<PathBuf>,
 }
 
 impl Default for RestConfig {
@@ -490,6 +550,8 @@ impl Default for RestConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3000,
+            allow_key_reload: false,
+            token_file: None,
         }
     }
 }
@@ -622,6 +684,16 @@ pub struct ChainConfig {
     #[serde(default, with = "humantime_serde")]
     pub trusting_period: Option<Duration>,
 
+    /// The safety margin before the end of the trusting period of the consensus states of
+    /// the clients of this chain, within which they are not used to build client updates,
+    /// and before which the clients are refreshed.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub trusting_period_margin: Option<Duration>,
+
     /// CCV consumer chain
     #[serde(default = "default::ccv_consumer_chain")]
     pub ccv_consumer_chain: bool,
@@ -637,6 +709,15 @@ pub struct ChainConfig {
     #[serde(default)]
     pub sequential_batch_tx: bool,
 
+    /// How long the chain runtime waits for other workers to submit messages to the chain,
+    /// to merge them into shared transactions. Disabled if unset.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub aggregation_window: Option<Duration>,
+
     // Note: These last few need to be last otherwise we run into `ValueAfterTable` error when serializing to TOML.
     //       That's because these are all tables and have to come last when serializing.
     #[serde(
@@ -654,15 +735,73 @@ pub struct ChainConfig {
 
     pub gas_price: GasPrice,
 
+    /// Derives the gas price of each transaction from the base fee of the chain,
+    /// queried before the transaction is submitted, instead of using `gas_price`
+    #[serde(default, skip_serializing_if = "DynamicGasPriceConfig::is_default")]
+    pub dynamic_gas_price: DynamicGasPriceConfig,
+
     #[serde(default)]
     pub packet_filter: PacketFilter,
 
+    /// The proxy through which to connect to the RPC and gRPC endpoints of the chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+
+    /// The remote process to which the messages are sent to be signed and submitted,
+    /// in which case the key of the chain does not need to be held by this process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submitter: Option<SubmitterConfig>,
+
+    /// The maximum rate of the requests to the RPC and gRPC endpoints of the chain
+    #[serde(default, skip_serializing_if = "RateLimitConfig::is_unlimited")]
+    pub rate_limit: RateLimitConfig,
+
+    /// The policies for retrying the submission of messages to the chain,
+    /// for each category of error raised by the chain
+    #[serde(default, skip_serializing_if = "RetryConfig::is_default")]
+    pub retry: RetryConfig,
+
+    /// The maximum size of the memo of the packets sent by the chain,
+    /// and what to do with the packets whose memo is larger
+    #[serde(default, skip_serializing_if = "PacketMemoConfig::is_default")]
+    pub packet_memo: PacketMemoConfig,
+
+    /// When to halt relaying from and to the chain if its height goes backwards
+    #[serde(default, skip_serializing_if = "HeightRollbackConfig::is_default")]
+    pub height_rollback: HeightRollbackConfig,
+
+    /// How long the results of the queries made by the workers to the chain are cached
+    #[serde(default, skip_serializing_if = "CacheConfig::is_default")]
+    pub cache: CacheConfig,
+
+    /// Adapts the batch limits to the block capacity of the chain,
+    /// between the configured minima and `max_msg_num` and `max_tx_size`
+    #[serde(default, skip_serializing_if = "AdaptiveBatchConfig::is_default")]
+    pub adaptive_batch: AdaptiveBatchConfig,
+
+    /// Overrides of the batching of the chain for some of its channels
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_batch: Vec<ChannelBatchConfig>,
+
     #[serde(default)]
     pub address_type: AddressType,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub extension_options: Vec<ExtensionOption>,
 }
 
+impl ChainConfig {
+    /// The overrides of the batching of the chain for the given channel, if any.
+    pub fn channel_batch(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Option<&ChannelBatchConfig> {
+        self.channel_batch
+            .iter()
+            .find(|config| config.matches(port_id, channel_id))
+    }
+}
+
 /// Attempt to load and parse the TOML config file as a `Config`.
 pub fn load(path: impl AsRef<Path>) -> Result<Config, Error> {
     let config_toml = std::fs::read_to_string(&path).map_err(Error::io)?;
@@ -699,6 +838,7 @@ mod tests {
 
     use super::{load, parse_gas_prices, store_writer};
     use crate::config::GasPrice;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
     use test_log::test;
 
     #[test]
@@ -772,6 +912,35 @@ mod tests {
         store_writer(&config, &mut buffer).unwrap();
     }
 
+    #[test]
+    fn follow_chain_upgrade() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        let mut config = load(path).expect("could not parse config");
+        config.chains[0].id = ChainId::from_string("ibc-0");
+        config.chains[1].id = ChainId::from_string("ibc-1");
+
+        let id = ChainId::from_string("ibc-0");
+        let upgraded_id = ChainId::from_string("ibc-2");
+
+        // Not an upgrade of the chain
+        assert!(!config.follow_chain_upgrade(&id, &ChainId::from_string("other-2")));
+        // The counterparty chain already uses this identifier
+        assert!(!config.follow_chain_upgrade(&id, &ChainId::from_string("ibc-1")));
+
+        assert!(config.follow_chain_upgrade(&id, &upgraded_id));
+        assert!(!config.has_chain(&id));
+
+        let chain_config = config.find_chain(&upgraded_id).unwrap();
+        assert_eq!(chain_config.id, upgraded_id);
+
+        // The chain can only be renamed once
+        assert!(!config.follow_chain_upgrade(&id, &upgraded_id));
+    }
+
     #[test]
     fn gas_price_from_str() {
         let gp_original = GasPrice::new(10.0, "atom".to_owned());
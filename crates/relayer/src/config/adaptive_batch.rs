@@ -0,0 +1,78 @@
+//! Configuration of the adaptation of the batch limits of a chain to its block capacity.
+
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+/// When enabled, the batches of messages sent to the chain start at `max_msg_num` and
+/// `max_tx_size`, are shrunk while the chain is congested and grown back towards these
+/// limits while its blocks have room to spare.
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdaptiveBatchConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The smallest number of messages per transaction the batches can be shrunk to
+    #[serde(default = "default::min_msg_num")]
+    pub min_msg_num: usize,
+
+    /// The smallest size of a transaction, in bytes, the batches can be shrunk to
+    #[serde(default = "default::min_tx_size")]
+    pub min_tx_size: usize,
+
+    /// The time to get a transaction included in a block above which the chain is congested
+    #[serde(default = "default::target_latency", with = "humantime_serde")]
+    pub target_latency: Duration,
+
+    /// The fraction of the gas of a block above which the chain is congested
+    #[serde(default = "default::high_utilization")]
+    pub high_utilization: f64,
+
+    /// The fraction of the gas of a block below which the batches are grown
+    #[serde(default = "default::low_utilization")]
+    pub low_utilization: f64,
+}
+
+impl AdaptiveBatchConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for AdaptiveBatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_msg_num: default::min_msg_num(),
+            min_tx_size: default::min_tx_size(),
+            target_latency: default::target_latency(),
+            high_utilization: default::high_utilization(),
+            low_utilization: default::low_utilization(),
+        }
+    }
+}
+
+pub mod default {
+    use core::time::Duration;
+
+    pub fn min_msg_num() -> usize {
+        1
+    }
+
+    pub fn min_tx_size() -> usize {
+        32 * 1024
+    }
+
+    pub fn target_latency() -> Duration {
+        Duration::from_secs(15)
+    }
+
+    pub fn high_utilization() -> f64 {
+        0.9
+    }
+
+    pub fn low_utilization() -> f64 {
+        0.5
+    }
+}
@@ -0,0 +1,34 @@
+//! Configuration of the caching of the queries made by the workers to a chain.
+
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CacheConfig {
+    /// How long the status of the chain queried by a worker is reused by the other workers
+    #[serde(
+        default = "CacheConfig::default_application_status_ttl",
+        with = "humantime_serde"
+    )]
+    pub application_status_ttl: Duration,
+}
+
+impl CacheConfig {
+    fn default_application_status_ttl() -> Duration {
+        Duration::from_millis(200)
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            application_status_ttl: Self::default_application_status_ttl(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Configuration of the batching of the messages relayed on a given channel.
+
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+use super::types::{MaxMsgNum, MaxTxSize};
+
+/// Overrides the batching of the chain for one of its channels, eg. to send the large
+/// packets of a channel in smaller transactions, or to batch the tiny packets of another.
+///
+/// The limits apply to the transactions sent to the chain for the channel, and
+/// the batch delay applies to the packet events emitted by the chain on the channel.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChannelBatchConfig {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+
+    /// The maximum number of messages per transaction, which must not exceed
+    /// the `max_msg_num` of the chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_msg_num: Option<MaxMsgNum>,
+
+    /// The maximum size of a transaction, in bytes, which must not exceed
+    /// the `max_tx_size` of the chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tx_size: Option<MaxTxSize>,
+
+    /// How long to accumulate the packet events of the channel before relaying them together
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub batch_delay: Option<Duration>,
+}
+
+impl ChannelBatchConfig {
+    pub fn matches(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.port_id == *port_id && self.channel_id == *channel_id
+    }
+
+    pub fn limits(&self) -> BatchLimits {
+        BatchLimits {
+            max_msg_num: self.max_msg_num,
+            max_tx_size: self.max_tx_size,
+        }
+    }
+}
+
+/// The limits of the batches of messages sent in a request to the chain,
+/// which lower the limits of the chain, if set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchLimits {
+    pub max_msg_num: Option<MaxMsgNum>,
+    pub max_tx_size: Option<MaxTxSize>,
+}
+
+impl BatchLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_msg_num.is_none() && self.max_tx_size.is_none()
+    }
+
+    /// The lowest of both limits, for messages sent together.
+    pub fn min(self, other: Self) -> Self {
+        fn min<T: Ord>(a: Option<T>, b: Option<T>) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        }
+
+        Self {
+            max_msg_num: min(self.max_msg_num, other.max_msg_num),
+            max_tx_size: min(self.max_tx_size, other.max_tx_size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowest_limits() {
+        let a = BatchLimits {
+            max_msg_num: Some(MaxMsgNum::new(10).unwrap()),
+            max_tx_size: None,
+        };
+
+        let b = BatchLimits {
+            max_msg_num: Some(MaxMsgNum::new(5).unwrap()),
+            max_tx_size: Some(MaxTxSize::new(1000).unwrap()),
+        };
+
+        assert_eq!(a.min(b), b);
+        assert_eq!(a.min(BatchLimits::default()), a);
+        assert!(BatchLimits::default().is_unlimited());
+    }
+}
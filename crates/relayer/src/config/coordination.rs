@@ -0,0 +1,115 @@
+//! Configuration of the coordination of several Hermes instances relaying for the same chains.
+
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CoordinationConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The identifier of this instance, which must be unique among the instances
+    /// sharing the same database
+    #[serde(default)]
+    pub instance_id: String,
+
+    /// The connection string of the Postgres database holding the leases
+    #[serde(default)]
+    pub database_url: String,
+
+    /// The table holding the leases, which is created if it does not exist
+    #[serde(default = "default::table")]
+    pub table: String,
+
+    /// How long a channel stays claimed by an instance which stopped renewing its lease
+    #[serde(default = "default::lease_duration", with = "humantime_serde")]
+    pub lease_duration: Duration,
+
+    /// How often the leases are renewed, which must be shorter than their duration
+    #[serde(default = "default::renew_interval", with = "humantime_serde")]
+    pub renew_interval: Duration,
+}
+
+impl CoordinationConfig {
+    /// Whether the `table` is a valid unquoted SQL identifier, ie. matches
+    /// `[A-Za-z_][A-Za-z0-9_]*`, as it is spliced as is into the queries.
+    pub fn has_valid_table(&self) -> bool {
+        let mut chars = self.table.chars();
+
+        chars
+            .next()
+            .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+}
+
+impl Default for CoordinationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            instance_id: String::new(),
+            database_url: String::new(),
+            table: default::table(),
+            lease_duration: default::lease_duration(),
+            renew_interval: default::renew_interval(),
+        }
+    }
+}
+
+pub mod default {
+    use core::time::Duration;
+
+    pub fn table() -> String {
+        "hermes_channel_leases".to_string()
+    }
+
+    pub fn lease_duration() -> Duration {
+        Duration::from_secs(30)
+    }
+
+    pub fn renew_interval() -> Duration {
+        Duration::from_secs(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_coordination_config() {
+        let config: CoordinationConfig = toml::from_str(
+            r#"
+            enabled = true
+            instance_id = 'hermes-0'
+            database_url = 'postgresql://hermes@127.0.0.1/hermes'
+            lease_duration = '1m'
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.enabled);
+        assert_eq!(config.instance_id, "hermes-0");
+        assert_eq!(config.table, default::table());
+        assert_eq!(config.lease_duration, Duration::from_secs(60));
+        assert_eq!(config.renew_interval, default::renew_interval());
+    }
+
+    #[test]
+    fn only_accept_identifiers_as_table() {
+        let with_table = |table: &str| CoordinationConfig {
+            table: table.to_string(),
+            ..Default::default()
+        };
+
+        for table in ["hermes_channel_leases", "_leases", "Leases2"] {
+            assert!(with_table(table).has_valid_table(), "{table}");
+        }
+
+        for table in ["", "2leases", "leases;", "public.leases", "leases\"", "lé"] {
+            assert!(!with_table(table).has_valid_table(), "{table}");
+        }
+    }
+}
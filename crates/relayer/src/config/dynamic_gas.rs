@@ -0,0 +1,31 @@
+//! Configuration of the gas price of the chains with a fee market, whose base fee varies
+//! with the demand for block space, such as the EIP-1559 fee market of Osmosis.
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::config::gas_multiplier::GasMultiplier;
+
+/// When enabled, the gas price of each transaction sent to the chain is its base fee,
+/// queried before the transaction is submitted, multiplied by `multiplier` and capped at
+/// `max`, if set. The static `gas_price` of the chain is used if the base fee cannot be queried.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicGasPriceConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Multiplies the base fee, to account for its increase until the transaction is included
+    #[serde(default)]
+    pub multiplier: GasMultiplier,
+
+    /// The highest gas price the relayer pays, in the denomination of `gas_price`,
+    /// unbounded if unset. It cannot be lower than the price of `gas_price`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+}
+
+impl DynamicGasPriceConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -0,0 +1,91 @@
+//! Configuration of the sink publishing the lifecycle events of the relayed packets.
+
+use serde_derive::{Deserialize, Serialize};
+
+/// The message broker to publish the relay events to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventSinkBackend {
+    /// Publish the events to a Kafka topic
+    #[default]
+    Kafka,
+    /// Publish the events to NATS subjects
+    Nats,
+}
+
+impl EventSinkBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Kafka => "kafka",
+            Self::Nats => "nats",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventSinkConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub backend: EventSinkBackend,
+
+    /// The comma-separated list of brokers for Kafka, or the URL of the server for NATS
+    #[serde(default)]
+    pub url: String,
+
+    /// The Kafka topic to publish the events to, or the prefix of the
+    /// NATS subjects, which are suffixed with the kind of the event
+    #[serde(default = "default::topic")]
+    pub topic: String,
+
+    /// The maximum number of events waiting to be published,
+    /// beyond which new events are dropped
+    #[serde(default = "default::buffer_size")]
+    pub buffer_size: usize,
+}
+
+impl Default for EventSinkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: EventSinkBackend::default(),
+            url: String::new(),
+            topic: default::topic(),
+            buffer_size: default::buffer_size(),
+        }
+    }
+}
+
+pub mod default {
+    pub fn topic() -> String {
+        "hermes.relay".to_string()
+    }
+
+    pub fn buffer_size() -> usize {
+        10_000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_event_sink_config() {
+        let config: EventSinkConfig = toml::from_str(
+            r#"
+            enabled = true
+            backend = 'nats'
+            url = 'nats://127.0.0.1:4222'
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.enabled);
+        assert_eq!(config.backend, EventSinkBackend::Nats);
+        assert_eq!(config.topic, default::topic());
+        assert_eq!(config.buffer_size, default::buffer_size());
+    }
+}
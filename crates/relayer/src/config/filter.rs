@@ -17,8 +17,15 @@ use ibc_relayer_types::events::IbcEventType;
 pub struct PacketFilter {
     #[serde(flatten)]
     pub channel_policy: ChannelPolicy,
+    /// Exceptions to the channel policy, which take precedence over its list.
+    #[serde(default, skip_serializing_if = "ChannelFilters::is_empty")]
+    pub except: ChannelFilters,
     #[serde(default)]
     pub min_fees: HashMap<ChannelFilterMatch, FeePolicy>,
+    /// The channels on which to validate the fungible token packet data of the packets
+    /// before relaying them, so as to skip the packets that the receiving chain would reject.
+    #[serde(default, skip_serializing_if = "ChannelFilters::is_empty")]
+    pub validate_transfer: ChannelFilters,
 }
 
 impl Default for PacketFilter {
@@ -26,7 +33,9 @@ impl Default for PacketFilter {
     fn default() -> Self {
         Self {
             channel_policy: ChannelPolicy::default(),
+            except: ChannelFilters::default(),
             min_fees: HashMap::new(),
+            validate_transfer: ChannelFilters::default(),
         }
     }
 }
@@ -38,7 +47,9 @@ impl PacketFilter {
     ) -> Self {
         Self {
             channel_policy,
+            except: ChannelFilters::default(),
             min_fees,
+            validate_transfer: ChannelFilters::default(),
         }
     }
 
@@ -48,6 +59,28 @@ impl PacketFilter {
             HashMap::new(),
         )
     }
+
+    /// Returns true if the packets can be relayed on the channel with [`PortId`] and [`ChannelId`],
+    /// false otherwise.
+    ///
+    /// The exceptions take precedence over the list of the channel policy:
+    /// - with the `allow` policy, packets are denied on the channels matching an exception,
+    ///   even if they are in the list,
+    /// - with the `deny` policy, packets are allowed on the channels matching an exception,
+    ///   even if they are in the list,
+    /// - if there is no policy, packets are denied on the channels matching an exception.
+    pub fn is_allowed(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        let excepted = self.except.matches((port_id, channel_id));
+
+        match &self.channel_policy {
+            ChannelPolicy::Allow(_) | ChannelPolicy::AllowAll => {
+                !excepted && self.channel_policy.is_allowed(port_id, channel_id)
+            }
+            ChannelPolicy::Deny(_) => {
+                excepted || self.channel_policy.is_allowed(port_id, channel_id)
+            }
+        }
+    }
 }
 
 /// Represents the ways in which packets can be filtered.
@@ -231,7 +264,8 @@ impl Serialize for ChannelFilters {
     }
 }
 
-/// Newtype wrapper for expressing wildcard patterns compiled to a [`regex::Regex`].
+/// Newtype wrapper for expressing wildcard patterns and regular expressions
+/// compiled to a [`regex::Regex`].
 #[derive(Clone, Debug)]
 pub struct Wildcard {
     pattern: String,
@@ -239,9 +273,22 @@ pub struct Wildcard {
 }
 
 impl Wildcard {
+    /// Compiles the given pattern, where `*` matches any sequence of characters
+    /// and `?` matches any single character.
+    ///
+    /// A pattern enclosed in slashes, eg. `/^channel-(1|2)$/`, is instead interpreted
+    /// as a regular expression, which must match the whole identifier.
     pub fn new(pattern: String) -> Result<Self, regex::Error> {
-        let escaped = regex::escape(&pattern).replace("\\*", "(?:.*)");
-        let regex = format!("^{escaped}$").parse()?;
+        let regex = match pattern.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            Some(regex) if !regex.is_empty() => format!("^(?:{regex})$").parse()?,
+            _ => {
+                let escaped = regex::escape(&pattern)
+                    .replace("\\*", "(?:.*)")
+                    .replace("\\?", ".");
+                format!("^{escaped}$").parse()?
+            }
+        };
+
         Ok(Self { pattern, regex })
     }
 
@@ -293,7 +340,8 @@ impl Hash for Wildcard {
 pub enum FilterPattern<T> {
     /// A channel specified exactly with its [`PortId`] & [`ChannelId`].
     Exact(T),
-    /// A glob of channel(s) specified with a wildcard in either or both [`PortId`] & [`ChannelId`].
+    /// A glob of channel(s) specified with a wildcard or a regular expression
+    /// in either or both [`PortId`] & [`ChannelId`].
     Wildcard(Wildcard),
 }
 
@@ -448,6 +496,38 @@ mod tests {
         dbg!(filter_policy);
     }
 
+    #[test]
+    fn deserialize_packet_filter_validate_transfer() {
+        use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+        let toml_content = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', '*'],
+            ]
+            validate_transfer = [
+              ['transfer', 'channel-1*'],
+            ]
+            "#;
+
+        let packet_filter: PacketFilter =
+            toml::from_str(toml_content).expect("could not parse packet filter");
+
+        let transfer = PortId::transfer();
+
+        assert!(packet_filter
+            .validate_transfer
+            .matches((&transfer, &ChannelId::new(12))));
+        assert!(!packet_filter
+            .validate_transfer
+            .matches((&transfer, &ChannelId::new(0))));
+
+        let packet_filter: PacketFilter =
+            toml::from_str("policy = 'allow'\nlist = []").expect("could not parse packet filter");
+
+        assert!(packet_filter.validate_transfer.is_empty());
+    }
+
     #[test]
     fn serialize_packet_filter_policy() {
         use std::str::FromStr;
@@ -600,6 +680,94 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn packet_filter_single_char_wildcard_and_regex() {
+        let allow_policy = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', 'channel-1?'],
+              ['/^ica(host|controller-.+)$/', '*'],
+            ]
+            "#;
+
+        let pf: ChannelPolicy =
+            toml::from_str(allow_policy).expect("could not parse filter policy");
+
+        assert!(pf.is_allowed(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-12").unwrap()
+        ));
+        assert!(!pf.is_allowed(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-1").unwrap()
+        ));
+        assert!(!pf.is_allowed(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-123").unwrap()
+        ));
+        assert!(pf.is_allowed(
+            &PortId::from_str("icahost").unwrap(),
+            &ChannelId::from_str("channel-5").unwrap()
+        ));
+        assert!(pf.is_allowed(
+            &PortId::from_str("icacontroller-cosmos1owner").unwrap(),
+            &ChannelId::from_str("channel-5").unwrap()
+        ));
+        assert!(!pf.is_allowed(
+            &PortId::from_str("icahost-1").unwrap(),
+            &ChannelId::from_str("channel-5").unwrap()
+        ));
+    }
+
+    #[test]
+    fn packet_filter_exceptions_take_precedence() {
+        let allow = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', '*'],
+            ]
+            except = [
+              ['transfer', 'channel-1?'],
+            ]
+            "#;
+
+        let pf: PacketFilter = toml::from_str(allow).expect("could not parse packet filter");
+
+        assert!(pf.is_allowed(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-1").unwrap()
+        ));
+        assert!(!pf.is_allowed(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-10").unwrap()
+        ));
+
+        let deny = r#"
+            policy = 'deny'
+            list = [
+              ['ica*', '*'],
+            ]
+            except = [
+              ['icahost', 'channel-0'],
+            ]
+            "#;
+
+        let pf: PacketFilter = toml::from_str(deny).expect("could not parse packet filter");
+
+        assert!(pf.is_allowed(
+            &PortId::from_str("icahost").unwrap(),
+            &ChannelId::from_str("channel-0").unwrap()
+        ));
+        assert!(!pf.is_allowed(
+            &PortId::from_str("icahost").unwrap(),
+            &ChannelId::from_str("channel-1").unwrap()
+        ));
+        assert!(pf.is_allowed(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-1").unwrap()
+        ));
+    }
+
     #[test]
     fn to_string_wildcards() {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
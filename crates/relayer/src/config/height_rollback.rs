@@ -0,0 +1,47 @@
+//! Configuration of the detection of the height of a chain going backwards.
+
+use serde_derive::{Deserialize, Serialize};
+
+/// When to halt relaying from and to a chain whose height went backwards,
+/// eg. after a restart from genesis or a rollback of the chain.
+///
+/// The events of the chain are held back as soon as its height went back by at least
+/// `min_depth` blocks. Relaying is halted once the height stayed below its previous
+/// maximum for `persistence` new blocks, so that a node lagging behind, eg. behind a
+/// load balancer, does not halt relaying. Relaying resumes once the height of the
+/// chain passes its previous maximum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HeightRollbackConfig {
+    /// The minimum number of blocks by which the height must go back
+    #[serde(default = "HeightRollbackConfig::default_min_depth")]
+    pub min_depth: u64,
+
+    /// The number of new blocks below the previous maximum height after which to halt,
+    /// rather than resume processing the events of the chain
+    #[serde(default = "HeightRollbackConfig::default_persistence")]
+    pub persistence: u64,
+}
+
+impl HeightRollbackConfig {
+    fn default_min_depth() -> u64 {
+        10
+    }
+
+    fn default_persistence() -> u64 {
+        5
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for HeightRollbackConfig {
+    fn default() -> Self {
+        Self {
+            min_depth: Self::default_min_depth(),
+            persistence: Self::default_persistence(),
+        }
+    }
+}
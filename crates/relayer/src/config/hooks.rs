@@ -0,0 +1,236 @@
+//! Configuration of the hooks fired on selected IBC events and error conditions.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::events::IbcEventType;
+
+/// What fires a hook: an IBC event of the given type observed on a chain, or an error condition.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum HookTrigger {
+    /// An IBC event of the given type, named as in the events of the chain, eg. `send_packet`
+    Event(IbcEventType),
+    /// The messages relaying packets could not be submitted or confirmed
+    RelayError,
+    /// The height of a chain went backwards and relaying on the chain was halted
+    HeightRollback,
+    /// The events of a chain could not be received
+    EventSourceError,
+}
+
+impl HookTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Event(event_type) => event_type.as_str(),
+            Self::RelayError => "relay_error",
+            Self::HeightRollback => "height_rollback",
+            Self::EventSourceError => "event_source_error",
+        }
+    }
+}
+
+impl Display for HookTrigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for HookTrigger {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "relay_error" => Ok(Self::RelayError),
+            "height_rollback" => Ok(Self::HeightRollback),
+            "event_source_error" => Ok(Self::EventSourceError),
+            _ => IbcEventType::from_str(s)
+                .map(Self::Event)
+                .map_err(|_| format!("unknown hook trigger '{s}'")),
+        }
+    }
+}
+
+impl TryFrom<String> for HookTrigger {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<HookTrigger> for String {
+    fn from(trigger: HookTrigger) -> Self {
+        trigger.as_str().to_string()
+    }
+}
+
+fn matches(
+    triggers: &[HookTrigger],
+    chains: &[ChainId],
+    trigger: &HookTrigger,
+    chain_id: &ChainId,
+) -> bool {
+    triggers.contains(trigger) && (chains.is_empty() || chains.contains(chain_id))
+}
+
+/// Posts the JSON payload of the trigger to the given URL.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    pub url: String,
+
+    /// The IBC event types and error conditions firing the hook
+    pub triggers: Vec<HookTrigger>,
+
+    /// The chains on which the triggers fire the hook, or all the chains if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chains: Vec<ChainId>,
+
+    /// How long to wait for the response of the server
+    #[serde(default = "default::timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+impl WebhookConfig {
+    pub fn matches(&self, trigger: &HookTrigger, chain_id: &ChainId) -> bool {
+        matches(&self.triggers, &self.chains, trigger, chain_id)
+    }
+}
+
+/// Runs the given command, with the JSON payload of the trigger on its standard input.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExecHookConfig {
+    /// The program to run, followed by its arguments
+    pub command: Vec<String>,
+
+    /// The IBC event types and error conditions firing the hook
+    pub triggers: Vec<HookTrigger>,
+
+    /// The chains on which the triggers fire the hook, or all the chains if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chains: Vec<ChainId>,
+
+    /// How long to let the command run before killing it
+    #[serde(default = "default::timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+impl ExecHookConfig {
+    pub fn matches(&self, trigger: &HookTrigger, chain_id: &ChainId) -> bool {
+        matches(&self.triggers, &self.chains, trigger, chain_id)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HooksConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The maximum number of hooks running at the same time
+    #[serde(default = "default::max_concurrency")]
+    pub max_concurrency: usize,
+
+    /// The maximum number of triggers waiting for their hooks to run,
+    /// beyond which new triggers are dropped
+    #[serde(default = "default::buffer_size")]
+    pub buffer_size: usize,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<WebhookConfig>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exec: Vec<ExecHookConfig>,
+}
+
+impl HooksConfig {
+    /// Whether any hook is fired by the given trigger on the given chain.
+    pub fn is_triggered(&self, trigger: &HookTrigger, chain_id: &ChainId) -> bool {
+        self.webhooks
+            .iter()
+            .any(|hook| hook.matches(trigger, chain_id))
+            || self.exec.iter().any(|hook| hook.matches(trigger, chain_id))
+    }
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_concurrency: default::max_concurrency(),
+            buffer_size: default::buffer_size(),
+            webhooks: Vec::new(),
+            exec: Vec::new(),
+        }
+    }
+}
+
+pub mod default {
+    use core::time::Duration;
+
+    pub fn max_concurrency() -> usize {
+        4
+    }
+
+    pub fn buffer_size() -> usize {
+        1000
+    }
+
+    pub fn timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hooks_config() {
+        let config: HooksConfig = toml::from_str(
+            r#"
+            enabled = true
+
+            [[webhooks]]
+            url = 'https://example.com/hermes'
+            triggers = ['send_packet', 'relay_error']
+
+            [[exec]]
+            command = ['/usr/local/bin/page', '--urgent']
+            triggers = ['height_rollback']
+            chains = ['ibc-0']
+            timeout = '1m'
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.max_concurrency, default::max_concurrency());
+        assert_eq!(
+            config.webhooks[0].triggers,
+            vec![
+                HookTrigger::Event(IbcEventType::SendPacket),
+                HookTrigger::RelayError
+            ]
+        );
+        assert_eq!(config.exec[0].timeout, Duration::from_secs(60));
+
+        let ibc_0 = ChainId::from_string("ibc-0");
+        let ibc_1 = ChainId::from_string("ibc-1");
+
+        assert!(config.is_triggered(&HookTrigger::HeightRollback, &ibc_0));
+        assert!(!config.is_triggered(&HookTrigger::HeightRollback, &ibc_1));
+        assert!(config.is_triggered(&HookTrigger::RelayError, &ibc_1));
+        assert!(!config.is_triggered(&HookTrigger::EventSourceError, &ibc_1));
+
+        assert!(
+            toml::from_str::<HooksConfig>("[[exec]]\ncommand = ['true']\ntriggers = ['foo']")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Configuration of the guard against packets carrying oversized memos.
+
+use serde_derive::{Deserialize, Serialize};
+
+/// What to do with a packet whose memo exceeds the maximum size.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PacketMemoPolicy {
+    /// Relay the packet anyway
+    #[default]
+    Relay,
+    /// Do not relay the packet, but still time it out once it expires
+    Skip,
+    /// Relay the packet, but strip the memo from the packet data of the events printed
+    /// by `hermes listen`. The logs, the event sink and the hooks are not affected.
+    Strip,
+}
+
+impl PacketMemoPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Relay => "relay",
+            Self::Skip => "skip",
+            Self::Strip => "strip",
+        }
+    }
+}
+
+/// Guards against packets carrying oversized memos, eg. spam packets whose
+/// memo would make the transactions relaying them exceed the maximum tx size.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PacketMemoConfig {
+    /// The maximum size of the memo of a packet, in bytes. Unlimited if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<usize>,
+
+    /// What to do with the packets whose memo exceeds `max_size`
+    #[serde(default)]
+    pub policy: PacketMemoPolicy,
+}
+
+impl PacketMemoConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the size of the memo in the given packet data,
+    /// if it exceeds the maximum size.
+    pub fn oversized_memo(&self, data: &[u8]) -> Option<usize> {
+        let max_size = self.max_size?;
+
+        memo_size(data).filter(|size| *size > max_size)
+    }
+}
+
+/// The size of the memo in the given packet data, if any.
+///
+/// The memo is the `memo` field of the JSON-encoded packet data,
+/// as in ICS-20 transfers and interchain accounts packets.
+pub fn memo_size(data: &[u8]) -> Option<usize> {
+    let value = serde_json::from_slice::<serde_json::Value>(data).ok()?;
+    let memo = value.get("memo")?.as_str()?;
+
+    Some(memo.len())
+}
+
+/// Strips the memo from the given packet data, if it has one.
+pub fn strip_memo(data: &mut Vec<u8>) {
+    let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(data) else {
+        return;
+    };
+
+    let Some(memo) = value.get_mut("memo").filter(|memo| memo.is_string()) else {
+        return;
+    };
+
+    *memo = serde_json::Value::String(String::new());
+
+    if let Ok(stripped) = serde_json::to_vec(&value) {
+        *data = stripped;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &str = r#"{"amount":"100","denom":"uatom","memo":"0123456789","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#;
+
+    #[test]
+    fn oversized_memo() {
+        let config = PacketMemoConfig {
+            max_size: Some(8),
+            policy: PacketMemoPolicy::Skip,
+        };
+
+        assert_eq!(config.oversized_memo(DATA.as_bytes()), Some(10));
+        assert_eq!(config.oversized_memo(b"not json"), None);
+        assert_eq!(
+            PacketMemoConfig::default().oversized_memo(DATA.as_bytes()),
+            None
+        );
+    }
+
+    #[test]
+    fn strip_packet_memo() {
+        let mut data = DATA.as_bytes().to_vec();
+        strip_memo(&mut data);
+
+        assert_eq!(memo_size(&data), Some(0));
+    }
+}
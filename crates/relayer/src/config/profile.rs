@@ -0,0 +1,39 @@
+//! Configuration of the relaying profiles hosted by a single Hermes process.
+
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+
+use super::{load, Config, Error};
+
+/// A relaying profile, eg. for a customer of a service provider, relayed by its own
+/// supervisor, with its own chain runtimes and keys, within the same Hermes process.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    /// The name of the profile, which labels the logs of its supervisor
+    pub name: String,
+
+    /// The configuration file of the profile
+    pub config: PathBuf,
+
+    /// The folder holding the keys of the profile, overriding
+    /// the `key_store_folder` of all the chains of the profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_store_folder: Option<PathBuf>,
+}
+
+impl ProfileConfig {
+    /// Loads the configuration of the profile, with its chains using the keys of the profile.
+    pub fn load(&self) -> Result<Config, Error> {
+        let mut config = load(&self.config)?;
+
+        if let Some(key_store_folder) = &self.key_store_folder {
+            for chain in &mut config.chains {
+                chain.key_store_folder = Some(key_store_folder.clone());
+            }
+        }
+
+        Ok(config)
+    }
+}
@@ -0,0 +1,48 @@
+//! Configuration of the rate at which Hermes sends requests to a chain's endpoints.
+
+use core::num::NonZeroU32;
+
+use serde_derive::{Deserialize, Serialize};
+
+/// The maximum number of requests per second to send to the RPC and gRPC endpoints of a chain.
+///
+/// Requests in excess of the limit are queued until they can be sent,
+/// rather than failing, so that Hermes stays within the quotas of
+/// RPC providers when it has a lot of work to do, eg. when clearing packets.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitConfig {
+    /// The maximum number of requests per second to the RPC endpoint.
+    /// Unlimited if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc: Option<NonZeroU32>,
+
+    /// The maximum number of requests per second to the gRPC endpoint.
+    /// Unlimited if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grpc: Option<NonZeroU32>,
+}
+
+impl RateLimitConfig {
+    pub fn is_unlimited(&self) -> bool {
+        self.rpc.is_none() && self.grpc.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rate_limit_config() {
+        let config: RateLimitConfig = toml::from_str("rpc = 20").unwrap();
+        assert_eq!(config.rpc, NonZeroU32::new(20));
+        assert_eq!(config.grpc, None);
+        assert!(!config.is_unlimited());
+
+        let config: RateLimitConfig = toml::from_str("").unwrap();
+        assert!(config.is_unlimited());
+
+        assert!(toml::from_str::<RateLimitConfig>("grpc = 0").is_err());
+    }
+}
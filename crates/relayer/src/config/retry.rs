@@ -0,0 +1,180 @@
+//! Configuration of the policies for retrying the submission of messages
+//! to a chain, depending on the category of the error raised by the chain.
+
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::error::ErrorCategory;
+use crate::util::retry::ConstantGrowth;
+
+/// How many times to retry submitting messages which failed with a given category
+/// of error, and how long to wait before each retry.
+///
+/// The delay before the first retry is `backoff`, and it then grows by `backoff`
+/// at each retry, up to `max_backoff`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+
+    #[serde(default, with = "humantime_serde")]
+    pub backoff: Duration,
+
+    #[serde(default = "default::max_backoff", with = "humantime_serde")]
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub const fn new(max_retries: usize, backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            backoff,
+            max_backoff,
+        }
+    }
+
+    /// The delay to wait for before the given retry, starting from zero,
+    /// or `None` if all the retries have been exhausted.
+    pub fn delay(&self, retry: usize) -> Option<Duration> {
+        ConstantGrowth::new(self.backoff, self.backoff)
+            .clamp(self.max_backoff, self.max_retries)
+            .nth(retry)
+    }
+}
+
+/// The retry policy for each category of error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryConfig {
+    /// The full node could not be reached
+    #[serde(default = "default::network")]
+    pub network: RetryPolicy,
+
+    /// The mempool of the full node is full
+    #[serde(default = "default::mempool_full")]
+    pub mempool_full: RetryPolicy,
+
+    /// The transaction ran out of gas
+    #[serde(default = "default::out_of_gas")]
+    pub out_of_gas: RetryPolicy,
+
+    /// The account sequence of the transaction was outdated
+    #[serde(default = "default::sequence_mismatch")]
+    pub sequence_mismatch: RetryPolicy,
+
+    /// The application rejected the transaction
+    #[serde(default = "default::permanent")]
+    pub permanent: RetryPolicy,
+
+    /// Any other error
+    #[serde(default = "default::other")]
+    pub other: RetryPolicy,
+}
+
+impl RetryConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn policy(&self, category: ErrorCategory) -> &RetryPolicy {
+        match category {
+            ErrorCategory::Network => &self.network,
+            ErrorCategory::MempoolFull => &self.mempool_full,
+            ErrorCategory::OutOfGas => &self.out_of_gas,
+            ErrorCategory::SequenceMismatch => &self.sequence_mismatch,
+            ErrorCategory::Permanent => &self.permanent,
+            ErrorCategory::Other => &self.other,
+        }
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            network: default::network(),
+            mempool_full: default::mempool_full(),
+            out_of_gas: default::out_of_gas(),
+            sequence_mismatch: default::sequence_mismatch(),
+            permanent: default::permanent(),
+            other: default::other(),
+        }
+    }
+}
+
+pub mod default {
+    use super::*;
+
+    pub fn max_backoff() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    pub fn network() -> RetryPolicy {
+        RetryPolicy::new(5, Duration::from_secs(1), Duration::from_secs(10))
+    }
+
+    pub fn mempool_full() -> RetryPolicy {
+        RetryPolicy::new(10, Duration::from_secs(2), Duration::from_secs(30))
+    }
+
+    pub fn out_of_gas() -> RetryPolicy {
+        // The gas is estimated anew when retrying, which may be enough if the state changed
+        RetryPolicy::new(1, Duration::ZERO, Duration::ZERO)
+    }
+
+    pub fn sequence_mismatch() -> RetryPolicy {
+        RetryPolicy::new(5, Duration::from_millis(300), Duration::from_secs(3))
+    }
+
+    pub fn permanent() -> RetryPolicy {
+        RetryPolicy::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    pub fn other() -> RetryPolicy {
+        RetryPolicy::new(4, Duration::ZERO, Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_delays() {
+        let policy = RetryPolicy::new(4, Duration::from_secs(1), Duration::from_secs(3));
+
+        let delays = (0..6).map(|i| policy.delay(i)).collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(3)),
+                Some(Duration::from_secs(3)),
+                None,
+                None,
+            ]
+        );
+
+        assert_eq!(default::permanent().delay(0), None);
+    }
+
+    #[test]
+    fn parse_retry_config() {
+        let config: RetryConfig = toml::from_str(
+            r#"
+            network = { max_retries = 10, backoff = '500ms' }
+            permanent = { max_retries = 1 }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.policy(ErrorCategory::Network),
+            &RetryPolicy::new(10, Duration::from_millis(500), default::max_backoff())
+        );
+        assert_eq!(config.permanent.max_retries, 1);
+        assert_eq!(config.mempool_full, default::mempool_full());
+    }
+}
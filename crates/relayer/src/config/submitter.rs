@@ -0,0 +1,28 @@
+//! Configuration of the remote process signing and submitting the transactions to a chain.
+
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+use tendermint_rpc::Url;
+
+/// When set on a chain, Hermes does not sign the transactions to the chain itself,
+/// but sends the messages to the given submitter, which holds the key of the chain.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitterConfig {
+    /// The gRPC URL of the submitter, as served by `hermes submitter`
+    pub url: Url,
+
+    /// The address of the account of the submitter on the chain,
+    /// which is set as the signer of the messages
+    pub signer: String,
+
+    /// The file holding the token shared with the submitter, which authenticates
+    /// this process to the submitter
+    pub token_file: PathBuf,
+
+    /// The PEM file holding the certificate of the authority which issued the TLS
+    /// certificate of the submitter, if not one of the system root authorities
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_file: Option<PathBuf>,
+}
@@ -19,8 +19,11 @@ use ibc_relayer_types::mock::consensus_state::MockConsensusState;
 use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
+use crate::light_client::registry::client_registry;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[non_exhaustive]
@@ -29,6 +32,11 @@ pub enum AnyConsensusState {
 
     #[cfg(test)]
     Mock(MockConsensusState),
+
+    /// A consensus state of a client type registered with
+    /// [`register_client_handler`](crate::light_client::registry::register_client_handler).
+    #[serde(skip_deserializing)]
+    Other(Box<dyn ConsensusState>),
 }
 
 impl AnyConsensusState {
@@ -38,6 +46,8 @@ impl AnyConsensusState {
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.timestamp(),
+
+            Self::Other(state) => state.timestamp(),
         }
     }
 
@@ -47,6 +57,8 @@ impl AnyConsensusState {
 
             #[cfg(test)]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
+
+            AnyConsensusState::Other(cs) => cs.client_type(),
         }
     }
 }
@@ -57,22 +69,7 @@ impl TryFrom<Any> for AnyConsensusState {
     type Error = Error;
 
     fn try_from(value: Any) -> Result<Self, Self::Error> {
-        match value.type_url.as_str() {
-            "" => Err(Error::empty_consensus_state_response()),
-
-            TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Tendermint(
-                Protobuf::<RawConsensusState>::decode_vec(&value.value)
-                    .map_err(Error::decode_raw_client_state)?,
-            )),
-
-            #[cfg(test)]
-            MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
-                Protobuf::<RawMockConsensusState>::decode_vec(&value.value)
-                    .map_err(Error::decode_raw_client_state)?,
-            )),
-
-            _ => Err(Error::unknown_consensus_state_type(value.type_url)),
-        }
+        client_registry().decode_consensus_state(&value)
     }
 }
 
@@ -88,6 +85,9 @@ impl From<AnyConsensusState> for Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockConsensusState>::encode_vec(&value),
             },
+
+            AnyConsensusState::Other(value) => Any::decode(value.encode_vec().as_slice())
+                .expect("a consensus state is encoded as an `Any`"),
         }
     }
 }
@@ -115,7 +115,7 @@ impl From<&dyn ConsensusState> for AnyConsensusState {
         if let Some(cs) = downcast_consensus_state::<TmConsensusState>(cs) {
             AnyConsensusState::from(cs.clone())
         } else {
-            unreachable!()
+            AnyConsensusState::Other(dyn_clone::clone_box(cs))
         }
     }
 }
@@ -168,6 +168,8 @@ impl ConsensusState for AnyConsensusState {
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.root(),
+
+            Self::Other(state) => state.root(),
         }
     }
 
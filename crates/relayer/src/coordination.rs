@@ -0,0 +1,386 @@
+//! Coordination of several Hermes instances relaying for the same chains, so that
+//! the channels can be partitioned across instances without relaying twice.
+//!
+//! Each instance claims the channel ends it relays on by taking a lease on them in
+//! a table shared by all the instances, and only relays on the channel ends it holds
+//! a lease for. The leases are renewed periodically, so that when an instance dies
+//! its leases expire and its channel ends are claimed by the remaining instances
+//! as soon as they observe events on them, or otherwise when they next renew their
+//! own leases. An instance taking over a channel end clears the packets left pending
+//! on it by the instance which died.
+//!
+//! The leases are stored in Postgres when Hermes is built with the `postgres` feature.
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+use core::mem;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use flex_error::define_error;
+use tracing::{info, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::config::coordination::CoordinationConfig;
+
+define_error! {
+    Error {
+        UnsupportedBuild
+            |_| {
+                "Hermes was built without support for coordinating instances, \
+                    build Hermes with --features=postgres to enable it"
+            },
+
+        Database
+            { reason: String }
+            |e| { format_args!("failed to access the leases database: {}", e.reason) },
+    }
+}
+
+/// A channel end which can be claimed by an instance.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChannelKey {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl ChannelKey {
+    pub fn new(chain_id: &ChainId, port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self {
+            chain_id: chain_id.clone(),
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+        }
+    }
+}
+
+/// The store holding the leases shared by all the instances.
+trait LeaseStore: Send {
+    /// Takes or extends the lease of this instance on the given channel end,
+    /// returning whether this instance now holds the lease.
+    fn claim(&mut self, key: &ChannelKey) -> Result<bool, Error>;
+
+    /// Extends all the leases held by this instance, returning the channel ends still held.
+    fn renew(&mut self) -> Result<Vec<ChannelKey>, Error>;
+}
+
+/// The leases taken by this instance for a profile, as each profile relays on its own
+/// channels, given to the supervisor of the profile in its [`Profile`](crate::util::profile::Profile).
+pub struct ChannelLeases {
+    config: CoordinationConfig,
+    store: Mutex<Box<dyn LeaseStore>>,
+    /// The channel ends held by this instance, along with the expiry of their lease
+    owned: Mutex<HashMap<ChannelKey, Instant>>,
+    /// The channel ends held by other instances, along with the time at which to try to claim them again
+    denied: Mutex<HashMap<ChannelKey, Instant>>,
+    /// The channel ends taken over from other instances since they were last checked
+    taken_over: Mutex<Vec<ChannelKey>>,
+}
+
+impl ChannelLeases {
+    fn new(config: CoordinationConfig, store: Box<dyn LeaseStore>) -> Self {
+        Self {
+            config,
+            store: Mutex::new(store),
+            owned: Mutex::new(HashMap::new()),
+            denied: Mutex::new(HashMap::new()),
+            taken_over: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Whether this instance holds the lease on the given channel end, claiming it if it is free.
+    pub fn is_channel_claimed(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> bool {
+        self.is_claimed(ChannelKey::new(chain_id, port_id, channel_id))
+    }
+
+    /// How often the leases must be renewed.
+    pub fn renew_interval(&self) -> core::time::Duration {
+        self.config.renew_interval
+    }
+
+    fn is_claimed(&self, key: ChannelKey) -> bool {
+        let now = Instant::now();
+
+        if let Some(expiry) = self.owned.lock().unwrap().get(&key) {
+            if *expiry > now {
+                return true;
+            }
+        }
+
+        if let Some(retry_at) = self.denied.lock().unwrap().get(&key) {
+            if *retry_at > now {
+                return false;
+            }
+        }
+
+        let claimed = match self.store.lock().unwrap().claim(&key) {
+            Ok(claimed) => claimed,
+            Err(e) => {
+                warn!(
+                    "failed to claim channel {}/{} on chain {}: {e}",
+                    key.port_id, key.channel_id, key.chain_id
+                );
+
+                false
+            }
+        };
+
+        if claimed {
+            info!(
+                "claimed channel {}/{} on chain {}",
+                key.port_id, key.channel_id, key.chain_id
+            );
+
+            if self.denied.lock().unwrap().remove(&key).is_some() {
+                self.taken_over.lock().unwrap().push(key.clone());
+            }
+
+            self.owned
+                .lock()
+                .unwrap()
+                .insert(key, now + self.config.lease_duration);
+        } else {
+            self.owned.lock().unwrap().remove(&key);
+            self.denied
+                .lock()
+                .unwrap()
+                .insert(key, now + self.config.renew_interval);
+        }
+
+        claimed
+    }
+
+    /// Renews the leases held by this instance, returning the channel ends
+    /// whose lease was lost and on which this instance must stop relaying.
+    pub fn renew(&self) -> Vec<ChannelKey> {
+        let now = Instant::now();
+        let renewed = self.store.lock().unwrap().renew();
+
+        let mut owned = self.owned.lock().unwrap();
+
+        match renewed {
+            Ok(renewed) => {
+                for (key, expiry) in owned.iter_mut() {
+                    if renewed.contains(key) {
+                        *expiry = now + self.config.lease_duration;
+                    } else {
+                        *expiry = now;
+                    }
+                }
+            }
+            // Keep relaying until the leases expire, as the other instances
+            // cannot claim them before then
+            Err(e) => warn!("failed to renew the leases: {e}"),
+        }
+
+        let lost: Vec<_> = owned
+            .iter()
+            .filter(|(_, expiry)| **expiry <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in &lost {
+            owned.remove(key);
+        }
+
+        lost
+    }
+
+    /// Tries to claim the channel ends held by other instances whose lease may have
+    /// expired, so that the channel ends without events are taken over too, returning
+    /// the channel ends taken over since the last call.
+    pub fn claim_released(&self) -> Vec<ChannelKey> {
+        let now = Instant::now();
+
+        let released: Vec<_> = self
+            .denied
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, retry_at)| **retry_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in released {
+            self.is_claimed(key);
+        }
+
+        mem::take(&mut *self.taken_over.lock().unwrap())
+    }
+}
+
+/// Connects to the leases database, to start taking leases on the channel ends relayed on.
+pub fn start(config: CoordinationConfig) -> Result<ChannelLeases, Error> {
+    let store = connect(&config)?;
+
+    Ok(ChannelLeases::new(config, store))
+}
+
+#[cfg(feature = "postgres")]
+fn connect(config: &CoordinationConfig) -> Result<Box<dyn LeaseStore>, Error> {
+    Ok(Box::new(postgres::PostgresLeaseStore::connect(config)?))
+}
+
+#[cfg(not(feature = "postgres"))]
+fn connect(_config: &CoordinationConfig) -> Result<Box<dyn LeaseStore>, Error> {
+    Err(Error::unsupported_build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::sync::Arc;
+    use core::time::Duration;
+    use std::thread;
+
+    /// Holds the leases in memory, shared by the instances created from the same store.
+    struct MemoryLeaseStore {
+        instance_id: String,
+        lease_duration: Duration,
+        leases: Arc<Mutex<HashMap<ChannelKey, (String, Instant)>>>,
+    }
+
+    impl MemoryLeaseStore {
+        fn instance(&self, instance_id: &str) -> Self {
+            Self {
+                instance_id: instance_id.to_string(),
+                lease_duration: self.lease_duration,
+                leases: self.leases.clone(),
+            }
+        }
+    }
+
+    impl LeaseStore for MemoryLeaseStore {
+        fn claim(&mut self, key: &ChannelKey) -> Result<bool, Error> {
+            let now = Instant::now();
+            let mut leases = self.leases.lock().unwrap();
+
+            match leases.get(key) {
+                Some((owner, expires_at)) if *owner != self.instance_id && *expires_at >= now => {
+                    Ok(false)
+                }
+                _ => {
+                    leases.insert(
+                        key.clone(),
+                        (self.instance_id.clone(), now + self.lease_duration),
+                    );
+                    Ok(true)
+                }
+            }
+        }
+
+        fn renew(&mut self) -> Result<Vec<ChannelKey>, Error> {
+            let now = Instant::now();
+
+            Ok(self
+                .leases
+                .lock()
+                .unwrap()
+                .iter_mut()
+                .filter(|(_, (owner, _))| *owner == self.instance_id)
+                .map(|(key, (_, expires_at))| {
+                    *expires_at = now + self.lease_duration;
+                    key.clone()
+                })
+                .collect())
+        }
+    }
+
+    const LEASE_DURATION: Duration = Duration::from_millis(200);
+    const RENEW_INTERVAL: Duration = Duration::from_millis(50);
+
+    fn store() -> MemoryLeaseStore {
+        MemoryLeaseStore {
+            instance_id: String::new(),
+            lease_duration: LEASE_DURATION,
+            leases: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn leases(store: &MemoryLeaseStore, instance_id: &str) -> ChannelLeases {
+        let config = CoordinationConfig {
+            enabled: true,
+            instance_id: instance_id.to_string(),
+            lease_duration: LEASE_DURATION,
+            renew_interval: RENEW_INTERVAL,
+            ..CoordinationConfig::default()
+        };
+
+        ChannelLeases::new(config, Box::new(store.instance(instance_id)))
+    }
+
+    fn key(channel: u64) -> ChannelKey {
+        ChannelKey::new(
+            &ChainId::from_string("ibc-0"),
+            &PortId::transfer(),
+            &ChannelId::new(channel),
+        )
+    }
+
+    #[test]
+    fn a_channel_is_claimed_by_a_single_instance() {
+        let store = store();
+        let a = leases(&store, "a");
+        let b = leases(&store, "b");
+
+        assert!(a.is_claimed(key(0)));
+        assert!(!b.is_claimed(key(0)));
+        assert!(b.is_claimed(key(1)));
+        assert!(!a.is_claimed(key(1)));
+
+        // Nothing is taken over while the leases are held
+        assert!(a.is_claimed(key(0)));
+        assert!(a.claim_released().is_empty());
+    }
+
+    #[test]
+    fn renewed_leases_are_kept() {
+        let store = store();
+        let a = leases(&store, "a");
+        let b = leases(&store, "b");
+
+        assert!(a.is_claimed(key(0)));
+
+        for _ in 0..6 {
+            thread::sleep(RENEW_INTERVAL);
+            assert!(a.renew().is_empty());
+        }
+
+        // The lease outlived its initial duration thanks to the renewals
+        assert!(b.claim_released().is_empty());
+        assert!(!b.is_claimed(key(0)));
+        assert!(a.is_claimed(key(0)));
+    }
+
+    #[test]
+    fn expired_leases_are_taken_over_and_lost() {
+        let store = store();
+        let a = leases(&store, "a");
+        let b = leases(&store, "b");
+
+        assert!(a.is_claimed(key(0)));
+        assert!(!b.is_claimed(key(0)));
+
+        // Instance `a` stops renewing its lease, which expires
+        thread::sleep(LEASE_DURATION + RENEW_INTERVAL);
+
+        // Instance `b` takes over the channel without observing events on it
+        assert_eq!(b.claim_released(), vec![key(0)]);
+        assert!(b.is_claimed(key(0)));
+        assert!(b.claim_released().is_empty());
+
+        // Instance `a` finds out it lost the lease when renewing its leases
+        assert_eq!(a.renew(), vec![key(0)]);
+        assert!(!a.is_claimed(key(0)));
+    }
+}
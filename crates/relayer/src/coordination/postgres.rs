@@ -0,0 +1,133 @@
+//! Stores the leases on the channel ends in a Postgres table.
+//!
+//! The connection to the database is not encrypted, so the connection strings
+//! requiring TLS with `sslmode=require` are refused.
+
+use ::postgres::config::SslMode;
+use ::postgres::{Client, Config, NoTls};
+
+use super::{ChannelKey, Error, LeaseStore};
+use crate::config::coordination::CoordinationConfig;
+
+pub struct PostgresLeaseStore {
+    client: Client,
+    instance_id: String,
+    lease_millis: i64,
+    claim_query: String,
+    renew_query: String,
+}
+
+impl PostgresLeaseStore {
+    pub fn connect(config: &CoordinationConfig) -> Result<Self, Error> {
+        // The table is spliced into the queries, so it must be a plain identifier
+        if !config.has_valid_table() {
+            return Err(Error::database(format!(
+                "invalid table name `{}`",
+                config.table
+            )));
+        }
+
+        let database_config: Config = config.database_url.parse().map_err(database_error)?;
+
+        if !matches!(
+            database_config.get_ssl_mode(),
+            SslMode::Disable | SslMode::Prefer
+        ) {
+            return Err(Error::database(
+                "TLS connections to the database are not supported, \
+                    the `sslmode` of the `database_url` must be `disable` or `prefer`"
+                    .to_string(),
+            ));
+        }
+
+        let mut client: Client = database_config.connect(NoTls).map_err(database_error)?;
+
+        let table = &config.table;
+
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {table} (
+                    chain_id TEXT NOT NULL,
+                    port_id TEXT NOT NULL,
+                    channel_id TEXT NOT NULL,
+                    owner TEXT NOT NULL,
+                    expires_at TIMESTAMPTZ NOT NULL,
+                    PRIMARY KEY (chain_id, port_id, channel_id)
+                )"
+            ))
+            .map_err(database_error)?;
+
+        // Take the lease if it is free, expired, or already held by this instance
+        let claim_query = format!(
+            "INSERT INTO {table} (chain_id, port_id, channel_id, owner, expires_at)
+             VALUES ($1, $2, $3, $4, now() + $5::BIGINT * INTERVAL '1 millisecond')
+             ON CONFLICT (chain_id, port_id, channel_id) DO UPDATE
+             SET owner = EXCLUDED.owner, expires_at = EXCLUDED.expires_at
+             WHERE {table}.owner = EXCLUDED.owner OR {table}.expires_at < now()
+             RETURNING owner"
+        );
+
+        let renew_query = format!(
+            "UPDATE {table}
+             SET expires_at = now() + $2::BIGINT * INTERVAL '1 millisecond'
+             WHERE owner = $1
+             RETURNING chain_id, port_id, channel_id"
+        );
+
+        Ok(Self {
+            client,
+            instance_id: config.instance_id.clone(),
+            lease_millis: config.lease_duration.as_millis() as i64,
+            claim_query,
+            renew_query,
+        })
+    }
+}
+
+impl LeaseStore for PostgresLeaseStore {
+    fn claim(&mut self, key: &ChannelKey) -> Result<bool, Error> {
+        let rows = self
+            .client
+            .query(
+                &self.claim_query,
+                &[
+                    &key.chain_id.as_str(),
+                    &key.port_id.as_str(),
+                    &key.channel_id.as_str(),
+                    &self.instance_id,
+                    &self.lease_millis,
+                ],
+            )
+            .map_err(database_error)?;
+
+        Ok(!rows.is_empty())
+    }
+
+    fn renew(&mut self) -> Result<Vec<ChannelKey>, Error> {
+        let rows = self
+            .client
+            .query(&self.renew_query, &[&self.instance_id, &self.lease_millis])
+            .map_err(database_error)?;
+
+        let keys = rows
+            .iter()
+            .filter_map(|row| {
+                let chain_id: &str = row.get(0);
+                let port_id: &str = row.get(1);
+                let channel_id: &str = row.get(2);
+
+                Some(ChannelKey {
+                    chain_id: chain_id.parse().ok()?,
+                    port_id: port_id.parse().ok()?,
+                    channel_id: channel_id.parse().ok()?,
+                })
+            })
+            .collect();
+
+        Ok(keys)
+    }
+}
+
+fn database_error(e: ::postgres::Error) -> Error {
+    Error::database(e.to_string())
+}
@@ -1,5 +1,6 @@
 //! This module defines the various errors that be raised in the relayer.
 
+use core::fmt::{Display, Error as FmtError, Formatter};
 use core::time::Duration;
 
 use flex_error::{define_error, DisplayOnly, TraceError};
@@ -43,7 +44,7 @@ use crate::chain::cosmos::BLOCK_MAX_BYTES_MAX_FRACTION;
 use crate::config::Error as ConfigError;
 use crate::event::source;
 use crate::keyring::{errors::Error as KeyringError, KeyType};
-use crate::sdk_error::SdkError;
+use crate::sdk_error::{SdkError, SdkErrorDetail};
 
 define_error! {
     Error {
@@ -302,6 +303,23 @@ define_error! {
             [ TraceError<InvalidUri> ]
             |e| { format!("error parsing URI {}", e.uri) },
 
+        RemoteSubmitter
+            { reason: String }
+            |e| { format!("remote submitter error: {}", e.reason) },
+
+        RemoteSubmission
+            { category: ErrorCategory, reason: String }
+            |e| { format!("the remote submitter failed to submit the messages: {}", e.reason) },
+
+        AggregatedTx
+            { category: ErrorCategory, reason: String }
+            |e| { format!("failed to send the transactions shared with other workers: {}", e.reason) },
+
+        ProxyBridge
+            { proxy: String }
+            [ TraceError<std::io::Error> ]
+            |e| { format!("failed to start the local bridge to proxy {}", e.proxy) },
+
         ChainIdentifier
             { chain_id: String }
             |e| { format!("invalid chain identifier format: {0}", e.chain_id) },
@@ -483,6 +501,17 @@ define_error! {
                     e.chain_id, format_duration(e.trusting_period), format_duration(e.unbonding_period))
             },
 
+        ConfigValidationTrustingPeriodMarginTooLarge
+            {
+                chain_id: ChainId,
+                margin: Duration,
+                trusting_period: Duration,
+            }
+            |e| {
+                format!("semantic config validation failed for option `trusting_period_margin` of chain '{}', reason: trusting period margin ({}) must be smaller than the trusting period ({})",
+                    e.chain_id, format_duration(e.margin), format_duration(e.trusting_period))
+            },
+
         ConfigValidationDefaultGasTooHigh
             {
                 chain_id: ChainId,
@@ -556,6 +585,10 @@ define_error! {
             { chain_id: ChainId }
             |e| { format!("Hermes gas price is lower than the minimum gas price set by node operator'{}'", e.chain_id) },
 
+        InvalidBaseFee
+            { base_fee: String }
+            |e| { format!("invalid base fee returned by the fee market of the chain: '{}'", e.base_fee) },
+
         TxIndexingDisabled
             { chain_id: ChainId }
             |e| {
@@ -610,6 +643,125 @@ impl Error {
     }
 }
 
+/// The category of an error raised by a chain, which determines
+/// the retry policy to apply when relaying fails with that error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The chain could not be reached, eg. the full node is down or the request timed out
+    Network,
+    /// The mempool of the full node is full
+    MempoolFull,
+    /// The transaction ran out of gas
+    OutOfGas,
+    /// The account sequence of the transaction does not match the one of the account
+    SequenceMismatch,
+    /// The application rejected the transaction, which will fail again if resubmitted as is
+    Permanent,
+    /// Any other error
+    Other,
+}
+
+impl ErrorCategory {
+    const ALL: [Self; 6] = [
+        Self::Network,
+        Self::MempoolFull,
+        Self::OutOfGas,
+        Self::SequenceMismatch,
+        Self::Permanent,
+        Self::Other,
+    ];
+
+    /// The category with the given name, as returned by [`ErrorCategory::as_str`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.as_str() == name)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Network => "network",
+            Self::MempoolFull => "mempool_full",
+            Self::OutOfGas => "out_of_gas",
+            Self::SequenceMismatch => "sequence_mismatch",
+            Self::Permanent => "permanent",
+            Self::Other => "other",
+        }
+    }
+
+    /// Classifies the error reported by a chain for a transaction, from its log.
+    ///
+    /// The log is matched against the messages of the corresponding cosmos-sdk errors,
+    /// as the codes alone are ambiguous without their codespace.
+    /// Errors which are not known to be transient are deemed permanent.
+    pub fn from_tx_log(log: &str) -> Self {
+        if log.contains("mempool is full") {
+            Self::MempoolFull
+        } else if log.contains("out of gas") {
+            Self::OutOfGas
+        } else if log.contains("account sequence mismatch")
+            || log.contains("incorrect account sequence")
+        {
+            Self::SequenceMismatch
+        } else if log.contains("packet sequence is out of order") {
+            // This error is transient, see `GrpcStatusSubdetail::is_out_of_order_packet_sequence_error`
+            Self::Other
+        } else {
+            Self::Permanent
+        }
+    }
+}
+
+impl Display for ErrorCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Error {
+    /// Classifies this error, to determine how to retry the operation which raised it.
+    pub fn category(&self) -> ErrorCategory {
+        self.detail().category()
+    }
+}
+
+impl ErrorDetail {
+    /// Classifies this error, to determine how to retry the operation which raised it.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            ErrorDetail::Io(_)
+            | ErrorDetail::Rpc(_)
+            | ErrorDetail::WebSocket(_)
+            | ErrorDetail::GrpcTransport(_)
+            | ErrorDetail::TxNoConfirmation(_) => ErrorCategory::Network,
+
+            ErrorDetail::GrpcStatus(e) => match e.status.code() {
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => ErrorCategory::Network,
+                _ => match ErrorCategory::from_tx_log(e.status.message()) {
+                    // A failed simulation is not necessarily a permanent failure of the tx
+                    ErrorCategory::Permanent => ErrorCategory::Other,
+                    category => category,
+                },
+            },
+
+            ErrorDetail::CheckTx(e) => ErrorCategory::from_tx_log(&e.response.log),
+
+            ErrorDetail::DeliverTx(e) => match e.detail.detail() {
+                SdkErrorDetail::OutOfGas(_) => ErrorCategory::OutOfGas,
+                _ => ErrorCategory::from_tx_log(&e.tx.log),
+            },
+
+            ErrorDetail::TxSimulateGasEstimateExceeded(_) => ErrorCategory::OutOfGas,
+
+            ErrorDetail::AggregatedTx(e) => e.category,
+
+            ErrorDetail::RemoteSubmission(e) => e.category,
+
+            _ => ErrorCategory::Other,
+        }
+    }
+}
+
 impl GrpcStatusSubdetail {
     /// Check whether this gRPC error matches
     /// - message: verification failed: ... failed packet acknowledgement verification for client: client state height < proof height ...
@@ -770,4 +922,31 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_error_category_from_tx_log() {
+        let tests = [
+            (
+                "mempool is full: number of txs 5000 (max: 5000)",
+                ErrorCategory::MempoolFull,
+            ),
+            (
+                "out of gas in location: WriteFlat; gasWanted: 200000, gasUsed: 200523: out of gas",
+                ErrorCategory::OutOfGas,
+            ),
+            (
+                "account sequence mismatch, expected 10, got 9: incorrect account sequence",
+                ErrorCategory::SequenceMismatch,
+            ),
+            ("packet sequence is out of order", ErrorCategory::Other),
+            (
+                "failed to execute message; message index: 0: packet messages are redundant",
+                ErrorCategory::Permanent,
+            ),
+        ];
+
+        for (log, category) in tests {
+            assert_eq!(ErrorCategory::from_tx_log(log), category, "{log}");
+        }
+    }
 }
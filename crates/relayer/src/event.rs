@@ -1,5 +1,5 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tendermint::abci::Event as AbciEvent;
 
 use ibc_relayer_types::{
@@ -26,12 +26,13 @@ use ibc_relayer_types::{
 };
 
 use crate::light_client::decode_header;
+use crate::light_client::registry::client_registry;
 
 pub mod bus;
 pub mod error;
 pub mod source;
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IbcEventWithHeight {
     pub event: IbcEvent,
     pub height: Height,
@@ -311,9 +312,9 @@ fn client_extract_attributes_from_tx(event: &AbciEvent) -> Result<ClientAttribut
                     .map_err(ClientError::invalid_client_identifier)?
             }
             client_events::CLIENT_TYPE_ATTRIBUTE_KEY => {
-                attr.client_type = value
-                    .parse()
-                    .map_err(|_| ClientError::unknown_client_type(value.to_string()))?
+                attr.client_type = client_registry()
+                    .client_type(value)
+                    .ok_or_else(|| ClientError::unknown_client_type(value.to_string()))?
             }
             client_events::CONSENSUS_HEIGHT_ATTRIBUTE_KEY => {
                 attr.consensus_height = value
@@ -3,6 +3,7 @@ use flex_error::{define_error, TraceError};
 use tendermint_rpc::{Error as RpcError, WebSocketClientUrl};
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::Height;
 
 define_error! {
     #[derive(Debug, Clone)]
@@ -48,6 +49,13 @@ define_error! {
         Rpc
             [ TraceError<RpcError> ]
             |_| { "RPC error" },
+
+        HeightRollback
+            { chain_id: ChainId, previous: Height, current: Height }
+            |e| {
+                format!("height of chain {0} went back from {1} to {2}, the chain may have been restarted from genesis or rolled back",
+                    e.chain_id, e.previous, e.current)
+            },
     }
 }
 
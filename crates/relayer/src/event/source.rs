@@ -7,7 +7,7 @@ use crossbeam_channel as channel;
 
 use futures::Stream;
 use tendermint_rpc::{
-    client::CompatMode, event::Event as RpcEvent, Error as RpcError, HttpClient, WebSocketClientUrl,
+    client::CompatMode, event::Event as RpcEvent, Error as RpcError, WebSocketClientUrl,
 };
 use tokio::runtime::Runtime as TokioRuntime;
 
@@ -15,9 +15,10 @@ use ibc_relayer_types::{
     core::ics02_client::height::Height, core::ics24_host::identifier::ChainId,
 };
 
-pub use super::error::{Error, ErrorDetail};
+pub use super::error::{Error, ErrorDetail, HeightRollbackSubdetail};
 
 use super::IbcEventWithHeight;
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::chain::{handle::Subscription, tracking::TrackingId};
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -45,7 +46,7 @@ impl EventSource {
 
     pub fn rpc(
         chain_id: ChainId,
-        rpc_client: HttpClient,
+        rpc_client: RpcClient,
         poll_interval: Duration,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
@@ -7,11 +7,11 @@ use tokio::{
     runtime::Runtime as TokioRuntime,
     time::{sleep, Duration, Instant},
 };
-use tracing::{debug, error, error_span, trace};
+use tracing::{debug, error, error_span, trace, warn};
 
 use tendermint::abci;
 use tendermint::block::Height as BlockHeight;
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::Client;
 
 use ibc_relayer_types::{
     core::{
@@ -21,6 +21,7 @@ use ibc_relayer_types::{
     events::IbcEvent,
 };
 
+use crate::chain::cosmos::types::rpc::RpcClient;
 use crate::{
     chain::tracking::TrackingId,
     event::{bus::EventBus, source::Error, IbcEventWithHeight},
@@ -39,8 +40,13 @@ pub struct EventSource {
     /// Chain identifier
     chain_id: ChainId,
 
+    /// The chain identifier reported by the node, which holds the revision number
+    /// of the heights of the events, and differs from `chain_id` once the chain
+    /// was upgraded to a new revision number
+    network_id: ChainId,
+
     /// RPC client
-    rpc_client: HttpClient,
+    rpc_client: RpcClient,
 
     /// Poll interval
     poll_interval: Duration,
@@ -61,7 +67,7 @@ pub struct EventSource {
 impl EventSource {
     pub fn new(
         chain_id: ChainId,
-        rpc_client: HttpClient,
+        rpc_client: RpcClient,
         poll_interval: Duration,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
@@ -70,6 +76,7 @@ impl EventSource {
 
         let source = Self {
             rt,
+            network_id: chain_id.clone(),
             chain_id,
             rpc_client,
             poll_interval,
@@ -92,7 +99,7 @@ impl EventSource {
             let mut backoff = poll_backoff(self.poll_interval);
 
             // Initialize the latest fetched height
-            if let Ok(latest_height) = latest_height(&self.rpc_client).await {
+            if let Ok((latest_height, _)) = latest_status(&self.rpc_client).await {
                 self.last_fetched_height = latest_height;
             }
 
@@ -140,7 +147,37 @@ impl EventSource {
             return Ok(Next::Abort);
         }
 
-        let latest_height = latest_height(&self.rpc_client).await?;
+        let (latest_height, network_id) = latest_status(&self.rpc_client).await?;
+
+        let upgraded = self.network_id.is_upgraded_to(&network_id);
+
+        if upgraded {
+            warn!(
+                "chain was upgraded from {} to {}, now using revision number {}",
+                self.network_id,
+                network_id,
+                network_id.version()
+            );
+
+            self.network_id = network_id;
+        }
+
+        if latest_height < self.last_fetched_height {
+            // The height of a chain restarted with a new revision number starts over,
+            // otherwise the chain was restarted from genesis or rolled back.
+            if !upgraded {
+                let e = Error::height_rollback(
+                    self.chain_id.clone(),
+                    Height::from_tm(self.last_fetched_height, &self.network_id),
+                    Height::from_tm(latest_height, &self.network_id),
+                );
+
+                error!("{e}");
+                self.event_bus.broadcast(Arc::new(Err(e)));
+            }
+
+            self.last_fetched_height = latest_height;
+        }
 
         let batches = if latest_height > self.last_fetched_height {
             trace!(
@@ -203,7 +240,8 @@ impl EventSource {
         for height in heights {
             trace!("collecting events at height {height}");
 
-            let result = collect_events(&self.rpc_client, &self.chain_id, height).await;
+            let result =
+                collect_events(&self.rpc_client, &self.chain_id, &self.network_id, height).await;
 
             match result {
                 Ok(batch) => {
@@ -289,10 +327,11 @@ fn dedupe(events: Vec<abci::Event>) -> Vec<abci::Event> {
         .collect()
 }
 
-/// Collect the IBC events from an RPC event
+/// Collect the IBC events from an RPC event, at the revision number of the given network
 async fn collect_events(
-    rpc_client: &HttpClient,
+    rpc_client: &RpcClient,
     chain_id: &ChainId,
+    network_id: &ChainId,
     latest_block_height: BlockHeight,
 ) -> Result<Option<EventBatch>> {
     let abci_events = fetch_all_events(rpc_client, latest_block_height).await?;
@@ -301,11 +340,11 @@ async fn collect_events(
     let abci_events = dedupe(abci_events);
     trace!("Found {} ABCI events after dedupe", abci_events.len());
 
-    let height = Height::from_tm(latest_block_height, chain_id);
+    let height = Height::from_tm(latest_block_height, network_id);
     let new_block_event =
         IbcEventWithHeight::new(IbcEvent::NewBlock(NewBlock::new(height)), height);
 
-    let mut block_events = extract_events(chain_id, height, &abci_events).unwrap_or_default();
+    let mut block_events = extract_events(network_id, height, &abci_events).unwrap_or_default();
     let mut events = Vec::with_capacity(block_events.len() + 1);
     events.push(new_block_event);
     events.append(&mut block_events);
@@ -324,10 +363,7 @@ async fn collect_events(
     }))
 }
 
-async fn fetch_all_events(
-    rpc_client: &HttpClient,
-    height: BlockHeight,
-) -> Result<Vec<abci::Event>> {
+async fn fetch_all_events(rpc_client: &RpcClient, height: BlockHeight) -> Result<Vec<abci::Event>> {
     let mut response = rpc_client.block_results(height).await.map_err(Error::rpc)?;
     let mut events = vec![];
 
@@ -353,11 +389,17 @@ async fn fetch_all_events(
     Ok(events)
 }
 
-async fn latest_height(rpc_client: &HttpClient) -> Result<BlockHeight> {
+/// The latest height of the chain, and the chain identifier reported by the node
+async fn latest_status(rpc_client: &RpcClient) -> Result<(BlockHeight, ChainId)> {
     rpc_client
-        .abci_info()
+        .status()
         .await
-        .map(|status| status.last_block_height)
+        .map(|status| {
+            (
+                status.sync_info.latest_block_height,
+                ChainId::from_string(status.node_info.network.as_str()),
+            )
+        })
         .map_err(Error::rpc)
 }
 
@@ -12,14 +12,16 @@ use futures::{
 };
 use tokio::task::JoinHandle;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use tendermint_rpc::{
-    client::CompatMode, event::Event as RpcEvent, query::Query, SubscriptionClient,
-    WebSocketClient, WebSocketClientDriver, WebSocketClientUrl,
+    client::CompatMode,
+    event::{Event as RpcEvent, EventData as RpcEventData},
+    query::Query,
+    SubscriptionClient, WebSocketClient, WebSocketClientDriver, WebSocketClientUrl,
 };
 
-use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent};
+use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent, Height};
 
 use crate::{
     chain::tracking::TrackingId,
@@ -84,6 +86,8 @@ pub struct EventSource {
     event_queries: Vec<Query>,
     /// All subscriptions combined in a single stream
     subscriptions: Box<SubscriptionStream>,
+    /// Height of the latest block received
+    latest_block_height: Option<Height>,
     /// Tokio runtime
     rt: Arc<TokioRuntime>,
 }
@@ -132,6 +136,7 @@ impl EventSource {
             ws_url,
             rpc_compat,
             subscriptions: Box::new(futures::stream::empty()),
+            latest_block_height: None,
         };
 
         Ok((source, TxEventSourceCmd(tx_cmd)))
@@ -330,7 +335,14 @@ impl EventSource {
             }
 
             match result {
-                Ok(batch) => self.broadcast_batch(batch),
+                Ok(batch) => {
+                    if let Err(e) = self.check_height_rollback(&batch) {
+                        error!("{e}");
+                        self.propagate_error(e);
+                    }
+
+                    self.broadcast_batch(batch)
+                }
                 Err(e) => {
                     if let ErrorDetail::SubscriptionCancelled(reason) = e.detail() {
                         error!("subscription cancelled, reason: {}", reason);
@@ -350,6 +362,38 @@ impl EventSource {
         }
     }
 
+    /// Checks that the height of the new blocks never goes backwards within the same revision,
+    /// which would mean that the chain was restarted from genesis or rolled back.
+    ///
+    /// Only the batches holding a `NewBlock` event are checked, as the events of the
+    /// transactions of a block may be received after the next block.
+    fn check_height_rollback(&mut self, batch: &EventBatch) -> Result<()> {
+        let is_new_block = batch
+            .events
+            .iter()
+            .any(|ev| matches!(ev.event, IbcEvent::NewBlock(_)));
+
+        if !is_new_block {
+            return Ok(());
+        }
+
+        let previous = self.latest_block_height.replace(batch.height);
+
+        match previous {
+            Some(previous)
+                if previous.revision_number() == batch.height.revision_number()
+                    && previous > batch.height =>
+            {
+                Err(Error::height_rollback(
+                    self.chain_id.clone(),
+                    previous,
+                    batch.height,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Propagate error to subscribers.
     ///
     /// The main use case for propagating RPC errors is for the [`Supervisor`]
@@ -409,12 +453,33 @@ fn stream_batches(
     chain_id: ChainId,
     batch_delay: Duration,
 ) -> impl Stream<Item = Result<EventBatch>> {
-    let id = chain_id.clone();
+    // The chain identifier reported in the blocks, which holds the revision number of
+    // the heights of the events, and differs from `chain_id` once the chain was upgraded
+    let mut id = chain_id.clone();
 
     // Collect IBC events from each RPC event
     let events = subscriptions
         .map_ok(move |rpc_event| {
             trace!(chain = %id, "received an RPC event: {}", rpc_event.query);
+
+            if let RpcEventData::NewBlock {
+                block: Some(block), ..
+            } = &rpc_event.data
+            {
+                let network_id = ChainId::from_string(block.header.chain_id.as_str());
+
+                if id.is_upgraded_to(&network_id) {
+                    warn!(
+                        "chain was upgraded from {} to {}, now using revision number {}",
+                        id,
+                        network_id,
+                        network_id.version()
+                    );
+
+                    id = network_id;
+                }
+            }
+
             collect_events(&id, rpc_event)
         })
         .map_err(Error::canceled_or_generic)
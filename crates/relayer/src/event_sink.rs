@@ -0,0 +1,217 @@
+//! Publishes the lifecycle of the packets relayed by Hermes as structured JSON
+//! messages to a message broker, so that exchanges and indexers can follow the
+//! activity of the relayer without scraping its logs or the chains.
+//!
+//! The events are published to Kafka when Hermes is built with the `kafka` feature,
+//! and to NATS when it is built with the `nats` feature.
+//!
+//! The errors are also handed to the dashboard of the REST server, whether or not
+//! the sink is enabled.
+
+#[cfg(any(feature = "kafka", feature = "nats"))]
+mod connect;
+
+#[cfg(feature = "kafka")]
+mod kafka;
+
+#[cfg(feature = "nats")]
+mod nats;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flex_error::define_error;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tracing::{error, warn};
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::config::event_sink::{EventSinkBackend, EventSinkConfig};
+use crate::config::hooks::HookTrigger;
+use crate::hooks;
+use crate::rest::dashboard;
+use crate::util::profile::Profile;
+use crate::util::spawn_blocking;
+
+define_error! {
+    Error {
+        UnsupportedBackend
+            { backend: &'static str }
+            |e| {
+                format!("Hermes was built without support for the `{0}` event sink, \
+                    build Hermes with --features={0} to enable it", e.backend)
+            },
+    }
+}
+
+/// The sending end of the relay events of a profile, given to its supervisor in its [`Profile`].
+#[derive(Clone, Debug)]
+pub struct EventSink {
+    sender: mpsc::Sender<RelayEvent>,
+}
+
+/// The step of the lifecycle of the relayed packets that an event reports.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayEventKind {
+    /// Packet events to relay were observed on the chain
+    PacketObserved,
+    /// Messages relaying the packets were submitted to the chain
+    MessagesSubmitted,
+    /// The transactions carrying the messages were committed
+    TxConfirmed,
+    /// The messages could not be submitted or confirmed
+    Error,
+}
+
+impl RelayEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PacketObserved => "packet_observed",
+            Self::MessagesSubmitted => "messages_submitted",
+            Self::TxConfirmed => "tx_confirmed",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// A relay action on the given channel end, as published to the message broker.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RelayEvent {
+    pub kind: RelayEventKind,
+    pub tracking_id: String,
+    pub chain_id: ChainId,
+    pub counterparty_chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The relaying profile which relayed on the channel end, if not the main configuration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    pub sequences: Vec<Sequence>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tx_hashes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The time at which the action happened, in milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl RelayEvent {
+    pub fn new(
+        kind: RelayEventKind,
+        tracking_id: impl ToString,
+        chain_id: ChainId,
+        counterparty_chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+
+        Self {
+            kind,
+            tracking_id: tracking_id.to_string(),
+            chain_id,
+            counterparty_chain_id,
+            port_id,
+            channel_id,
+            profile: None,
+            sequences: Vec::new(),
+            tx_hashes: Vec::new(),
+            error: None,
+            timestamp,
+        }
+    }
+
+    pub fn with_sequences(self, sequences: Vec<Sequence>) -> Self {
+        Self { sequences, ..self }
+    }
+
+    pub fn with_tx_hashes(self, tx_hashes: Vec<String>) -> Self {
+        Self { tx_hashes, ..self }
+    }
+
+    pub fn with_error(self, error: impl ToString) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..self
+        }
+    }
+
+    /// The key of the event, which is the same for all the events of a channel end,
+    /// so that their order is preserved by the brokers partitioning by key.
+    pub fn key(&self) -> String {
+        format!("{}/{}/{}", self.chain_id, self.port_id, self.channel_id)
+    }
+
+    /// The JSON encoding of the event, as published to the message broker.
+    pub fn encode(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| error!("failed to encode relay event: {e}"))
+            .ok()
+    }
+}
+
+/// Whether the relay events of the given kind should be published for the given profile, ie.
+/// whether its event sink is running, or the event is an error to show in the dashboard.
+pub fn is_enabled(profile: &Profile, kind: RelayEventKind) -> bool {
+    kind == RelayEventKind::Error
+        || profile
+            .event_sink()
+            .map_or(false, |sink| !sink.sender.is_closed())
+}
+
+/// Publishes the given event to the event sink of the given profile, if it is running,
+/// and records it for the dashboard if it is an error.
+///
+/// This never blocks: the event is dropped if too many events are already waiting to be published.
+pub fn publish(profile: &Profile, event: RelayEvent) {
+    let event = RelayEvent {
+        profile: profile.name().map(str::to_owned),
+        ..event
+    };
+
+    if event.kind == RelayEventKind::Error {
+        hooks::fire(profile, HookTrigger::RelayError, &event.chain_id, &event);
+        dashboard::record_error(event.clone());
+    }
+
+    if let Some(sink) = profile.event_sink() {
+        match sink.sender.try_send(event) {
+            Ok(()) => {}
+            Err(e @ TrySendError::Full(_)) => warn!("dropping relay event: {e}"),
+            // The event sink gave up connecting to the message broker, which was already reported
+            Err(TrySendError::Closed(_)) => {}
+        }
+    }
+}
+
+/// Spawns the task publishing the relay events sent to the returned sink
+/// to the configured message broker.
+pub fn spawn(config: EventSinkConfig) -> Result<EventSink, Error> {
+    match config.backend {
+        EventSinkBackend::Kafka if cfg!(feature = "kafka") => {}
+        EventSinkBackend::Nats if cfg!(feature = "nats") => {}
+        backend => return Err(Error::unsupported_backend(backend.as_str())),
+    }
+
+    let (sender, receiver) = mpsc::channel(config.buffer_size.max(1));
+
+    spawn_blocking(async move {
+        match config.backend {
+            #[cfg(feature = "kafka")]
+            EventSinkBackend::Kafka => kafka::run(&config, receiver).await,
+
+            #[cfg(feature = "nats")]
+            EventSinkBackend::Nats => nats::run(&config, receiver).await,
+
+            #[allow(unreachable_patterns)]
+            _ => drop(receiver),
+        }
+    });
+
+    Ok(EventSink { sender })
+}
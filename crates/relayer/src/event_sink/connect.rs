@@ -0,0 +1,54 @@
+//! Connects to the message broker, giving up after a few attempts.
+
+use core::fmt::Display;
+use core::future::Future;
+use core::time::Duration;
+
+use tracing::{error, warn};
+
+use crate::config::event_sink::EventSinkBackend;
+use crate::util::retry::ConstantGrowth;
+
+/// How many times to try connecting to the message broker before disabling the event sink
+const MAX_CONNECT_ATTEMPTS: usize = 5;
+
+/// Connects to the message broker, retrying with a growing delay on failure.
+///
+/// Returns `None` once all the attempts have failed, in which case the caller drops the
+/// receiving end of the relay events, which disables the event sink for the rest of the run.
+pub async fn connect<T, E, F>(
+    backend: EventSinkBackend,
+    mut connect: impl FnMut() -> F,
+) -> Option<T>
+where
+    E: Display,
+    F: Future<Output = Result<T, E>>,
+{
+    let mut delays = ConstantGrowth::new(Duration::from_secs(1), Duration::from_secs(1))
+        .clamp(Duration::from_secs(10), MAX_CONNECT_ATTEMPTS - 1);
+
+    loop {
+        match connect().await {
+            Ok(client) => return Some(client),
+            Err(e) => match delays.next() {
+                Some(delay) => {
+                    warn!(
+                        "failed to connect to the {} event sink: {e}, retrying in {delay:?}",
+                        backend.as_str()
+                    );
+
+                    tokio::time::sleep(delay).await;
+                }
+                None => {
+                    error!(
+                        "failed to connect to the {} event sink after {MAX_CONNECT_ATTEMPTS} attempts: {e}, \
+                        disabling the event sink",
+                        backend.as_str()
+                    );
+
+                    return None;
+                }
+            },
+        }
+    }
+}
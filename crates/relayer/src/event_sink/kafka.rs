@@ -0,0 +1,58 @@
+//! Publishes the relay events to a Kafka topic.
+
+use core::time::Duration;
+
+use rdkafka::config::ClientConfig;
+use rdkafka::error::KafkaError;
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
+use tokio::sync::mpsc::Receiver;
+use tracing::{error, info};
+
+use super::connect::connect;
+use super::RelayEvent;
+use crate::config::event_sink::{EventSinkBackend, EventSinkConfig};
+
+/// How long to wait for room in the producer queue before giving up on an event
+const QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait for the brokers to answer when connecting to them
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn run(config: &EventSinkConfig, mut receiver: Receiver<RelayEvent>) {
+    // Creating the producer does not reach the brokers, so fetch the metadata
+    // of the topic to find out whether they can be connected to.
+    let try_connect = move || async move {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &config.url)
+            .create()?;
+
+        producer
+            .client()
+            .fetch_metadata(Some(&config.topic), CONNECT_TIMEOUT)?;
+
+        Ok::<_, KafkaError>(producer)
+    };
+
+    let Some(producer) = connect(EventSinkBackend::Kafka, try_connect).await else {
+        return;
+    };
+
+    info!(
+        brokers = %config.url,
+        topic = %config.topic,
+        "publishing relay events to Kafka"
+    );
+
+    while let Some(event) = receiver.recv().await {
+        let Some(payload) = event.encode() else {
+            continue;
+        };
+
+        let key = event.key();
+        let record = FutureRecord::to(&config.topic).key(&key).payload(&payload);
+
+        if let Err((e, _)) = producer.send(record, QUEUE_TIMEOUT).await {
+            error!("failed to publish relay event to Kafka: {e}");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Publishes the relay events to NATS subjects.
+
+use tokio::sync::mpsc::Receiver;
+use tracing::{error, info};
+
+use super::connect::connect;
+use super::RelayEvent;
+use crate::config::event_sink::{EventSinkBackend, EventSinkConfig};
+
+pub async fn run(config: &EventSinkConfig, mut receiver: Receiver<RelayEvent>) {
+    let try_connect = move || async_nats::connect(&config.url);
+
+    let Some(client) = connect(EventSinkBackend::Nats, try_connect).await else {
+        return;
+    };
+
+    info!(
+        server = %config.url,
+        subjects = %format!("{}.*", config.topic),
+        "publishing relay events to NATS"
+    );
+
+    while let Some(event) = receiver.recv().await {
+        let Some(payload) = event.encode() else {
+            continue;
+        };
+
+        // Eg. `hermes.relay.tx_confirmed`
+        let subject = format!("{}.{}", config.topic, event.kind.as_str());
+
+        if let Err(e) = client.publish(subject, payload.into()).await {
+            error!("failed to publish relay event to NATS: {e}");
+        }
+    }
+}
@@ -243,6 +243,17 @@ define_error! {
                     e.height, e.elapsed)
             },
 
+        ConsensusStateNearExpiry
+            {
+                height: Height,
+                elapsed: Duration,
+                margin: Duration,
+            }
+            |e| {
+                format_args!("the consensus state at height {} is within the safety margin ({:?}) of the end of the trusting period: elapsed {:?}",
+                    e.height, e.margin, e.elapsed)
+            },
+
         Misbehaviour
             {
                 description: String,
@@ -786,6 +797,70 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    /// The safety margin before the end of the trusting period of the consensus states of
+    /// the client, within which they are not used to build client updates, if configured.
+    fn trusting_period_margin(&self) -> Option<Duration> {
+        self.src_chain.config().ok()?.trusting_period_margin
+    }
+
+    /// Checks that the consensus state at the given trusted height is not within the safety
+    /// margin of the end of its trusting period, and returns the trusted height to build the
+    /// client update to `target_height` from.
+    ///
+    /// If the consensus state is within the margin, the update is instead built from the latest
+    /// consensus state of the client, if it is below the target height, as updating the client
+    /// from it refreshes the client. Otherwise, no fresher consensus state can be used, and the
+    /// client update is refused.
+    fn check_trusting_period_margin(
+        &self,
+        client_state: &AnyClientState,
+        trusted_height: Height,
+        target_height: Height,
+    ) -> Result<Height, ForeignClientError> {
+        let Some(margin) = self.trusting_period_margin() else {
+            return Ok(trusted_height);
+        };
+
+        let ConsensusStateTrusted::Trusted { elapsed } =
+            self.check_consensus_state_trusting_period(client_state, &trusted_height)?
+        else {
+            return Ok(trusted_height);
+        };
+
+        if !within_trusting_period_margin(client_state, elapsed, margin) {
+            return Ok(trusted_height);
+        }
+
+        match refreshing_trusted_height(client_state.latest_height(), target_height) {
+            Some(latest_height) => {
+                warn!(
+                    %trusted_height,
+                    %latest_height,
+                    ?elapsed,
+                    ?margin,
+                    "consensus state is close to the end of its trusting period, \
+                     refreshing the client from its latest consensus state instead",
+                );
+
+                Ok(latest_height)
+            }
+            None => {
+                warn!(
+                    %trusted_height,
+                    ?elapsed,
+                    ?margin,
+                    "refusing to build a client update from a consensus state close to the end of its trusting period",
+                );
+
+                Err(ForeignClientError::consensus_state_near_expiry(
+                    trusted_height,
+                    elapsed,
+                    margin,
+                ))
+            }
+        }
+    }
+
     pub fn is_expired_or_frozen(&self) -> bool {
         match self.validated_client_state() {
             Ok(_) => false,
@@ -836,6 +911,21 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         // we can backoff between subsequent client updates.
         let refresh_window = client_state.refresh_period();
 
+        // Refresh the client before its latest consensus state gets within the safety margin
+        if let (Some(elapsed), Some(margin)) = (elapsed, self.trusting_period_margin()) {
+            if within_trusting_period_margin(&client_state, elapsed, margin) {
+                info!(
+                    ?elapsed,
+                    ?margin,
+                    "client needs to be refreshed before the end of its trusting period"
+                );
+
+                return self
+                    .build_latest_update_client_and_send()
+                    .map_or_else(Err, |ev| Ok(Some(ev)));
+            }
+        }
+
         match (elapsed, refresh_window) {
             (None, _) | (_, None) => Ok(None),
             (Some(elapsed), Some(refresh_window)) => {
@@ -1114,7 +1204,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         // Get the latest client state on destination.
         let (client_state, _) = self.validated_client_state()?;
 
-        let trusted_height = match maybe_trusted_height {
+        let mut trusted_height = match maybe_trusted_height {
             Some(trusted_height) => {
                 self.validate_trusted_height(trusted_height, &client_state)?;
                 trusted_height
@@ -1144,6 +1234,12 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                     elapsed,
                 ));
             }
+
+            // The client update would be built against a consensus state which may expire
+            // before the update is submitted. The latest consensus state is not subject to
+            // the margin, since updating from it refreshes the client.
+            trusted_height =
+                self.check_trusting_period_margin(&client_state, trusted_height, target_height)?;
         }
 
         if trusted_height >= target_height {
@@ -1787,3 +1883,94 @@ pub fn extract_client_id(event: &IbcEvent) -> Result<&ClientId, ForeignClientErr
         )),
     }
 }
+
+/// Whether a consensus state of the given client, whose timestamp is `elapsed` old, is within
+/// the safety `margin` of the end of its trusting period, or past its trusting period.
+fn within_trusting_period_margin(
+    client_state: &AnyClientState,
+    elapsed: Duration,
+    margin: Duration,
+) -> bool {
+    client_state.expired(elapsed + margin)
+}
+
+/// The height of the latest consensus state of a client, from which to build an update to
+/// `target_height` refreshing the client, instead of from an older consensus state which is
+/// close to the end of its trusting period, if the latest consensus state is below the target.
+fn refreshing_trusted_height(
+    client_latest_height: Height,
+    target_height: Height,
+) -> Option<Height> {
+    (client_latest_height < target_height).then_some(client_latest_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::{
+        AllowUpdate, ClientState as TmClientState,
+    };
+    use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    fn client_state(trusting_period: Duration) -> AnyClientState {
+        let client_state = TmClientState::new(
+            ChainId::from_string("ibc-0"),
+            TrustThreshold::TWO_THIRDS,
+            trusting_period,
+            2 * trusting_period,
+            Duration::from_secs(10),
+            height(10),
+            ProofSpecs::default(),
+            vec![],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap();
+
+        AnyClientState::Tendermint(client_state)
+    }
+
+    fn height(h: u64) -> Height {
+        Height::new(0, h).unwrap()
+    }
+
+    #[test]
+    fn consensus_states_within_the_margin() {
+        let client_state = client_state(10 * HOUR);
+
+        assert!(!within_trusting_period_margin(
+            &client_state,
+            8 * HOUR,
+            HOUR
+        ));
+        assert!(within_trusting_period_margin(
+            &client_state,
+            9 * HOUR + Duration::from_secs(1),
+            HOUR
+        ));
+
+        // Expired consensus states are within the margin too
+        assert!(within_trusting_period_margin(
+            &client_state,
+            11 * HOUR,
+            HOUR
+        ));
+    }
+
+    #[test]
+    fn refresh_from_the_latest_consensus_state_below_the_target() {
+        assert_eq!(
+            refreshing_trusted_height(height(10), height(20)),
+            Some(height(10))
+        );
+
+        // No consensus state below the target is fresher than the one in the margin
+        assert_eq!(refreshing_trusted_height(height(20), height(20)), None);
+        assert_eq!(refreshing_trusted_height(height(30), height(20)), None);
+    }
+}
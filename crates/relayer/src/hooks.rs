@@ -0,0 +1,225 @@
+//! Fires the hooks configured for selected IBC events and error conditions, so that
+//! operators can automate their own reactions to the activity of the relayer.
+//!
+//! A hook either posts the JSON payload of its trigger to a webhook, or runs an external
+//! command with the payload on its standard input. The hooks run in the background,
+//! at most `max_concurrency` at a time, and never slow down relaying: the triggers
+//! are dropped if too many of them are already waiting for their hooks to run.
+
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::sync::{mpsc, Semaphore};
+use tracing::{debug, error, info, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::hooks::{ExecHookConfig, HookTrigger, HooksConfig, WebhookConfig};
+use crate::util::profile::Profile;
+use crate::util::spawn_blocking;
+
+/// The sending end of the triggers of the hooks of a profile,
+/// given to its supervisor in its [`Profile`].
+#[derive(Clone, Debug)]
+pub struct Hooks {
+    config: Arc<HooksConfig>,
+    sender: mpsc::Sender<HookPayload>,
+}
+
+/// What a hook receives when it fires, encoded as JSON.
+#[derive(Clone, Debug, Serialize)]
+pub struct HookPayload {
+    pub trigger: HookTrigger,
+    pub chain_id: ChainId,
+    /// The relaying profile the trigger fired for, if not the main configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// The time at which the trigger fired, in milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// The IBC event with its height, or the details of the error condition
+    pub data: serde_json::Value,
+}
+
+/// Fires the hooks of the given profile configured for the given trigger on the given chain,
+/// if any, with the given data as the payload.
+///
+/// This never blocks: the trigger is dropped if too many triggers are already waiting.
+pub fn fire(profile: &Profile, trigger: HookTrigger, chain_id: &ChainId, data: &impl Serialize) {
+    let Some(hooks) = profile.hooks() else {
+        return;
+    };
+
+    if !hooks.config.is_triggered(&trigger, chain_id) {
+        return;
+    }
+
+    let data = match serde_json::to_value(data) {
+        Ok(data) => data,
+        Err(e) => {
+            error!("failed to encode the payload of the `{trigger}` hooks: {e}");
+            return;
+        }
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default();
+
+    let payload = HookPayload {
+        trigger,
+        chain_id: chain_id.clone(),
+        profile: profile.name().map(str::to_owned),
+        timestamp,
+        data,
+    };
+
+    if let Err(e) = hooks.sender.try_send(payload) {
+        warn!("dropping hook trigger: {e}");
+    }
+}
+
+/// Spawns the task running the hooks fired by the triggers sent to the returned hooks.
+pub fn spawn(config: HooksConfig) -> Hooks {
+    let (sender, receiver) = mpsc::channel(config.buffer_size.max(1));
+
+    let hooks = Hooks {
+        config: Arc::new(config.clone()),
+        sender,
+    };
+
+    info!(
+        webhooks = config.webhooks.len(),
+        exec = config.exec.len(),
+        max_concurrency = config.max_concurrency,
+        "running hooks"
+    );
+
+    spawn_blocking(run(config, receiver));
+
+    hooks
+}
+
+async fn run(config: HooksConfig, mut receiver: mpsc::Receiver<HookPayload>) {
+    let client = reqwest::Client::new();
+    let semaphore = Arc::new(Semaphore::new(config.max_concurrency.max(1)));
+
+    while let Some(payload) = receiver.recv().await {
+        let body = match serde_json::to_vec(&payload) {
+            Ok(body) => body,
+            Err(e) => {
+                error!("failed to encode hook payload: {e}");
+                continue;
+            }
+        };
+
+        let webhooks = config
+            .webhooks
+            .iter()
+            .filter(|hook| hook.matches(&payload.trigger, &payload.chain_id));
+
+        for hook in webhooks {
+            // Waiting for a permit holds back the triggers, which pile up in the channel
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("the semaphore is never closed");
+
+            let (client, hook, body) = (client.clone(), hook.clone(), body.clone());
+
+            tokio::spawn(async move {
+                post_webhook(&client, &hook, body).await;
+                drop(permit);
+            });
+        }
+
+        let exec_hooks = config
+            .exec
+            .iter()
+            .filter(|hook| hook.matches(&payload.trigger, &payload.chain_id));
+
+        for hook in exec_hooks {
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("the semaphore is never closed");
+
+            let (hook, body) = (hook.clone(), body.clone());
+
+            tokio::spawn(async move {
+                run_command(&hook, &body).await;
+                drop(permit);
+            });
+        }
+    }
+}
+
+async fn post_webhook(client: &reqwest::Client, hook: &WebhookConfig, body: Vec<u8>) {
+    let response = client
+        .post(&hook.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .timeout(hook.timeout)
+        .body(body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status());
+
+    match response {
+        Ok(_) => debug!(url = %hook.url, "webhook called"),
+        Err(e) => warn!(url = %hook.url, "webhook failed: {e}"),
+    }
+}
+
+async fn run_command(hook: &ExecHookConfig, body: &[u8]) {
+    let Some((program, args)) = hook.command.split_first() else {
+        return;
+    };
+
+    let child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            warn!(%program, "failed to run hook command: {e}");
+            return;
+        }
+    };
+
+    let run = async {
+        if let Some(mut stdin) = child.stdin.take() {
+            if let Err(e) = stdin.write_all(body).await {
+                warn!(%program, "failed to write the payload to the hook command: {e}");
+            }
+            // Close the standard input, so that the command sees the end of the payload
+            drop(stdin);
+        }
+
+        child.wait_with_output().await
+    };
+
+    // The command is killed if it runs past its timeout, including while it is not
+    // reading its payload, as the child is then dropped
+    match tokio::time::timeout(hook.timeout, run).await {
+        Ok(Ok(output)) if output.status.success() => debug!(%program, "hook command succeeded"),
+        Ok(Ok(output)) => warn!(
+            %program,
+            "hook command failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Ok(Err(e)) => warn!(%program, "failed to wait for the hook command: {e}"),
+        Err(_) => warn!(%program, "hook command timed out after {:?}", hook.timeout),
+    }
+}
@@ -2,9 +2,11 @@ pub mod errors;
 pub use any_signing_key_pair::AnySigningKeyPair;
 pub use ed25519_key_pair::Ed25519KeyPair;
 pub use key_type::KeyType;
-pub use secp256k1_key_pair::Secp256k1KeyPair;
+pub use secp256k1_key_pair::{parse_derivation_path, Secp256k1KeyPair};
 pub use signing_key_pair::{SigningKeyPair, SigningKeyPairSized};
 
+pub use bitcoin::bip32::DerivationPath;
+
 mod any_signing_key_pair;
 mod ed25519_key_pair;
 mod key_type;
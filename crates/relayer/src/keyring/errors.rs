@@ -135,6 +135,18 @@ define_error! {
                         e.expected_length)
             },
 
+        InvalidSecp256k1PublicKey
+            { public_key: Vec<u8> }
+            [ TraceError<secp256k1::Error> ]
+            |e| {
+                format!("invalid secp256k1 public key: {:?}", e.public_key)
+            },
+
+        WatchOnlyKey
+            { account: String }
+            |e| {
+                format!("key for account {} is watch-only and cannot be used for signing", e.account)
+            },
 
         Bs58Decode
             [ TraceError<bs58::decode::Error> ]
@@ -1,4 +1,5 @@
 use core::any::Any;
+use core::str::FromStr;
 
 use bip39::{Language, Mnemonic, Seed};
 use bitcoin::{
@@ -22,9 +23,11 @@ use super::{
 };
 use crate::config::AddressType;
 
-pub fn private_key_from_mnemonic(
+/// Derive the private key from the given mnemonic, following an arbitrary
+/// BIP-32 derivation path instead of a standard BIP-44 path.
+pub fn private_key_from_mnemonic_with_derivation_path(
     mnemonic_words: &str,
-    hd_path: &StandardHDPath,
+    derivation_path: &DerivationPath,
 ) -> Result<ExtendedPrivKey, Error> {
     let mnemonic = Mnemonic::from_phrase(mnemonic_words, Language::English)
         .map_err(Error::invalid_mnemonic)?;
@@ -37,10 +40,7 @@ pub fn private_key_from_mnemonic(
         })?;
 
     let private_key = base_key
-        .derive_priv(
-            &Secp256k1::new(),
-            &standard_path_to_derivation_path(hd_path),
-        )
+        .derive_priv(&Secp256k1::new(), derivation_path)
         .map_err(|err| {
             Error::bip32_key_generation_failed(Secp256k1KeyPair::KEY_TYPE, err.into())
         })?;
@@ -48,6 +48,13 @@ pub fn private_key_from_mnemonic(
     Ok(private_key)
 }
 
+/// Parse a BIP-32 derivation path, eg. `m/44'/118'/0'/0/0` or `m/44'/60'/0'/0/0/1`.
+///
+/// Unlike [`StandardHDPath`], the path can have an arbitrary number of components.
+pub fn parse_derivation_path(path: &str) -> Result<DerivationPath, Error> {
+    DerivationPath::from_str(path).map_err(|_| Error::invalid_hd_path(path.to_string()))
+}
+
 fn standard_path_to_derivation_path(path: &StandardHDPath) -> DerivationPath {
     let child_numbers = vec![
         ChildNumber::from_hardened_idx(path.purpose().as_value().as_number())
@@ -135,7 +142,10 @@ fn encode_address(account_prefix: &str, address: &[u8]) -> Result<String, Error>
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(try_from = "VersionedKeyPair")]
 pub struct Secp256k1KeyPair {
-    private_key: SecretKey,
+    /// The private key, absent for watch-only keys which can only be used
+    /// to query the chain and cannot sign transactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private_key: Option<SecretKey>,
     pub public_key: PublicKey,
     address: [u8; 20],
     address_type: Secp256k1AddressType,
@@ -160,6 +170,15 @@ struct KeyPairV2 {
     account: String,
 }
 
+// A watch-only key, which only holds the public key
+#[derive(Debug, Deserialize)]
+struct KeyPairWatchOnly {
+    public_key: PublicKey,
+    address: [u8; 20],
+    address_type: Secp256k1AddressType,
+    account: String,
+}
+
 // Note: Since this uses Serde's untagged enums, the serialized formats between
 // versions must be incompatible with each other.
 #[derive(Debug, Deserialize)]
@@ -167,6 +186,7 @@ struct KeyPairV2 {
 enum VersionedKeyPair {
     V1(KeyPairV1),
     V2(KeyPairV2),
+    WatchOnly(KeyPairWatchOnly),
 }
 
 impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
@@ -185,7 +205,7 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
                     .map_err(|address_bytes| Error::invalid_address_length(address_bytes, 20))?;
                 let address_type = Secp256k1AddressType::derive(&public_key.public_key, &address)?;
                 Ok(Self {
-                    private_key: private_key.private_key,
+                    private_key: Some(private_key.private_key),
                     public_key: public_key.public_key,
                     address,
                     address_type,
@@ -199,7 +219,19 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
                 address_type,
                 account,
             }) => Ok(Self {
-                private_key,
+                private_key: Some(private_key),
+                public_key,
+                address,
+                address_type,
+                account,
+            }),
+            VersionedKeyPair::WatchOnly(KeyPairWatchOnly {
+                public_key,
+                address,
+                address_type,
+                account,
+            }) => Ok(Self {
+                private_key: None,
                 public_key,
                 address,
                 address_type,
@@ -212,29 +244,29 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
 impl Secp256k1KeyPair {
     fn from_mnemonic_internal(
         mnemonic: &str,
-        hd_path: &StandardHDPath,
+        derivation_path: &DerivationPath,
         address_type: Secp256k1AddressType,
         account_prefix: &str,
     ) -> Result<Self, Error> {
-        let private_key = private_key_from_mnemonic(mnemonic, hd_path)?;
+        let private_key =
+            private_key_from_mnemonic_with_derivation_path(mnemonic, derivation_path)?;
         let public_key = ExtendedPubKey::from_priv(&Secp256k1::signing_only(), &private_key);
         let address = get_address(&public_key.public_key, address_type);
         let account = encode_address(account_prefix, &address)?;
 
         Ok(Self {
-            private_key: private_key.private_key,
+            private_key: Some(private_key.private_key),
             public_key: public_key.public_key,
             address,
             address_type,
             account,
         })
     }
-}
 
-impl SigningKeyPair for Secp256k1KeyPair {
-    const KEY_TYPE: KeyType = KeyType::Secp256k1;
-
-    fn from_key_file(key_file: KeyFile, hd_path: &StandardHDPath) -> Result<Self, Error> {
+    fn from_key_file_internal(
+        key_file: KeyFile,
+        derivation_path: &DerivationPath,
+    ) -> Result<Self, Error> {
         // Decode the Bech32-encoded address from the key file
         let keyfile_address_bytes = decode_bech32(&key_file.address)?;
 
@@ -242,7 +274,8 @@ impl SigningKeyPair for Secp256k1KeyPair {
         let mut keyfile_pubkey_bytes = encoded_key.into_bytes();
 
         // Decode the private key from the mnemonic
-        let private_key = private_key_from_mnemonic(&key_file.mnemonic, hd_path)?;
+        let private_key =
+            private_key_from_mnemonic_with_derivation_path(&key_file.mnemonic, derivation_path)?;
         let derived_pubkey = ExtendedPubKey::from_priv(&Secp256k1::signing_only(), &private_key);
         let derived_pubkey_bytes = derived_pubkey.public_key.serialize().to_vec();
         assert!(derived_pubkey_bytes.len() <= keyfile_pubkey_bytes.len());
@@ -268,7 +301,7 @@ impl SigningKeyPair for Secp256k1KeyPair {
         let address_type = Secp256k1AddressType::derive(&derived_pubkey.public_key, &address)?;
 
         Ok(Self {
-            private_key: private_key.private_key,
+            private_key: Some(private_key.private_key),
             public_key: derived_pubkey.public_key,
             address,
             address_type,
@@ -276,13 +309,94 @@ impl SigningKeyPair for Secp256k1KeyPair {
         })
     }
 
+    /// Restore a key pair from a mnemonic, using an arbitrary BIP-32 derivation path.
+    pub fn from_mnemonic_with_derivation_path(
+        mnemonic: &str,
+        derivation_path: &DerivationPath,
+        address_type: &AddressType,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        Self::from_mnemonic_internal(
+            mnemonic,
+            derivation_path,
+            address_type.try_into()?,
+            account_prefix,
+        )
+    }
+
+    /// Import a key pair from the JSON contents of a key file produced by a
+    /// Cosmos SDK chain binary, using an arbitrary BIP-32 derivation path.
+    pub fn from_seed_file_with_derivation_path(
+        contents: &str,
+        derivation_path: &DerivationPath,
+    ) -> Result<Self, Error> {
+        let key_file = serde_json::from_str(contents).map_err(Error::encode)?;
+        Self::from_key_file_internal(key_file, derivation_path)
+    }
+
+    /// Import a watch-only key from an encoded public key, either in the
+    /// JSON format output by `<chain-binary> keys show --pubkey` or Bech32-encoded.
+    ///
+    /// A watch-only key can be used to query the chain but cannot sign transactions.
+    pub fn from_public_key(
+        encoded_public_key: &str,
+        address_type: &AddressType,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        let encoded_key: EncodedPubKey = encoded_public_key.trim().parse()?;
+        let public_key_bytes = encoded_key.into_bytes();
+
+        // Bech32-encoded public keys may carry an amino prefix before the compressed key.
+        let key_bytes = public_key_bytes
+            .len()
+            .checked_sub(COMPRESSED_PUBLIC_KEY_SIZE)
+            .map(|start| &public_key_bytes[start..])
+            .unwrap_or(&public_key_bytes);
+
+        let public_key = PublicKey::from_slice(key_bytes)
+            .map_err(|e| Error::invalid_secp256k1_public_key(key_bytes.to_vec(), e))?;
+
+        let address_type = address_type.try_into()?;
+        let address = get_address(&public_key, address_type);
+        let account = encode_address(account_prefix, &address)?;
+
+        Ok(Self {
+            private_key: None,
+            public_key,
+            address,
+            address_type,
+            account,
+        })
+    }
+
+    /// Whether this key pair only holds a public key and thus cannot sign.
+    pub fn is_watch_only(&self) -> bool {
+        self.private_key.is_none()
+    }
+}
+
+/// Size in bytes of a compressed secp256k1 public key.
+const COMPRESSED_PUBLIC_KEY_SIZE: usize = 33;
+
+impl SigningKeyPair for Secp256k1KeyPair {
+    const KEY_TYPE: KeyType = KeyType::Secp256k1;
+
+    fn from_key_file(key_file: KeyFile, hd_path: &StandardHDPath) -> Result<Self, Error> {
+        Self::from_key_file_internal(key_file, &standard_path_to_derivation_path(hd_path))
+    }
+
     fn from_mnemonic(
         mnemonic: &str,
         hd_path: &StandardHDPath,
         address_type: &AddressType,
         account_prefix: &str,
     ) -> Result<Self, Error> {
-        Self::from_mnemonic_internal(mnemonic, hd_path, address_type.try_into()?, account_prefix)
+        Self::from_mnemonic_internal(
+            mnemonic,
+            &standard_path_to_derivation_path(hd_path),
+            address_type.try_into()?,
+            account_prefix,
+        )
     }
 
     fn account(&self) -> String {
@@ -302,12 +416,17 @@ impl SigningKeyPair for Secp256k1KeyPair {
             Secp256k1AddressType::Cosmos => Sha256::digest(message),
         };
 
+        let private_key = self
+            .private_key
+            .as_ref()
+            .ok_or_else(|| Error::watch_only_key(self.account.clone()))?;
+
         // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`,
         // so `unwrap` is safe.
         let message = Message::from_slice(&hashed_message).unwrap();
 
         Ok(Secp256k1::signing_only()
-            .sign_ecdsa(&message, &self.private_key)
+            .sign_ecdsa(&message, private_key)
             .serialize_compact()
             .to_vec())
     }
@@ -316,3 +435,77 @@ impl SigningKeyPair for Secp256k1KeyPair {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hdpath::StandardHDPath;
+    use subtle_encoding::base64;
+
+    use super::{parse_derivation_path, Secp256k1KeyPair};
+    use crate::config::AddressType;
+    use crate::keyring::SigningKeyPair;
+
+    const MNEMONIC: &str = "such walnut usual noble image raise cabin suspect combine key absurd detail present bless yard grief amazing slam brown donate fabric opera desk minor";
+
+    #[test]
+    fn custom_derivation_path_matches_standard_path() {
+        let standard: StandardHDPath = "m/44'/118'/0'/0/0".parse().unwrap();
+        let custom = parse_derivation_path("m/44'/118'/0'/0/0").unwrap();
+
+        let from_standard =
+            Secp256k1KeyPair::from_mnemonic(MNEMONIC, &standard, &AddressType::Cosmos, "cosmos")
+                .unwrap();
+        let from_custom = Secp256k1KeyPair::from_mnemonic_with_derivation_path(
+            MNEMONIC,
+            &custom,
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap();
+
+        assert_eq!(from_standard.account(), from_custom.account());
+
+        let longer = parse_derivation_path("m/44'/118'/0'/0/0/1").unwrap();
+        let from_longer = Secp256k1KeyPair::from_mnemonic_with_derivation_path(
+            MNEMONIC,
+            &longer,
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap();
+
+        assert_ne!(from_standard.account(), from_longer.account());
+        assert!(parse_derivation_path("not/a/path").is_err());
+    }
+
+    #[test]
+    fn watch_only_key_round_trip() {
+        let hd_path: StandardHDPath = "m/44'/118'/0'/0/0".parse().unwrap();
+        let key_pair =
+            Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos")
+                .unwrap();
+
+        let encoded_public_key = format!(
+            r#"{{"@type":"/cosmos.crypto.secp256k1.PubKey","key":"{}"}}"#,
+            String::from_utf8(base64::encode(key_pair.public_key.serialize())).unwrap()
+        );
+
+        let watch_only =
+            Secp256k1KeyPair::from_public_key(&encoded_public_key, &AddressType::Cosmos, "cosmos")
+                .unwrap();
+
+        assert!(watch_only.is_watch_only());
+        assert!(!key_pair.is_watch_only());
+        assert_eq!(watch_only.account(), key_pair.account());
+        assert!(watch_only.sign(b"message").is_err());
+
+        let serialized = serde_json::to_string(&watch_only).unwrap();
+        let deserialized: Secp256k1KeyPair = serde_json::from_str(&serialized).unwrap();
+        assert!(deserialized.is_watch_only());
+        assert_eq!(deserialized.account(), key_pair.account());
+
+        let serialized = serde_json::to_string(&key_pair).unwrap();
+        let deserialized: Secp256k1KeyPair = serde_json::from_str(&serialized).unwrap();
+        assert!(!deserialized.is_watch_only());
+    }
+}
@@ -28,11 +28,14 @@ pub mod client_state;
 pub mod config;
 pub mod connection;
 pub mod consensus_state;
+pub mod coordination;
 pub mod denom;
 pub mod error;
 pub mod event;
+pub mod event_sink;
 pub mod extension_options;
 pub mod foreign_client;
+pub mod hooks;
 pub mod keyring;
 pub mod light_client;
 pub mod link;
@@ -43,9 +46,11 @@ pub mod registry;
 pub mod rest;
 pub mod sdk_error;
 pub mod spawn;
+pub mod submitter;
 pub mod supervisor;
 pub mod telemetry;
 pub mod transfer;
+pub mod unwind;
 pub mod upgrade_chain;
 pub mod util;
 pub mod worker;
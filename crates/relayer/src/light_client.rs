@@ -1,13 +1,12 @@
 pub mod io;
+pub mod registry;
 pub mod tendermint;
 
-use core::ops::Deref;
-
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawTmHeader;
 use ibc_proto::protobuf::Protobuf as ErasedProtobuf;
 use ibc_relayer_types::clients::ics07_tendermint::header::{
-    decode_header as tm_decode_header, Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
+    Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
@@ -15,6 +14,7 @@ use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::Header;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use crate::chain::endpoint::ChainEndpoint;
@@ -22,6 +22,8 @@ use crate::client_state::AnyClientState;
 use crate::error;
 use crate::misbehaviour::MisbehaviourEvidence;
 
+use self::registry::client_registry;
+
 /// Defines a light block from the point of view of the relayer.
 pub trait LightBlock<C: ChainEndpoint>: Send + Sync {
     fn signed_header(&self) -> &C::Header;
@@ -68,39 +70,53 @@ pub trait LightClient<C: ChainEndpoint>: Send + Sync {
     fn fetch(&mut self, height: Height) -> Result<C::LightBlock, error::Error>;
 }
 
-/// Decodes an encoded header into a known `Header` type,
+/// Decodes an encoded header into the `Header` type of its client type,
+/// as registered in the [`registry`].
 pub fn decode_header(header_bytes: &[u8]) -> Result<Box<dyn Header>, Error> {
-    // For now, we only have tendermint; however when there is more than one, we
-    // can try decoding into all the known types, and return an error only if
-    // none work
-    let header: TendermintHeader =
-        ErasedProtobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)?;
+    let header = AnyHeader::decode_vec(header_bytes).map_err(Error::invalid_raw_header)?;
 
-    Ok(Box::new(header))
+    Ok(header.into_inner())
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+
+    /// A header of a client type registered with [`registry::register_client_handler`].
+    #[serde(skip_deserializing)]
+    Other(Box<dyn Header>),
+}
+
+impl AnyHeader {
+    /// The header of the actual client type, which can be downcast to that type.
+    pub fn into_inner(self) -> Box<dyn Header> {
+        match self {
+            Self::Tendermint(header) => Box::new(header),
+            Self::Other(header) => header,
+        }
+    }
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::Other(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::Other(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+            Self::Other(header) => header.timestamp(),
         }
     }
 }
@@ -111,15 +127,7 @@ impl TryFrom<Any> for AnyHeader {
     type Error = Error;
 
     fn try_from(raw: Any) -> Result<Self, Error> {
-        match raw.type_url.as_str() {
-            TENDERMINT_HEADER_TYPE_URL => {
-                let val = tm_decode_header(raw.value.deref())?;
-
-                Ok(AnyHeader::Tendermint(val))
-            }
-
-            _ => Err(Error::unknown_header_type(raw.type_url)),
-        }
+        client_registry().decode_header(&raw)
     }
 }
 
@@ -130,6 +138,10 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: ErasedProtobuf::<RawTmHeader>::encode_vec(&header),
             },
+            AnyHeader::Other(header) => {
+                Any::decode(ErasedProtobuf::<Any>::encode_vec(header.as_ref()).as_slice())
+                    .expect("a header is encoded as an `Any`")
+            }
         }
     }
 }
@@ -1,17 +1,24 @@
-use tendermint::{account, block::Height};
+use core::future::Future;
+use core::time::Duration;
+
+use tendermint::{account, block::signed_header::SignedHeader, block::Height};
 use tendermint_light_client::{
-    components::io::{AtHeight, Io, IoError, ProdIo},
-    types::LightBlock,
+    components::io::{AtHeight, Io, IoError},
+    types::{LightBlock, PeerId},
 };
+use tendermint_rpc::{Client, Paging};
+
+use crate::chain::cosmos::types::rpc::RpcClient;
+use crate::util::spawn_blocking;
 
 #[derive(Clone, Debug)]
 pub enum AnyIo {
-    Prod(ProdIo),
-    RestartAware(RestartAwareIo),
+    Prod(RpcIo),
+    RestartAware(Box<RestartAwareIo>),
 }
 
 impl AnyIo {
-    pub fn rpc_client(&self) -> &tendermint_rpc::HttpClient {
+    pub fn rpc_client(&self) -> &RpcClient {
         match self {
             AnyIo::Prod(io) => io.rpc_client(),
             AnyIo::RestartAware(io) => io.rpc_client(),
@@ -39,15 +46,120 @@ impl Io for AnyIo {
     }
 }
 
+/// Fetches the light blocks from a full node like the `ProdIo` of the light client,
+/// but through the [`RpcClient`] of the chain, so that its requests are held back
+/// by the same rate limiter as all the other requests to the RPC endpoint.
+#[derive(Clone, Debug)]
+pub struct RpcIo {
+    peer_id: PeerId,
+    rpc_client: RpcClient,
+    timeout: Option<Duration>,
+}
+
+impl RpcIo {
+    pub fn new(peer_id: PeerId, rpc_client: RpcClient, timeout: Option<Duration>) -> Self {
+        Self {
+            peer_id,
+            rpc_client,
+            timeout,
+        }
+    }
+
+    pub fn rpc_client(&self) -> &RpcClient {
+        &self.rpc_client
+    }
+
+    pub fn fetch_signed_header(&self, height: AtHeight) -> Result<SignedHeader, IoError> {
+        let client = self.rpc_client.clone();
+
+        let response = self.block_on(async move {
+            match height {
+                AtHeight::Highest => client.latest_commit().await,
+                AtHeight::At(height) => client.commit(height).await,
+            }
+        })?;
+
+        response
+            .map(|response| response.signed_header)
+            .map_err(IoError::from_rpc)
+    }
+
+    pub fn fetch_validator_set(
+        &self,
+        height: AtHeight,
+        proposer_address: Option<account::Id>,
+    ) -> Result<tendermint::validator::Set, IoError> {
+        let height = match height {
+            AtHeight::Highest => return Err(IoError::invalid_height()),
+            AtHeight::At(height) => height,
+        };
+
+        let client = self.rpc_client.clone();
+
+        let response = self
+            .block_on(async move { client.validators(height, Paging::All).await })?
+            .map_err(IoError::rpc)?;
+
+        match proposer_address {
+            Some(proposer_address) => {
+                tendermint::validator::Set::with_proposer(response.validators, proposer_address)
+                    .map_err(IoError::invalid_validator_set)
+            }
+            None => Ok(tendermint::validator::Set::without_proposer(
+                response.validators,
+            )),
+        }
+    }
+
+    /// Runs the given request on a runtime of its own, as the light client
+    /// may itself be called from within the runtime of the chain.
+    fn block_on<F>(&self, future: F) -> Result<F::Output, IoError>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send,
+    {
+        let timeout = self.timeout;
+
+        spawn_blocking(async move {
+            match timeout {
+                Some(timeout) => tokio::time::timeout(timeout, future)
+                    .await
+                    .map_err(|e| IoError::timeout(timeout, e)),
+                None => Ok(future.await),
+            }
+        })
+        .join()
+        .expect("the light client request panicked")
+    }
+}
+
+impl Io for RpcIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let signed_header = self.fetch_signed_header(height)?;
+        let height = signed_header.header.height;
+        let proposer_address = signed_header.header.proposer_address;
+
+        let validator_set = self.fetch_validator_set(height.into(), Some(proposer_address))?;
+        let next_validator_set = self.fetch_validator_set(height.increment().into(), None)?;
+
+        Ok(LightBlock::new(
+            signed_header,
+            validator_set,
+            next_validator_set,
+            self.peer_id,
+        ))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RestartAwareIo {
     restart_height: Height,
-    live_io: ProdIo,
-    archive_io: ProdIo,
+    live_io: RpcIo,
+    archive_io: RpcIo,
 }
 
 impl RestartAwareIo {
-    pub fn new(restart_height: Height, live_io: ProdIo, archive_io: ProdIo) -> Self {
+    pub fn new(restart_height: Height, live_io: RpcIo, archive_io: RpcIo) -> Self {
         Self {
             restart_height,
             live_io,
@@ -55,7 +167,7 @@ impl RestartAwareIo {
         }
     }
 
-    pub fn rpc_client(&self) -> &tendermint_rpc::HttpClient {
+    pub fn rpc_client(&self) -> &RpcClient {
         self.live_io.rpc_client()
     }
 
@@ -0,0 +1,796 @@
+//! Registry of the light client types known to the relayer.
+//!
+//! Each client type, identified by its client type string (eg. `07-tendermint`), is
+//! handled by a [`ClientHandler`], which knows the Protobuf type URLs of the client
+//! states, consensus states, headers and misbehaviours of the client type, and how to
+//! decode them. The conversions of the `Any*` types from their Protobuf encoding go
+//! through the registry, rather than through a match on the type URLs in each of them.
+//!
+//! The handler of a client type also verifies the headers of the chain tracked by the
+//! clients of this type, builds the headers updating these clients, and detects their
+//! misbehaviours, from the headers fetched by the chain endpoint (see [`HeaderSource`]).
+//! The chain runtime goes through the handler of the type of the client state at hand.
+//!
+//! A client type registered with [`register_client_handler`] decodes its values into the
+//! `Other` variants of the `Any*` enums, which box the types implementing the traits of
+//! ICS 02, and identifies itself with [`ClientType::Other`], so that adding a client type
+//! does not require a new variant in each of these enums.
+
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+
+use flex_error::define_error;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::tendermint::v1::{
+    ClientState as RawTmClientState, ConsensusState as RawTmConsensusState,
+    Misbehaviour as RawTmMisbehaviour,
+};
+use ibc_proto::protobuf::Protobuf;
+use ibc_relayer_types::clients::ics07_tendermint::client_state::{
+    ClientState as TmClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::TENDERMINT_CONSENSUS_STATE_TYPE_URL;
+use ibc_relayer_types::clients::ics07_tendermint::header::{
+    decode_header as tm_decode_header, TENDERMINT_HEADER_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::TENDERMINT_MISBEHAVIOR_TYPE_URL;
+use ibc_relayer_types::core::ics02_client::client_state::{downcast_client_state, ClientState};
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::error::Error;
+use ibc_relayer_types::core::ics02_client::events::UpdateClient;
+use ibc_relayer_types::Height;
+use once_cell::sync::Lazy;
+
+use crate::chain::endpoint::ChainEndpoint;
+use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
+use crate::error::Error as RelayerError;
+use crate::light_client::AnyHeader;
+use crate::misbehaviour::{AnyMisbehaviour, MisbehaviourEvidence};
+
+static CLIENT_REGISTRY: Lazy<RwLock<ClientRegistry>> =
+    Lazy::new(|| RwLock::new(ClientRegistry::default()));
+
+define_error! {
+    ClientRegistryError {
+        DuplicateClientType
+            { client_type: String }
+            |e| { format_args!("a handler is already registered for client type {}", e.client_type) },
+
+        DuplicateTypeUrl
+            {
+                type_url: String,
+                client_type: String,
+            }
+            |e| {
+                format_args!("type URL {} is already handled by client type {}",
+                    e.type_url, e.client_type)
+            },
+    }
+}
+
+/// The headers of the chain tracked by a client, as fetched and verified
+/// by the light client of its chain endpoint.
+pub trait HeaderSource {
+    /// Fetches and verifies the header at the `target` height from the `trusted` height,
+    /// and builds the consensus state of the chain at the `target` height.
+    fn build_consensus_state(
+        &mut self,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<AnyConsensusState, RelayerError>;
+
+    /// Fetches and verifies the header at the `target` height from the `trusted` height,
+    /// and returns it along with its minimal supporting set.
+    fn build_header(
+        &mut self,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError>;
+
+    /// Runs the light client attack detector on the header of the given client update.
+    fn check_misbehaviour(
+        &mut self,
+        update: &UpdateClient,
+        client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, RelayerError>;
+}
+
+impl<Chain: ChainEndpoint> HeaderSource for Chain {
+    fn build_consensus_state(
+        &mut self,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<AnyConsensusState, RelayerError> {
+        let verified = self.verify_header(trusted, target, client_state)?;
+
+        ChainEndpoint::build_consensus_state(self, verified).map(Into::into)
+    }
+
+    fn build_header(
+        &mut self,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError> {
+        let (header, support) = ChainEndpoint::build_header(self, trusted, target, client_state)?;
+
+        Ok((header.into(), support.into_iter().map(Into::into).collect()))
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        update: &UpdateClient,
+        client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, RelayerError> {
+        ChainEndpoint::check_misbehaviour(self, update, client_state)
+    }
+}
+
+/// Handles the values of one client type, and the updates of the clients of this type.
+pub trait ClientHandler: Send + Sync + 'static {
+    /// The client type string, eg. `07-tendermint`
+    fn client_type(&self) -> &'static str;
+
+    fn client_state_type_url(&self) -> &'static str;
+
+    fn consensus_state_type_url(&self) -> &'static str;
+
+    fn header_type_url(&self) -> &'static str;
+
+    fn misbehaviour_type_url(&self) -> &'static str;
+
+    /// Decodes the Protobuf encoding of a client state of this client type.
+    fn decode_client_state(&self, value: &[u8]) -> Result<AnyClientState, Error>;
+
+    /// Decodes the Protobuf encoding of a consensus state of this client type.
+    fn decode_consensus_state(&self, value: &[u8]) -> Result<AnyConsensusState, Error>;
+
+    /// Decodes the Protobuf encoding of a header of this client type.
+    fn decode_header(&self, value: &[u8]) -> Result<AnyHeader, Error>;
+
+    /// Decodes the Protobuf encoding of a misbehaviour of this client type.
+    fn decode_misbehaviour(&self, value: &[u8]) -> Result<AnyMisbehaviour, Error>;
+
+    /// Verifies the header of the tracked chain at the `target` height from the `trusted`
+    /// height, and builds the consensus state of the given client at the `target` height.
+    fn build_consensus_state(
+        &self,
+        source: &mut dyn HeaderSource,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<AnyConsensusState, RelayerError>;
+
+    /// Builds the header updating the given client from the `trusted` height
+    /// to the `target` height, along with its supporting headers.
+    fn build_header(
+        &self,
+        source: &mut dyn HeaderSource,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError>;
+
+    /// Checks whether the header of the given client update conflicts with the tracked
+    /// chain, in which case the evidence of the misbehaviour is returned.
+    fn check_misbehaviour(
+        &self,
+        source: &mut dyn HeaderSource,
+        update: &UpdateClient,
+        client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, RelayerError>;
+
+    /// How often the clients with the given state should be refreshed, if at all.
+    fn refresh_period(&self, _client_state: &dyn ClientState) -> Option<Duration> {
+        None
+    }
+
+    fn type_urls(&self) -> [&'static str; 4] {
+        [
+            self.client_state_type_url(),
+            self.consensus_state_type_url(),
+            self.header_type_url(),
+            self.misbehaviour_type_url(),
+        ]
+    }
+}
+
+/// The handlers of the client types known to the relayer, indexed by client type.
+#[derive(Clone)]
+pub struct ClientRegistry {
+    handlers: BTreeMap<&'static str, Arc<dyn ClientHandler>>,
+}
+
+impl ClientRegistry {
+    /// A registry without any client type.
+    pub fn empty() -> Self {
+        Self {
+            handlers: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the handler of a new client type.
+    ///
+    /// Fails if the client type, or any of the type URLs of the handler,
+    /// is already handled by a registered handler.
+    pub fn register(&mut self, handler: Arc<dyn ClientHandler>) -> Result<(), ClientRegistryError> {
+        let client_type = handler.client_type();
+
+        if self.handlers.contains_key(client_type) {
+            return Err(ClientRegistryError::duplicate_client_type(
+                client_type.to_string(),
+            ));
+        }
+
+        for type_url in handler.type_urls() {
+            if let Some(existing) = self
+                .handlers
+                .values()
+                .find(|existing| existing.type_urls().contains(&type_url))
+            {
+                return Err(ClientRegistryError::duplicate_type_url(
+                    type_url.to_string(),
+                    existing.client_type().to_string(),
+                ));
+            }
+        }
+
+        self.handlers.insert(client_type, handler);
+
+        Ok(())
+    }
+
+    /// The handler of the given client type, if registered.
+    pub fn handler(&self, client_type: &str) -> Option<Arc<dyn ClientHandler>> {
+        self.handlers.get(client_type).cloned()
+    }
+
+    /// The handler of the type of the given client state.
+    pub fn handler_for(
+        &self,
+        client_state: &AnyClientState,
+    ) -> Result<Arc<dyn ClientHandler>, Error> {
+        let client_type = client_state.client_type();
+
+        self.handler(client_type.as_str())
+            .ok_or_else(|| Error::unknown_client_type(client_type.as_str().to_string()))
+    }
+
+    /// Parses the given client type string, if registered.
+    pub fn client_type(&self, client_type: &str) -> Option<ClientType> {
+        self.handlers
+            .get_key_value(client_type)
+            .map(|(client_type, _)| {
+                client_type
+                    .parse()
+                    .unwrap_or(ClientType::Other(client_type))
+            })
+    }
+
+    /// The registered client types, in lexicographic order.
+    pub fn client_types(&self) -> Vec<&'static str> {
+        self.handlers.keys().copied().collect()
+    }
+
+    pub fn decode_client_state(&self, raw: &Any) -> Result<AnyClientState, Error> {
+        if raw.type_url.is_empty() {
+            return Err(Error::empty_client_state_response());
+        }
+
+        self.handlers
+            .values()
+            .find(|handler| handler.client_state_type_url() == raw.type_url)
+            .ok_or_else(|| Error::unknown_client_state_type(raw.type_url.clone()))?
+            .decode_client_state(&raw.value)
+    }
+
+    pub fn decode_consensus_state(&self, raw: &Any) -> Result<AnyConsensusState, Error> {
+        if raw.type_url.is_empty() {
+            return Err(Error::empty_consensus_state_response());
+        }
+
+        self.handlers
+            .values()
+            .find(|handler| handler.consensus_state_type_url() == raw.type_url)
+            .ok_or_else(|| Error::unknown_consensus_state_type(raw.type_url.clone()))?
+            .decode_consensus_state(&raw.value)
+    }
+
+    pub fn decode_header(&self, raw: &Any) -> Result<AnyHeader, Error> {
+        self.handlers
+            .values()
+            .find(|handler| handler.header_type_url() == raw.type_url)
+            .ok_or_else(|| Error::unknown_header_type(raw.type_url.clone()))?
+            .decode_header(&raw.value)
+    }
+
+    pub fn decode_misbehaviour(&self, raw: &Any) -> Result<AnyMisbehaviour, Error> {
+        self.handlers
+            .values()
+            .find(|handler| handler.misbehaviour_type_url() == raw.type_url)
+            .ok_or_else(|| Error::unknown_misbehaviour_type(raw.type_url.clone()))?
+            .decode_misbehaviour(&raw.value)
+    }
+}
+
+impl Default for ClientRegistry {
+    /// A registry with the client types built into the relayer.
+    fn default() -> Self {
+        let mut registry = Self::empty();
+
+        registry
+            .register(Arc::new(TendermintClientHandler))
+            .expect("the built-in client types are distinct");
+
+        #[cfg(test)]
+        registry
+            .register(Arc::new(mock::MockClientHandler))
+            .expect("the built-in client types are distinct");
+
+        registry
+    }
+}
+
+/// The global registry of the client types known to the relayer.
+pub fn client_registry() -> RwLockReadGuard<'static, ClientRegistry> {
+    CLIENT_REGISTRY.read().expect("poisoned lock")
+}
+
+/// Registers the handler of a new client type in the global registry.
+///
+/// The handler decodes the values of the client type into the `Other` variants
+/// of the `Any*` enums, see the [module documentation](self).
+pub fn register_client_handler(handler: impl ClientHandler) -> Result<(), ClientRegistryError> {
+    CLIENT_REGISTRY
+        .write()
+        .expect("poisoned lock")
+        .register(Arc::new(handler))
+}
+
+/// Handles the ICS 07 Tendermint client type.
+pub struct TendermintClientHandler;
+
+impl ClientHandler for TendermintClientHandler {
+    fn client_type(&self) -> &'static str {
+        ClientType::Tendermint.as_str()
+    }
+
+    fn client_state_type_url(&self) -> &'static str {
+        TENDERMINT_CLIENT_STATE_TYPE_URL
+    }
+
+    fn consensus_state_type_url(&self) -> &'static str {
+        TENDERMINT_CONSENSUS_STATE_TYPE_URL
+    }
+
+    fn header_type_url(&self) -> &'static str {
+        TENDERMINT_HEADER_TYPE_URL
+    }
+
+    fn misbehaviour_type_url(&self) -> &'static str {
+        TENDERMINT_MISBEHAVIOR_TYPE_URL
+    }
+
+    fn decode_client_state(&self, value: &[u8]) -> Result<AnyClientState, Error> {
+        let client_state = Protobuf::<RawTmClientState>::decode_vec(value)
+            .map_err(Error::decode_raw_client_state)?;
+
+        Ok(AnyClientState::Tendermint(client_state))
+    }
+
+    fn decode_consensus_state(&self, value: &[u8]) -> Result<AnyConsensusState, Error> {
+        let consensus_state = Protobuf::<RawTmConsensusState>::decode_vec(value)
+            .map_err(Error::decode_raw_client_state)?;
+
+        Ok(AnyConsensusState::Tendermint(consensus_state))
+    }
+
+    fn decode_header(&self, value: &[u8]) -> Result<AnyHeader, Error> {
+        Ok(AnyHeader::Tendermint(tm_decode_header(value)?))
+    }
+
+    fn decode_misbehaviour(&self, value: &[u8]) -> Result<AnyMisbehaviour, Error> {
+        let misbehaviour = Protobuf::<RawTmMisbehaviour>::decode_vec(value)
+            .map_err(Error::decode_raw_misbehaviour)?;
+
+        Ok(AnyMisbehaviour::Tendermint(misbehaviour))
+    }
+
+    // The headers of the chains tracked by Tendermint clients are verified and built
+    // by the Tendermint light client of their chain endpoint, as is the detection of
+    // the attacks on that light client.
+
+    fn build_consensus_state(
+        &self,
+        source: &mut dyn HeaderSource,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<AnyConsensusState, RelayerError> {
+        source.build_consensus_state(trusted, target, client_state)
+    }
+
+    fn build_header(
+        &self,
+        source: &mut dyn HeaderSource,
+        trusted: Height,
+        target: Height,
+        client_state: &AnyClientState,
+    ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError> {
+        source.build_header(trusted, target, client_state)
+    }
+
+    fn check_misbehaviour(
+        &self,
+        source: &mut dyn HeaderSource,
+        update: &UpdateClient,
+        client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, RelayerError> {
+        source.check_misbehaviour(update, client_state)
+    }
+
+    fn refresh_period(&self, client_state: &dyn ClientState) -> Option<Duration> {
+        downcast_client_state::<TmClientState>(client_state)
+            .and_then(|client_state| client_state.refresh_time())
+    }
+}
+
+#[cfg(test)]
+mod mock {
+    use ibc_proto::ibc::mock::{
+        ClientState as RawMockClientState, ConsensusState as RawMockConsensusState,
+        Misbehaviour as RawMockMisbehaviour,
+    };
+    use ibc_relayer_types::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
+    use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
+    use ibc_relayer_types::mock::header::MOCK_HEADER_TYPE_URL;
+    use ibc_relayer_types::mock::misbehaviour::MOCK_MISBEHAVIOUR_TYPE_URL;
+
+    use super::*;
+
+    /// Handles the mock client type, whose headers the relayer does not support.
+    pub struct MockClientHandler;
+
+    impl ClientHandler for MockClientHandler {
+        fn client_type(&self) -> &'static str {
+            ClientType::Mock.as_str()
+        }
+
+        fn client_state_type_url(&self) -> &'static str {
+            MOCK_CLIENT_STATE_TYPE_URL
+        }
+
+        fn consensus_state_type_url(&self) -> &'static str {
+            MOCK_CONSENSUS_STATE_TYPE_URL
+        }
+
+        fn header_type_url(&self) -> &'static str {
+            MOCK_HEADER_TYPE_URL
+        }
+
+        fn misbehaviour_type_url(&self) -> &'static str {
+            MOCK_MISBEHAVIOUR_TYPE_URL
+        }
+
+        fn decode_client_state(&self, value: &[u8]) -> Result<AnyClientState, Error> {
+            let client_state = Protobuf::<RawMockClientState>::decode_vec(value)
+                .map_err(Error::decode_raw_client_state)?;
+
+            Ok(AnyClientState::Mock(client_state))
+        }
+
+        fn decode_consensus_state(&self, value: &[u8]) -> Result<AnyConsensusState, Error> {
+            let consensus_state = Protobuf::<RawMockConsensusState>::decode_vec(value)
+                .map_err(Error::decode_raw_client_state)?;
+
+            Ok(AnyConsensusState::Mock(consensus_state))
+        }
+
+        fn decode_header(&self, _value: &[u8]) -> Result<AnyHeader, Error> {
+            Err(Error::unknown_header_type(MOCK_HEADER_TYPE_URL.to_string()))
+        }
+
+        fn decode_misbehaviour(&self, value: &[u8]) -> Result<AnyMisbehaviour, Error> {
+            let misbehaviour = Protobuf::<RawMockMisbehaviour>::decode_vec(value)
+                .map_err(Error::decode_raw_misbehaviour)?;
+
+            Ok(AnyMisbehaviour::Mock(misbehaviour))
+        }
+
+        fn build_consensus_state(
+            &self,
+            source: &mut dyn HeaderSource,
+            trusted: Height,
+            target: Height,
+            client_state: &AnyClientState,
+        ) -> Result<AnyConsensusState, RelayerError> {
+            source.build_consensus_state(trusted, target, client_state)
+        }
+
+        fn build_header(
+            &self,
+            source: &mut dyn HeaderSource,
+            trusted: Height,
+            target: Height,
+            client_state: &AnyClientState,
+        ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError> {
+            source.build_header(trusted, target, client_state)
+        }
+
+        fn check_misbehaviour(
+            &self,
+            source: &mut dyn HeaderSource,
+            update: &UpdateClient,
+            client_state: &AnyClientState,
+        ) -> Result<Option<MisbehaviourEvidence>, RelayerError> {
+            source.check_misbehaviour(update, client_state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::mock::Header as RawMockHeader;
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use ibc_relayer_types::core::ics02_client::header::{downcast_header, Header};
+    use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+    use ibc_relayer_types::mock::header::MockHeader;
+    use ibc_relayer_types::timestamp::Timestamp;
+    use serde::Serialize;
+
+    use super::*;
+
+    const WRAPPED_CLIENT_TYPE: &str = "08-wrapped";
+
+    const WRAPPED_HEADER_TYPE_URL: &str = "/ibc.lightclients.wrapped.v1.Header";
+
+    /// A header of the wrapped client type, unknown to the relayer,
+    /// wrapping a header of the mock chain tracked by the client.
+    #[derive(Clone, Debug, PartialEq, Serialize)]
+    struct WrappedHeader(MockHeader);
+
+    impl Header for WrappedHeader {
+        fn client_type(&self) -> ClientType {
+            ClientType::Other(WRAPPED_CLIENT_TYPE)
+        }
+
+        fn height(&self) -> Height {
+            self.0.height()
+        }
+
+        fn timestamp(&self) -> Timestamp {
+            self.0.timestamp()
+        }
+    }
+
+    impl Protobuf<Any> for WrappedHeader {}
+
+    impl TryFrom<Any> for WrappedHeader {
+        type Error = Error;
+
+        fn try_from(raw: Any) -> Result<Self, Error> {
+            Protobuf::<RawMockHeader>::decode_vec(&raw.value)
+                .map(Self)
+                .map_err(Error::invalid_raw_header)
+        }
+    }
+
+    impl From<WrappedHeader> for Any {
+        fn from(header: WrappedHeader) -> Self {
+            Any {
+                type_url: WRAPPED_HEADER_TYPE_URL.to_string(),
+                value: Protobuf::<RawMockHeader>::encode_vec(&header.0),
+            }
+        }
+    }
+
+    /// Handles a wrapped client type, as a plugin would: its client states are the ones of
+    /// Tendermint clients under another type URL, while its headers are of a type of its own.
+    struct WrappedClientHandler;
+
+    impl WrappedClientHandler {
+        fn wrap(header: AnyHeader) -> AnyHeader {
+            let header = header.into_inner();
+            let header = downcast_header::<MockHeader>(header.as_ref())
+                .expect("the mock chain builds mock headers");
+
+            AnyHeader::Other(Box::new(WrappedHeader(*header)))
+        }
+    }
+
+    impl ClientHandler for WrappedClientHandler {
+        fn client_type(&self) -> &'static str {
+            WRAPPED_CLIENT_TYPE
+        }
+
+        fn client_state_type_url(&self) -> &'static str {
+            "/ibc.lightclients.wrapped.v1.ClientState"
+        }
+
+        fn consensus_state_type_url(&self) -> &'static str {
+            "/ibc.lightclients.wrapped.v1.ConsensusState"
+        }
+
+        fn header_type_url(&self) -> &'static str {
+            WRAPPED_HEADER_TYPE_URL
+        }
+
+        fn misbehaviour_type_url(&self) -> &'static str {
+            "/ibc.lightclients.wrapped.v1.Misbehaviour"
+        }
+
+        fn decode_client_state(&self, value: &[u8]) -> Result<AnyClientState, Error> {
+            TendermintClientHandler.decode_client_state(value)
+        }
+
+        fn decode_consensus_state(&self, value: &[u8]) -> Result<AnyConsensusState, Error> {
+            TendermintClientHandler.decode_consensus_state(value)
+        }
+
+        fn decode_header(&self, value: &[u8]) -> Result<AnyHeader, Error> {
+            let header =
+                Protobuf::<RawMockHeader>::decode_vec(value).map_err(Error::invalid_raw_header)?;
+
+            Ok(AnyHeader::Other(Box::new(WrappedHeader(header))))
+        }
+
+        fn decode_misbehaviour(&self, value: &[u8]) -> Result<AnyMisbehaviour, Error> {
+            TendermintClientHandler.decode_misbehaviour(value)
+        }
+
+        fn build_consensus_state(
+            &self,
+            source: &mut dyn HeaderSource,
+            trusted: Height,
+            target: Height,
+            client_state: &AnyClientState,
+        ) -> Result<AnyConsensusState, RelayerError> {
+            source.build_consensus_state(trusted, target, client_state)
+        }
+
+        fn build_header(
+            &self,
+            source: &mut dyn HeaderSource,
+            trusted: Height,
+            target: Height,
+            client_state: &AnyClientState,
+        ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError> {
+            let (target, supporting) = source.build_header(trusted, target, client_state)?;
+
+            Ok((
+                Self::wrap(target),
+                supporting.into_iter().map(Self::wrap).collect(),
+            ))
+        }
+
+        fn check_misbehaviour(
+            &self,
+            _source: &mut dyn HeaderSource,
+            _update: &UpdateClient,
+            _client_state: &AnyClientState,
+        ) -> Result<Option<MisbehaviourEvidence>, RelayerError> {
+            Ok(None)
+        }
+    }
+
+    /// A chain whose headers are mock headers, trusted without verification.
+    struct MockChain;
+
+    impl HeaderSource for MockChain {
+        fn build_consensus_state(
+            &mut self,
+            _trusted: Height,
+            target: Height,
+            _client_state: &AnyClientState,
+        ) -> Result<AnyConsensusState, RelayerError> {
+            Ok(MockConsensusState::new(MockHeader::new(target)).into())
+        }
+
+        fn build_header(
+            &mut self,
+            trusted: Height,
+            target: Height,
+            _client_state: &AnyClientState,
+        ) -> Result<(AnyHeader, Vec<AnyHeader>), RelayerError> {
+            let header = |height| AnyHeader::Other(Box::new(MockHeader::new(height)));
+
+            Ok((header(target), vec![header(trusted.increment())]))
+        }
+
+        fn check_misbehaviour(
+            &mut self,
+            _update: &UpdateClient,
+            _client_state: &AnyClientState,
+        ) -> Result<Option<MisbehaviourEvidence>, RelayerError> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn decode_registered_client_types() {
+        let mut registry = ClientRegistry::default();
+
+        let client_state: AnyClientState =
+            get_dummy_tendermint_client_state(get_dummy_tendermint_header()).into();
+
+        let mut raw = Any::from(client_state.clone());
+        assert_eq!(registry.decode_client_state(&raw).unwrap(), client_state);
+
+        raw.type_url = WrappedClientHandler.client_state_type_url().to_string();
+        assert!(registry.decode_client_state(&raw).is_err());
+
+        registry.register(Arc::new(WrappedClientHandler)).unwrap();
+        assert_eq!(registry.decode_client_state(&raw).unwrap(), client_state);
+        assert!(registry.handler("08-wrapped").is_some());
+        assert!(registry.client_types().contains(&"07-tendermint"));
+
+        raw.type_url = String::new();
+        assert!(registry.decode_client_state(&raw).is_err());
+
+        assert_eq!(
+            registry.client_type("08-wrapped"),
+            Some(ClientType::Other(WRAPPED_CLIENT_TYPE))
+        );
+        assert_eq!(
+            registry.client_type("07-tendermint"),
+            Some(ClientType::Tendermint)
+        );
+        assert_eq!(registry.client_type("08-unknown"), None);
+    }
+
+    #[test]
+    fn registered_client_types_build_their_headers() {
+        let mut registry = ClientRegistry::default();
+        registry.register(Arc::new(WrappedClientHandler)).unwrap();
+
+        let client_state: AnyClientState =
+            get_dummy_tendermint_client_state(get_dummy_tendermint_header()).into();
+
+        assert_eq!(
+            registry.handler_for(&client_state).unwrap().client_type(),
+            "07-tendermint"
+        );
+
+        let trusted = Height::new(0, 5).unwrap();
+        let target = Height::new(0, 10).unwrap();
+
+        let (header, supporting) = registry
+            .handler(WRAPPED_CLIENT_TYPE)
+            .unwrap()
+            .build_header(&mut MockChain, trusted, target, &client_state)
+            .unwrap();
+
+        assert_eq!(header.client_type(), ClientType::Other(WRAPPED_CLIENT_TYPE));
+        assert_eq!(header.height(), target);
+        assert_eq!(supporting.len(), 1);
+
+        // The headers of the registered client type are encoded with
+        // their own type URL, and decoded by the handler of their type
+        let raw = Any::from(header.clone());
+        assert_eq!(raw.type_url, WRAPPED_HEADER_TYPE_URL);
+        assert_eq!(registry.decode_header(&raw).unwrap(), header);
+    }
+
+    #[test]
+    fn reject_duplicate_client_types() {
+        let mut registry = ClientRegistry::default();
+
+        assert!(registry
+            .register(Arc::new(TendermintClientHandler))
+            .is_err());
+
+        let mut registry = ClientRegistry::empty();
+
+        registry
+            .register(Arc::new(TendermintClientHandler))
+            .unwrap();
+        registry.register(Arc::new(WrappedClientHandler)).unwrap();
+        assert!(registry.register(Arc::new(WrappedClientHandler)).is_err());
+    }
+}
@@ -9,7 +9,7 @@ use tracing::{debug, error, trace, warn};
 use tendermint_light_client::{
     components::{
         self,
-        io::{AtHeight, Io, ProdIo},
+        io::{AtHeight, Io},
     },
     light_client::LightClient as TmLightClient,
     state::State as LightClientState,
@@ -32,16 +32,17 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    chain::cosmos::CosmosSdkChain,
+    chain::cosmos::{types::rpc::RpcClient, CosmosSdkChain},
     client_state::AnyClientState,
     config::ChainConfig,
     error::Error,
     light_client::AnyHeader,
     misbehaviour::{AnyMisbehaviour, MisbehaviourEvidence},
+    util::proxy::{self, ProxyBridge},
 };
 
 use super::{
-    io::{AnyIo, RestartAwareIo},
+    io::{AnyIo, RestartAwareIo, RpcIo},
     Verified,
 };
 
@@ -261,14 +262,27 @@ fn io_for_addr(
     addr: &rpc::Url,
     peer_id: PeerId,
     timeout: Option<Duration>,
-) -> Result<ProdIo, Error> {
-    let rpc_client = rpc::HttpClient::new(addr.clone()).map_err(|e| Error::rpc(addr.clone(), e))?;
-    Ok(ProdIo::new(peer_id, rpc_client, timeout))
+    proxy: Option<&ProxyBridge>,
+) -> Result<RpcIo, Error> {
+    let rpc_client = proxy::rpc_client(addr, proxy).map_err(|e| Error::rpc(addr.clone(), e))?;
+    Ok(RpcIo::new(peer_id, rpc_client.into(), timeout))
 }
 
 impl LightClient {
-    pub fn from_config(config: &ChainConfig, peer_id: PeerId) -> Result<Self, Error> {
-        let live_io = io_for_addr(&config.rpc_addr, peer_id, Some(config.rpc_timeout))?;
+    /// Sets the identifier of the chain after an upgrade which bumped its revision number.
+    pub fn set_chain_id(&mut self, chain_id: ChainId) {
+        self.chain_id = chain_id;
+    }
+
+    /// Builds the light client of the chain, which fetches the light blocks through
+    /// the given RPC client of the chain, so that they are subject to its rate limit.
+    pub fn from_config(
+        config: &ChainConfig,
+        peer_id: PeerId,
+        rpc_client: RpcClient,
+        proxy: Option<&ProxyBridge>,
+    ) -> Result<Self, Error> {
+        let live_io = RpcIo::new(peer_id, rpc_client, Some(config.rpc_timeout));
 
         let io = match &config.genesis_restart {
             None => AnyIo::Prod(live_io),
@@ -277,13 +291,14 @@ impl LightClient {
                     &genesis_restart.archive_addr,
                     peer_id,
                     Some(config.rpc_timeout),
+                    proxy,
                 )?;
 
-                AnyIo::RestartAware(RestartAwareIo::new(
+                AnyIo::RestartAware(Box::new(RestartAwareIo::new(
                     genesis_restart.restart_height,
                     live_io,
                     archive_io,
-                ))
+                )))
             }
         };
 
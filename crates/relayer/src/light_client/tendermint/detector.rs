@@ -6,24 +6,26 @@ use tendermint::{
 };
 use tendermint_light_client::{
     builder::LightClientBuilder,
-    components::{clock::FixedClock, io::ProdIo, scheduler},
+    components::{clock::FixedClock, scheduler},
     predicates::ProdPredicates,
     store::memory::MemoryStore,
     types::{LightBlock, PeerId},
     verifier::ProdVerifier,
 };
 use tendermint_light_client_detector::{detect_divergence, Divergence, Provider};
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::Client;
 
 use ibc_relayer_types::clients::ics07_tendermint::client_state::ClientState;
 
-use crate::{error::Error, util::block_on};
+use crate::{
+    chain::cosmos::types::rpc::RpcClient, error::Error, light_client::io::RpcIo, util::block_on,
+};
 
 type Hasher = tendermint::crypto::default::Sha256;
 
 pub fn detect(
     peer_id: PeerId,
-    rpc_client: HttpClient,
+    rpc_client: RpcClient,
     target_block: LightBlock,
     trusted_block: LightBlock,
     client_state: &ClientState,
@@ -71,7 +73,7 @@ pub fn detect(
 
 fn make_provider(
     peer_id: PeerId,
-    rpc_client: HttpClient,
+    rpc_client: RpcClient,
     client_state: &ClientState,
     trusted_block: LightBlock,
     now: Time,
@@ -83,7 +85,7 @@ fn make_provider(
         peer_id,
         options,
         light_store,
-        Box::new(ProdIo::new(peer_id, rpc_client.clone(), None)),
+        Box::new(RpcIo::new(peer_id, rpc_client.clone(), None)),
         Box::new(FixedClock::new(now)),
         Box::<ProdVerifier>::default(),
         Box::new(scheduler::basic_bisecting_schedule),
@@ -98,12 +100,12 @@ fn make_provider(
     Ok(Provider::new(
         client_state.chain_id.to_string(),
         instance,
-        rpc_client,
+        rpc_client.inner().clone(),
     ))
 }
 
 pub fn report_evidence(
-    rpc_client: HttpClient,
+    rpc_client: RpcClient,
     attack: LightClientAttackEvidence,
 ) -> Result<Hash, Error> {
     block_on(rpc_client.broadcast_evidence(Evidence::from(attack)))
@@ -1,15 +1,17 @@
 use ibc_relayer_types::core::{
     ics03_connection::connection::State as ConnectionState,
-    ics04_channel::channel::State as ChannelState,
+    ics04_channel::channel::{ChannelEnd, State as ChannelState},
     ics24_host::identifier::{ChannelId, PortChannelId, PortId},
 };
-use tracing::info;
+use tracing::{info, warn};
 
+use crate::chain::endpoint::ChainFeatures;
 use crate::chain::requests::{QueryChannelRequest, QueryHeight};
 use crate::chain::{counterparty::check_channel_counterparty, requests::QueryConnectionRequest};
 use crate::chain::{handle::ChainHandle, requests::IncludeProof};
 use crate::channel::{Channel, ChannelSide};
 use crate::link::error::LinkError;
+use crate::util::profile::Profile;
 
 pub mod cli;
 pub mod error;
@@ -29,6 +31,46 @@ pub use relay_summary::RelaySummary;
 
 pub use relay_path::{RelayPath, Resubmit};
 
+/// Registers on chain B the current address of the relayer on chain A as the counterparty
+/// payee of the channel end of chain B whose counterparty is the given channel end of chain A,
+/// if the channel is fee-enabled and chain B runs the fee middleware.
+///
+/// This is done when a link is created, and again when the key of either chain is reloaded,
+/// so that the relayer keeps being paid the fees for receiving the packets.
+pub fn register_counterparty_payee<ChainA: ChainHandle, ChainB: ChainHandle>(
+    a_chain: &ChainA,
+    b_chain: &ChainB,
+    a_channel: &ChannelEnd,
+    b_channel_id: &ChannelId,
+    b_port_id: &PortId,
+) -> Result<(), LinkError> {
+    if !a_channel.version.supports_fee() {
+        return Ok(());
+    }
+
+    if !ChainFeatures::detected_or_all(&b_chain.id(), b_chain.query_features()).fee_middleware {
+        warn!(
+            "not registering counterparty payee on chain {} which does not run the fee middleware",
+            b_chain.id()
+        );
+
+        return Ok(());
+    }
+
+    let address_a = a_chain.get_signer().map_err(LinkError::relayer)?;
+
+    info!(
+        "auto registering counterparty payee on chain {} as {} on chain {}",
+        b_chain.id(),
+        address_a,
+        a_chain.id()
+    );
+
+    b_chain
+        .maybe_register_counterparty_payee(b_channel_id.clone(), b_port_id.clone(), address_a)
+        .map_err(LinkError::relayer)
+}
+
 #[derive(Clone, Debug)]
 pub struct LinkParameters {
     pub src_port_id: PortId,
@@ -49,6 +91,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         })
     }
 
+    /// Relays on the link for the profile with the given services.
+    pub fn with_profile(self, profile: Profile) -> Self {
+        Self {
+            a_to_b: self.a_to_b.with_profile(profile),
+        }
+    }
+
     pub fn new_from_opts(
         a_chain: ChainA,
         b_chain: ChainB,
@@ -154,19 +203,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             connection_delay: a_connection.delay_period(),
         };
 
-        if auto_register_counterparty_payee && a_channel.version.supports_fee() {
-            let address_a = a_chain.get_signer().map_err(LinkError::relayer)?;
-
-            info!(
-                "auto registering counterparty payee on chain {} as {} on chain {}",
-                b_chain.id(),
-                address_a,
-                a_chain.id()
-            );
-
-            b_chain
-                .maybe_register_counterparty_payee(b_channel_id.clone(), b_port_id, address_a)
-                .map_err(LinkError::relayer)?;
+        if auto_register_counterparty_payee {
+            register_counterparty_payee(&a_chain, &b_chain, &a_channel, b_channel_id, &b_port_id)?;
         }
 
         Link::new(channel, with_tx_confirmation)
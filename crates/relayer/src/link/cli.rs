@@ -2,21 +2,24 @@ use std::convert::TryInto;
 use std::thread;
 use std::time::{Duration, Instant};
 
-use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::core::ics04_channel::events::SendPacket;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
 use itertools::Itertools;
 use tracing::{error_span, info};
 
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
 
 use crate::chain::counterparty::{unreceived_acknowledgements, unreceived_packets};
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::Qualified;
-use crate::chain::tracking::TrackingId;
+use crate::chain::tracking::{TrackedMsgs, TrackingId};
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 use crate::link::error::LinkError;
-use crate::link::operational_data::{OperationalData, TrackedEvents};
+use crate::link::operational_data::{OperationalData, OperationalDataTarget, TrackedEvents};
 use crate::link::packet_events::{
     query_packet_events_with, query_send_packet_events, query_write_ack_events,
 };
@@ -27,9 +30,20 @@ use crate::path::PathIdentifiers;
 use crate::util::collate::CollatedIterExt;
 use crate::util::pretty::{PrettyDuration, PrettySlice};
 
+/// The outcome of the simulation of a transaction made of messages relayed on a path.
+#[derive(Debug)]
+pub struct SimulatedMsgs {
+    /// The chain on which the transaction was simulated
+    pub chain_id: ChainId,
+    /// The type URLs of the messages in the transaction
+    pub msg_types: Vec<String>,
+    /// The gas used by the transaction, if reported by the chain, or the simulation error
+    pub result: Result<Option<u64>, Error>,
+}
+
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
-    /// Fetches an operational data that has fulfilled its predefined delay period. May _block_
-    /// waiting for the delay period to pass.
+    /// Fetches an operational data that has fulfilled its predefined delay period, along with
+    /// its retry delay, if any. May _block_ waiting for the delay period to pass.
     /// Returns `Ok(None)` if there is no operational data scheduled.
     pub(crate) fn fetch_scheduled_operational_data(
         &self,
@@ -68,9 +82,153 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         Ok(())
     }
+
+    /// Simulates, without broadcasting it, a transaction made of the messages in the
+    /// given operational data, preceded by the client update needed to verify their proofs.
+    ///
+    /// If the connection has a non-zero delay, the proofs cannot be verified in the same
+    /// transaction as the client update, which is therefore simulated on its own.
+    fn simulate_operational_data(&self, od: &OperationalData) -> Result<SimulatedMsgs, LinkError> {
+        let update_height = od.proofs_height.increment();
+
+        let mut msgs = match od.target {
+            OperationalDataTarget::Source => self.build_update_client_on_src(update_height)?,
+            OperationalDataTarget::Destination => self.build_update_client_on_dst(update_height)?,
+        };
+
+        if !od.conn_delay_needed() {
+            msgs.extend(od.batch.iter().map(|gm| gm.msg.clone()));
+        }
+
+        Ok(self.simulate_msgs(od.target, msgs))
+    }
+
+    fn simulate_msgs(&self, target: OperationalDataTarget, msgs: Vec<Any>) -> SimulatedMsgs {
+        let msg_types = msgs.iter().map(|msg| msg.type_url.clone()).collect();
+        let tracked_msgs = TrackedMsgs::new_static(msgs, "simulate");
+
+        let (chain_id, result) = match target {
+            OperationalDataTarget::Source => (
+                self.src_chain().id(),
+                self.src_chain().simulate_messages(tracked_msgs),
+            ),
+            OperationalDataTarget::Destination => (
+                self.dst_chain().id(),
+                self.dst_chain().simulate_messages(tracked_msgs),
+            ),
+        };
+
+        SimulatedMsgs {
+            chain_id,
+            msg_types,
+            result,
+        }
+    }
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
+    /// Implements the `simulate-paths` CLI
+    ///
+    /// Builds the messages relaying the first pending packet and acknowledgement of the path,
+    /// with their current proofs, and simulates them without broadcasting anything.
+    /// If nothing is pending, the update of the client on the destination chain is simulated.
+    pub fn simulate_messages(&self) -> Result<Vec<SimulatedMsgs>, LinkError> {
+        let _span = error_span!(
+            "simulate_messages",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+        )
+        .entered();
+
+        let mut simulations = vec![];
+
+        let (sequences, src_response_height) = unreceived_packets(
+            self.a_to_b.dst_chain(),
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+        )
+        .map_err(LinkError::supervisor)?;
+
+        simulations.extend(self.simulate_packet_messages(
+            &sequences,
+            src_response_height,
+            query_send_packet_events,
+            TrackingId::new_static("simulate-recv"),
+        )?);
+
+        if let Some((sequences, src_response_height)) = unreceived_acknowledgements(
+            self.a_to_b.dst_chain(),
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+        )
+        .map_err(LinkError::supervisor)?
+        {
+            simulations.extend(self.simulate_packet_messages(
+                &sequences,
+                src_response_height,
+                query_write_ack_events,
+                TrackingId::new_static("simulate-ack"),
+            )?);
+        }
+
+        if simulations.is_empty() {
+            let src_height = self.a_to_b.src_latest_height()?;
+            let msgs = self.a_to_b.build_update_client_on_dst(src_height)?;
+
+            // Empty if the client already has a consensus state at the latest height
+            if !msgs.is_empty() {
+                simulations.push(
+                    self.a_to_b
+                        .simulate_msgs(OperationalDataTarget::Destination, msgs),
+                );
+            }
+        }
+
+        Ok(simulations)
+    }
+
+    fn simulate_packet_messages<QueryFn>(
+        &self,
+        sequences: &[Sequence],
+        src_response_height: Height,
+        query_fn: QueryFn,
+        tracking_id: TrackingId,
+    ) -> Result<Vec<SimulatedMsgs>, LinkError>
+    where
+        QueryFn: Fn(
+            &ChainA,
+            &PathIdentifiers,
+            &[Sequence],
+            Qualified<Height>,
+        ) -> Result<Vec<IbcEventWithHeight>, Error>,
+    {
+        // The first pending packet is representative of the messages relayed on the path
+        let Some(sequence) = sequences.first() else { return Ok(vec![]) };
+
+        let Some(events) = query_packet_events_with(
+            std::slice::from_ref(sequence),
+            Qualified::SmallerEqual(src_response_height),
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+            query_fn,
+        )
+        .next() else {
+            return Ok(vec![]);
+        };
+
+        let (src_od, dst_od) = self
+            .a_to_b
+            .generate_operational_data(TrackedEvents::new(events, tracking_id))?;
+
+        src_od
+            .iter()
+            .chain(dst_od.iter())
+            .map(|od| self.a_to_b.simulate_operational_data(od))
+            .collect()
+    }
+
     pub fn relay_recv_packet_and_timeout_messages(&self) -> Result<Vec<IbcEvent>, LinkError> {
         self.relay_recv_packet_and_timeout_messages_with_packet_data_query_height(None)
     }
@@ -143,7 +301,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             self.a_to_b.src_chain(),
             &self.a_to_b.path_id,
         )
-        .map_err(LinkError::supervisor)? else { return Ok(vec![]) };
+        .map_err(LinkError::supervisor)?
+        else {
+            return Ok(vec![]);
+        };
 
         if sequences.is_empty() {
             return Ok(vec![]);
@@ -168,6 +329,96 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
     }
 
+    /// Implements the `tx relay-packet` CLI
+    ///
+    /// Relays the packet with the given sequence, queried from the events of the source chain,
+    /// without clearing the other pending packets. Nothing is relayed if the packet was already
+    /// received or its commitment cleared.
+    pub fn relay_packet(&self, sequence: Sequence) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "relay_packet",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+            %sequence,
+        )
+        .entered();
+
+        let src_height = self.a_to_b.src_latest_height()?;
+
+        let events = query_send_packet_events(
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+            &[sequence],
+            Qualified::SmallerEqual(src_height),
+        )
+        .map_err(LinkError::relayer)?;
+
+        self.relay_packet_events([events], TrackingId::new_static("relay-packet"))
+    }
+
+    /// Implements the `tx relay-packet` CLI, for a packet given in full
+    ///
+    /// Relays the given packet, whose proofs are queried at the latest height of the source
+    /// chain, without looking up the event emitted when it was sent, which may have been pruned.
+    pub fn relay_given_packet(&self, packet: Packet) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "relay_packet",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+            sequence = %packet.sequence,
+        )
+        .entered();
+
+        if &packet.source_port != self.a_to_b.src_port_id()
+            || &packet.source_channel != self.a_to_b.src_channel_id()
+            || &packet.destination_port != self.a_to_b.dst_port_id()
+            || &packet.destination_channel != self.a_to_b.dst_channel_id()
+        {
+            return Err(LinkError::packet_not_on_path(
+                packet,
+                self.a_to_b.src_port_id().clone(),
+                self.a_to_b.src_channel_id().clone(),
+            ));
+        }
+
+        let src_height = self.a_to_b.src_latest_height()?;
+        let event = IbcEventWithHeight::new(SendPacket { packet }.into(), src_height);
+
+        self.relay_packet_events([vec![event]], TrackingId::new_static("relay-packet"))
+    }
+
+    /// Implements the `tx relay-packet` CLI, for the acknowledgement of a packet
+    ///
+    /// Relays the acknowledgement written on the source chain of this link for the packet
+    /// with the given sequence, which was received from the destination chain.
+    pub fn relay_packet_ack(&self, sequence: Sequence) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "relay_packet_ack",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+            %sequence,
+        )
+        .entered();
+
+        let src_height = self.a_to_b.src_latest_height()?;
+
+        let events = query_write_ack_events(
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+            &[sequence],
+            Qualified::SmallerEqual(src_height),
+        )
+        .map_err(LinkError::relayer)?;
+
+        self.relay_packet_events([events], TrackingId::new_static("relay-packet-ack"))
+    }
+
     fn relay_packet_messages<QueryFn>(
         &self,
         sequences: Vec<Sequence>,
@@ -191,6 +442,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             query_fn,
         );
 
+        self.relay_packet_events(event_chunks, tracking_id)
+    }
+
+    fn relay_packet_events(
+        &self,
+        event_chunks: impl IntoIterator<Item = Vec<IbcEventWithHeight>>,
+        tracking_id: TrackingId,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
         let mut results = vec![];
 
         for event_chunk in event_chunks {
@@ -227,6 +486,19 @@ where
     MaxBlockTime: Fn() -> Result<Duration, LinkError>,
     LatestHeight: Fn() -> Result<Height, LinkError>,
 {
+    let retry_delay = odata.retry_delay_remaining();
+
+    if !retry_delay.is_zero() {
+        info!(
+            "waiting ({} left) to retry a scheduled op. data with batch of size {} targeting {}",
+            PrettyDuration(&retry_delay),
+            odata.batch.len(),
+            odata.target,
+        );
+
+        thread::sleep(retry_delay);
+    }
+
     let (time_left, blocks_left) =
         odata.conn_delay_remaining(chain_time, max_expected_time_per_block, latest_height)?;
 
@@ -1,12 +1,13 @@
 use flex_error::define_error;
 use ibc_relayer_types::core::ics02_client::error::Error as Ics02Error;
+use ibc_relayer_types::core::ics04_channel::packet::Packet;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
 
 use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
-use crate::error::Error;
+use crate::error::{Error, ErrorCategory};
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
 use crate::supervisor::Error as SupervisorError;
 use crate::transfer::TransferError;
@@ -148,6 +149,17 @@ define_error! {
 
         UpdateClientFailed
              |_| { "failed to update client" },
+
+        PacketNotOnPath
+            {
+                packet: Packet,
+                port_id: PortId,
+                channel_id: ChannelId,
+            }
+            |e| {
+                format!("packet {} is not sent from {}/{} to its counterparty channel",
+                    e.packet, e.port_id, e.channel_id)
+            },
    }
 }
 
@@ -165,3 +177,18 @@ impl HasExpiredOrFrozenError for LinkError {
         self.detail().is_expired_or_frozen_error()
     }
 }
+
+impl LinkError {
+    /// Classifies the error raised by the target chain when submitting
+    /// messages, to determine how to retry the submission.
+    pub fn category(&self) -> ErrorCategory {
+        match self.detail() {
+            LinkErrorDetail::Send(e) => match &e.event {
+                IbcEvent::ChainError(log) => ErrorCategory::from_tx_log(log),
+                _ => ErrorCategory::Other,
+            },
+            LinkErrorDetail::Relayer(e) => e.source.category(),
+            _ => ErrorCategory::Other,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::collections::{BTreeMap, VecDeque};
 use core::fmt::{Display, Error as FmtError, Formatter};
 use std::ops::Add;
 use std::time::{Duration, Instant};
@@ -6,8 +7,15 @@ use ibc_proto::google::protobuf::Any;
 use tracing::{debug, info};
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics04_channel::events::SendPacket;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
 
+use crate::chain::endpoint::ChainStatus;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::IncludeProof;
 use crate::chain::requests::QueryClientStateRequest;
@@ -98,6 +106,10 @@ pub struct OperationalData {
     pub tracking_id: TrackingId,
     /// Stores `Some(ConnectionDelay)` if the delay is non-zero and `None` otherwise
     connection_delay: Option<ConnectionDelay>,
+    /// The number of times the submission of the messages was retried
+    retries: usize,
+    /// The time before which the messages must not be submitted again, after a failed submission
+    retry_at: Option<Instant>,
 }
 
 impl OperationalData {
@@ -119,9 +131,31 @@ impl OperationalData {
             target,
             connection_delay,
             tracking_id,
+            retries: 0,
+            retry_at: None,
         }
     }
 
+    /// The number of times the submission of the messages was retried.
+    pub fn retries(&self) -> usize {
+        self.retries
+    }
+
+    /// Records that the submission of the messages is to be retried for the given
+    /// number of times, once the given delay elapsed.
+    pub fn set_retry(&mut self, retries: usize, delay: Duration) {
+        self.retries = retries;
+        self.retry_at = Some(Instant::now() + delay);
+    }
+
+    /// Returns the time left before the submission of the messages can be retried,
+    /// and `Duration::ZERO` if it can be retried, or was not tried yet.
+    pub fn retry_delay_remaining(&self) -> Duration {
+        self.retry_at.map_or(Duration::ZERO, |retry_at| {
+            retry_at.saturating_duration_since(Instant::now())
+        })
+    }
+
     pub fn push(&mut self, msg: TransitMessage) {
         self.batch.push(msg)
     }
@@ -136,6 +170,15 @@ impl OperationalData {
         }
     }
 
+    /// Returns the sequences of the packets relayed by this operational data.
+    pub fn sequences(&self) -> Vec<Sequence> {
+        self.batch
+            .iter()
+            .filter_map(|msg| msg.event_with_height.event.packet())
+            .map(|packet| packet.sequence)
+            .collect()
+    }
+
     /// Transforms `self` into the list of events accompanied with the tracking ID.
     pub fn into_events(self) -> TrackedEvents {
         let events = self
@@ -222,6 +265,21 @@ impl OperationalData {
         Ok(tm)
     }
 
+    /// Returns the estimated time left until the packet in this batch which is
+    /// the closest to its timeout times out on the destination chain,
+    /// or `None` if none of the packets in the batch have a timeout.
+    pub fn time_to_timeout(
+        &self,
+        dst_status: &ChainStatus,
+        max_block_time: Duration,
+    ) -> Option<Duration> {
+        self.batch
+            .iter()
+            .filter_map(|msg| msg.event_with_height.event.packet())
+            .filter_map(|packet| packet_time_to_timeout(packet, dst_status, max_block_time))
+            .min()
+    }
+
     /// Returns true iff the batch contains a packet event
     fn has_packet_msgs(&self) -> bool {
         self.batch
@@ -290,6 +348,8 @@ impl OperationalData {
         }
     }
 
+    /// Whether the messages can be submitted, ie. whether the connection delay elapsed,
+    /// along with the delay before retrying their submission, if it failed before.
     pub fn has_conn_delay_elapsed<ChainTime, MaxBlockTime, LatestHeight>(
         &self,
         chain_time: &ChainTime,
@@ -301,6 +361,10 @@ impl OperationalData {
         MaxBlockTime: Fn() -> Result<Duration, LinkError>,
         LatestHeight: Fn() -> Result<Height, LinkError>,
     {
+        if !self.retry_delay_remaining().is_zero() {
+            return Ok(false);
+        }
+
         Ok(self.conn_time_delay_remaining(chain_time)?.is_zero()
             && self.conn_block_delay_remaining(max_expected_time_per_block, latest_height)? == 0)
     }
@@ -323,6 +387,178 @@ impl OperationalData {
     }
 }
 
+/// Estimates the time left until the given packet times out on the destination chain,
+/// based on the destination chain's latest status and its expected block time.
+///
+/// Returns `Duration::ZERO` if the packet has already timed out, and `None` if the
+/// packet has neither a timeout height nor a timeout timestamp.
+fn packet_time_to_timeout(
+    packet: &Packet,
+    dst_status: &ChainStatus,
+    max_block_time: Duration,
+) -> Option<Duration> {
+    let by_height = match packet.timeout_height {
+        TimeoutHeight::At(timeout_height)
+            if timeout_height.revision_number() == dst_status.height.revision_number() =>
+        {
+            let remaining_blocks = timeout_height
+                .revision_height()
+                .saturating_sub(dst_status.height.revision_height());
+
+            let remaining_blocks = u32::try_from(remaining_blocks).unwrap_or(u32::MAX);
+
+            Some(max_block_time.saturating_mul(remaining_blocks))
+        }
+        // A timeout height in a past revision has already expired,
+        // while one in a future revision is too far away to be estimated.
+        TimeoutHeight::At(timeout_height) => {
+            if timeout_height.revision_number() < dst_status.height.revision_number() {
+                Some(Duration::ZERO)
+            } else {
+                None
+            }
+        }
+        TimeoutHeight::Never => None,
+    };
+
+    let by_timestamp = if packet.timeout_timestamp == Timestamp::none() {
+        None
+    } else {
+        Some(
+            packet
+                .timeout_timestamp
+                .duration_since(&dst_status.timestamp)
+                .unwrap_or(Duration::ZERO),
+        )
+    };
+
+    match (by_height, by_timestamp) {
+        (Some(by_height), Some(by_timestamp)) => Some(by_height.min(by_timestamp)),
+        (by_height, by_timestamp) => by_height.or(by_timestamp),
+    }
+}
+
+/// Orders the given operational data so that the batches holding the packets which
+/// are closest to timing out on the destination chain come first.
+///
+/// Batches without any packet timeout are placed last. The sort is stable, so batches
+/// which are as close to timing out keep their original relative order.
+pub fn sort_by_timeout_proximity(
+    mut odata: VecDeque<OperationalData>,
+    dst_status: &ChainStatus,
+    max_block_time: Duration,
+) -> VecDeque<OperationalData> {
+    odata.make_contiguous().sort_by_key(|od| {
+        od.time_to_timeout(dst_status, max_block_time)
+            .unwrap_or(Duration::MAX)
+    });
+
+    odata
+}
+
+/// The maximum number of packets watched for expiry on a path, past which the packets which
+/// could not be delivered are only timed out by the packet clearing.
+pub const MAX_EXPIRING_PACKETS: usize = 1_000;
+
+/// The outcome of checking an expired packet against the destination chain.
+pub enum ExpiryCheck {
+    /// The packet was received or timed out since, and is no longer watched
+    Handled,
+    /// The timeout message to submit to the source chain
+    Expired(Any),
+    /// The packet could not be timed out yet, and is still watched
+    Pending,
+}
+
+/// The `SendPacket` events which could not be delivered to the destination chain, keyed by
+/// the channel and the sequence of their packet, and bounded by [`MAX_EXPIRING_PACKETS`].
+#[derive(Default)]
+pub struct ExpiringPackets {
+    packets: BTreeMap<(ChannelId, Sequence), IbcEventWithHeight>,
+}
+
+impl ExpiringPackets {
+    pub fn is_empty(&self) -> bool {
+        self.packets.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.packets.len()
+    }
+
+    /// Watches the packet of the given `SendPacket` event, unless it is already watched,
+    /// or the bound on the number of watched packets was reached.
+    /// Returns whether the packet was added.
+    pub fn watch(&mut self, event_with_height: IbcEventWithHeight) -> bool {
+        let IbcEvent::SendPacket(ref event) = event_with_height.event else {
+            return false;
+        };
+
+        let key = (event.packet.source_channel.clone(), event.packet.sequence);
+
+        if self.packets.contains_key(&key) || self.packets.len() >= MAX_EXPIRING_PACKETS {
+            return false;
+        }
+
+        self.packets.insert(key, event_with_height);
+        true
+    }
+
+    /// Adds the timeout messages of the watched packets which expired at the given status of
+    /// the destination chain to the given operational data, and stops watching them.
+    ///
+    /// Only the packets which expired are checked against the destination chain, so that the
+    /// chain is not queried for every watched packet on each refresh.
+    ///
+    /// On ordered channels a single timeout closes the channel, so at most one timeout is added,
+    /// and the other expired packets are still watched.
+    pub fn schedule_expired(
+        &mut self,
+        timeouts: &mut OperationalData,
+        unordered: bool,
+        dst_status: &ChainStatus,
+        mut check: impl FnMut(&SendPacket) -> ExpiryCheck,
+    ) {
+        self.packets.retain(|_, event_with_height| {
+            let IbcEvent::SendPacket(ref event) = event_with_height.event else {
+                return false;
+            };
+
+            if !event
+                .packet
+                .timed_out(&dst_status.timestamp, dst_status.height)
+            {
+                return true;
+            }
+
+            if !unordered && !timeouts.batch.is_empty() {
+                return true;
+            }
+
+            match check(event) {
+                ExpiryCheck::Handled => {
+                    debug!(?event, "SendPacket event has already been handled");
+                    false
+                }
+                ExpiryCheck::Expired(msg) => {
+                    debug!(
+                        sequence = %event.packet.sequence,
+                        "packet expired, scheduling timeout"
+                    );
+
+                    timeouts.push(TransitMessage {
+                        event_with_height: event_with_height.clone(),
+                        msg,
+                    });
+
+                    false
+                }
+                ExpiryCheck::Pending => true,
+            }
+        });
+    }
+}
+
 /// A struct that holds everything that is required to calculate and deal with the connection-delay
 /// feature.
 #[derive(Clone)]
@@ -415,3 +651,250 @@ impl Display for OperationalInfo {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCK_TIME: Duration = Duration::from_secs(5);
+
+    fn dst_status() -> ChainStatus {
+        ChainStatus {
+            height: Height::new(0, 100).unwrap(),
+            timestamp: Timestamp::from_nanoseconds(1_000_000_000_000).unwrap(),
+        }
+    }
+
+    fn odata_with_packet(
+        sequence: u64,
+        timeout_height: TimeoutHeight,
+        timeout_timestamp: Timestamp,
+    ) -> OperationalData {
+        let packet = Packet {
+            sequence: Sequence::from(sequence),
+            timeout_height,
+            timeout_timestamp,
+            ..Default::default()
+        };
+
+        let mut od = OperationalData::new(
+            Height::new(0, 10).unwrap(),
+            OperationalDataTarget::Destination,
+            TrackingId::new_static("test"),
+            Duration::ZERO,
+        );
+
+        od.push(TransitMessage {
+            event_with_height: IbcEventWithHeight::new(
+                IbcEvent::SendPacket(SendPacket { packet }),
+                Height::new(0, 10).unwrap(),
+            ),
+            msg: Any::default(),
+        });
+
+        od
+    }
+
+    fn watched_packets(timeout_heights: &[(u64, u64)]) -> ExpiringPackets {
+        let mut watched = ExpiringPackets::default();
+
+        for &(sequence, timeout_height) in timeout_heights {
+            let timeout_height = TimeoutHeight::At(Height::new(0, timeout_height).unwrap());
+
+            watched.watch(
+                odata_with_packet(sequence, timeout_height, Timestamp::none())
+                    .batch
+                    .remove(0)
+                    .event_with_height,
+            );
+        }
+
+        watched
+    }
+
+    fn timeouts() -> OperationalData {
+        OperationalData::new(
+            dst_status().height,
+            OperationalDataTarget::Source,
+            TrackingId::new_static("test"),
+            Duration::ZERO,
+        )
+    }
+
+    fn status_at(height: u64) -> ChainStatus {
+        ChainStatus {
+            height: Height::new(0, height).unwrap(),
+            ..dst_status()
+        }
+    }
+
+    /// Checks the expired packets against the destination chain, where the packet
+    /// with sequence 3 was already received, and records the checked sequences.
+    fn check(checked: &mut Vec<u64>) -> impl FnMut(&SendPacket) -> ExpiryCheck + '_ {
+        move |event| {
+            checked.push(event.packet.sequence.into());
+
+            if event.packet.sequence == Sequence::from(3) {
+                ExpiryCheck::Handled
+            } else {
+                ExpiryCheck::Expired(Any::default())
+            }
+        }
+    }
+
+    fn sequences<'a>(events: impl IntoIterator<Item = &'a IbcEventWithHeight>) -> Vec<u64> {
+        events
+            .into_iter()
+            .map(|e| e.event.packet().unwrap().sequence.into())
+            .collect()
+    }
+
+    #[test]
+    fn expired_packets_are_timed_out_once_the_destination_chain_passes_their_expiry() {
+        let mut watched = watched_packets(&[(1, 90), (2, 110), (3, 95), (4, 99)]);
+        let mut checked = vec![];
+
+        let mut timeouts_at_100 = timeouts();
+        watched.schedule_expired(
+            &mut timeouts_at_100,
+            true,
+            &status_at(100),
+            check(&mut checked),
+        );
+
+        assert_eq!(
+            sequences(timeouts_at_100.batch.iter().map(|m| &m.event_with_height)),
+            vec![1, 4]
+        );
+        assert_eq!(sequences(watched.packets.values()), vec![2]);
+
+        // The remaining packet is only checked in the first block past its expiry
+        let mut timeouts_at_110 = timeouts();
+        watched.schedule_expired(
+            &mut timeouts_at_110,
+            true,
+            &status_at(110),
+            check(&mut checked),
+        );
+
+        assert!(timeouts_at_110.batch.is_empty());
+        assert_eq!(sequences(watched.packets.values()), vec![2]);
+
+        let mut timeouts_at_111 = timeouts();
+        watched.schedule_expired(
+            &mut timeouts_at_111,
+            true,
+            &status_at(111),
+            check(&mut checked),
+        );
+
+        assert_eq!(
+            sequences(timeouts_at_111.batch.iter().map(|m| &m.event_with_height)),
+            vec![2]
+        );
+        assert!(watched.is_empty());
+
+        assert_eq!(checked, vec![1, 3, 4, 2]);
+    }
+
+    #[test]
+    fn a_single_packet_is_timed_out_at_once_on_ordered_channels() {
+        let mut watched = watched_packets(&[(1, 90), (2, 90)]);
+        let mut checked = vec![];
+
+        let mut timeouts = timeouts();
+        watched.schedule_expired(&mut timeouts, false, &status_at(100), check(&mut checked));
+
+        assert_eq!(
+            sequences(timeouts.batch.iter().map(|m| &m.event_with_height)),
+            vec![1]
+        );
+        assert_eq!(sequences(watched.packets.values()), vec![2]);
+        assert_eq!(checked, vec![1]);
+    }
+
+    #[test]
+    fn watched_packets_are_deduplicated_and_bounded() {
+        let mut watched = watched_packets(&[(1, 90), (1, 90), (2, 90)]);
+        assert_eq!(watched.len(), 2);
+
+        for sequence in 3..=MAX_EXPIRING_PACKETS as u64 + 10 {
+            watched.watch(
+                odata_with_packet(sequence, TimeoutHeight::Never, Timestamp::none())
+                    .batch
+                    .remove(0)
+                    .event_with_height,
+            );
+        }
+
+        assert_eq!(watched.len(), MAX_EXPIRING_PACKETS);
+    }
+
+    fn first_sequence(od: &OperationalData) -> u64 {
+        od.batch[0]
+            .event_with_height
+            .event
+            .packet()
+            .unwrap()
+            .sequence
+            .into()
+    }
+
+    #[test]
+    fn retries_wait_for_their_delay() {
+        let mut od = odata_with_packet(1, TimeoutHeight::Never, Timestamp::none());
+
+        let elapsed = |od: &OperationalData| {
+            od.has_conn_delay_elapsed(&|| Ok(Instant::now()), &|| Ok(BLOCK_TIME), &|| {
+                Ok(Height::new(0, 10).unwrap())
+            })
+            .unwrap()
+        };
+
+        assert_eq!(od.retries(), 0);
+        assert!(elapsed(&od));
+
+        od.set_retry(1, Duration::from_secs(60));
+        assert_eq!(od.retries(), 1);
+        assert!(!od.retry_delay_remaining().is_zero());
+        assert!(!elapsed(&od));
+
+        od.set_retry(2, Duration::ZERO);
+        assert_eq!(od.retries(), 2);
+        assert!(elapsed(&od));
+    }
+
+    #[test]
+    fn packets_closest_to_timeout_come_first() {
+        let status = dst_status();
+        let in_seconds = |secs: u64| {
+            Timestamp::from_nanoseconds(
+                status.timestamp.nanoseconds() + Duration::from_secs(secs).as_nanos() as u64,
+            )
+            .unwrap()
+        };
+
+        let odata = VecDeque::from(vec![
+            odata_with_packet(1, TimeoutHeight::Never, Timestamp::none()),
+            odata_with_packet(2, TimeoutHeight::Never, in_seconds(600)),
+            // 10 blocks away, ie. 50 seconds
+            odata_with_packet(
+                3,
+                TimeoutHeight::At(Height::new(0, 110).unwrap()),
+                Timestamp::none(),
+            ),
+            odata_with_packet(4, TimeoutHeight::Never, in_seconds(20)),
+            // Already timed out
+            odata_with_packet(
+                5,
+                TimeoutHeight::At(Height::new(0, 90).unwrap()),
+                in_seconds(1000),
+            ),
+        ]);
+
+        let sorted = sort_by_timeout_proximity(odata, &status, BLOCK_TIME);
+        let sequences: Vec<u64> = sorted.iter().map(first_sequence).collect();
+
+        assert_eq!(sequences, vec![5, 4, 3, 2, 1]);
+    }
+}
@@ -10,8 +10,10 @@ use ibc_relayer_types::events::IbcEvent;
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
 use crate::error::Error as RelayerError;
+use crate::event_sink::{self, RelayEvent, RelayEventKind};
 use crate::link::{error::LinkError, RelayPath};
 use crate::telemetry;
+use crate::util::profile::Profile;
 use crate::util::queue::Queue;
 use crate::{
     chain::handle::ChainHandle,
@@ -73,6 +75,45 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.chain.id()
     }
 
+    /// Publishes the outcome of the given pending transactions
+    /// to the event sink of the given profile, if it is enabled.
+    fn publish_relay_event(
+        &self,
+        profile: &Profile,
+        kind: RelayEventKind,
+        pending: &PendingData,
+        error: Option<&str>,
+    ) {
+        if !event_sink::is_enabled(profile, kind) {
+            return;
+        }
+
+        let tx_hashes = pending
+            .tx_hashes
+            .0
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let event = RelayEvent::new(
+            kind,
+            pending.tracking_id(),
+            self.chain_id(),
+            self.counterparty_chain_id.clone(),
+            self.port_id.clone(),
+            self.channel_id.clone(),
+        )
+        .with_sequences(pending.original_od.sequences())
+        .with_tx_hashes(tx_hashes);
+
+        let event = match error {
+            Some(e) => event.with_error(e),
+            None => event,
+        };
+
+        event_sink::publish(profile, event);
+    }
+
     /// Insert a new pending transaction to the back of the queue.
     pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
         let mut tx_hashes = Vec::new();
@@ -191,6 +232,13 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         // relayer to resubmit the transaction to the chain again.
                         error!("timed out while confirming {}", tx_hashes);
 
+                        self.publish_relay_event(
+                            relay_path.profile(),
+                            RelayEventKind::Error,
+                            &pending,
+                            Some("timed out while confirming the transactions"),
+                        );
+
                         match resubmit {
                             Some(f) => {
                                 // The pending tx needs to be resubmitted. This involves replacing the tx's
@@ -241,6 +289,32 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         "transactions confirmed",
                     );
 
+                    // A committed transaction may still have failed, in which case
+                    // its events hold the log of the failure instead of the IBC events.
+                    let failure = events
+                        .iter()
+                        .filter_map(|event| match event {
+                            IbcEvent::ChainError(log) => Some(log.as_str()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+
+                    if failure.is_empty() {
+                        self.publish_relay_event(
+                            relay_path.profile(),
+                            RelayEventKind::TxConfirmed,
+                            &pending,
+                            None,
+                        );
+                    } else {
+                        self.publish_relay_event(
+                            relay_path.profile(),
+                            RelayEventKind::Error,
+                            &pending,
+                            Some(&failure.join("; ")),
+                        );
+                    }
+
                     telemetry!(
                         tx_confirmed,
                         tx_hashes.0.len(),
@@ -1,12 +1,15 @@
 use alloc::collections::BTreeMap as HashMap;
 use alloc::collections::VecDeque;
 use std::ops::Sub;
+use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
 use ibc_proto::google::protobuf::Any;
 use itertools::Itertools;
+use once_cell::sync::OnceCell;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement as TransferAcknowledgement;
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Ordering, State as ChannelState,
@@ -42,12 +45,18 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::channel_batch::BatchLimits;
+use crate::config::packet_memo::{PacketMemoConfig, PacketMemoPolicy};
+use crate::config::retry::RetryPolicy;
+use crate::error::ErrorCategory;
 use crate::event::source::EventBatch;
 use crate::event::IbcEventWithHeight;
+use crate::event_sink::{self, RelayEvent, RelayEventKind};
 use crate::foreign_client::{ForeignClient, ForeignClientError};
 use crate::link::error::{self, LinkError};
 use crate::link::operational_data::{
-    OperationalData, OperationalDataTarget, TrackedEvents, TransitMessage,
+    sort_by_timeout_proximity, ExpiringPackets, ExpiryCheck, OperationalData,
+    OperationalDataTarget, TrackedEvents, TransitMessage,
 };
 use crate::link::packet_events::query_packet_events_with;
 use crate::link::packet_events::query_send_packet_events;
@@ -58,8 +67,11 @@ use crate::link::relay_summary::RelaySummary;
 use crate::link::{pending, relay_sender};
 use crate::path::PathIdentifiers;
 use crate::telemetry;
+use crate::transfer::validate_packet_data;
 use crate::util::collate::CollatedIterExt;
+use crate::util::lock::LockExt;
 use crate::util::pretty::PrettyEvents;
+use crate::util::profile::Profile;
 use crate::util::queue::Queue;
 
 const MAX_RETRIES: usize = 5;
@@ -108,6 +120,21 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     // transactions if [`confirm_txes`] is true.
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
+
+    // `SendPacket` events which the relayer failed to deliver to the destination
+    // chain, and which will thus most likely have to be timed out.
+    // The relaying path keeps track of a bounded number of these packets so that
+    // it can submit the corresponding timeout messages as soon as they expire.
+    expiring_packets: Arc<RwLock<ExpiringPackets>>,
+
+    // The maximum block time of each chain, read from its configuration on first use,
+    // as it is needed on every execution of the schedule.
+    src_max_block_time: OnceCell<Duration>,
+    dst_max_block_time: OnceCell<Duration>,
+
+    // The services of the profile relaying on the path, whose event sink
+    // the relay actions are published to.
+    profile: Profile,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -152,9 +179,25 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             confirm_txes: with_tx_confirmation,
             pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
             pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+
+            expiring_packets: Arc::new(RwLock::new(ExpiringPackets::default())),
+
+            src_max_block_time: OnceCell::new(),
+            dst_max_block_time: OnceCell::new(),
+
+            profile: Profile::default(),
         })
     }
 
+    /// Relays on the path for the profile with the given services.
+    pub fn with_profile(self, profile: Profile) -> Self {
+        Self { profile, ..self }
+    }
+
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
     pub fn src_chain(&self) -> &ChainA {
         self.channel.src_chain()
     }
@@ -290,19 +333,27 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     pub(crate) fn src_max_block_time(&self) -> Result<Duration, LinkError> {
         // TODO(hu55a1n1): Ideally, we should get the `max_expected_time_per_block` using the
         // `/genesis` endpoint once it is working in tendermint-rs.
-        Ok(self
-            .src_chain()
-            .config()
-            .map_err(LinkError::relayer)?
-            .max_block_time)
+        self.src_max_block_time
+            .get_or_try_init(|| {
+                Ok(self
+                    .src_chain()
+                    .config()
+                    .map_err(LinkError::relayer)?
+                    .max_block_time)
+            })
+            .copied()
     }
 
     pub(crate) fn dst_max_block_time(&self) -> Result<Duration, LinkError> {
-        Ok(self
-            .dst_chain()
-            .config()
-            .map_err(LinkError::relayer)?
-            .max_block_time)
+        self.dst_max_block_time
+            .get_or_try_init(|| {
+                Ok(self
+                    .dst_chain()
+                    .config()
+                    .map_err(LinkError::relayer)?
+                    .max_block_time)
+            })
+            .copied()
     }
 
     fn unordered_channel(&self) -> bool {
@@ -463,10 +514,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         // Collect relevant events from the incoming batch & adjust their height.
         let events = self.filter_relaying_events(batch.events, batch.tracking_id);
 
+        self.publish_observed_packets(&events);
+
         // Update telemetry info
         telemetry!({
+            let mut block_times = HashMap::new();
+
             for event_with_height in events.events() {
-                self.backlog_update(&event_with_height.event);
+                self.backlog_update(event_with_height, &mut block_times);
             }
         });
 
@@ -501,7 +556,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ///
     /// For the source chain, the op. data will contain timeout packet messages (`MsgTimeoutOnClose`
     /// or `MsgTimeout`).
-    fn generate_operational_data(
+    pub(crate) fn generate_operational_data(
         &self,
         events: TrackedEvents,
     ) -> Result<(Option<OperationalData>, Option<OperationalData>), LinkError> {
@@ -541,6 +596,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             self.channel.connection_delay,
         );
 
+        let src_config = self.src_chain().config().map_err(LinkError::relayer)?;
+        let packet_memo = src_config.packet_memo;
+
+        // The bech32 prefix of the accounts on the destination chain,
+        // if the fungible token packet data must be validated on this channel
+        let receiver_prefix = if src_config
+            .packet_filter
+            .validate_transfer
+            .matches((self.src_port_id(), self.src_channel_id()))
+        {
+            let dst_config = self.dst_chain().config().map_err(LinkError::relayer)?;
+            Some(dst_config.account_prefix)
+        } else {
+            None
+        };
+
         for event_with_height in input {
             trace!(event = %event_with_height, "processing event");
 
@@ -578,6 +649,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             event,
                             &dst_latest_info,
                             event_with_height.height,
+                            receiver_prefix.as_deref(),
+                            &packet_memo,
                         )?
                     }
                 }
@@ -637,63 +710,207 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
     /// Relays an [`OperationalData`] using a specific
     /// sender, which implements [`relay_sender::Submit`].
+    ///
+    /// If the submission fails with an error which the retry policy of its category allows
+    /// to retry, the operational data is regenerated and scheduled again, to be submitted
+    /// once the backoff delay of the policy elapsed, without holding up the relaying of
+    /// the other operational data in the meantime.
     pub(crate) fn relay_from_operational_data<S: relay_sender::Submit>(
         &self,
-        initial_od: OperationalData,
+        odata: OperationalData,
     ) -> Result<S::Reply, LinkError> {
-        // We will operate on potentially different operational data if the initial one fails.
-        let _span = span!(Level::INFO, "relay", odata = %initial_od.info()).entered();
-
-        let mut odata = initial_od;
-
-        for i in 0..MAX_RETRIES {
-            debug!(retry.current = i + 1, retry.max = MAX_RETRIES, "retrying");
-
-            // Consume the operational data by attempting to send its messages
-            match self.send_from_operational_data::<S>(&odata) {
-                Ok(reply) => {
-                    // Done with this op. data
-                    info!("submitted");
-
-                    telemetry!({
-                        let (chain, counterparty, channel_id, port_id) =
-                            self.target_info(odata.target);
-
-                        ibc_telemetry::global().tx_submitted(
-                            reply.len(),
-                            odata.tracking_id,
-                            &chain,
-                            channel_id,
-                            port_id,
-                            &counterparty,
-                        );
-                    });
+        let _span = span!(Level::INFO, "relay", odata = %odata.info()).entered();
 
-                    return Ok(reply);
-                }
-                Err(LinkError(error::LinkErrorDetail::Send(e), _)) => {
-                    // This error means we could retry
-                    error!("error {}", e.event);
-                    if i + 1 == MAX_RETRIES {
-                        error!("{}/{} retries exhausted. giving up", i + 1, MAX_RETRIES)
-                    } else {
-                        // If we haven't exhausted all retries, regenerate the op. data & retry
-                        match self.regenerate_operational_data(odata.clone()) {
-                            None => return Ok(S::Reply::empty()), // Nothing to retry
-                            Some(new_od) => odata = new_od,
-                        }
-                    }
-                }
-                Err(e) => {
-                    // Unrecoverable error, propagate up the stack
+        let retry = odata.retries();
+
+        debug!(retry.current = retry, "sending");
+
+        // Consume the operational data by attempting to send its messages
+        let e = match self.send_from_operational_data::<S>(&odata) {
+            Ok(reply) => {
+                // Done with this op. data
+                info!("submitted");
+
+                self.publish_relay_event(RelayEventKind::MessagesSubmitted, &odata, None);
+
+                telemetry!({
+                    let (chain, counterparty, channel_id, port_id) = self.target_info(odata.target);
+
+                    ibc_telemetry::global().tx_submitted(
+                        reply.len(),
+                        odata.tracking_id,
+                        &chain,
+                        channel_id,
+                        port_id,
+                        &counterparty,
+                    );
+                });
+
+                return Ok(reply);
+            }
+            Err(e) => e,
+        };
+
+        let category = e.category();
+
+        // Errors which were not raised by the chain when submitting
+        // the messages are unrecoverable, propagate them up the stack
+        let is_chain_error = matches!(e.detail(), error::LinkErrorDetail::Send(_));
+        if !is_chain_error && category == ErrorCategory::Other {
+            self.publish_relay_event(RelayEventKind::Error, &odata, Some(&e));
+            return Err(e);
+        }
+
+        error!(%category, "failed to submit messages: {}", e);
+
+        let policy = self.retry_policy(odata.target, category)?;
+
+        let delay = match policy.delay(retry) {
+            Some(delay) => delay,
+            None => {
+                error!(
+                    %category,
+                    "{}/{} retries exhausted. giving up",
+                    retry, policy.max_retries
+                );
+
+                self.publish_relay_event(RelayEventKind::Error, &odata, Some(&e));
+
+                if !is_chain_error {
                     return Err(e);
                 }
+
+                self.watch_for_expiry(&odata);
+
+                return Ok(S::Reply::empty());
+            }
+        };
+
+        debug!(
+            %category,
+            retry.current = retry + 1,
+            retry.max = policy.max_retries,
+            "retrying in {:?}",
+            delay
+        );
+
+        // Regenerate the op. data & schedule it for retry once the delay elapsed
+        if let Some(mut new_od) = self.regenerate_operational_data(odata) {
+            new_od.set_retry(retry + 1, delay);
+
+            if let Err(e) = self.schedule_operational_data(new_od) {
+                error!("failed to schedule the retry of the operational data: {e}");
             }
         }
 
         Ok(S::Reply::empty())
     }
 
+    /// Publishes the packets to relay among the given events to the event sink, if it is enabled.
+    fn publish_observed_packets(&self, events: &TrackedEvents) {
+        if !event_sink::is_enabled(&self.profile, RelayEventKind::PacketObserved) {
+            return;
+        }
+
+        let sequences = events
+            .events()
+            .iter()
+            .filter_map(|event_with_height| event_with_height.event.packet())
+            .map(|packet| packet.sequence)
+            .collect::<Vec<_>>();
+
+        if sequences.is_empty() {
+            return;
+        }
+
+        let event = RelayEvent::new(
+            RelayEventKind::PacketObserved,
+            events.tracking_id(),
+            self.src_chain().id(),
+            self.dst_chain().id(),
+            self.src_port_id().clone(),
+            self.src_channel_id().clone(),
+        )
+        .with_sequences(sequences);
+
+        event_sink::publish(&self.profile, event);
+    }
+
+    /// Publishes the relay action on the given operational data
+    /// to the event sink, if it is enabled.
+    fn publish_relay_event(
+        &self,
+        kind: RelayEventKind,
+        odata: &OperationalData,
+        error: Option<&LinkError>,
+    ) {
+        if !event_sink::is_enabled(&self.profile, kind) {
+            return;
+        }
+
+        let (chain, counterparty, channel_id, port_id) = self.target_info(odata.target);
+
+        let event = RelayEvent::new(
+            kind,
+            odata.tracking_id,
+            chain,
+            counterparty,
+            port_id.clone(),
+            channel_id.clone(),
+        )
+        .with_sequences(odata.sequences());
+
+        let event = match error {
+            Some(e) => event.with_error(e),
+            None => event,
+        };
+
+        event_sink::publish(&self.profile, event);
+    }
+
+    /// The policy for retrying the submission of messages to the target
+    /// chain, when it failed with an error of the given category.
+    fn retry_policy(
+        &self,
+        target: OperationalDataTarget,
+        category: ErrorCategory,
+    ) -> Result<RetryPolicy, LinkError> {
+        let config = match target {
+            OperationalDataTarget::Source => self.src_chain().config(),
+            OperationalDataTarget::Destination => self.dst_chain().config(),
+        }
+        .map_err(LinkError::relayer)?;
+
+        Ok(*config.retry.policy(category))
+    }
+
+    /// Checks the size of the memo of the given packet against the maximum size
+    /// configured for the source chain, and returns whether the packet must be skipped.
+    fn skip_oversized_memo(&self, config: &PacketMemoConfig, packet: &Packet) -> bool {
+        let Some(size) = config.oversized_memo(&packet.data) else {
+            return false;
+        };
+
+        warn!(
+            packet = %packet,
+            memo_size = size,
+            max_size = ?config.max_size,
+            policy = config.policy.as_str(),
+            "packet memo exceeds the maximum size"
+        );
+
+        telemetry!(
+            oversized_memo_packet,
+            &self.src_chain().id(),
+            self.src_channel_id(),
+            self.src_port_id(),
+            &self.dst_chain().id(),
+            config.policy.as_str()
+        );
+
+        config.policy == PacketMemoPolicy::Skip
+    }
+
     /// Generates fresh operational data for a tx given the initial operational data
     /// that failed to send.
     ///
@@ -790,6 +1007,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
 
         let msgs = odata.assemble_msgs(self)?;
+        let msgs = msgs.with_limits(self.batch_limits(odata.target)?);
 
         match odata.target {
             OperationalDataTarget::Source => S::submit(self.src_chain(), msgs),
@@ -797,6 +1015,30 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// The limits of the batches of messages sent to the target chain for the channel
+    /// of this path, as overridden in the config of the target chain.
+    fn batch_limits(&self, target: OperationalDataTarget) -> Result<BatchLimits, LinkError> {
+        let (config, port_id, channel_id) = match target {
+            OperationalDataTarget::Source => (
+                self.src_chain().config(),
+                self.src_port_id(),
+                self.src_channel_id(),
+            ),
+            OperationalDataTarget::Destination => (
+                self.dst_chain().config(),
+                self.dst_port_id(),
+                self.dst_channel_id(),
+            ),
+        };
+
+        let config = config.map_err(LinkError::relayer)?;
+
+        Ok(config
+            .channel_batch(port_id, channel_id)
+            .map(|channel_batch| channel_batch.limits())
+            .unwrap_or_default())
+    }
+
     fn enqueue_pending_tx(&self, reply: AsyncReply, odata: OperationalData) {
         if !self.confirm_txes {
             return;
@@ -1134,8 +1376,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         ) {
             // Update telemetry info
             telemetry!({
+                let mut block_times = HashMap::new();
+
                 for event_with_height in events_chunk.iter() {
-                    self.record_cleared_send_packet(event_with_height);
+                    self.record_cleared_send_packet(event_with_height, &mut block_times);
                 }
             });
 
@@ -1166,7 +1410,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             unreceived_acknowledgements(self.dst_chain(), self.src_chain(), &self.path_id)
                 .map_err(LinkError::supervisor)?;
 
-        let Some((sequences, src_response_height)) = sequences_and_height else { return Ok(()) };
+        let Some((sequences, src_response_height)) = sequences_and_height else {
+            return Ok(());
+        };
 
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
@@ -1224,6 +1470,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> Result<Option<Any>, LinkError> {
         let packet = event.packet.clone();
 
+        if let Some(TransferAcknowledgement::Error(e)) = event.transfer_ack() {
+            warn!(
+                sequence = %packet.sequence,
+                "transfer failed on the destination chain, relaying error acknowledgement: {e}"
+            );
+        }
+
         let proofs = self
             .src_chain()
             .build_packet_proofs(
@@ -1364,18 +1617,45 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Builds the timeout message for the packet if it has expired, or else the
+    /// receive message, unless the packet carries fungible token packet data
+    /// which the destination chain would reject, or a memo larger than allowed
+    /// by the given config.
+    ///
+    /// The packet data is only validated if the bech32 prefix of the accounts
+    /// on the destination chain is given.
+    ///
+    /// The packets which are skipped are still timed out once they expire.
     fn build_recv_or_timeout_from_send_packet_event(
         &self,
         event: &SendPacket,
         dst_info: &ChainStatus,
         height: Height,
+        receiver_prefix: Option<&str>,
+        packet_memo: &PacketMemoConfig,
     ) -> Result<(Option<Any>, Option<Any>), LinkError> {
         let timeout = self.build_timeout_from_send_packet_event(event, dst_info)?;
         if timeout.is_some() {
-            Ok((None, timeout))
-        } else {
-            Ok((self.build_recv_packet(&event.packet, height)?, None))
+            return Ok((None, timeout));
         }
+
+        if let Some(receiver_prefix) = receiver_prefix {
+            if let Err(e) = validate_packet_data(&event.packet.data, receiver_prefix) {
+                warn!(
+                    packet = %event.packet,
+                    "skipping packet which would be rejected by the destination chain: {}",
+                    e
+                );
+
+                return Ok((None, None));
+            }
+        }
+
+        if self.skip_oversized_memo(packet_memo, &event.packet) {
+            return Ok((None, None));
+        }
+
+        Ok((self.build_recv_packet(&event.packet, height)?, None))
     }
 
     /// Drives the relaying of elapsed operational data items meant for
@@ -1436,6 +1716,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             // The relaying process failed; return all of the subsequent pieces of operational
                             // data along with the underlying error that occurred.
                             Err(e) => {
+                                self.watch_for_expiry(&od);
                                 unprocessed.extend(operations);
 
                                 return Err((unprocessed, e));
@@ -1470,26 +1751,37 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ///
     /// Any operational data items that do not get successfully relayed are
     /// dropped. Subsequent pending operational data items that went unprocessed
-    /// are queued up again for re-submission.
+    /// are queued up again for re-submission, ahead of the operational data
+    /// scheduled in the meantime, eg. for retrying a failed submission.
     pub fn execute_schedule(&mut self) -> Result<(), LinkError> {
         let src_od_iter = self.src_operational_data.take().into_iter();
 
-        match self.execute_schedule_for_target_chain(src_od_iter, OperationalDataTarget::Source) {
-            Ok(unprocessed_src_data) => self.src_operational_data = unprocessed_src_data.into(),
+        let result =
+            self.execute_schedule_for_target_chain(src_od_iter, OperationalDataTarget::Source);
+
+        match result {
+            Ok(unprocessed_src_data) => {
+                requeue(&self.src_operational_data, unprocessed_src_data);
+            }
             Err((unprocessed_src_data, e)) => {
-                self.src_operational_data = unprocessed_src_data.into();
+                requeue(&self.src_operational_data, unprocessed_src_data);
                 return Err(e);
             }
         }
 
-        let dst_od_iter = self.dst_operational_data.take().into_iter();
+        let dst_od_iter = self
+            .prioritize_by_timeout(self.dst_operational_data.take())
+            .into_iter();
 
-        match self
-            .execute_schedule_for_target_chain(dst_od_iter, OperationalDataTarget::Destination)
-        {
-            Ok(unprocessed_dst_data) => self.dst_operational_data = unprocessed_dst_data.into(),
+        let result =
+            self.execute_schedule_for_target_chain(dst_od_iter, OperationalDataTarget::Destination);
+
+        match result {
+            Ok(unprocessed_dst_data) => {
+                requeue(&self.dst_operational_data, unprocessed_dst_data);
+            }
             Err((unprocessed_dst_data, e)) => {
-                self.dst_operational_data = unprocessed_dst_data.into();
+                requeue(&self.dst_operational_data, unprocessed_dst_data);
                 return Err(e);
             }
         }
@@ -1497,6 +1789,42 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Orders the operational data targeting the destination chain so that the batches
+    /// holding the packets closest to timing out are relayed first, which matters most
+    /// when throughput is constrained and the queue builds up.
+    ///
+    /// The order is left untouched for ordered channels, on which packets must be
+    /// relayed in sequence order, and when the destination chain status cannot be queried.
+    ///
+    /// The status of the destination chain is served from the cache of its handle when
+    /// relaying with `hermes start`, and its maximum block time is only read once from its
+    /// configuration, so this does not query the chain on every execution of the schedule.
+    fn prioritize_by_timeout(&self, odata: VecDeque<OperationalData>) -> VecDeque<OperationalData> {
+        if self.ordered_channel() || odata.len() < 2 {
+            return odata;
+        }
+
+        let dst_status = match self.dst_chain().query_application_status() {
+            Ok(status) => status,
+            Err(e) => {
+                debug!("failed to query destination chain status, keeping the schedule order: {e}");
+                return odata;
+            }
+        };
+
+        let max_block_time = match self.dst_max_block_time() {
+            Ok(max_block_time) => max_block_time,
+            Err(e) => {
+                debug!(
+                    "failed to query destination chain block time, keeping the schedule order: {e}"
+                );
+                return odata;
+            }
+        };
+
+        sort_by_timeout_proximity(odata, &dst_status, max_block_time)
+    }
+
     /// Kicks off the process of relaying pending txs to the source and destination chains.
     ///
     /// See [`Resubmit::from_clear_interval`] for more info about the `resubmit` parameter.
@@ -1561,8 +1889,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     pub fn refresh_schedule(&self) -> Result<(), LinkError> {
         let _span = span!(Level::ERROR, "refresh_schedule").entered();
 
-        // Bail fast if no op. data to refresh
-        if self.dst_operational_data.is_empty() {
+        // Bail fast if no op. data to refresh and no packet is watched for expiry
+        if self.dst_operational_data.is_empty() && self.expiring_packets.acquire_read().is_empty() {
             return Ok(());
         }
 
@@ -1571,6 +1899,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .query_application_status()
             .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
 
+        self.schedule_expired_timeouts(&dst_status);
+
+        if self.dst_operational_data.is_empty() {
+            return Ok(());
+        }
+
         let dst_current_height = dst_status.height;
 
         // Intermediary data struct to help better manage the transfer from dst. operational data
@@ -1662,6 +1996,99 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Keeps track of the `SendPacket` events in an operational data targeting the destination
+    /// chain which could not be delivered, so that they can be timed out as soon as they expire.
+    fn watch_for_expiry(&self, odata: &OperationalData) {
+        if odata.target != OperationalDataTarget::Destination {
+            return;
+        }
+
+        let mut watched = self.expiring_packets.acquire_write();
+
+        for msg in odata.batch.iter() {
+            let IbcEvent::SendPacket(ref event) = msg.event_with_height.event else {
+                continue;
+            };
+
+            if watched.watch(msg.event_with_height.clone()) {
+                debug!(
+                    sequence = %event.packet.sequence,
+                    "packet could not be delivered, watching it for expiry"
+                );
+            }
+        }
+    }
+
+    /// Schedules timeout messages for the packets which could not be delivered to the destination
+    /// chain and which have expired at the given status of the destination chain, so that the
+    /// timeouts are submitted in the first block following the expiry instead of waiting for the
+    /// next packet clearing.
+    ///
+    /// A proof of non-receipt can only be obtained at a height past the expiry of the packet,
+    /// so the timeout messages are built at the latest height of the destination chain as soon as
+    /// it reaches it. Packets which have since been received or timed out are no longer watched.
+    ///
+    /// Failures are not fatal to the refresh of the schedule: the packets stay watched, and are
+    /// checked again at the next refresh.
+    fn schedule_expired_timeouts(&self, dst_status: &ChainStatus) {
+        let mut timeouts = OperationalData::new(
+            dst_status.height,
+            OperationalDataTarget::Source,
+            TrackingId::new_uuid(),
+            self.channel.connection_delay,
+        );
+
+        self.expiring_packets.acquire_write().schedule_expired(
+            &mut timeouts,
+            self.unordered_channel(),
+            dst_status,
+            |event| {
+                let timeout = self.send_packet_event_handled(event).and_then(|handled| {
+                    if handled {
+                        Ok(None)
+                    } else {
+                        self.build_timeout_from_send_packet_event(event, dst_status)
+                            .map(Some)
+                    }
+                });
+
+                match timeout {
+                    Ok(None) => ExpiryCheck::Handled,
+                    Ok(Some(Some(msg))) => ExpiryCheck::Expired(msg),
+                    Ok(Some(None)) => ExpiryCheck::Pending,
+                    Err(e) => {
+                        warn!(
+                            sequence = %event.packet.sequence,
+                            "failed to check packet for expiry, will retry: {e}"
+                        );
+
+                        ExpiryCheck::Pending
+                    }
+                }
+            },
+        );
+
+        if timeouts.batch.is_empty() {
+            return;
+        }
+
+        let expired: Vec<_> = timeouts
+            .batch
+            .iter()
+            .map(|msg| msg.event_with_height.clone())
+            .collect();
+
+        if let Err(e) = self.schedule_operational_data(timeouts) {
+            warn!("failed to schedule the timeouts of the expired packets, will retry: {e}");
+
+            let mut watched = self.expiring_packets.acquire_write();
+
+            for event_with_height in expired {
+                watched.watch(event_with_height);
+            }
+        }
+    }
+
     /// Adds a new operational data item for this relaying path to process later.
     /// If the relaying path has non-zero packet delays, this method also updates the client on the
     /// target chain with the appropriate headers.
@@ -1774,7 +2201,6 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     // we need fully qualified ChainId to avoid unneeded imports warnings
-    #[cfg(feature = "telemetry")]
     fn target_info(
         &self,
         target: OperationalDataTarget,
@@ -1800,9 +2226,38 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// The time of the block of the source chain at the given height, eg. the block
+    /// in which a packet was sent, queried once per height and kept in `block_times`.
+    /// Falls back to the current time if the block time cannot be queried.
+    #[cfg(feature = "telemetry")]
+    fn src_block_time(
+        &self,
+        height: Height,
+        block_times: &mut HashMap<Height, std::time::SystemTime>,
+    ) -> std::time::SystemTime {
+        *block_times.entry(height).or_insert_with(|| {
+            self.src_chain()
+                .query_host_consensus_state(QueryHostConsensusStateRequest {
+                    height: QueryHeight::Specific(height),
+                })
+                .map(|consensus_state| {
+                    std::time::SystemTime::UNIX_EPOCH
+                        + Duration::from_nanos(consensus_state.timestamp().nanoseconds())
+                })
+                .unwrap_or_else(|e| {
+                    debug!(%height, "failed to query the time of the block, using the current time: {e}");
+                    std::time::SystemTime::now()
+                })
+        })
+    }
+
     #[cfg(feature = "telemetry")]
-    fn backlog_update(&self, event: &IbcEvent) {
-        match event {
+    fn backlog_update(
+        &self,
+        event_with_height: &IbcEventWithHeight,
+        block_times: &mut HashMap<Height, std::time::SystemTime>,
+    ) {
+        match &event_with_height.event {
             IbcEvent::SendPacket(send_packet_ev) => {
                 ibc_telemetry::global().backlog_insert(
                     send_packet_ev.packet.sequence.into(),
@@ -1811,6 +2266,15 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     self.src_port_id(),
                     &self.dst_chain().id(),
                 );
+                ibc_telemetry::global().packet_sent(
+                    send_packet_ev.packet.sequence.into(),
+                    send_packet_ev.packet.data.len(),
+                    self.src_block_time(event_with_height.height, block_times),
+                    &self.src_chain().id(),
+                    self.src_channel_id(),
+                    self.src_port_id(),
+                    &self.dst_chain().id(),
+                );
             }
             IbcEvent::WriteAcknowledgement(write_ack_ev) => {
                 ibc_telemetry::global().backlog_remove(
@@ -1820,6 +2284,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     self.dst_port_id(),
                     &self.src_chain().id(),
                 );
+                ibc_telemetry::global().packet_acknowledged(
+                    write_ack_ev.packet.sequence.into(),
+                    &self.dst_chain().id(),
+                    self.dst_channel_id(),
+                    self.dst_port_id(),
+                    &self.src_chain().id(),
+                );
             }
             IbcEvent::TimeoutPacket(timeout_packet) => {
                 ibc_telemetry::global().backlog_remove(
@@ -1829,13 +2300,23 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     self.src_port_id(),
                     &self.dst_chain().id(),
                 );
+                ibc_telemetry::global().packet_timed_out(
+                    timeout_packet.packet.sequence.into(),
+                    &self.src_chain().id(),
+                    self.src_channel_id(),
+                    self.src_port_id(),
+                );
             }
             _ => {}
         }
     }
 
     #[cfg(feature = "telemetry")]
-    fn record_cleared_send_packet(&self, event_with_height: &IbcEventWithHeight) {
+    fn record_cleared_send_packet(
+        &self,
+        event_with_height: &IbcEventWithHeight,
+        block_times: &mut HashMap<Height, std::time::SystemTime>,
+    ) {
         if let IbcEvent::SendPacket(send_packet_ev) = &event_with_height.event {
             ibc_telemetry::global().send_packet_events(
                 send_packet_ev.packet.sequence.into(),
@@ -1853,6 +2334,15 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 self.src_port_id(),
                 &self.dst_chain().id(),
             );
+            ibc_telemetry::global().packet_sent(
+                send_packet_ev.packet.sequence.into(),
+                send_packet_ev.packet.data.len(),
+                self.src_block_time(event_with_height.height, block_times),
+                &self.src_chain().id(),
+                self.src_channel_id(),
+                self.src_port_id(),
+                &self.dst_chain().id(),
+            );
         }
     }
 
@@ -1875,3 +2365,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 }
+
+/// Puts the given unprocessed operational data back in the queue, ahead of the
+/// operational data scheduled in the meantime.
+fn requeue(queue: &Queue<OperationalData>, mut unprocessed: VecDeque<OperationalData>) {
+    unprocessed.extend(queue.take());
+    queue.replace(unprocessed);
+}
@@ -1,4 +1,9 @@
-use ibc_proto::{google::protobuf::Any, protobuf::Protobuf};
+#[cfg(test)]
+use ibc_proto::ibc::mock::Misbehaviour as RawMockMisbehaviour;
+use ibc_proto::{
+    google::protobuf::Any, ibc::lightclients::tendermint::v1::Misbehaviour as RawTmMisbehaviour,
+    protobuf::Protobuf,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
@@ -11,8 +16,10 @@ use ibc_relayer_types::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
 #[cfg(test)]
 use ibc_relayer_types::mock::misbehaviour::MOCK_MISBEHAVIOUR_TYPE_URL;
 use ibc_relayer_types::Height;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
+use crate::light_client::registry::client_registry;
 use crate::light_client::AnyHeader;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -28,6 +35,11 @@ pub enum AnyMisbehaviour {
 
     #[cfg(test)]
     Mock(MockMisbehaviour),
+
+    /// A misbehaviour of a client type registered with
+    /// [`register_client_handler`](crate::light_client::registry::register_client_handler).
+    #[serde(skip_deserializing)]
+    Other(Box<dyn Misbehaviour>),
 }
 
 impl Misbehaviour for AnyMisbehaviour {
@@ -37,6 +49,8 @@ impl Misbehaviour for AnyMisbehaviour {
 
             #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.client_id(),
+
+            Self::Other(misbehaviour) => misbehaviour.client_id(),
         }
     }
 
@@ -46,6 +60,8 @@ impl Misbehaviour for AnyMisbehaviour {
 
             #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.height(),
+
+            Self::Other(misbehaviour) => misbehaviour.height(),
         }
     }
 }
@@ -56,18 +72,7 @@ impl TryFrom<Any> for AnyMisbehaviour {
     type Error = Error;
 
     fn try_from(raw: Any) -> Result<Self, Error> {
-        match raw.type_url.as_str() {
-            TENDERMINT_MISBEHAVIOR_TYPE_URL => Ok(AnyMisbehaviour::Tendermint(
-                TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
-            )),
-
-            #[cfg(test)]
-            MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
-                MockMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
-            )),
-
-            _ => Err(Error::unknown_misbehaviour_type(raw.type_url)),
-        }
+        client_registry().decode_misbehaviour(&raw)
     }
 }
 
@@ -76,14 +81,19 @@ impl From<AnyMisbehaviour> for Any {
         match value {
             AnyMisbehaviour::Tendermint(misbehaviour) => Any {
                 type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
-                value: misbehaviour.encode_vec(),
+                value: Protobuf::<RawTmMisbehaviour>::encode_vec(&misbehaviour),
             },
 
             #[cfg(test)]
             AnyMisbehaviour::Mock(misbehaviour) => Any {
                 type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
-                value: misbehaviour.encode_vec(),
+                value: Protobuf::<RawMockMisbehaviour>::encode_vec(&misbehaviour),
             },
+
+            AnyMisbehaviour::Other(misbehaviour) => {
+                Any::decode(misbehaviour.encode_vec().as_slice())
+                    .expect("a misbehaviour is encoded as an `Any`")
+            }
         }
     }
 }
@@ -95,6 +105,8 @@ impl core::fmt::Display for AnyMisbehaviour {
 
             #[cfg(test)]
             AnyMisbehaviour::Mock(mock) => write!(f, "{mock:?}"),
+
+            AnyMisbehaviour::Other(misbehaviour) => write!(f, "{misbehaviour:?}"),
         }
     }
 }
@@ -89,6 +89,22 @@ impl<Chain: ChainHandle> Registry<Chain> {
             }
         }
     }
+
+    /// Sets the name of the key of the given chain in the configuration
+    /// its runtime is respawned with, eg. after the key was reloaded.
+    pub fn set_key_name(&mut self, chain_id: &ChainId, key_name: String) {
+        if let Some(chain_config) = self.config.find_chain_mut(chain_id) {
+            chain_config.key_name = key_name;
+        }
+    }
+
+    /// Follows an upgrade of the given chain to the given identifier, by shutting down
+    /// the runtime of the chain, so that it is respawned for the new identifier.
+    pub fn follow_chain_upgrade(&mut self, chain_id: &ChainId, upgraded_id: &ChainId) {
+        if self.config.follow_chain_upgrade(chain_id, upgraded_id) {
+            self.shutdown(chain_id);
+        }
+    }
 }
 
 impl<Chain: ChainHandle> SharedRegistry<Chain> {
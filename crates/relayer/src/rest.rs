@@ -1,13 +1,19 @@
 use crossbeam_channel::TryRecvError;
-use tracing::{error, trace};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::signer::Signer;
+use tracing::{error, trace, warn};
 
 use crate::{
-    config::Config,
+    config::{Config, RestConfig},
+    object::Client,
+    rest::dashboard::DashboardState,
     rest::request::ReplySender,
     rest::request::{Request, VersionInfo},
+    submitter::constant_time_eq,
     supervisor::dump_state::SupervisorState,
 };
 
+pub mod dashboard;
 pub mod request;
 
 mod error;
@@ -31,6 +37,17 @@ pub type Receiver = crossbeam_channel::Receiver<Request>;
 //  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
+    Dashboard(ReplySender<DashboardState>),
+    ReloadKey {
+        chain_id: ChainId,
+        key_name: Option<String>,
+        reply_to: ReplySender<Signer>,
+    },
+    AcknowledgeClient {
+        chain_id: ChainId,
+        client_id: ClientId,
+        reply_to: ReplySender<Client>,
+    },
 }
 
 /// Process incoming REST requests.
@@ -82,6 +99,67 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::DumpState(reply_to));
             }
+
+            Request::Dashboard { reply_to } => {
+                trace!("Dashboard");
+
+                return Some(Command::Dashboard(reply_to));
+            }
+
+            Request::ReloadKey {
+                chain_id,
+                key_name,
+                token,
+                reply_to,
+            } => {
+                trace!("ReloadKey {}", chain_id);
+
+                match authorize_key_reload(&config.rest, token.as_deref()) {
+                    Ok(()) => {
+                        return Some(Command::ReloadKey {
+                            chain_id,
+                            key_name,
+                            reply_to,
+                        })
+                    }
+                    Err(e) => {
+                        warn!("rejected the request reloading the key of chain {chain_id}: {e}");
+
+                        reply_to
+                            .send(Err(e))
+                            .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+                    }
+                }
+            }
+
+            Request::AcknowledgeClient {
+                chain_id,
+                client_id,
+                token,
+                reply_to,
+            } => {
+                trace!("AcknowledgeClient {} {}", chain_id, client_id);
+
+                match authorize_token(&config.rest, token.as_deref()) {
+                    Ok(()) => {
+                        return Some(Command::AcknowledgeClient {
+                            chain_id,
+                            client_id,
+                            reply_to,
+                        })
+                    }
+                    Err(e) => {
+                        warn!(
+                            "rejected the request acknowledging client {client_id} \
+                            on chain {chain_id}: {e}"
+                        );
+
+                        reply_to
+                            .send(Err(e))
+                            .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+                    }
+                }
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
@@ -92,3 +170,125 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
     None
 }
+
+/// Checks that the keys can be reloaded through the REST API, and that the request
+/// carries the token held in the `token_file` of the REST server, which is required.
+fn authorize_key_reload(config: &RestConfig, token: Option<&str>) -> Result<(), RestApiError> {
+    if !config.allow_key_reload {
+        return Err(RestApiError::KeyReloadDisabled);
+    }
+
+    authorize_token(config, token)
+}
+
+/// Checks that the request carries the token held in the `token_file`
+/// of the REST server, which is required.
+fn authorize_token(config: &RestConfig, token: Option<&str>) -> Result<(), RestApiError> {
+    let Some(token_file) = &config.token_file else {
+        return Err(RestApiError::Unauthorized(
+            "no `token_file` is set in the `[rest]` section to authenticate the request".to_owned(),
+        ));
+    };
+
+    let expected = std::fs::read_to_string(token_file).map_err(|e| {
+        RestApiError::Unauthorized(format!(
+            "failed to read the REST token from {}: {e}",
+            token_file.display()
+        ))
+    })?;
+
+    let expected = expected.trim();
+
+    match token {
+        Some(token)
+            if !expected.is_empty() && constant_time_eq(token.as_bytes(), expected.as_bytes()) =>
+        {
+            Ok(())
+        }
+        _ => Err(RestApiError::Unauthorized(
+            "missing or invalid REST token".to_owned(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    fn rest_config(allow_key_reload: bool, token_file: Option<PathBuf>) -> RestConfig {
+        RestConfig {
+            allow_key_reload,
+            token_file,
+            ..RestConfig::default()
+        }
+    }
+
+    #[test]
+    fn key_reload_is_disabled_by_default() {
+        assert!(matches!(
+            authorize_key_reload(&RestConfig::default(), None),
+            Err(RestApiError::KeyReloadDisabled)
+        ));
+
+        // Without a token to check, the key reloads are never authorized
+        assert!(matches!(
+            authorize_key_reload(&rest_config(true, None), None),
+            Err(RestApiError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn key_reload_requires_the_token() {
+        let token_file =
+            std::env::temp_dir().join(format!("hermes-rest-token-{}", std::process::id()));
+
+        std::fs::write(&token_file, "s3cr3t\n").unwrap();
+
+        let config = rest_config(true, Some(token_file.clone()));
+
+        assert!(authorize_key_reload(&config, Some("s3cr3t")).is_ok());
+
+        for token in [None, Some(""), Some("s3cr3"), Some("wrong!")] {
+            assert!(matches!(
+                authorize_key_reload(&config, token),
+                Err(RestApiError::Unauthorized(_))
+            ));
+        }
+
+        // Even with the right token, the key reloads must be allowed
+        assert!(matches!(
+            authorize_key_reload(
+                &rest_config(false, Some(token_file.clone())),
+                Some("s3cr3t")
+            ),
+            Err(RestApiError::KeyReloadDisabled)
+        ));
+
+        std::fs::remove_file(token_file).unwrap();
+    }
+
+    #[test]
+    fn acknowledging_clients_requires_the_token() {
+        let token_file =
+            std::env::temp_dir().join(format!("hermes-rest-ack-token-{}", std::process::id()));
+
+        std::fs::write(&token_file, "s3cr3t\n").unwrap();
+
+        // The token is required even though the keys cannot be reloaded
+        let config = rest_config(false, Some(token_file.clone()));
+
+        assert!(authorize_token(&config, Some("s3cr3t")).is_ok());
+        assert!(matches!(
+            authorize_token(&config, None),
+            Err(RestApiError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            authorize_token(&rest_config(false, None), Some("s3cr3t")),
+            Err(RestApiError::Unauthorized(_))
+        ));
+
+        std::fs::remove_file(token_file).unwrap();
+    }
+}
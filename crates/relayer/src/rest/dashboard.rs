@@ -0,0 +1,166 @@
+//! The state of the relayer shown by the dashboard of the REST server.
+
+use alloc::collections::VecDeque;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::Height;
+
+use crate::event_sink::RelayEvent;
+
+/// The number of recent errors kept in memory
+const RECENT_ERRORS_CAPACITY: usize = 50;
+
+static RECENT_ERRORS: Lazy<Mutex<VecDeque<RelayEvent>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(RECENT_ERRORS_CAPACITY)));
+
+/// The health of a chain, as seen from its full node.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChainHealth {
+    pub chain_id: ChainId,
+    /// The latest height of the chain, if its full node could be reached
+    pub latest_height: Option<Height>,
+    /// The error raised when querying the status of the chain, if any
+    pub error: Option<String>,
+    /// The number of workers relaying for this chain
+    pub workers: usize,
+}
+
+/// The packets sent on a channel which have not been relayed yet.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChannelBacklog {
+    pub chain_id: String,
+    pub counterparty_chain_id: String,
+    pub port_id: String,
+    pub channel_id: String,
+    pub size: u64,
+    pub oldest_sequence: u64,
+    /// The time at which the oldest packet was observed, in seconds since the Unix epoch
+    pub oldest_timestamp: u64,
+}
+
+/// The balance of a wallet used by Hermes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletBalance {
+    pub chain_id: String,
+    pub account: String,
+    pub denom: String,
+    pub amount: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DashboardState {
+    pub chains: Vec<ChainHealth>,
+    /// The backlogs are only available when Hermes is built with telemetry support
+    pub backlogs: Vec<ChannelBacklog>,
+    /// The balances are only available when Hermes is built with telemetry support
+    pub wallets: Vec<WalletBalance>,
+    pub recent_errors: Vec<RelayEvent>,
+}
+
+impl DashboardState {
+    /// Assembles the state of the dashboard from the given health of the chains,
+    /// the telemetry state, and the recent errors of the given profile.
+    pub fn new(chains: Vec<ChainHealth>, profile: Option<&str>) -> Self {
+        Self {
+            chains,
+            backlogs: backlogs(),
+            wallets: wallets(),
+            recent_errors: recent_errors(profile),
+        }
+    }
+}
+
+/// Keeps the given error in memory, to be shown in the dashboard,
+/// forgetting the oldest error once [`RECENT_ERRORS_CAPACITY`] are kept.
+pub fn record_error(event: RelayEvent) {
+    let Ok(mut recent_errors) = RECENT_ERRORS.lock() else {
+        return;
+    };
+
+    if recent_errors.len() == RECENT_ERRORS_CAPACITY {
+        recent_errors.pop_front();
+    }
+
+    recent_errors.push_back(event);
+}
+
+/// The most recent errors of the given profile, or of the main
+/// configuration for `None`, from the most recent to the oldest.
+pub fn recent_errors(profile: Option<&str>) -> Vec<RelayEvent> {
+    RECENT_ERRORS
+        .lock()
+        .map(|recent_errors| {
+            recent_errors
+                .iter()
+                .rev()
+                .filter(|error| error.profile.as_deref() == profile)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "telemetry")]
+fn backlogs() -> Vec<ChannelBacklog> {
+    use alloc::collections::BTreeMap;
+
+    let telemetry = ibc_telemetry::global();
+
+    let oldest_sequences: BTreeMap<_, _> = telemetry
+        .gauge_values("backlog_oldest_sequence")
+        .into_iter()
+        .collect();
+
+    let oldest_timestamps: BTreeMap<_, _> = telemetry
+        .gauge_values("backlog_oldest_timestamp")
+        .into_iter()
+        .collect();
+
+    telemetry
+        .gauge_values("backlog_size")
+        .into_iter()
+        .filter(|(_, size)| *size > 0.0)
+        .map(|(labels, size)| ChannelBacklog {
+            chain_id: label(&labels, "chain"),
+            counterparty_chain_id: label(&labels, "counterparty"),
+            port_id: label(&labels, "port"),
+            channel_id: label(&labels, "channel"),
+            size: size as u64,
+            oldest_sequence: oldest_sequences.get(&labels).copied().unwrap_or_default() as u64,
+            oldest_timestamp: oldest_timestamps.get(&labels).copied().unwrap_or_default() as u64,
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "telemetry"))]
+fn backlogs() -> Vec<ChannelBacklog> {
+    Vec::new()
+}
+
+#[cfg(feature = "telemetry")]
+fn wallets() -> Vec<WalletBalance> {
+    ibc_telemetry::global()
+        .gauge_values("wallet_balance")
+        .into_iter()
+        .map(|(labels, amount)| WalletBalance {
+            chain_id: label(&labels, "chain"),
+            account: label(&labels, "account"),
+            denom: label(&labels, "denom"),
+            amount,
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "telemetry"))]
+fn wallets() -> Vec<WalletBalance> {
+    Vec::new()
+}
+
+#[cfg(feature = "telemetry")]
+fn label(labels: &alloc::collections::BTreeMap<String, String>, name: &str) -> String {
+    labels.get(name).cloned().unwrap_or_default()
+}
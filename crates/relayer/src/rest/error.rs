@@ -1,7 +1,10 @@
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use thiserror::Error;
 
-use ibc_relayer_types::core::ics24_host::{error::ValidationErrorDetail, identifier::ChainId};
+use ibc_relayer_types::core::ics24_host::{
+    error::ValidationErrorDetail,
+    identifier::{ChainId, ClientId},
+};
 
 #[derive(Error, Debug)]
 pub enum RestApiError {
@@ -20,9 +23,24 @@ pub enum RestApiError {
     #[error("failed to parse the string {0} into a valid chain identifier: {1}")]
     InvalidChainId(String, ValidationErrorDetail),
 
+    #[error("failed to parse the string {0} into a valid client identifier: {1}")]
+    InvalidClientId(String, ValidationErrorDetail),
+
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to reload the key of chain {0}: {1}")]
+    KeyReload(ChainId, String),
+
+    #[error("reloading the keys through the REST API is disabled, set `allow_key_reload` in the `[rest]` section of the configuration to enable it")]
+    KeyReloadDisabled,
+
+    #[error("client {1} on chain {0} is not suspect")]
+    ClientNotSuspect(ChainId, ClientId),
+
+    #[error("unauthorized request: {0}")]
+    Unauthorized(String),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -35,7 +53,12 @@ impl RestApiError {
             RestApiError::Serialization(_) => "Serialization",
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
+            RestApiError::InvalidClientId(_, _) => "InvalidClientId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::KeyReload(_, _) => "KeyReload",
+            RestApiError::KeyReloadDisabled => "KeyReloadDisabled",
+            RestApiError::ClientNotSuspect(_, _) => "ClientNotSuspect",
+            RestApiError::Unauthorized(_) => "Unauthorized",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
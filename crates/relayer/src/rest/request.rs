@@ -1,8 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::signer::Signer;
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    config::ChainConfig,
+    object::Client,
+    rest::{dashboard::DashboardState, RestApiError},
+    supervisor::dump_state::SupervisorState,
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -36,4 +42,28 @@ pub enum Request {
         chain_id: ChainId,
         reply_to: ReplySender<ChainConfig>,
     },
+
+    Dashboard {
+        reply_to: ReplySender<DashboardState>,
+    },
+
+    /// Reload the key signing the transactions on a chain,
+    /// optionally switching to the key with the given name.
+    ReloadKey {
+        chain_id: ChainId,
+        key_name: Option<String>,
+        /// The bearer token carried by the request, if any
+        token: Option<String>,
+        reply_to: ReplySender<Signer>,
+    },
+
+    /// Acknowledge that a client marked as suspect, as the height of the chain
+    /// it tracks went backwards, was checked, so that relaying on it resumes.
+    AcknowledgeClient {
+        chain_id: ChainId,
+        client_id: ClientId,
+        /// The bearer token carried by the request, if any
+        token: Option<String>,
+        reply_to: ReplySender<Client>,
+    },
 }
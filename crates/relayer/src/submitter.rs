@@ -0,0 +1,682 @@
+//! Splits the relaying between a process which monitors the chains, queries them and
+//! builds the messages, and a minimal submitter process which holds the keys, signs the
+//! messages it receives over gRPC and submits them to the chains.
+//!
+//! The chains whose config has a [`SubmitterConfig`] send their messages to the
+//! [`RemoteSubmitter`] instead of signing them, while `hermes submitter` serves the
+//! [`SubmitterServer`] for the chains whose keys it holds.
+//!
+//! The relayers authenticate to the submitter with a token shared with it, which is only
+//! ever sent over TLS, and the submitter only signs the messages relaying IBC packets,
+//! registering the fee payees of the relayer and answering cross-chain queries,
+//! see [`ALLOWED_TYPE_URLS`].
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+use core::convert::Infallible;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Mutex;
+
+use http::uri::PathAndQuery;
+use ibc_proto::google::protobuf::Any;
+use once_cell::sync::Lazy;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
+use tendermint_rpc::Scheme;
+use tonic::body::BoxBody;
+use tonic::codec::ProstCodec;
+use tonic::codegen::{empty_body, Service};
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::transport::{
+    Body, Certificate, Channel, ClientTlsConfig, Endpoint, Identity, NamedService, Server,
+    ServerTlsConfig,
+};
+use tonic::{Code, Request, Status};
+use tracing::{error_span, info, warn};
+use uuid::Uuid;
+
+use ibc_relayer_types::applications::ics29_fee::msgs::register_payee::{
+    REGISTER_COUNTERPARTY_PAYEE_TYPE_URL, REGISTER_PAYEE_TYPE_URL,
+};
+use ibc_relayer_types::applications::ics31_icq::response as cross_chain_query_response;
+use ibc_relayer_types::core::ics02_client::msgs::update_client;
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, recv_packet, timeout, timeout_on_close,
+};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::tracking::{TrackedMsgs, TrackingId};
+use crate::config::channel_batch::BatchLimits;
+use crate::config::submitter::SubmitterConfig;
+use crate::config::types::{MaxMsgNum, MaxTxSize};
+use crate::error::{Error, ErrorCategory};
+use crate::event::IbcEventWithHeight;
+
+const SERVICE_NAME: &str = "hermes.submitter.v1.Submitter";
+const SUBMIT_PATH: &str = "/hermes.submitter.v1.Submitter/Submit";
+
+/// The metadata holding the token authenticating the relayer to the submitter.
+const AUTHORIZATION_HEADER: &str = "authorization";
+
+/// The metadata holding the category of the error raised when submitting the messages,
+/// so that the relayer applies the retry policy of that category.
+const ERROR_CATEGORY_HEADER: &str = "hermes-error-category";
+
+/// The type URLs of the only messages the submitter signs, which are those relaying packets,
+/// registering the payees of the fee middleware and answering the cross-chain queries.
+pub const ALLOWED_TYPE_URLS: &[&str] = &[
+    update_client::TYPE_URL,
+    recv_packet::TYPE_URL,
+    acknowledgement::TYPE_URL,
+    timeout::TYPE_URL,
+    timeout_on_close::TYPE_URL,
+    REGISTER_PAYEE_TYPE_URL,
+    REGISTER_COUNTERPARTY_PAYEE_TYPE_URL,
+    cross_chain_query_response::TYPE_URL,
+];
+
+/// The tracking identifier of the submissions whose identifier cannot be forwarded.
+const REMOTE_TRACKING_ID: &str = "remote-submission";
+
+/// The maximum number of distinct static tracking identifiers forwarded by the submitter,
+/// which are interned for the lifetime of the process.
+const MAX_STATIC_TRACKING_IDS: usize = 256;
+
+static STATIC_TRACKING_IDS: Lazy<Mutex<BTreeSet<&'static str>>> =
+    Lazy::new(|| Mutex::new(BTreeSet::new()));
+
+/// Reads the token shared between the relayer and the submitter from the given file.
+pub fn read_token(path: &Path) -> Result<String, Error> {
+    let token = read_file(path, "token")?;
+
+    let token = String::from_utf8(token).map_err(|_| {
+        Error::remote_submitter(format!(
+            "the submitter token in {} is not valid UTF-8",
+            path.display()
+        ))
+    })?;
+
+    let token = token.trim();
+
+    if token.is_empty() {
+        return Err(Error::remote_submitter(format!(
+            "the submitter token in {} is empty",
+            path.display()
+        )));
+    }
+
+    Ok(token.to_owned())
+}
+
+/// Reads the PEM files holding the TLS certificate of the submitter and its private key.
+pub fn read_identity(cert_file: &Path, key_file: &Path) -> Result<Identity, Error> {
+    let cert = read_file(cert_file, "TLS certificate")?;
+    let key = read_file(key_file, "TLS key")?;
+
+    Ok(Identity::from_pem(cert, key))
+}
+
+fn read_file(path: &Path, what: &str) -> Result<Vec<u8>, Error> {
+    std::fs::read(path).map_err(|e| {
+        Error::remote_submitter(format!(
+            "failed to read the submitter {what} from {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// How long the submitter must wait for the messages to be processed by the chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, prost::Enumeration)]
+#[repr(i32)]
+pub enum WaitFor {
+    /// Wait until the transactions are committed, and return the IBC events they emitted
+    Commit = 0,
+    /// Wait until the transactions pass `CheckTx`, and return the responses of the chain
+    CheckTx = 1,
+}
+
+/// The messages to sign and submit to a chain.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmitRequest {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(string, tag = "2")]
+    pub tracking_id: String,
+    #[prost(message, repeated, tag = "3")]
+    pub msgs: Vec<Any>,
+    #[prost(enumeration = "WaitFor", tag = "4")]
+    pub wait_for: i32,
+    /// The maximum number of messages per transaction set for the channel, if any
+    #[prost(uint64, optional, tag = "5")]
+    pub max_msg_num: Option<u64>,
+    /// The maximum size of the transactions set for the channel, if any
+    #[prost(uint64, optional, tag = "6")]
+    pub max_tx_size: Option<u64>,
+}
+
+/// The outcome of the submission, as the JSON encoding of the IBC events
+/// or of the `CheckTx` responses, depending on what was waited for.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmitResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub result: Vec<u8>,
+}
+
+/// The client sending the messages of a chain to a remote submitter.
+#[derive(Clone, Debug)]
+pub struct RemoteSubmitter {
+    grpc: tonic::client::Grpc<Channel>,
+    authorization: MetadataValue<tonic::metadata::Ascii>,
+}
+
+impl RemoteSubmitter {
+    /// Creates a client for the configured submitter, which only connects on the first submission.
+    ///
+    /// The submitter must be served over TLS, so that the token is never sent in the clear.
+    pub fn new(config: &SubmitterConfig) -> Result<Self, Error> {
+        if config.url.scheme() != Scheme::Https {
+            return Err(Error::remote_submitter(format!(
+                "the URL {} of the submitter must use `https`, so that the token is not sent in the clear",
+                config.url
+            )));
+        }
+
+        let mut tls_config = ClientTlsConfig::new();
+
+        if let Some(ca_file) = &config.ca_file {
+            let ca = read_file(ca_file, "CA certificate")?;
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(ca));
+        }
+
+        let endpoint = Endpoint::from_shared(config.url.to_string())
+            .and_then(|endpoint| endpoint.tls_config(tls_config))
+            .map_err(|e| Error::remote_submitter(format!("invalid URL {}: {e}", config.url)))?;
+
+        let token = read_token(&config.token_file)?;
+
+        let authorization = format!("Bearer {token}").parse().map_err(|_| {
+            Error::remote_submitter("the submitter token must be printable ASCII".to_owned())
+        })?;
+
+        Ok(Self {
+            grpc: tonic::client::Grpc::new(endpoint.connect_lazy()),
+            authorization,
+        })
+    }
+
+    pub async fn submit_and_wait_commit(
+        &mut self,
+        chain_id: &ChainId,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let result = self.submit(chain_id, tracked_msgs, WaitFor::Commit).await?;
+
+        serde_json::from_slice(&result)
+            .map_err(|e| Error::remote_submitter(format!("failed to decode the events: {e}")))
+    }
+
+    pub async fn submit_and_wait_check_tx(
+        &mut self,
+        chain_id: &ChainId,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<Response>, Error> {
+        let result = self
+            .submit(chain_id, tracked_msgs, WaitFor::CheckTx)
+            .await?;
+
+        serde_json::from_slice(&result)
+            .map_err(|e| Error::remote_submitter(format!("failed to decode the responses: {e}")))
+    }
+
+    async fn submit(
+        &mut self,
+        chain_id: &ChainId,
+        tracked_msgs: TrackedMsgs,
+        wait_for: WaitFor,
+    ) -> Result<Vec<u8>, Error> {
+        let mut request = Request::new(SubmitRequest {
+            chain_id: chain_id.to_string(),
+            tracking_id: encode_tracking_id(tracked_msgs.tracking_id),
+            msgs: tracked_msgs.msgs,
+            wait_for: wait_for as i32,
+            max_msg_num: tracked_msgs.limits.max_msg_num.map(|n| n.to_usize() as u64),
+            max_tx_size: tracked_msgs.limits.max_tx_size.map(|n| n.to_usize() as u64),
+        });
+
+        request
+            .metadata_mut()
+            .insert(AUTHORIZATION_HEADER, self.authorization.clone());
+
+        self.grpc
+            .ready()
+            .await
+            .map_err(|e| Error::remote_submitter(format!("submitter is not ready: {e}")))?;
+
+        let response = self
+            .grpc
+            .unary::<_, SubmitResponse, _>(
+                request,
+                PathAndQuery::from_static(SUBMIT_PATH),
+                ProstCodec::default(),
+            )
+            .await
+            .map_err(error_from_status)?;
+
+        Ok(response.into_inner().result)
+    }
+}
+
+/// The gRPC service signing and submitting the messages it receives
+/// to the chains whose keys are held by this process.
+pub struct SubmitterServer<Chain> {
+    chains: Arc<BTreeMap<ChainId, Chain>>,
+    token: Arc<String>,
+}
+
+impl<Chain> Clone for SubmitterServer<Chain> {
+    fn clone(&self) -> Self {
+        Self {
+            chains: self.chains.clone(),
+            token: self.token.clone(),
+        }
+    }
+}
+
+impl<Chain> SubmitterServer<Chain> {
+    /// Checks that the request carries the token shared with the relayers.
+    fn authorize(&self, metadata: &MetadataMap) -> Result<(), Status> {
+        let token = metadata
+            .get(AUTHORIZATION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        match token {
+            Some(token) if constant_time_eq(token.as_bytes(), self.token.as_bytes()) => Ok(()),
+            _ => Err(Status::unauthenticated(
+                "missing or invalid submitter token",
+            )),
+        }
+    }
+}
+
+impl<Chain: ChainHandle> SubmitterServer<Chain> {
+    /// Creates a submitter for the given chains, only serving the requests
+    /// authenticated with the given token.
+    pub fn new(chains: BTreeMap<ChainId, Chain>, token: String) -> Self {
+        Self {
+            chains: Arc::new(chains),
+            token: Arc::new(token),
+        }
+    }
+
+    /// Serves the submitter over TLS with the given identity at the given address,
+    /// until the process is stopped.
+    pub async fn serve(self, addr: SocketAddr, identity: Identity) -> Result<(), Error> {
+        info!("submitter listening on {addr}");
+
+        Server::builder()
+            .tls_config(ServerTlsConfig::new().identity(identity))
+            .map_err(|e| Error::remote_submitter(format!("invalid TLS configuration: {e}")))?
+            .add_service(self)
+            .serve(addr)
+            .await
+            .map_err(|e| Error::remote_submitter(format!("failed to serve the submitter: {e}")))
+    }
+
+    fn submit(&self, request: SubmitRequest) -> Result<SubmitResponse, Status> {
+        let chain_id = ChainId::from_string(&request.chain_id);
+
+        let _span = error_span!(
+            "submitter",
+            chain = %chain_id,
+            tracking_id = %request.tracking_id
+        )
+        .entered();
+
+        let chain = self
+            .chains
+            .get(&chain_id)
+            .ok_or_else(|| Status::not_found(format!("no key is held for chain {chain_id}")))?;
+
+        check_allowed_msgs(&request.msgs)?;
+
+        info!("submitting {} messages", request.msgs.len());
+
+        let limits = decode_limits(&request)?;
+
+        let tracked_msgs = TrackedMsgs::new(request.msgs, decode_tracking_id(&request.tracking_id))
+            .with_limits(limits);
+
+        let result = match WaitFor::from_i32(request.wait_for) {
+            Some(WaitFor::Commit) => chain
+                .send_messages_and_wait_commit(tracked_msgs)
+                .map(|events| serde_json::to_vec(&events)),
+            Some(WaitFor::CheckTx) => chain
+                .send_messages_and_wait_check_tx(tracked_msgs)
+                .map(|responses| serde_json::to_vec(&responses)),
+            None => return Err(Status::invalid_argument("unknown value for `wait_for`")),
+        };
+
+        let result = result
+            .map_err(|e| status_from_error(&e))?
+            .map_err(|e| Status::internal(format!("failed to encode the result: {e}")))?;
+
+        Ok(SubmitResponse { result })
+    }
+}
+
+/// Rejects the requests holding any message which is not relaying IBC packets,
+/// so that the key of the submitter cannot be used to eg. transfer its funds.
+fn check_allowed_msgs(msgs: &[Any]) -> Result<(), Status> {
+    let disallowed = msgs
+        .iter()
+        .find(|msg| !ALLOWED_TYPE_URLS.contains(&msg.type_url.as_str()));
+
+    match disallowed {
+        Some(msg) => {
+            warn!(type_url = %msg.type_url, "rejecting a request holding a disallowed message");
+
+            Err(Status::permission_denied(format!(
+                "the submitter does not sign messages of type {}",
+                msg.type_url
+            )))
+        }
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Encodes the tracking identifier of the messages without losing the full UUIDs,
+/// unlike its `Display` implementation.
+fn encode_tracking_id(tracking_id: TrackingId) -> String {
+    match tracking_id {
+        TrackingId::Uuid(uuid) => uuid.to_string(),
+        TrackingId::ClearedUuid(uuid) => format!("cleared/{uuid}"),
+        TrackingId::Static(s) => s.to_owned(),
+    }
+}
+
+fn decode_tracking_id(tracking_id: &str) -> TrackingId {
+    if let Some(uuid) = tracking_id.strip_prefix("cleared/") {
+        if let Ok(uuid) = Uuid::parse_str(uuid) {
+            return TrackingId::ClearedUuid(uuid);
+        }
+    }
+
+    if let Ok(uuid) = Uuid::parse_str(tracking_id) {
+        return TrackingId::Uuid(uuid);
+    }
+
+    TrackingId::Static(intern_static_tracking_id(tracking_id))
+}
+
+/// The limits of the channel the messages were built for, which the submitter applies
+/// on top of the limits of the chain when splitting the messages into transactions.
+fn decode_limits(request: &SubmitRequest) -> Result<BatchLimits, Status> {
+    let max_msg_num = request
+        .max_msg_num
+        .map(|n| MaxMsgNum::new(n as usize))
+        .transpose()
+        .map_err(|e| Status::invalid_argument(format!("invalid `max_msg_num`: {e}")))?;
+
+    let max_tx_size = request
+        .max_tx_size
+        .map(|n| MaxTxSize::new(n as usize))
+        .transpose()
+        .map_err(|e| Status::invalid_argument(format!("invalid `max_tx_size`: {e}")))?;
+
+    Ok(BatchLimits {
+        max_msg_num,
+        max_tx_size,
+    })
+}
+
+/// The static tracking identifiers of the relayer are few, so they are interned
+/// to be forwarded as is, up to a bound after which they are not forwarded anymore.
+fn intern_static_tracking_id(tracking_id: &str) -> &'static str {
+    let mut interned = STATIC_TRACKING_IDS.lock().unwrap();
+
+    if let Some(s) = interned.get(tracking_id) {
+        return s;
+    }
+
+    if interned.len() >= MAX_STATIC_TRACKING_IDS {
+        return REMOTE_TRACKING_ID;
+    }
+
+    let s: &'static str = Box::leak(tracking_id.to_owned().into_boxed_str());
+    interned.insert(s);
+    s
+}
+
+/// Converts the error raised when submitting messages to a status
+/// carrying its category, see [`error_from_status`].
+fn status_from_error(e: &Error) -> Status {
+    let category = e.category();
+
+    let code = match category {
+        ErrorCategory::Network => Code::Unavailable,
+        ErrorCategory::MempoolFull | ErrorCategory::OutOfGas => Code::ResourceExhausted,
+        ErrorCategory::SequenceMismatch => Code::Aborted,
+        ErrorCategory::Permanent => Code::FailedPrecondition,
+        ErrorCategory::Other => Code::Internal,
+    };
+
+    let mut metadata = MetadataMap::new();
+    metadata.insert(
+        ERROR_CATEGORY_HEADER,
+        MetadataValue::from_static(category.as_str()),
+    );
+
+    Status::with_metadata(code, e.to_string(), metadata)
+}
+
+/// Converts the status returned by the submitter back to an error,
+/// of the category of the error raised by the submitter if any.
+fn error_from_status(status: Status) -> Error {
+    let category = status
+        .metadata()
+        .get(ERROR_CATEGORY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(ErrorCategory::from_name);
+
+    match category {
+        Some(category) => Error::remote_submission(category, status.message().to_owned()),
+        None => Error::grpc_status(status, "submit".to_owned()),
+    }
+}
+
+type BoxFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'static>>;
+
+struct SubmitSvc<Chain>(SubmitterServer<Chain>);
+
+impl<Chain: ChainHandle> tonic::server::UnaryService<SubmitRequest> for SubmitSvc<Chain> {
+    type Response = SubmitResponse;
+    type Future = BoxFuture<tonic::Response<Self::Response>, Status>;
+
+    fn call(&mut self, request: Request<SubmitRequest>) -> Self::Future {
+        let server = self.0.clone();
+
+        Box::pin(async move {
+            server.authorize(request.metadata())?;
+
+            // Submitting blocks until the messages are processed by the chain
+            tokio::task::spawn_blocking(move || server.submit(request.into_inner()))
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .map(tonic::Response::new)
+        })
+    }
+}
+
+impl<Chain: ChainHandle> Service<http::Request<Body>> for SubmitterServer<Chain> {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        let server = self.clone();
+
+        match request.uri().path() {
+            SUBMIT_PATH => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(ProstCodec::default());
+                Ok(grpc.unary(SubmitSvc(server), request).await)
+            }),
+            _ => Box::pin(async move {
+                // Reply with the `Unimplemented` gRPC status to unknown methods
+                Ok(http::Response::builder()
+                    .status(200)
+                    .header("grpc-status", "12")
+                    .header("content-type", "application/grpc")
+                    .body(empty_body())
+                    .unwrap())
+            }),
+        }
+    }
+}
+
+impl<Chain> NamedService for SubmitterServer<Chain> {
+    const NAME: &'static str = SERVICE_NAME;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::chain::handle::BaseChainHandle;
+
+    fn server() -> SubmitterServer<BaseChainHandle> {
+        SubmitterServer::new(BTreeMap::new(), "secret".to_owned())
+    }
+
+    fn metadata(authorization: &'static str) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(
+            AUTHORIZATION_HEADER,
+            MetadataValue::from_static(authorization),
+        );
+        metadata
+    }
+
+    fn msg(type_url: &str) -> Any {
+        Any {
+            type_url: type_url.to_owned(),
+            value: vec![],
+        }
+    }
+
+    #[test]
+    fn reject_unauthorized_callers() {
+        let server = server();
+
+        assert!(server.authorize(&metadata("Bearer secret")).is_ok());
+
+        for unauthorized in [
+            MetadataMap::new(),
+            metadata("Bearer wrong"),
+            metadata("Bearer secret2"),
+            metadata("secret"),
+        ] {
+            let status = server.authorize(&unauthorized).unwrap_err();
+            assert_eq!(status.code(), Code::Unauthenticated);
+        }
+    }
+
+    #[test]
+    fn reject_disallowed_msgs() {
+        assert!(check_allowed_msgs(&[
+            msg("/ibc.core.client.v1.MsgUpdateClient"),
+            msg("/ibc.core.channel.v1.MsgRecvPacket"),
+            msg("/ibc.core.channel.v1.MsgAcknowledgement"),
+            msg("/ibc.core.channel.v1.MsgTimeout"),
+            msg("/ibc.core.channel.v1.MsgTimeoutOnClose"),
+            msg("/ibc.applications.fee.v1.MsgRegisterPayee"),
+            msg("/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee"),
+            msg("/stride.interchainquery.v1.MsgSubmitQueryResponse"),
+        ])
+        .is_ok());
+
+        for type_url in [
+            "/ibc.core.client.v1.MsgCreateClient",
+            "/ibc.core.client.v1.MsgUpgradeClient",
+            "/ibc.core.channel.v1.MsgChannelCloseInit",
+            "/ibc.applications.fee.v1.MsgPayPacketFee",
+            "/cosmos.bank.v1beta1.MsgSend",
+            "/ibc.applications.transfer.v1.MsgTransfer",
+            "/ibc.corex.Msg",
+        ] {
+            let status =
+                check_allowed_msgs(&[msg("/ibc.core.channel.v1.MsgRecvPacket"), msg(type_url)])
+                    .unwrap_err();
+
+            assert_eq!(status.code(), Code::PermissionDenied);
+        }
+    }
+
+    #[test]
+    fn forward_tracking_ids() {
+        let uuid = Uuid::new_v4();
+
+        for tracking_id in [
+            TrackingId::Uuid(uuid),
+            TrackingId::ClearedUuid(uuid),
+            TrackingId::Static("packet-recv"),
+        ] {
+            assert_eq!(
+                decode_tracking_id(&encode_tracking_id(tracking_id)).to_string(),
+                tracking_id.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn forward_batch_limits() {
+        let limits = BatchLimits {
+            max_msg_num: Some(MaxMsgNum::new(5).unwrap()),
+            max_tx_size: Some(MaxTxSize::new(100_000).unwrap()),
+        };
+
+        for limits in [limits, BatchLimits::default()] {
+            let request = SubmitRequest {
+                max_msg_num: limits.max_msg_num.map(|n| n.to_usize() as u64),
+                max_tx_size: limits.max_tx_size.map(|n| n.to_usize() as u64),
+                ..Default::default()
+            };
+
+            assert_eq!(decode_limits(&request).unwrap(), limits);
+        }
+
+        let request = SubmitRequest {
+            max_msg_num: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            decode_limits(&request).unwrap_err().code(),
+            Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn forward_error_categories() {
+        for category in [ErrorCategory::MempoolFull, ErrorCategory::Permanent] {
+            let e = Error::aggregated_tx(category, "failed".to_owned());
+
+            assert_eq!(
+                error_from_status(status_from_error(&e)).category(),
+                category
+            );
+        }
+    }
+}
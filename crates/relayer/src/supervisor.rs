@@ -4,31 +4,47 @@ use core::convert::Infallible;
 use core::ops::Deref;
 use core::time::Duration;
 use std::sync::RwLock;
+use std::time::Instant;
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId},
     events::IbcEvent,
+    signer::Signer,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryChannelRequest, QueryHeight},
+        tracking::TrackingId,
+    },
+    config::{hooks::HookTrigger, Config},
+    coordination::{self, ChannelKey, ChannelLeases},
     event::{
         source::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
-    object::Object,
+    hooks,
+    link::{self, error::LinkError},
+    object::{self, Client, Object},
     registry::{Registry, SharedRegistry},
-    rest,
+    rest::{
+        self,
+        dashboard::{ChainHealth, DashboardState},
+        request::ReplySender,
+        RestApiError,
+    },
     supervisor::scan::ScanMode,
     telemetry,
     util::{
         lock::LockExt,
+        profile::{self, Profile},
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
     worker::WorkerMap,
@@ -49,11 +65,23 @@ pub mod spawn;
 pub mod cmd;
 use cmd::SupervisorCmd;
 
-use self::{scan::ChainScanner, spawn::SpawnContext};
+pub mod rollback;
+use rollback::{RollbackAction, RollbackGuard};
+
+use self::{
+    scan::{scan_channel, ChainScanner},
+    spawn::SpawnContext,
+};
 
 type ArcBatch = Arc<source::Result<EventBatch>>;
 type Subscription = Receiver<ArcBatch>;
 
+/// How often to check whether a chain was upgraded to a new identifier
+const CHAIN_UPGRADE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long to wait for the chains to report their latest height to the dashboard
+const DASHBOARD_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
 /**
     A wrapper around the SupervisorCmd sender so that we can
     send stop signal to the supervisor before stopping the
@@ -66,7 +94,7 @@ pub struct SupervisorHandle {
 }
 
 /// Options for the supervisor
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SupervisorOptions {
     /// Perform a health check of all chains we connect to
     pub health_check: bool,
@@ -75,6 +103,9 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// The services of the profile to relay for, the main configuration by default
+    pub profile: Profile,
 }
 
 /**
@@ -87,13 +118,41 @@ pub fn spawn_supervisor(
     config: Config,
     registry: SharedRegistry<impl ChainHandle>,
     rest_rx: Option<rest::Receiver>,
-    options: SupervisorOptions,
+    mut options: SupervisorOptions,
 ) -> Result<SupervisorHandle, Error> {
     let (sender, receiver) = unbounded();
 
-    let tasks = spawn_supervisor_tasks(config, registry, rest_rx, receiver, options)?;
+    // Connect to the leases database once, so that the leases are kept
+    // when the tasks are restarted after a chain upgrade.
+    if config.coordination.enabled && options.profile.leases().is_none() {
+        let leases =
+            coordination::start(config.coordination.clone()).map_err(Error::coordination)?;
+        options.profile = options.profile.with_leases(Some(leases));
+    }
 
-    Ok(SupervisorHandle { sender, tasks })
+    // The metrics of the tasks, of the workers they spawn, and of
+    // the chain runtimes they spawn are labeled with the profile
+    let profile = options.profile.clone();
+
+    profile::scope(profile.name(), || {
+        let tasks = spawn_supervisor_tasks(
+            config.clone(),
+            registry.clone(),
+            rest_rx.clone(),
+            receiver.clone(),
+            options.clone(),
+        )?;
+
+        // The tasks are owned by the chain upgrade worker, which restarts them
+        // whenever a chain is upgraded to a new identifier
+        let upgrade_task =
+            spawn_chain_upgrade_worker(config, registry, rest_rx, receiver, options, tasks);
+
+        Ok(SupervisorHandle {
+            sender,
+            tasks: vec![upgrade_task],
+        })
+    })
 }
 
 impl SupervisorHandle {
@@ -170,13 +229,17 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
         }
     }
 
-    let workers = Arc::new(RwLock::new(WorkerMap::new()));
+    let profile = options.profile.clone();
+
+    let workers = Arc::new(RwLock::new(WorkerMap::new(profile.clone())));
     let client_state_filter = Arc::new(RwLock::new(FilterPolicy::default()));
 
     // Only scan when needed
     if should_scan(&config, &options) {
+        // Only the workers for the channels claimed by this instance are spawned
         let scan = chain_scanner(
             &config,
+            &profile,
             &mut registry.write(),
             &mut client_state_filter.acquire_write(),
             if options.force_full_scan {
@@ -196,10 +259,19 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
+    // Detect the optional features of IBC supported by each chain, so that the
+    // workers and the messages relying on them are gated from the start.
+    for chain in registry.read().chains() {
+        match chain.query_features() {
+            Ok(features) => info!(chain = %chain.id(), "detected features: {features}"),
+            Err(e) => warn!(chain = %chain.id(), "failed to detect features: {e}"),
+        }
+    }
+
     let batch_tasks = spawn_batch_workers(
         &config,
         registry.clone(),
-        client_state_filter,
+        client_state_filter.clone(),
         workers.clone(),
         subscriptions,
     );
@@ -209,8 +281,24 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     let mut tasks = vec![cmd_task];
     tasks.extend(batch_tasks);
 
+    if let Some(leases) = profile.leases() {
+        let lease_task = spawn_lease_worker(
+            config.clone(),
+            registry.clone(),
+            workers.clone(),
+            leases.clone(),
+        );
+        tasks.push(lease_task);
+    }
+
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers.clone(), rest_rx);
+        let rest_task = spawn_rest_worker(
+            config,
+            registry,
+            client_state_filter,
+            workers.clone(),
+            rest_rx,
+        );
         tasks.push(rest_task);
     }
 
@@ -235,6 +323,13 @@ fn spawn_batch_workers<Chain: ChainHandle>(
         let client_state_filter = client_state_filter.clone();
         let workers = workers.clone();
 
+        let mut rollback = RollbackGuard::new(
+            config
+                .find_chain(&chain.id())
+                .map(|chain_config| chain_config.height_rollback)
+                .unwrap_or_default(),
+        );
+
         let handle = spawn_background_task(
             error_span!("worker.batch", chain = %chain.id()),
             Some(Duration::from_millis(5)),
@@ -245,6 +340,7 @@ fn spawn_batch_workers<Chain: ChainHandle>(
                         &mut registry.write(),
                         &mut client_state_filter.acquire_write(),
                         &mut workers.acquire_write(),
+                        &mut rollback,
                         chain.clone(),
                         batch,
                     );
@@ -285,20 +381,102 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
 pub fn spawn_rest_worker<Chain: ChainHandle>(
     config: Config,
     registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
+    let mut config = config;
+
     spawn_background_task(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(
+                &mut config,
+                &registry,
+                &client_state_filter,
+                &workers,
+                &rest_rx,
+            );
 
             Ok(Next::Continue)
         },
     )
 }
 
+/// Spawn a background task which checks whether a chain was upgraded to a new identifier,
+/// with a higher revision number, eg. from `cosmoshub-3` to `cosmoshub-4`, in which case
+/// the supervisor tasks and their workers are stopped, the runtime of the chain is shut down,
+/// and they are all restarted for the new identifier.
+///
+/// The given tasks are the ones of the supervisor, which are stopped along with this task.
+fn spawn_chain_upgrade_worker<Chain: ChainHandle>(
+    mut config: Config,
+    registry: SharedRegistry<Chain>,
+    rest_rx: Option<rest::Receiver>,
+    cmd_rx: Receiver<SupervisorCmd>,
+    options: SupervisorOptions,
+    mut tasks: Vec<TaskHandle>,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.chain_upgrade"),
+        Some(CHAIN_UPGRADE_CHECK_INTERVAL),
+        move || -> Result<Next, TaskError<Error>> {
+            let upgrades = chain_upgrades(&registry.read());
+
+            if upgrades.is_empty() {
+                return Ok(Next::Continue);
+            }
+
+            // Stop the tasks, and with them the workers, in parallel before
+            // shutting down the runtimes of the chains they relay for
+            for task in &tasks {
+                task.shutdown();
+            }
+            tasks.clear();
+
+            for (chain_id, upgraded_id) in upgrades {
+                warn!(
+                    "chain {chain_id} was upgraded to {upgraded_id}, \
+                    restarting the supervisor for the new chain identifier"
+                );
+
+                config.follow_chain_upgrade(&chain_id, &upgraded_id);
+                registry
+                    .write()
+                    .follow_chain_upgrade(&chain_id, &upgraded_id);
+            }
+
+            tasks = spawn_supervisor_tasks(
+                config.clone(),
+                registry.clone(),
+                rest_rx.clone(),
+                cmd_rx.clone(),
+                options.clone(),
+            )
+            .map_err(TaskError::Fatal)?;
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// The chains whose runtime followed an upgrade of the chain to a new identifier,
+/// along with that identifier.
+fn chain_upgrades<Chain: ChainHandle>(registry: &Registry<Chain>) -> Vec<(ChainId, ChainId)> {
+    registry
+        .chains()
+        .filter_map(|chain| {
+            let chain_id = chain.id();
+            let upgraded_id = chain.config().ok()?.id;
+
+            chain_id
+                .is_upgraded_to(&upgraded_id)
+                .then_some((chain_id, upgraded_id))
+        })
+        .collect()
+}
+
 /// Spawn a background task which verifies if there are idle workers and removes them if.
 pub fn spawn_cleanup_worker(workers: Arc<RwLock<WorkerMap>>) -> TaskHandle {
     spawn_background_task(
@@ -311,6 +489,114 @@ pub fn spawn_cleanup_worker(workers: Arc<RwLock<WorkerMap>>) -> TaskHandle {
     )
 }
 
+/// Spawn a background task which renews the leases held by this instance on the channels,
+/// and shuts down the workers relaying on the channels whose lease was lost. The task also
+/// claims the channels whose lease was released by other instances, and clears the packets
+/// left pending on the channels taken over.
+pub fn spawn_lease_worker<Chain: ChainHandle>(
+    config: Config,
+    registry: SharedRegistry<Chain>,
+    workers: Arc<RwLock<WorkerMap>>,
+    leases: Arc<ChannelLeases>,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("lease_worker"),
+        Some(leases.renew_interval()),
+        move || -> Result<Next, TaskError<Infallible>> {
+            for key in leases.renew() {
+                warn!(
+                    "lost the lease on channel {}/{} on chain {}, stopping its workers",
+                    key.port_id, key.channel_id, key.chain_id
+                );
+
+                let mut workers = workers.acquire_write();
+
+                let objects = workers
+                    .objects_for_chain(&key.chain_id)
+                    .into_iter()
+                    .filter(|object| relays_on_channel(object, &key))
+                    .collect_vec();
+
+                for object in objects {
+                    workers.shutdown_worker(&object);
+                }
+            }
+
+            for key in leases.claim_released() {
+                take_over_channel(
+                    &config,
+                    &mut registry.write(),
+                    &mut workers.acquire_write(),
+                    &key,
+                );
+            }
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// Whether the worker for the given object relays on the given channel end.
+fn relays_on_channel(object: &Object, key: &ChannelKey) -> bool {
+    match object {
+        Object::Packet(p) => {
+            p.src_chain_id == key.chain_id
+                && p.src_port_id == key.port_id
+                && p.src_channel_id == key.channel_id
+        }
+        Object::Channel(c) => {
+            c.src_chain_id == key.chain_id
+                && c.src_port_id == key.port_id
+                && c.src_channel_id == key.channel_id
+        }
+        _ => false,
+    }
+}
+
+/// Spawns the workers for a channel end taken over from another instance, and clears
+/// the packets left pending on it, as the other instance stopped relaying them.
+#[instrument(
+    name = "supervisor.take_over_channel",
+    level = "error",
+    skip_all,
+    fields(chain = %key.chain_id, port = %key.port_id, channel = %key.channel_id)
+)]
+fn take_over_channel<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    workers: &mut WorkerMap,
+    key: &ChannelKey,
+) {
+    info!("took over the lease on the channel, clearing its pending packets");
+
+    let chain = match registry.get_or_spawn(&key.chain_id) {
+        Ok(chain) => chain,
+        Err(e) => {
+            error!("failed to spawn chain runtime: {e}");
+            return;
+        }
+    };
+
+    match scan_channel(registry, &chain, &key.port_id, &key.channel_id) {
+        Ok((client, channel_scan)) => {
+            if let Err(e) = spawn_context(config, registry, workers).spawn_workers_for_channel(
+                chain,
+                &client,
+                channel_scan,
+            ) {
+                error!("failed to spawn the workers for the channel: {e}");
+            }
+        }
+        Err(e) => error!("failed to scan the channel: {e}"),
+    }
+
+    for worker in workers.handles() {
+        if relays_on_channel(worker.object(), key) {
+            worker.clear_pending_packets();
+        }
+    }
+}
+
 /// Returns `true` if the relayer should filter based on
 /// client state attributes, e.g., trust threshold.
 /// Returns `false` otherwise.
@@ -330,6 +616,7 @@ fn channel_filter_enabled(_config: &Config) -> bool {
 /// Whether or not the given channel is allowed by the filter policy, if any.
 fn is_channel_allowed(
     config: &Config,
+    profile: &Profile,
     chain_id: &ChainId,
     port_id: &PortId,
     channel_id: &ChannelId,
@@ -340,12 +627,14 @@ fn is_channel_allowed(
     }
 
     config.packets_on_channel_allowed(chain_id, port_id, channel_id)
+        && profile.is_channel_claimed(chain_id, port_id, channel_id)
 }
 
 /// Whether or not the relayer should relay packets
 /// or complete handshakes for the given [`Object`].
 fn relay_on_object<Chain: ChainHandle>(
     config: &Config,
+    profile: &Profile,
     registry: &mut Registry<Chain>,
     client_state_filter: &mut FilterPolicy,
     chain_id: &ChainId,
@@ -359,13 +648,13 @@ fn relay_on_object<Chain: ChainHandle>(
     // First, apply the channel filter on packets and channel workers
     match object {
         Object::Packet(p) => {
-            if !is_channel_allowed(config, chain_id, &p.src_port_id, &p.src_channel_id) {
+            if !is_channel_allowed(config, profile, chain_id, &p.src_port_id, &p.src_channel_id) {
                 // Forbid relaying packets on that channel
                 return false;
             }
         }
         Object::Channel(c) => {
-            if !is_channel_allowed(config, chain_id, &c.src_port_id, &c.src_channel_id) {
+            if !is_channel_allowed(config, profile, chain_id, &c.src_port_id, &c.src_channel_id) {
                 // Forbid completing handshake for that channel
                 return false;
             }
@@ -605,11 +894,18 @@ fn spawn_context<'a, Chain: ChainHandle>(
 
 fn chain_scanner<'a, Chain: ChainHandle>(
     config: &'a Config,
+    profile: &Profile,
     registry: &'a mut Registry<Chain>,
     client_state_filter: &'a mut FilterPolicy,
     full_scan: ScanMode,
 ) -> ChainScanner<'a, Chain> {
-    ChainScanner::new(config, registry, client_state_filter, full_scan)
+    ChainScanner::new(
+        config,
+        profile.clone(),
+        registry,
+        client_state_filter,
+        full_scan,
+    )
 }
 
 /// Perform a health check on all connected chains
@@ -696,34 +992,310 @@ fn dump_state<Chain: ChainHandle>(
 /// as a [`SupervisorState`].
 fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) -> SupervisorState {
     let chains = registry.chains().map(|c| c.id()).collect_vec();
-    SupervisorState::new(chains, workers.handles())
+    SupervisorState::new(chains, workers.handles(), workers.suspect_clients())
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
-    config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    config: &mut Config,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
     rest_rx: &rest::Receiver,
 ) {
     if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+        handle_rest_cmd(config, registry, client_state_filter, workers, cmd);
     }
 }
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
 fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    config: &mut Config,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
     m: rest::Command,
 ) {
     match m {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&registry.read(), &workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
         }
+
+        rest::Command::Dashboard(reply) => {
+            let workers = workers.acquire_read();
+
+            let chains = registry
+                .read()
+                .chains()
+                .map(|chain| (chain.clone(), workers.workers_for_chain(&chain.id()).len()))
+                .collect();
+
+            spawn_dashboard_state(chains, workers.profile().name(), reply);
+        }
+
+        rest::Command::ReloadKey {
+            chain_id,
+            key_name,
+            reply_to,
+        } => {
+            let result = reload_key(config, registry, &chain_id, key_name)
+                .map_err(|e| RestApiError::KeyReload(chain_id.clone(), e));
+
+            let reloaded = result.is_ok();
+
+            reply_to
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+
+            if reloaded {
+                register_counterparty_payees(config, registry, &workers.acquire_read(), &chain_id);
+            }
+        }
+
+        rest::Command::AcknowledgeClient {
+            chain_id,
+            client_id,
+            reply_to,
+        } => {
+            let result = acknowledge_client(
+                config,
+                &mut registry.write(),
+                &mut client_state_filter.acquire_write(),
+                &mut workers.acquire_write(),
+                &chain_id,
+                &client_id,
+            );
+
+            reply_to
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+    }
+}
+
+/// Clears the suspect state of a client, once the operator checked it, and scans
+/// the chains again to spawn the workers which were held back while it was suspect.
+#[instrument(
+    name = "supervisor.acknowledge_client",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_id, client = %client_id)
+)]
+fn acknowledge_client<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+) -> Result<Client, RestApiError> {
+    let client = workers
+        .suspect_clients()
+        .find(|client| &client.dst_chain_id == chain_id && &client.dst_client_id == client_id)
+        .cloned()
+        .ok_or_else(|| RestApiError::ClientNotSuspect(chain_id.clone(), client_id.clone()))?;
+
+    workers.acknowledge_suspect(chain_id, client_id);
+
+    info!("suspect client was acknowledged, resuming relaying on it");
+
+    let scan = chain_scanner(
+        config,
+        workers.profile(),
+        registry,
+        client_state_filter,
+        ScanMode::Auto,
+    )
+    .scan_chains();
+
+    spawn_context(config, registry, workers).spawn_workers(scan);
+
+    Ok(client)
+}
+
+/// Reloads the key signing the transactions on the given chain.
+///
+/// The workers keep relaying through the same chain handle, and sign the
+/// transactions they submit after the reload with the reloaded key.
+///
+/// The name of the reloaded key is recorded in the configuration of the chain
+/// reported by the REST API, and in the one its runtime is respawned with.
+fn reload_key<Chain: ChainHandle>(
+    config: &mut Config,
+    registry: &SharedRegistry<Chain>,
+    chain_id: &ChainId,
+    key_name: Option<String>,
+) -> Result<Signer, String> {
+    let chain = registry
+        .read()
+        .chains()
+        .find(|chain| &chain.id() == chain_id)
+        .cloned()
+        .ok_or_else(|| "the relayer is not running on this chain".to_string())?;
+
+    let signer = chain.reload_key(key_name).map_err(|e| e.to_string())?;
+
+    match chain.config() {
+        Ok(chain_config) => {
+            if let Some(config) = config.find_chain_mut(chain_id) {
+                config.key_name = chain_config.key_name.clone();
+            }
+
+            registry
+                .write()
+                .set_key_name(chain_id, chain_config.key_name);
+        }
+        Err(e) => warn!(chain = %chain_id, "failed to fetch the name of the reloaded key: {e}"),
     }
+
+    Ok(signer)
+}
+
+/// Registers again the counterparty payees of the channels relayed from and to a chain
+/// whose key was reloaded, as the ones registered when their links were created are
+/// for the address of the previous key, which would otherwise keep the fees of the
+/// packets received with the reloaded key.
+#[instrument(
+    name = "supervisor.register_counterparty_payees",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_id)
+)]
+fn register_counterparty_payees<Chain: ChainHandle>(
+    config: &Config,
+    registry: &SharedRegistry<Chain>,
+    workers: &WorkerMap,
+    chain_id: &ChainId,
+) {
+    if !config.mode.packets.enabled || !config.mode.packets.auto_register_counterparty_payee {
+        return;
+    }
+
+    for object in workers.objects_for_chain(chain_id) {
+        let Object::Packet(path) = object else {
+            continue;
+        };
+
+        let chains = registry
+            .get_or_spawn(&path.src_chain_id)
+            .and_then(|src| Ok((src, registry.get_or_spawn(&path.dst_chain_id)?)));
+
+        let (src, dst) = match chains {
+            Ok(chains) => chains,
+            Err(e) => {
+                error!(
+                    "failed to spawn the chain runtimes of {}: {e}",
+                    path.short_name()
+                );
+                continue;
+            }
+        };
+
+        if let Err(e) = register_counterparty_payee_on_path(&src, &dst, &path) {
+            error!(
+                "failed to register the counterparty payee for {}: {e}",
+                path.short_name()
+            );
+        }
+    }
+}
+
+fn register_counterparty_payee_on_path<Chain: ChainHandle>(
+    src_chain: &Chain,
+    dst_chain: &Chain,
+    path: &object::Packet,
+) -> Result<(), LinkError> {
+    let (src_channel, _) = src_chain
+        .query_channel(
+            QueryChannelRequest {
+                port_id: path.src_port_id.clone(),
+                channel_id: path.src_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(LinkError::relayer)?;
+
+    let dst_channel_id = src_channel
+        .counterparty()
+        .channel_id()
+        .ok_or_else(|| LinkError::counterparty_channel_not_found(path.src_channel_id.clone()))?
+        .clone();
+
+    let dst_port_id = src_channel.counterparty().port_id.clone();
+
+    link::register_counterparty_payee(
+        src_chain,
+        dst_chain,
+        &src_channel,
+        &dst_channel_id,
+        &dst_port_id,
+    )
+}
+
+/// Replies with the state shown by the dashboard of the REST server, from a thread of its own,
+/// so that neither the REST requests nor the supervisor wait for the chains to be queried.
+///
+/// Each of the given chains, along with the number of workers relaying for it, is queried
+/// for its latest height in parallel, to assess its health. The chains which do not reply
+/// within [`DASHBOARD_QUERY_TIMEOUT`] are reported with an error.
+fn spawn_dashboard_state<Chain: ChainHandle>(
+    chains: Vec<(Chain, usize)>,
+    profile: Option<&str>,
+    reply: ReplySender<DashboardState>,
+) {
+    let profile = profile.map(str::to_owned);
+
+    profile::spawn_thread(move || {
+        let deadline = Instant::now() + DASHBOARD_QUERY_TIMEOUT;
+        let (sender, receiver) = crossbeam_channel::bounded(chains.len());
+
+        for (i, (chain, _)) in chains.iter().enumerate() {
+            let chain = chain.clone();
+            let sender = sender.clone();
+
+            profile::spawn_thread(move || {
+                let _ = sender.send((i, chain.query_application_status()));
+            });
+        }
+
+        let mut statuses = vec![None; chains.len()];
+
+        for _ in 0..chains.len() {
+            match receiver.recv_deadline(deadline) {
+                Ok((i, status)) => statuses[i] = Some(status.map_err(|e| e.to_string())),
+                Err(_) => break,
+            }
+        }
+
+        let chains = chains
+            .into_iter()
+            .zip(statuses)
+            .map(|((chain, workers), status)| {
+                let (latest_height, error) = match status {
+                    Some(Ok(status)) => (Some(status.height), None),
+                    Some(Err(e)) => (None, Some(e)),
+                    None => (
+                        None,
+                        Some("timed out querying the status of the chain".to_string()),
+                    ),
+                };
+
+                ChainHealth {
+                    chain_id: chain.id(),
+                    latest_height,
+                    error,
+                    workers,
+                }
+            })
+            .collect();
+
+        reply
+            .send(Ok(DashboardState::new(chains, profile.as_deref())))
+            .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+    });
 }
 
 #[instrument(
@@ -740,6 +1312,109 @@ fn clear_pending_packets(workers: &mut WorkerMap, chain_id: &ChainId) -> Result<
     Ok(())
 }
 
+/// Halt relaying from and to a chain whose height went backwards, eg. after a restart
+/// from genesis, so that no client update built from the new blocks of the chain gets
+/// submitted to, and freezes, the clients of its counterparties, which are marked as
+/// suspect until the operator checks and acknowledges them.
+#[instrument(
+    name = "supervisor.halt_chain",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_id)
+)]
+fn halt_chain(workers: &mut WorkerMap, chain_id: &ChainId, previous: Height, current: Height) {
+    error!(
+        %previous,
+        %current,
+        "height of the chain went backwards, halting relaying from and to the chain \
+        until its height passes its previous maximum"
+    );
+
+    telemetry!(height_rollback, chain_id);
+
+    hooks::fire(
+        workers.profile(),
+        HookTrigger::HeightRollback,
+        chain_id,
+        &serde_json::json!({
+            "previous": previous,
+            "current": current,
+        }),
+    );
+
+    for object in workers.objects_for_chain(chain_id) {
+        if let Object::Client(client) = object {
+            if &client.src_chain_id == chain_id {
+                error!(
+                    "client {} on chain {} is suspect, as it tracks chain {} whose height \
+                    went backwards, it must be checked and acknowledged before relaying \
+                    on it resumes",
+                    client.dst_client_id, client.dst_chain_id, chain_id
+                );
+
+                workers.mark_suspect(client);
+            }
+        }
+    }
+
+    workers.halt_chain(chain_id);
+}
+
+/// Resume relaying from and to a chain on which relaying was halted, by scanning
+/// the chains again to spawn the workers which were shut down when it was halted,
+/// eg. the workers refreshing the clients of its counterparties which track it.
+#[instrument(
+    name = "supervisor.resume_chain",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_id)
+)]
+fn resume_chain<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+    chain_id: &ChainId,
+) {
+    info!(
+        "height of the chain passed its maximum before it went backwards, \
+        resuming relaying from and to the chain"
+    );
+
+    workers.resume_chain(chain_id);
+
+    let scan = chain_scanner(
+        config,
+        workers.profile(),
+        registry,
+        client_state_filter,
+        ScanMode::Auto,
+    )
+    .scan_chains();
+
+    spawn_context(config, registry, workers).spawn_workers(scan);
+}
+
+/// Applies the action decided by the rollback guard of a chain.
+fn apply_rollback_action<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+    chain_id: &ChainId,
+    action: RollbackAction,
+) {
+    match action {
+        RollbackAction::Continue | RollbackAction::Pending => {}
+        RollbackAction::Halt { previous, current } => {
+            halt_chain(workers, chain_id, previous, current)
+        }
+        RollbackAction::Resume => {
+            resume_chain(config, registry, client_state_filter, workers, chain_id)
+        }
+    }
+}
+
 /// Process a batch of events received from a chain.
 #[instrument(
     name = "supervisor.process_batch",
@@ -757,8 +1432,23 @@ fn process_batch<Chain: ChainHandle>(
 ) -> Result<(), Error> {
     assert_eq!(src_chain.id(), batch.chain_id);
 
+    if workers.is_halted(&src_chain.id()) {
+        trace!("skipping batch of chain on which relaying was halted");
+        return Ok(());
+    }
+
     telemetry!(received_event_batch, batch.tracking_id);
 
+    for event_with_height in &batch.events {
+        let trigger = HookTrigger::Event(event_with_height.event.event_type());
+        hooks::fire(
+            workers.profile(),
+            trigger,
+            &batch.chain_id,
+            event_with_height,
+        );
+    }
+
     let collected = collect_events(config, workers, &src_chain, batch);
 
     // If there is a NewBlock event, forward this event first to any workers affected by it.
@@ -770,6 +1460,7 @@ fn process_batch<Chain: ChainHandle>(
     for (object, events_with_heights) in collected.per_object.into_iter() {
         if !relay_on_object(
             config,
+            workers.profile(),
             registry,
             client_state_filter,
             &src_chain.id(),
@@ -788,6 +1479,16 @@ fn process_batch<Chain: ChainHandle>(
             continue;
         }
 
+        if workers.is_blocked(&object) {
+            trace!(
+                "skipping events for '{}'. \
+                reason: relaying was halted on one of its chains, or it relies on a suspect client",
+                object.short_name()
+            );
+
+            continue;
+        }
+
         let src = registry
             .get_or_spawn(object.src_chain_id())
             .map_err(Error::spawn)?;
@@ -818,6 +1519,7 @@ fn process_batch<Chain: ChainHandle>(
 /// the corresponding event:
 /// * send_packet_events: The number of SendPacket events received
 /// * acknowledgement_events: The number of WriteAcknowledgment events received.
+/// * ics20_acknowledgements: The number of ICS-20 acknowledgements received, by outcome.
 /// * timeout_events: The number of TimeoutPacket events received.
 ///
 /// The labels `chain_id` represents the chain sending the event, and `counterparty_chain_id` represents
@@ -830,7 +1532,7 @@ fn send_telemetry<Src, Dst>(
     src: &Src,
     dst: &Dst,
     events: &[IbcEventWithHeight],
-    path: &crate::object::Packet,
+    path: &object::Packet,
 ) where
     Src: ChainHandle,
     Dst: ChainHandle,
@@ -857,6 +1559,16 @@ fn send_telemetry<Src, Dst>(
                         &path.src_port_id,
                         &dst.id(),
                     );
+
+                    if let Some(ack) = write_ack_ev.transfer_ack() {
+                        ibc_telemetry::global().ics20_acknowledgement(
+                            &src.id(),
+                            &path.src_channel_id,
+                            &path.src_port_id,
+                            &dst.id(),
+                            ack.is_success(),
+                        );
+                    }
                 }
                 IbcEvent::TimeoutPacket(_) => {
                     ibc_telemetry::global().timeout_events(
@@ -885,6 +1597,7 @@ fn handle_batch<Chain: ChainHandle>(
     registry: &mut Registry<Chain>,
     client_state_filter: &mut FilterPolicy,
     workers: &mut WorkerMap,
+    rollback: &mut RollbackGuard,
     chain: Chain,
     batch: ArcBatch,
 ) {
@@ -892,12 +1605,49 @@ fn handle_batch<Chain: ChainHandle>(
 
     match batch.deref() {
         Ok(batch) => {
+            let action = rollback.on_batch(batch.height);
+
+            apply_rollback_action(
+                config,
+                registry,
+                client_state_filter,
+                workers,
+                &chain_id,
+                action,
+            );
+
+            if action == RollbackAction::Pending {
+                debug!(
+                    height = %batch.height,
+                    "skipping batch of chain whose height went backwards, \
+                    until the rollback persists or is recovered from"
+                );
+
+                return;
+            }
+
             if let Err(e) =
                 process_batch(config, registry, client_state_filter, workers, chain, batch)
             {
                 error!("error during batch processing: {}", e);
             }
         }
+        Err(EventError(EventErrorDetail::HeightRollback(e), _)) => {
+            warn!(
+                previous = %e.previous,
+                current = %e.current,
+                "height of the chain went backwards"
+            );
+
+            apply_rollback_action(
+                config,
+                registry,
+                client_state_filter,
+                workers,
+                &chain_id,
+                rollback.on_rollback(e.previous, e.current),
+            );
+        }
         Err(EventError(EventErrorDetail::SubscriptionCancelled(_), _)) => {
             warn!("event subscription was cancelled, clearing pending packets");
 
@@ -905,7 +1655,14 @@ fn handle_batch<Chain: ChainHandle>(
                 .map_err(|e| error!("error during clearing pending packets: {}", e));
         }
         Err(e) => {
-            error!("error when receiving event batch: {}", e)
+            error!("error when receiving event batch: {}", e);
+
+            hooks::fire(
+                workers.profile(),
+                HookTrigger::EventSourceError,
+                &chain_id,
+                &serde_json::json!({ "error": e.to_string() }),
+            );
         }
     }
 }
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::{
-    object::{Object, ObjectType},
+    object::{Client, Object, ObjectType},
     worker::{WorkerData, WorkerHandle, WorkerId},
 };
 
@@ -28,12 +28,17 @@ impl WorkerDesc {
 pub struct SupervisorState {
     pub chains: Vec<ChainId>,
     pub workers: BTreeMap<ObjectType, Vec<WorkerDesc>>,
+    /// The clients tracking a chain whose height went backwards,
+    /// which have not been acknowledged by an operator yet
+    #[serde(default)]
+    pub suspect_clients: Vec<Client>,
 }
 
 impl SupervisorState {
     pub fn new<'a>(
         mut chains: Vec<ChainId>,
         workers: impl Iterator<Item = &'a WorkerHandle>,
+        suspect_clients: impl Iterator<Item = &'a Client>,
     ) -> Self {
         chains.sort();
 
@@ -44,7 +49,11 @@ impl SupervisorState {
             .update(|(_, os)| os.sort_by_key(|desc| desc.object.short_name()))
             .collect::<BTreeMap<_, _>>();
 
-        Self { chains, workers }
+        Self {
+            chains,
+            workers,
+            suspect_clients: suspect_clients.cloned().collect(),
+        }
     }
 
     pub fn print_info(&self) {
@@ -71,6 +80,12 @@ impl Display for SupervisorState {
                 }
             }
         }
+        if !self.suspect_clients.is_empty() {
+            writeln!(f, "* Suspect clients:")?;
+            for client in &self.suspect_clients {
+                writeln!(f, "  - {}", client.short_name())?;
+            }
+        }
 
         Ok(())
     }
@@ -0,0 +1,208 @@
+//! Decides when to halt, and to resume, relaying from and to a chain whose height
+//! went backwards, eg. after a restart from genesis or a rollback of the chain.
+
+use core::mem;
+
+use ibc_relayer_types::Height;
+
+use crate::config::height_rollback::HeightRollbackConfig;
+
+/// What to do after the height of a chain was observed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RollbackAction {
+    /// Keep relaying, or keep relaying halted
+    Continue,
+    /// Stop processing the batches of the chain, as its height went back by at least
+    /// `min_depth` blocks, until the rollback either persists or is recovered from
+    Pending,
+    /// Halt relaying, as the height of the chain went back
+    /// from `previous` and stayed below it up to `current`
+    Halt { previous: Height, current: Height },
+    /// Resume relaying, as the height of the chain passed its previous maximum
+    Resume,
+}
+
+/// A rollback of the height of a chain, which has not been recovered from yet.
+#[derive(Copy, Clone, Debug)]
+struct Rollback {
+    /// The maximum height of the chain before it went backwards
+    max_height: Height,
+    /// The latest height of the chain since it went backwards
+    latest_height: Height,
+    /// The number of new blocks below the maximum height since it went backwards
+    blocks: u64,
+}
+
+/// Tracks the rollbacks of the height of a chain, reported by its event source,
+/// along with the heights of its batches of events.
+#[derive(Debug)]
+pub struct RollbackGuard {
+    config: HeightRollbackConfig,
+    rollback: Option<Rollback>,
+    halted: bool,
+}
+
+impl RollbackGuard {
+    pub fn new(config: HeightRollbackConfig) -> Self {
+        Self {
+            config,
+            rollback: None,
+            halted: false,
+        }
+    }
+
+    /// Records that the height of the chain went back from `previous` to `current`.
+    ///
+    /// Rollbacks by less than `min_depth` blocks below the maximum height are ignored,
+    /// while deeper ones are pending from the start, until they persist.
+    pub fn on_rollback(&mut self, previous: Height, current: Height) -> RollbackAction {
+        let max_height = self
+            .rollback
+            .map_or(previous, |rollback| rollback.max_height.max(previous));
+
+        let depth = max_height
+            .revision_height()
+            .saturating_sub(current.revision_height());
+
+        if max_height.revision_number() != current.revision_number()
+            || depth < self.config.min_depth
+        {
+            return self.check_persistence();
+        }
+
+        let blocks = self.rollback.map_or(0, |rollback| rollback.blocks);
+
+        self.rollback = Some(Rollback {
+            max_height,
+            latest_height: current,
+            blocks: blocks + 1,
+        });
+
+        self.check_persistence()
+    }
+
+    /// Records a batch of events of the chain at the given height.
+    pub fn on_batch(&mut self, height: Height) -> RollbackAction {
+        let Some(rollback) = &mut self.rollback else {
+            return RollbackAction::Continue;
+        };
+
+        if height > rollback.max_height {
+            self.rollback = None;
+
+            return if mem::take(&mut self.halted) {
+                RollbackAction::Resume
+            } else {
+                RollbackAction::Continue
+            };
+        }
+
+        if height > rollback.latest_height {
+            rollback.latest_height = height;
+            rollback.blocks += 1;
+        }
+
+        self.check_persistence()
+    }
+
+    fn check_persistence(&mut self) -> RollbackAction {
+        match self.rollback {
+            Some(rollback) if !self.halted && rollback.blocks >= self.config.persistence => {
+                self.halted = true;
+
+                RollbackAction::Halt {
+                    previous: rollback.max_height,
+                    current: rollback.latest_height,
+                }
+            }
+            Some(_) if !self.halted => RollbackAction::Pending,
+            _ => RollbackAction::Continue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height(h: u64) -> Height {
+        Height::new(1, h).unwrap()
+    }
+
+    fn guard(min_depth: u64, persistence: u64) -> RollbackGuard {
+        RollbackGuard::new(HeightRollbackConfig {
+            min_depth,
+            persistence,
+        })
+    }
+
+    #[test]
+    fn halt_once_the_rollback_persists() {
+        let mut guard = guard(10, 3);
+
+        // The batches are held back as soon as the rollback is reported
+        assert_eq!(
+            guard.on_rollback(height(100), height(2)),
+            RollbackAction::Pending
+        );
+        assert_eq!(guard.on_batch(height(2)), RollbackAction::Pending);
+        assert_eq!(guard.on_batch(height(3)), RollbackAction::Pending);
+        assert_eq!(
+            guard.on_batch(height(4)),
+            RollbackAction::Halt {
+                previous: height(100),
+                current: height(4)
+            }
+        );
+
+        // Relaying stays halted until the height passes its previous maximum
+        assert_eq!(guard.on_batch(height(5)), RollbackAction::Continue);
+        assert_eq!(
+            guard.on_rollback(height(50), height(1)),
+            RollbackAction::Continue
+        );
+        assert_eq!(guard.on_batch(height(100)), RollbackAction::Continue);
+        assert_eq!(guard.on_batch(height(101)), RollbackAction::Resume);
+        assert_eq!(guard.on_batch(height(102)), RollbackAction::Continue);
+    }
+
+    #[test]
+    fn ignore_shallow_rollbacks() {
+        let mut guard = guard(10, 1);
+
+        assert_eq!(
+            guard.on_rollback(height(100), height(91)),
+            RollbackAction::Continue
+        );
+        assert_eq!(guard.on_batch(height(92)), RollbackAction::Continue);
+
+        assert!(matches!(
+            guard.on_rollback(height(100), height(90)),
+            RollbackAction::Halt { .. }
+        ));
+    }
+
+    #[test]
+    fn ignore_rollbacks_recovered_from_in_time() {
+        let mut guard = guard(10, 3);
+
+        // A node lagging behind catches up before the rollback persists
+        assert_eq!(
+            guard.on_rollback(height(100), height(80)),
+            RollbackAction::Pending
+        );
+        assert_eq!(guard.on_batch(height(90)), RollbackAction::Pending);
+        assert_eq!(guard.on_batch(height(101)), RollbackAction::Continue);
+
+        // The blocks seen below the maximum height before are forgotten
+        assert_eq!(
+            guard.on_rollback(height(110), height(90)),
+            RollbackAction::Pending
+        );
+        assert_eq!(guard.on_batch(height(91)), RollbackAction::Pending);
+        assert!(matches!(
+            guard.on_batch(height(92)),
+            RollbackAction::Halt { .. }
+        ));
+    }
+}
@@ -32,6 +32,7 @@ use crate::{
     path::PathIdentifiers,
     registry::Registry,
     supervisor::client_state_filter::{FilterPolicy, Permission},
+    util::profile::Profile,
 };
 
 use crate::chain::counterparty::{unreceived_acknowledgements, unreceived_packets};
@@ -269,6 +270,7 @@ pub enum ScanMode {
 
 pub struct ChainScanner<'a, Chain: ChainHandle> {
     config: &'a Config,
+    profile: Profile,
     registry: &'a mut Registry<Chain>,
     client_state_filter: &'a mut FilterPolicy,
     scan_mode: ScanMode,
@@ -277,12 +279,14 @@ pub struct ChainScanner<'a, Chain: ChainHandle> {
 impl<'a, Chain: ChainHandle> ChainScanner<'a, Chain> {
     pub fn new(
         config: &'a Config,
+        profile: Profile,
         registry: &'a mut Registry<Chain>,
         client_state_filter: &'a mut FilterPolicy,
         scan_mode: ScanMode,
     ) -> Self {
         Self {
             config,
+            profile,
             registry,
             client_state_filter,
             scan_mode,
@@ -350,6 +354,19 @@ impl<'a, Chain: ChainHandle> ChainScanner<'a, Chain> {
         info!("querying allowed channels...");
 
         for (port_id, channel_id) in filters.iter_exact() {
+            if !self
+                .profile
+                .is_channel_claimed(&chain.id(), port_id, channel_id)
+            {
+                info!(
+                    port = %port_id,
+                    channel = %channel_id,
+                    "channel is claimed by another instance, skipping"
+                );
+
+                continue;
+            }
+
             let result = scan_allowed_channel(self.registry, chain, port_id, channel_id);
 
             match result {
@@ -589,6 +606,11 @@ impl<'a, Chain: ChainHandle> ChainScanner<'a, Chain> {
             return None;
         }
 
+        // Exceptions are only matched against channels found by a full scan
+        if !chain_config.packet_filter.except.is_empty() {
+            return None;
+        }
+
         match chain_config.packet_filter.channel_policy {
             ChannelPolicy::Allow(ref filters) if filters.is_exact() => Some(filters),
             _ => None,
@@ -657,6 +679,9 @@ impl<'a, Chain: ChainHandle> ChainScanner<'a, Chain> {
     fn channel_allowed(&mut self, chain: &Chain, channel: &IdentifiedChannelEnd) -> bool {
         self.config
             .packets_on_channel_allowed(&chain.id(), &channel.port_id, &channel.channel_id)
+            && self
+                .profile
+                .is_channel_claimed(&chain.id(), &channel.port_id, &channel.channel_id)
     }
 }
 
@@ -668,6 +693,22 @@ struct ScannedChannel {
     client: IdentifiedAnyClientState,
 }
 
+/// Scans the given channel end, returning the client it is built upon along with its scan,
+/// eg. to spawn its workers once the channel is taken over from another instance.
+pub fn scan_channel<Chain: ChainHandle>(
+    registry: &'_ mut Registry<Chain>,
+    chain: &Chain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(IdentifiedAnyClientState, ChannelScan), Error> {
+    let scanned = scan_allowed_channel(registry, chain, port_id, channel_id)?;
+
+    Ok((
+        scanned.client,
+        ChannelScan::new(scanned.channel, scanned.counterparty_channel),
+    ))
+}
+
 fn scan_allowed_channel<Chain: ChainHandle>(
     registry: &'_ mut Registry<Chain>,
     chain: &Chain,
@@ -4,11 +4,13 @@ use std::str::FromStr;
 
 use core::time::Duration;
 
-use flex_error::{define_error, DetailOnly};
+use flex_error::{define_error, DetailOnly, DisplayOnly};
 use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
 use ibc_relayer_types::applications::transfer::msgs::transfer::MsgTransfer;
+use ibc_relayer_types::applications::transfer::packet::PacketData;
 use ibc_relayer_types::applications::transfer::Amount;
 use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
@@ -16,6 +18,7 @@ use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::{Timestamp, TimestampOverflowError};
 use ibc_relayer_types::tx_msg::Msg;
+use serde_derive::Deserialize;
 
 use crate::chain::endpoint::ChainStatus;
 use crate::chain::handle::ChainHandle;
@@ -68,9 +71,78 @@ define_error! {
 
         ZeroTimeout
             | _ | { "packet timeout height and packet timeout timestamp cannot both be 0" },
+
+        PacketData
+            [ DisplayOnly<serde_json::Error> ]
+            |_| { "invalid fungible token packet data" },
+
+        ZeroAmount
+            |_| { "the amount of the transfer must be positive" },
+
+        ReceiverBech32
+            { receiver: String }
+            [ DisplayOnly<bech32::Error> ]
+            |e| {
+                format!("receiver address '{}' is not a valid bech32 address",
+                    e.receiver)
+            },
+
+        ReceiverPrefix
+            { receiver: String, expected: String }
+            |e| {
+                format!("receiver address '{}' does not have the prefix '{}' of the receiving chain",
+                    e.receiver, e.expected)
+            },
     }
 }
 
+/// Validates the fungible token packet data carried by a packet as the receiving
+/// chain does before crediting the tokens, given the bech32 prefix of the accounts
+/// on the receiving chain, so that packets which are bound to fail are not relayed.
+///
+/// The denomination and amount are validated when parsing the packet data,
+/// as well as the presence of the sender and receiver addresses.
+pub fn validate_packet_data(data: &[u8], receiver_prefix: &str) -> Result<(), TransferError> {
+    // Not parsed as `RawPacketData`, whose memo is required but absent
+    // from the packets sent by older versions of ibc-go
+    #[derive(Deserialize)]
+    struct RawData {
+        denom: String,
+        amount: String,
+        sender: String,
+        receiver: String,
+    }
+
+    let raw: RawData = serde_json::from_slice(data).map_err(TransferError::packet_data)?;
+
+    let data = PacketData::try_from(RawPacketData {
+        denom: raw.denom,
+        amount: raw.amount,
+        sender: raw.sender,
+        receiver: raw.receiver,
+        memo: String::new(),
+    })
+    .map_err(TransferError::token_transfer)?;
+
+    if data.token.amount == Amount::from(0u64) {
+        return Err(TransferError::zero_amount());
+    }
+
+    let receiver = data.receiver.as_ref();
+
+    let (prefix, _, _) = bech32::decode(receiver)
+        .map_err(|e| TransferError::receiver_bech32(receiver.to_string(), e))?;
+
+    if prefix != receiver_prefix {
+        return Err(TransferError::receiver_prefix(
+            receiver.to_string(),
+            receiver_prefix.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 #[derive(Copy, Clone)]
 pub struct TransferTimeout {
     pub timeout_height: TimeoutHeight,
@@ -232,3 +304,27 @@ pub fn build_and_send_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHa
     let msgs = build_transfer_messages(src_chain, dst_chain, opts)?;
     send_messages(src_chain, msgs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet_data(amount: &str, receiver: &str) -> Vec<u8> {
+        format!(
+            r#"{{"amount":"{amount}","denom":"transfer/channel-0/uatom","receiver":"{receiver}","sender":"cosmos1sender"}}"#
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn validate_transfer_packet_data() {
+        let receiver = "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw";
+
+        assert!(validate_packet_data(&packet_data("100", receiver), "osmo").is_ok());
+        assert!(validate_packet_data(&packet_data("0", receiver), "osmo").is_err());
+        assert!(validate_packet_data(&packet_data("-1", receiver), "osmo").is_err());
+        assert!(validate_packet_data(&packet_data("100", receiver), "cosmos").is_err());
+        assert!(validate_packet_data(&packet_data("100", "osmo1invalid"), "osmo").is_err());
+        assert!(validate_packet_data(b"not a transfer", "osmo").is_err());
+    }
+}
@@ -0,0 +1,388 @@
+//! Unwinding of IBC vouchers, by sending them back over the channels they were
+//! received from, one hop at a time, until they reach the chain of their base denomination.
+
+use core::time::Duration;
+use std::str::FromStr;
+use std::thread;
+use std::time::Instant;
+
+use flex_error::define_error;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tracing::{debug, info};
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement;
+use ibc_relayer_types::applications::transfer::denom::PrefixedDenom;
+use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
+use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+
+use crate::chain::counterparty::counterparty_chain_from_channel;
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, QueryHeight, QueryPacketAcknowledgementRequest, QueryPacketCommitmentRequest,
+};
+use crate::error::Error;
+use crate::spawn::SpawnError;
+use crate::supervisor::Error as SupervisorError;
+use crate::transfer::{build_and_send_transfer_messages, TransferError, TransferOptions};
+
+/// How often to check whether the packet of a hop was acknowledged
+const ACK_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+define_error! {
+    UnwindError {
+        Relayer
+            [ Error ]
+            |_| { "relayer error" },
+
+        Spawn
+            [ SpawnError ]
+            |_| { "failed to spawn the runtime of a chain on the route" },
+
+        Counterparty
+            [ SupervisorError ]
+            |_| { "failed to find the chain at the other end of the channel" },
+
+        Denom
+            { denom: String }
+            [ Ics20Error ]
+            |e| { format!("invalid denomination '{}'", e.denom) },
+
+        NativeDenom
+            { denom: String, chain_id: ChainId }
+            |e| {
+                format!("denomination '{}' is native to chain {}, there is nothing to unwind",
+                    e.denom, e.chain_id)
+            },
+
+        Transfer
+            [ TransferError ]
+            |_| { "failed to send the transfer of a hop" },
+
+        MissingSendPacket
+            { chain_id: ChainId }
+            |e| { format!("no packet was sent by the transfer on chain {}", e.chain_id) },
+
+        AckTimeout
+            { chain_id: ChainId, channel_id: ChannelId, sequence: Sequence }
+            |e| {
+                format!("packet {} sent on channel {} of chain {} was not acknowledged in time",
+                    e.sequence, e.channel_id, e.chain_id)
+            },
+
+        TimedOut
+            { chain_id: ChainId, channel_id: ChannelId, sequence: Sequence }
+            |e| {
+                format!("packet {} sent on channel {} of chain {} timed out, the tokens were refunded",
+                    e.sequence, e.channel_id, e.chain_id)
+            },
+
+        ErrorAck
+            { chain_id: ChainId, channel_id: ChannelId, sequence: Sequence }
+            |e| {
+                format!("packet {} sent on channel {} of chain {} was acknowledged with an error, the tokens were refunded",
+                    e.sequence, e.channel_id, e.chain_id)
+            },
+    }
+}
+
+/// A transfer of the route unwinding a voucher.
+#[derive(Clone, Debug, Serialize)]
+pub struct UnwindHop {
+    pub src_chain_id: ChainId,
+    pub src_port_id: PortId,
+    pub src_channel_id: ChannelId,
+    pub dst_chain_id: ChainId,
+
+    /// The denomination of the voucher on the source chain, with its full trace
+    pub src_denom: String,
+
+    /// The denomination of the voucher on the destination chain, with its full trace
+    pub dst_denom: String,
+}
+
+impl UnwindHop {
+    /// The denomination of the voucher on the source chain, as expected by `MsgTransfer`.
+    pub fn src_ibc_denom(&self) -> String {
+        ibc_denom(&self.src_denom)
+    }
+
+    /// The denomination of the voucher on the destination chain, as reported by the bank module.
+    pub fn dst_ibc_denom(&self) -> String {
+        ibc_denom(&self.dst_denom)
+    }
+}
+
+/// The hashed `ibc/{hash}` form of the given denomination with its full trace,
+/// or the denomination itself if it is native to the chain.
+pub fn ibc_denom(denom: &str) -> String {
+    if !denom.contains('/') {
+        return denom.to_string();
+    }
+
+    format!(
+        "ibc/{}",
+        hex::encode_upper(Sha256::digest(denom.as_bytes()))
+    )
+}
+
+/// Resolves the given denomination on the chain, either in its hashed
+/// `ibc/{hash}` form or with its full trace, into its full trace.
+pub fn resolve_denom(chain: &impl ChainHandle, denom: &str) -> Result<PrefixedDenom, UnwindError> {
+    let full_denom = match denom.strip_prefix("ibc/") {
+        Some(hash) => {
+            let trace = chain
+                .query_denom_trace(hash.to_string())
+                .map_err(UnwindError::relayer)?;
+
+            format!("{}/{}", trace.path, trace.base_denom)
+        }
+        None => denom.to_string(),
+    };
+
+    PrefixedDenom::from_str(&full_denom).map_err(|e| UnwindError::denom(full_denom, e))
+}
+
+/// The route unwinding a voucher, along with the handles of the chains it goes through,
+/// ie. the source chain of each hop, followed by the origin chain of the voucher.
+pub struct UnwindRoute<Chain> {
+    pub chains: Vec<Chain>,
+    pub hops: Vec<UnwindHop>,
+}
+
+impl<Chain: ChainHandle> UnwindRoute<Chain> {
+    /// Computes the route unwinding the given voucher held on the given chain, by following
+    /// its trace back to its origin chain, spawning the runtimes of the chains along the way.
+    pub fn compute(
+        chain: Chain,
+        denom: &PrefixedDenom,
+        mut spawn: impl FnMut(&ChainId) -> Result<Chain, SpawnError>,
+    ) -> Result<Self, UnwindError> {
+        if denom.is_native() {
+            return Err(UnwindError::native_denom(denom.to_string(), chain.id()));
+        }
+
+        let mut denom = denom.clone();
+        let mut chains = vec![chain];
+        let mut hops = Vec::new();
+
+        while let Some(prefix) = denom.leading_trace_prefix().cloned() {
+            let src_chain = chains.last().expect("route always starts with a chain");
+
+            let dst_chain_id =
+                counterparty_chain_from_channel(src_chain, prefix.channel_id(), prefix.port_id())
+                    .map_err(UnwindError::counterparty)?;
+
+            let src_denom = denom.to_string();
+            denom.remove_trace_prefix(&prefix);
+
+            hops.push(UnwindHop {
+                src_chain_id: src_chain.id(),
+                src_port_id: prefix.port_id().clone(),
+                src_channel_id: prefix.channel_id().clone(),
+                dst_chain_id: dst_chain_id.clone(),
+                src_denom,
+                dst_denom: denom.to_string(),
+            });
+
+            chains.push(spawn(&dst_chain_id).map_err(UnwindError::spawn)?);
+        }
+
+        Ok(Self { chains, hops })
+    }
+
+    /// Executes the hops of the route in order, waiting for each one to be acknowledged before
+    /// sending the next one. The tokens are received by the relayer account on the intermediate
+    /// chains, which sends them on, and by the given receiver, if any, on the origin chain.
+    ///
+    /// On failure, the tokens are left on the source chain of the failing hop, as its denomination.
+    pub fn execute(
+        &self,
+        amount: Amount,
+        receiver: Option<String>,
+        timeout: Duration,
+    ) -> Result<(), UnwindError> {
+        for (i, hop) in self.hops.iter().enumerate() {
+            let receiver = if i + 1 == self.hops.len() {
+                receiver.clone()
+            } else {
+                None
+            };
+
+            execute_hop(
+                &self.chains[i],
+                &self.chains[i + 1],
+                hop,
+                amount,
+                receiver,
+                timeout,
+            )?;
+
+            info!(
+                "{} {} arrived on chain {}",
+                amount, hop.dst_denom, hop.dst_chain_id
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Sends the given amount of the voucher over the given hop to the relayer account on the
+/// destination chain, or to the given receiver, and waits for the packet to be acknowledged.
+///
+/// The packet must be relayed, eg. by a running Hermes instance. The hop succeeds only if the
+/// packet was acknowledged successfully, ie. if the tokens were not refunded.
+pub fn execute_hop<SrcChain: ChainHandle, DstChain: ChainHandle>(
+    src_chain: &SrcChain,
+    dst_chain: &DstChain,
+    hop: &UnwindHop,
+    amount: Amount,
+    receiver: Option<String>,
+    timeout: Duration,
+) -> Result<(), UnwindError> {
+    let opts = TransferOptions {
+        src_port_id: hop.src_port_id.clone(),
+        src_channel_id: hop.src_channel_id.clone(),
+        amount,
+        denom: hop.src_ibc_denom(),
+        receiver,
+        timeout_height_offset: 0,
+        timeout_duration: timeout,
+        number_msgs: 1,
+        memo: None,
+    };
+
+    info!(
+        "unwinding {} {} from chain {} to chain {} over channel {}",
+        amount, hop.src_denom, hop.src_chain_id, hop.dst_chain_id, hop.src_channel_id
+    );
+
+    let events = build_and_send_transfer_messages(src_chain, dst_chain, &opts)
+        .map_err(UnwindError::transfer)?;
+
+    let packet = events
+        .into_iter()
+        .find_map(|ev| match ev.event {
+            IbcEvent::SendPacket(send_packet) => Some(send_packet.packet),
+            _ => None,
+        })
+        .ok_or_else(|| UnwindError::missing_send_packet(hop.src_chain_id.clone()))?;
+
+    wait_for_ack(src_chain, hop, packet.sequence, timeout)?;
+    check_ack(dst_chain, hop, &packet)
+}
+
+/// Waits until the commitment of the packet is removed from the source chain, which happens
+/// once the packet is acknowledged, or timed out. The packet is deemed lost when it is still
+/// not acknowledged twice its timeout after it was sent.
+fn wait_for_ack(
+    src_chain: &impl ChainHandle,
+    hop: &UnwindHop,
+    sequence: Sequence,
+    timeout: Duration,
+) -> Result<(), UnwindError> {
+    let start = Instant::now();
+
+    loop {
+        let (commitment, _) = src_chain
+            .query_packet_commitment(
+                QueryPacketCommitmentRequest {
+                    port_id: hop.src_port_id.clone(),
+                    channel_id: hop.src_channel_id.clone(),
+                    sequence,
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(UnwindError::relayer)?;
+
+        if commitment.is_empty() {
+            return Ok(());
+        }
+
+        if start.elapsed() > timeout * 2 {
+            return Err(UnwindError::ack_timeout(
+                hop.src_chain_id.clone(),
+                hop.src_channel_id.clone(),
+                sequence,
+            ));
+        }
+
+        debug!("waiting for packet {sequence} to be acknowledged");
+        thread::sleep(ACK_POLL_INTERVAL);
+    }
+}
+
+/// Checks the outcome of a packet whose commitment was removed from the source chain, from the
+/// acknowledgement written on the destination chain: the packet timed out if there is none, and
+/// the tokens were refunded unless it is the successful ICS-20 acknowledgement.
+fn check_ack(
+    dst_chain: &impl ChainHandle,
+    hop: &UnwindHop,
+    packet: &Packet,
+) -> Result<(), UnwindError> {
+    let (ack_commitment, _) = dst_chain
+        .query_packet_acknowledgement(
+            QueryPacketAcknowledgementRequest {
+                port_id: packet.destination_port.clone(),
+                channel_id: packet.destination_channel.clone(),
+                sequence: packet.sequence,
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(UnwindError::relayer)?;
+
+    if ack_commitment.is_empty() {
+        return Err(UnwindError::timed_out(
+            hop.src_chain_id.clone(),
+            hop.src_channel_id.clone(),
+            packet.sequence,
+        ));
+    }
+
+    if ack_commitment != success_ack_commitment() {
+        return Err(UnwindError::error_ack(
+            hop.src_chain_id.clone(),
+            hop.src_channel_id.clone(),
+            packet.sequence,
+        ));
+    }
+
+    Ok(())
+}
+
+/// The commitment of the successful ICS-20 acknowledgement, ie. the hash of `{"result":"AQ=="}`,
+/// as stored by the destination chain.
+fn success_ack_commitment() -> Vec<u8> {
+    let ack = serde_json::to_vec(&Acknowledgement::success())
+        .expect("the successful acknowledgement can always be encoded");
+
+    Sha256::digest(ack).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashed_ibc_denom() {
+        assert_eq!(
+            ibc_denom("transfer/channel-0/uatom"),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(ibc_denom("uatom"), "uatom");
+    }
+
+    #[test]
+    fn success_ack_commitment_is_the_hash_of_the_json_ack() {
+        assert_eq!(
+            hex::encode_upper(success_ack_commitment()),
+            "08F7557ED51826FE18D84512BF24EC75001EDBAF2123A477DF72A0A9F3640A7C"
+        );
+    }
+}
@@ -5,10 +5,15 @@ use std::ops::Add;
 
 use bytes::BufMut;
 use flex_error::define_error;
+use serde::Serialize;
 
 use tendermint::Hash as TxHash;
 
-use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal;
+use ibc_proto::cosmos::gov::v1beta1::query_client::QueryClient as GovQueryClient;
+use ibc_proto::cosmos::gov::v1beta1::{
+    MsgSubmitProposal, Proposal as GovProposal, ProposalStatus, QueryProposalRequest,
+    QueryProposalsRequest,
+};
 use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::UpgradeProposal;
@@ -17,11 +22,14 @@ use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::{downcast, Height};
 
+use crate::chain::cosmos::types::grpc::{GrpcAddress, GrpcChannel};
 use crate::chain::handle::ChainHandle;
-use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::requests::{IncludeProof, PageRequest, QueryClientStateRequest, QueryHeight};
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::AnyClientState;
+use crate::config::default::max_grpc_decoding_size;
 use crate::error::Error;
+use crate::util::block_on;
 
 define_error! {
     UpgradeChainError {
@@ -53,7 +61,19 @@ define_error! {
             { revision: u64 }
             |r| {
                 format!("invalid upgrade height revision: {r}")
-            }
+            },
+
+        ProposalNotFound
+            { chain_id: ChainId, proposal_id: u64 }
+            |e| {
+                format!("upgrade proposal {} not found on chain {}", e.proposal_id, e.chain_id)
+            },
+
+        PlanNotFound
+            { chain_id: ChainId, plan_name: String }
+            |e| {
+                format!("no upgrade proposal with plan '{}' found on chain {}", e.plan_name, e.chain_id)
+            },
     }
 }
 
@@ -186,3 +206,132 @@ impl Proposal {
         .to_owned()
     }
 }
+
+/// The status of an upgrade proposal in the governance module of the chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum UpgradeProposalStatus {
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    Failed,
+    Unspecified,
+}
+
+impl UpgradeProposalStatus {
+    /// Whether the outcome of the proposal is decided.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Passed | Self::Rejected | Self::Failed)
+    }
+}
+
+impl From<i32> for UpgradeProposalStatus {
+    fn from(status: i32) -> Self {
+        match ProposalStatus::from_i32(status) {
+            Some(ProposalStatus::DepositPeriod) => Self::DepositPeriod,
+            Some(ProposalStatus::VotingPeriod) => Self::VotingPeriod,
+            Some(ProposalStatus::Passed) => Self::Passed,
+            Some(ProposalStatus::Rejected) => Self::Rejected,
+            Some(ProposalStatus::Failed) => Self::Failed,
+            Some(ProposalStatus::Unspecified) | None => Self::Unspecified,
+        }
+    }
+}
+
+/// An IBC upgrade proposal, as tracked in the governance module of the chain.
+#[derive(Clone, Debug, Serialize)]
+pub struct UpgradeProposalState {
+    pub proposal_id: u64,
+    pub plan_name: String,
+    pub plan_height: u64,
+    pub status: UpgradeProposalStatus,
+}
+
+impl UpgradeProposalState {
+    /// Decodes the governance proposal, if it is an IBC upgrade proposal.
+    fn from_gov_proposal(proposal: GovProposal) -> Option<Self> {
+        let content = proposal.content?;
+
+        if content.type_url != "/ibc.core.client.v1.UpgradeProposal" {
+            return None;
+        }
+
+        let plan = <UpgradeProposal as prost::Message>::decode(content.value.as_slice())
+            .ok()?
+            .plan?;
+
+        Some(Self {
+            proposal_id: proposal.proposal_id,
+            plan_name: plan.name,
+            plan_height: plan.height as u64,
+            status: UpgradeProposalStatus::from(proposal.status),
+        })
+    }
+}
+
+/// Finds the latest IBC upgrade proposal with the given plan name submitted by the
+/// relayer to the chain, which remains a depositor of the proposal until it is decided.
+pub fn find_ibc_upgrade_proposal(
+    chain: &impl ChainHandle,
+    plan_name: &str,
+) -> Result<Option<UpgradeProposalState>, UpgradeChainError> {
+    let depositor = chain.get_signer().map_err(UpgradeChainError::key)?;
+
+    let request = QueryProposalsRequest {
+        proposal_status: ProposalStatus::Unspecified as i32,
+        voter: String::new(),
+        depositor: depositor.to_string(),
+        pagination: Some(PageRequest::all().into()),
+    };
+
+    let proposals = block_on(async {
+        let mut client = gov_query_client(chain).await?;
+
+        client
+            .proposals(request)
+            .await
+            .map(|response| response.into_inner().proposals)
+            .map_err(|e| Error::grpc_status(e, "query_proposals".to_owned()))
+    })
+    .map_err(UpgradeChainError::query)?;
+
+    let proposal = proposals
+        .into_iter()
+        .filter_map(UpgradeProposalState::from_gov_proposal)
+        .filter(|proposal| proposal.plan_name == plan_name)
+        .max_by_key(|proposal| proposal.proposal_id);
+
+    Ok(proposal)
+}
+
+/// Queries the current state of the IBC upgrade proposal with the given identifier.
+pub fn query_ibc_upgrade_proposal(
+    chain: &impl ChainHandle,
+    proposal_id: u64,
+) -> Result<UpgradeProposalState, UpgradeChainError> {
+    let proposal = block_on(async {
+        let mut client = gov_query_client(chain).await?;
+
+        client
+            .proposal(QueryProposalRequest { proposal_id })
+            .await
+            .map(|response| response.into_inner().proposal)
+            .map_err(|e| Error::grpc_status(e, "query_proposal".to_owned()))
+    })
+    .map_err(UpgradeChainError::query)?;
+
+    proposal
+        .and_then(UpgradeProposalState::from_gov_proposal)
+        .ok_or_else(|| UpgradeChainError::proposal_not_found(chain.id(), proposal_id))
+}
+
+async fn gov_query_client(chain: &impl ChainHandle) -> Result<GovQueryClient<GrpcChannel>, Error> {
+    let grpc_address = GrpcAddress::from_config(&chain.config()?)?;
+
+    let client = grpc_address
+        .connect(GovQueryClient::new)
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    Ok(client.max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize))
+}
@@ -7,8 +7,11 @@ pub mod diff;
 pub mod iter;
 pub mod lock;
 pub mod pretty;
+pub mod profile;
 pub mod profiling;
+pub mod proxy;
 pub mod queue;
+pub mod rate_limit;
 pub mod retry;
 pub mod stream;
 pub mod task;
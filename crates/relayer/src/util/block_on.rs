@@ -19,7 +19,7 @@ where
     F: Future + Send + 'static,
     F::Output: Send,
 {
-    std::thread::spawn(move || {
+    crate::util::profile::spawn_thread(move || {
         tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
@@ -0,0 +1,180 @@
+//! The relaying profile a supervisor works for, when a single Hermes process hosts
+//! several profiles, each with its own supervisor.
+//!
+//! The services set up for a profile, such as its event sink, hooks and channel leases,
+//! are handed to its supervisor in a [`Profile`], which passes them on to the scanner,
+//! the workers and the links relaying for the profile.
+//!
+//! The metrics recorded by a thread are labeled with the profile the thread was
+//! spawned for, see [`scope`] and [`spawn_thread`].
+
+use alloc::sync::Arc;
+use core::fmt;
+use std::thread::{self, JoinHandle};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::coordination::ChannelLeases;
+use crate::event_sink::EventSink;
+use crate::hooks::Hooks;
+
+/// The services of a relaying profile, or of the main configuration.
+///
+/// The default value is the main configuration without any service,
+/// as used by the commands relaying outside of the supervisor.
+#[derive(Clone, Default)]
+pub struct Profile {
+    name: Option<Arc<str>>,
+    event_sink: Option<EventSink>,
+    hooks: Option<Hooks>,
+    leases: Option<Arc<ChannelLeases>>,
+}
+
+impl Profile {
+    /// The services of the profile with the given name, none of which is set up yet.
+    pub fn named(name: &str) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// The name of the profile, or `None` for the main configuration.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn event_sink(&self) -> Option<&EventSink> {
+        self.event_sink.as_ref()
+    }
+
+    pub fn hooks(&self) -> Option<&Hooks> {
+        self.hooks.as_ref()
+    }
+
+    pub fn leases(&self) -> Option<&Arc<ChannelLeases>> {
+        self.leases.as_ref()
+    }
+
+    pub fn with_event_sink(self, event_sink: Option<EventSink>) -> Self {
+        Self { event_sink, ..self }
+    }
+
+    pub fn with_hooks(self, hooks: Option<Hooks>) -> Self {
+        Self { hooks, ..self }
+    }
+
+    pub fn with_leases(self, leases: Option<ChannelLeases>) -> Self {
+        Self {
+            leases: leases.map(Arc::new),
+            ..self
+        }
+    }
+
+    /// Whether this instance should relay on the given channel end for this profile, ie. whether
+    /// the coordination of instances is disabled, or this instance holds the lease on it.
+    pub fn is_channel_claimed(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> bool {
+        self.leases().map_or(true, |leases| {
+            leases.is_channel_claimed(chain_id, port_id, channel_id)
+        })
+    }
+}
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Profile")
+            .field("name", &self.name)
+            .field("event_sink", &self.event_sink.is_some())
+            .field("hooks", &self.hooks.is_some())
+            .field("leases", &self.leases.is_some())
+            .finish()
+    }
+}
+
+/// Runs the given closure with the metrics recorded by the current thread, and by the threads
+/// it spawns with [`spawn_thread`], labeled with the given profile, eg. to spawn its supervisor.
+pub fn scope<R>(profile: Option<&str>, f: impl FnOnce() -> R) -> R {
+    let previous = current();
+
+    enter(profile.map(str::to_owned));
+    let result = f();
+    enter(previous);
+
+    result
+}
+
+/// Spawns a thread whose metrics are labeled with the same profile as those of the current thread.
+pub fn spawn_thread<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let profile = current();
+
+    thread::spawn(move || {
+        enter(profile);
+        f()
+    })
+}
+
+#[cfg(feature = "telemetry")]
+fn current() -> Option<String> {
+    ibc_telemetry::profile::current()
+}
+
+#[cfg(feature = "telemetry")]
+fn enter(profile: Option<String>) {
+    ibc_telemetry::profile::set_current(profile)
+}
+
+#[cfg(not(feature = "telemetry"))]
+fn current() -> Option<String> {
+    None
+}
+
+#[cfg(not(feature = "telemetry"))]
+fn enter(_profile: Option<String>) {}
+
+#[cfg(all(test, feature = "telemetry"))]
+mod tests {
+    use super::*;
+
+    use crate::util::task::{spawn_background_task, Next, TaskError};
+
+    #[test]
+    fn scope_restores_the_previous_profile() {
+        scope(Some("a"), || {
+            scope(Some("b"), || assert_eq!(current().as_deref(), Some("b")));
+            assert_eq!(current().as_deref(), Some("a"));
+
+            // Threads are not labeled unless spawned with `spawn_thread`
+            thread::spawn(|| assert_eq!(current(), None))
+                .join()
+                .unwrap();
+        });
+
+        assert_eq!(current(), None);
+    }
+
+    #[test]
+    fn threads_and_background_tasks_inherit_the_profile() {
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+
+        let _task = scope(Some("a"), || {
+            let thread = spawn_thread(current);
+            assert_eq!(thread.join().unwrap().as_deref(), Some("a"));
+
+            spawn_background_task(tracing::Span::none(), None, move || {
+                sender.send(current()).unwrap();
+                Ok::<_, TaskError<String>>(Next::Abort)
+            })
+        });
+
+        assert_eq!(receiver.recv().unwrap().as_deref(), Some("a"));
+    }
+}
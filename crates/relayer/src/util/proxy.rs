@@ -0,0 +1,620 @@
+//! Tunneling of the connections to a chain's endpoints through a SOCKS5 or HTTP proxy.
+//!
+//! The gRPC clients connect through the proxy with a [`ProxyConnector`].
+//! The RPC client only supports unauthenticated HTTP proxies, so it instead
+//! connects to a [`ProxyBridge`], a local HTTP proxy which forwards the
+//! connections through the configured proxy.
+//! The WebSocket client does not support proxies at all, so it connects to a
+//! [`WebSocketBridge`], a local endpoint which forwards the connections to the
+//! WebSocket endpoint of the chain through the configured proxy.
+
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+
+use http::Uri;
+use tendermint_rpc::{HttpClient, HttpClientUrl, Url, WebSocketClientUrl};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::rustls::{self, ClientConfig, RootCertStore, ServerName};
+use tokio_rustls::TlsConnector;
+use tracing::{debug, warn};
+
+use crate::config::proxy::{ProxyConfig, ProxyProtocol};
+use crate::util::retry::ConstantGrowth;
+
+/// The maximum size of the head of an HTTP message exchanged with a proxy.
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+
+/// Opens a TCP connection to `host:port`, tunneled through the given proxy.
+///
+/// The host name is resolved by the proxy, so that no DNS query leaks
+/// outside of the proxy, as required eg. when connecting through Tor.
+pub async fn connect(proxy: &ProxyConfig, host: &str, port: u16) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect((unbracket(&proxy.url.host), proxy.url.port)).await?;
+
+    match proxy.url.protocol {
+        ProxyProtocol::Socks5 => socks5_connect(&mut stream, proxy, host, port).await?,
+        ProxyProtocol::Http => http_connect(&mut stream, proxy, host, port).await?,
+    }
+
+    stream.set_nodelay(true)?;
+
+    Ok(stream)
+}
+
+/// A connector for `tonic` channels, which tunnels the connections through a proxy.
+#[derive(Clone, Debug)]
+pub struct ProxyConnector {
+    proxy: ProxyConfig,
+}
+
+impl ProxyConnector {
+    pub fn new(proxy: ProxyConfig) -> Self {
+        Self { proxy }
+    }
+}
+
+impl tonic::codegen::Service<Uri> for ProxyConnector {
+    type Response = TcpStream;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<TcpStream>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let proxy = self.proxy.clone();
+
+        Box::pin(async move {
+            let host = uri
+                .host()
+                .ok_or_else(|| proxy_error(format!("missing host in URI `{uri}`")))?;
+
+            let port = uri.port_u16().unwrap_or_else(|| default_port(&uri));
+
+            connect(&proxy, host, port).await
+        })
+    }
+}
+
+/// A local HTTP proxy, listening on the loopback interface, which forwards
+/// the connections it accepts through the given proxy.
+///
+/// As any local process can connect to the bridge, it only forwards the connections
+/// to the given RPC endpoints of the chain, so that the bridge cannot be used to send
+/// arbitrary traffic through the proxy with the credentials of the operator.
+#[derive(Clone, Debug)]
+pub struct ProxyBridge {
+    url: Url,
+}
+
+impl ProxyBridge {
+    /// Starts the bridge on the current Tokio runtime, forwarding the connections
+    /// to the given endpoints only.
+    pub async fn spawn(proxy: ProxyConfig, endpoints: &[&Url]) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let addr = listener.local_addr()?;
+
+        debug!(%addr, proxy = %proxy.url, "started local proxy bridge");
+
+        let targets: Arc<[Target]> = endpoints
+            .iter()
+            .map(|url| Target::new(url.host(), url.port()))
+            .collect();
+
+        tokio::spawn(async move {
+            loop {
+                let client = accept(&listener, "proxy bridge").await;
+
+                let proxy = proxy.clone();
+                let targets = targets.clone();
+
+                tokio::spawn(async move {
+                    if let Err(e) = forward(client, &proxy, &targets).await {
+                        debug!("proxied connection closed: {e}");
+                    }
+                });
+            }
+        });
+
+        let url = format!("http://{addr}")
+            .parse()
+            .map_err(|e| proxy_error(format!("invalid proxy bridge address `{addr}`: {e}")))?;
+
+        Ok(Self { url })
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+/// Creates an RPC client for the given address, which connects through the given
+/// proxy bridge, if any.
+pub fn rpc_client(
+    addr: &Url,
+    bridge: Option<&ProxyBridge>,
+) -> Result<HttpClient, tendermint_rpc::Error> {
+    let builder = HttpClient::builder(HttpClientUrl::try_from(addr.clone())?);
+
+    match bridge {
+        Some(bridge) => builder
+            .proxy_url(HttpClientUrl::try_from(bridge.url().clone())?)
+            .build(),
+        None => builder.build(),
+    }
+}
+
+/// A local WebSocket endpoint, listening on the loopback interface, which forwards
+/// the connections it accepts to the given WebSocket endpoint through the given proxy.
+///
+/// The WebSocket client connects to the bridge in the clear, while the bridge connects
+/// to the endpoint over TLS if it is a `wss` one, and sets the `Host` header of the
+/// handshake to the one of the endpoint, eg. for the reverse proxies in front of it.
+#[derive(Clone, Debug)]
+pub struct WebSocketBridge {
+    url: WebSocketClientUrl,
+}
+
+impl WebSocketBridge {
+    /// Starts the bridge on the current Tokio runtime, forwarding the connections
+    /// to the given endpoint only.
+    pub async fn spawn(proxy: ProxyConfig, endpoint: &WebSocketClientUrl) -> io::Result<Self> {
+        let endpoint = Url::from(endpoint.clone());
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let addr = listener.local_addr()?;
+
+        debug!(%addr, %endpoint, proxy = %proxy.url, "started local WebSocket proxy bridge");
+
+        let tls = endpoint.is_secure().then(tls_connector).transpose()?;
+
+        // Keep the credentials of the endpoint, which the client sends in the handshake
+        let credentials = match (endpoint.username(), endpoint.password()) {
+            (Some(username), Some(password)) => format!("{username}:{password}@"),
+            (Some(username), None) => format!("{username}@"),
+            _ => String::new(),
+        };
+
+        let url = format!("ws://{credentials}{addr}{}", endpoint.path())
+            .parse()
+            .map_err(|e| proxy_error(format!("invalid proxy bridge address `{addr}`: {e}")))?;
+
+        let target = Arc::new(endpoint);
+
+        tokio::spawn(async move {
+            loop {
+                let client = accept(&listener, "WebSocket proxy bridge").await;
+
+                let proxy = proxy.clone();
+                let target = target.clone();
+                let tls = tls.clone();
+
+                tokio::spawn(async move {
+                    if let Err(e) = forward_websocket(client, &proxy, &target, tls.as_ref()).await {
+                        debug!("proxied WebSocket connection closed: {e}");
+                    }
+                });
+            }
+        });
+
+        Ok(Self { url })
+    }
+
+    pub fn url(&self) -> &WebSocketClientUrl {
+        &self.url
+    }
+}
+
+/// Accepts the next connection on the listener of a bridge, waiting a little longer
+/// after each consecutive failure, so that a persistent error, eg. when running out of
+/// file descriptors, does not turn into a busy loop.
+async fn accept(listener: &TcpListener, bridge: &str) -> TcpStream {
+    let mut delays = ConstantGrowth::new(Duration::from_millis(100), Duration::from_millis(100))
+        .clamp(Duration::from_secs(1), usize::MAX);
+
+    loop {
+        match listener.accept().await {
+            Ok((client, _)) => return client,
+            Err(e) => {
+                let delay = delays.next().unwrap_or(Duration::from_secs(1));
+
+                warn!("{bridge} failed to accept a connection: {e}, retrying in {delay:?}");
+
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Forwards a connection accepted by a [`WebSocketBridge`] to its endpoint through the proxy.
+async fn forward_websocket(
+    mut client: TcpStream,
+    proxy: &ProxyConfig,
+    target: &Url,
+    tls: Option<&TlsConnector>,
+) -> io::Result<()> {
+    let head = read_head(&mut client).await?;
+    let head = with_host(&String::from_utf8_lossy(&head), target);
+
+    let upstream = connect(proxy, target.host(), target.port()).await?;
+
+    match tls {
+        Some(tls) => {
+            let server_name = ServerName::try_from(unbracket(target.host()))
+                .map_err(|e| proxy_error(format!("invalid TLS server name: {e}")))?;
+
+            let upstream = tls.connect(server_name, upstream).await?;
+            relay(client, upstream, head.as_bytes()).await
+        }
+        None => relay(client, upstream, head.as_bytes()).await,
+    }
+}
+
+/// Sends the given head of the request of the client upstream,
+/// and then forwards the data in both directions.
+async fn relay<S>(mut client: TcpStream, mut upstream: S, head: &[u8]) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    upstream.write_all(head).await?;
+    tokio::io::copy_bidirectional(&mut client, &mut upstream).await?;
+
+    Ok(())
+}
+
+/// Replaces the `Host` header of the given head of an HTTP request
+/// with the host and port of the given endpoint.
+fn with_host(head: &str, endpoint: &Url) -> String {
+    let default_port = if endpoint.is_secure() { 443 } else { 80 };
+
+    let host = if endpoint.port() == default_port {
+        endpoint.host().to_string()
+    } else {
+        format!("{}:{}", endpoint.host(), endpoint.port())
+    };
+
+    head.split_inclusive("\r\n")
+        .map(|line| {
+            let is_host = line
+                .split_once(':')
+                .map_or(false, |(name, _)| name.trim().eq_ignore_ascii_case("host"));
+
+            if is_host {
+                format!("Host: {host}\r\n")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+/// A TLS connector trusting the root certificates of the system,
+/// as the gRPC clients do.
+fn tls_connector() -> io::Result<TlsConnector> {
+    let mut roots = RootCertStore::empty();
+
+    for cert in rustls_native_certs::load_native_certs()? {
+        if let Err(e) = roots.add(&rustls::Certificate(cert.0)) {
+            debug!("ignoring invalid system root certificate: {e}");
+        }
+    }
+
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+/// An endpoint to which a [`ProxyBridge`] forwards the connections.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Target {
+    host: String,
+    port: u16,
+}
+
+impl Target {
+    fn new(host: &str, port: u16) -> Self {
+        Self {
+            host: unbracket(host).to_ascii_lowercase(),
+            port,
+        }
+    }
+}
+
+/// Whether the given host and port are one of the targets of the bridge.
+fn is_allowed(targets: &[Target], host: &str, port: u16) -> bool {
+    targets.contains(&Target::new(host, port))
+}
+
+/// Forwards a connection accepted by a [`ProxyBridge`] through the proxy,
+/// provided it targets one of the given endpoints.
+///
+/// Requests to HTTPS endpoints are tunneled with the `CONNECT` method, while
+/// requests to plain HTTP endpoints are sent with the target URI in absolute form.
+async fn forward(mut client: TcpStream, proxy: &ProxyConfig, targets: &[Target]) -> io::Result<()> {
+    let head = read_head(&mut client).await?;
+    let head_str = String::from_utf8_lossy(&head);
+
+    let mut request_line = head_str
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let target = request_line.next().unwrap_or_default();
+
+    let mut upstream = if method == "CONNECT" {
+        let (host, port) = target
+            .rsplit_once(':')
+            .and_then(|(host, port)| Some((host, port.parse().ok()?)))
+            .ok_or_else(|| proxy_error(format!("invalid CONNECT target `{target}`")))?;
+
+        reject_unless_allowed(&mut client, targets, host, port).await?;
+
+        let upstream = connect(proxy, host, port).await?;
+
+        client
+            .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+            .await?;
+
+        upstream
+    } else {
+        let uri: Uri = target
+            .parse()
+            .map_err(|e| proxy_error(format!("invalid request target `{target}`: {e}")))?;
+
+        let host = uri
+            .host()
+            .ok_or_else(|| proxy_error(format!("missing host in request target `{target}`")))?;
+
+        let port = uri.port_u16().unwrap_or_else(|| default_port(&uri));
+
+        reject_unless_allowed(&mut client, targets, host, port).await?;
+
+        let mut upstream = connect(proxy, host, port).await?;
+        upstream.write_all(&head).await?;
+
+        upstream
+    };
+
+    tokio::io::copy_bidirectional(&mut client, &mut upstream).await?;
+
+    Ok(())
+}
+
+/// Answers the client with `403 Forbidden` and fails if the given host and port
+/// are not one of the targets of the bridge.
+async fn reject_unless_allowed(
+    client: &mut TcpStream,
+    targets: &[Target],
+    host: &str,
+    port: u16,
+) -> io::Result<()> {
+    if is_allowed(targets, host, port) {
+        return Ok(());
+    }
+
+    warn!("proxy bridge rejected a connection to {host}:{port}, which is not an endpoint of the chain");
+
+    client.write_all(b"HTTP/1.1 403 Forbidden\r\n\r\n").await?;
+
+    Err(proxy_error(format!(
+        "connections to {host}:{port} are not allowed"
+    )))
+}
+
+/// Performs a SOCKS5 handshake (RFC 1928), authenticating with a username
+/// and password (RFC 1929) if the proxy is configured with credentials.
+async fn socks5_connect(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> io::Result<()> {
+    const VERSION: u8 = 0x05;
+    const NO_AUTHENTICATION: u8 = 0x00;
+    const USERNAME_PASSWORD: u8 = 0x02;
+    const CONNECT: u8 = 0x01;
+    const SUCCEEDED: u8 = 0x00;
+
+    let credentials = proxy.credentials();
+
+    let method = if credentials.is_some() {
+        USERNAME_PASSWORD
+    } else {
+        NO_AUTHENTICATION
+    };
+
+    stream.write_all(&[VERSION, 1, method]).await?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+
+    if reply != [VERSION, method] {
+        return Err(proxy_error(
+            "SOCKS5 proxy does not accept the authentication method",
+        ));
+    }
+
+    if let Some((username, password)) = credentials {
+        let mut request = vec![0x01];
+        request.push(length_prefix(username, "username")?);
+        request.extend_from_slice(username.as_bytes());
+        request.push(length_prefix(password, "password")?);
+        request.extend_from_slice(password.as_bytes());
+
+        stream.write_all(&request).await?;
+        stream.read_exact(&mut reply).await?;
+
+        if reply[1] != SUCCEEDED {
+            return Err(proxy_error("SOCKS5 proxy rejected the credentials"));
+        }
+    }
+
+    let host = unbracket(host);
+
+    let mut request = vec![VERSION, CONNECT, 0x00];
+
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(0x01);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(0x04);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            request.push(0x03);
+            request.push(length_prefix(host, "host name")?);
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+
+    if reply[1] != SUCCEEDED {
+        return Err(proxy_error(format!(
+            "SOCKS5 proxy failed to connect to {host}:{port} (reply code {:#04x})",
+            reply[1]
+        )));
+    }
+
+    // Skip the address and port the proxy bound to
+    let bound_addr_len = match reply[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len).await?;
+            len[0] as usize
+        }
+        _ => return Err(proxy_error("SOCKS5 proxy sent an invalid reply")),
+    };
+
+    let mut bound_addr = vec![0u8; bound_addr_len + 2];
+    stream.read_exact(&mut bound_addr).await?;
+
+    Ok(())
+}
+
+/// Opens a tunnel with the HTTP `CONNECT` method, authenticating with
+/// the `Basic` scheme if the proxy is configured with credentials.
+async fn http_connect(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> io::Result<()> {
+    let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+
+    if let Some((username, password)) = proxy.credentials() {
+        let token = subtle_encoding::base64::encode(format!("{username}:{password}"));
+        request.push_str("Proxy-Authorization: Basic ");
+        request.push_str(&String::from_utf8_lossy(&token));
+        request.push_str("\r\n");
+    }
+
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    let head = read_head(stream).await?;
+    let head = String::from_utf8_lossy(&head);
+    let status_line = head.lines().next().unwrap_or_default();
+
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(()),
+        _ => Err(proxy_error(format!(
+            "HTTP proxy failed to connect to {host}:{port}: {status_line}"
+        ))),
+    }
+}
+
+/// Reads the head of an HTTP message, up to and including the empty line which ends it.
+///
+/// The stream is read one byte at a time, so that none of the data following the head is consumed.
+async fn read_head(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let mut head = Vec::new();
+
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_HEAD_SIZE {
+            return Err(proxy_error("HTTP message head is too large"));
+        }
+
+        head.push(stream.read_u8().await?);
+    }
+
+    Ok(head)
+}
+
+fn length_prefix(value: &str, name: &str) -> io::Result<u8> {
+    u8::try_from(value.len())
+        .map_err(|_| proxy_error(format!("{name} is too long for a SOCKS5 request")))
+}
+
+fn default_port(uri: &Uri) -> u16 {
+    match uri.scheme_str() {
+        Some("https") => 443,
+        _ => 80,
+    }
+}
+
+/// Removes the brackets around an IPv6 address, as found in URLs.
+fn unbracket(host: &str) -> &str {
+    host.trim_start_matches('[').trim_end_matches(']')
+}
+
+fn proxy_error(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bridge_only_forwards_to_its_targets() {
+        let targets = [
+            Target::new("rpc.example.com", 26657),
+            Target::new("[::1]", 443),
+        ];
+
+        assert!(is_allowed(&targets, "rpc.example.com", 26657));
+        assert!(is_allowed(&targets, "RPC.Example.com", 26657));
+        assert!(is_allowed(&targets, "[::1]", 443));
+        assert!(is_allowed(&targets, "::1", 443));
+
+        assert!(!is_allowed(&targets, "rpc.example.com", 443));
+        assert!(!is_allowed(&targets, "attacker.example.com", 26657));
+    }
+
+    #[test]
+    fn websocket_bridge_sets_host_of_endpoint() {
+        let head = "GET /websocket HTTP/1.1\r\nhost: 127.0.0.1:4242\r\nUpgrade: websocket\r\n\r\n";
+
+        let endpoint: Url = "wss://rpc.example.com/websocket".parse().unwrap();
+        assert_eq!(
+            with_host(head, &endpoint),
+            "GET /websocket HTTP/1.1\r\nHost: rpc.example.com\r\nUpgrade: websocket\r\n\r\n"
+        );
+
+        let endpoint: Url = "ws://rpc.example.com:26657/websocket".parse().unwrap();
+        assert_eq!(
+            with_host(head, &endpoint),
+            "GET /websocket HTTP/1.1\r\nHost: rpc.example.com:26657\r\nUpgrade: websocket\r\n\r\n"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use core::num::NonZeroU32;
+use core::time::Duration;
+use std::sync::{Arc, RwLock};
+
+use tokio::time::Instant;
+use tracing::trace;
+
+use crate::util::lock::{LockExt, RwArc};
+
+/// Limits the rate at which requests are sent to an endpoint.
+///
+/// Requests are spaced evenly, and a request which would exceed the rate
+/// waits for its turn rather than failing. Clones of a limiter share the
+/// same schedule, so that all the clients of an endpoint are limited together.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next_slot: RwArc<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: NonZeroU32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.get(),
+            next_slot: Arc::new(RwLock::new(Instant::now())),
+        }
+    }
+
+    /// Waits until a request can be sent without exceeding the rate.
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.acquire_write();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+
+        let delay = slot.saturating_duration_since(Instant::now());
+
+        if !delay.is_zero() {
+            trace!("request is rate limited, waiting for {delay:?}");
+            tokio::time::sleep_until(slot).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_queued() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        let limiter = RateLimiter::new(NonZeroU32::new(100).unwrap());
+        let start = Instant::now();
+
+        rt.block_on(async {
+            for _ in 0..5 {
+                limiter.clone().acquire().await;
+            }
+        });
+
+        // The first request goes through immediately, the others are spaced by 10ms
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
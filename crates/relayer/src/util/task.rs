@@ -7,6 +7,7 @@ use std::thread;
 use tracing::{debug, error, warn};
 
 use crate::util::lock::LockExt;
+use crate::util::profile;
 
 /**
    A task handle holds the endpoints for stopping or waiting for a
@@ -97,7 +98,7 @@ pub fn spawn_background_task<E: Display>(
 
     let (shutdown_sender, receiver) = bounded(1);
 
-    let join_handle = thread::spawn(move || {
+    let join_handle = profile::spawn_thread(move || {
         let _entered = span.enter();
         loop {
             match receiver.try_recv() {
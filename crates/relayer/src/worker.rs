@@ -11,6 +11,7 @@ use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    util::profile::Profile,
 };
 
 pub mod retry_strategy;
@@ -59,6 +60,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
     id: WorkerId,
     object: Object,
     config: &Config,
+    profile: &Profile,
 ) -> WorkerHandle {
     let mut task_handles = Vec::new();
 
@@ -120,7 +122,8 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                 },
                 packets_config.tx_confirmation,
                 packets_config.auto_register_counterparty_payee,
-            );
+            )
+            .map(|link| link.with_profile(profile.clone()));
 
             match link_res {
                 Ok(link) => {
@@ -1,16 +1,18 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::btree_set::BTreeSet;
 use core::mem;
 
 use ibc_relayer_types::core::ics02_client::events::NewBlock;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::Height;
 use tracing::{debug, trace};
 
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
-    object::Object,
+    object::{Client, Object},
     telemetry,
+    util::profile::Profile,
 };
 
 use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
@@ -20,23 +22,40 @@ use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
     latest_worker_id: WorkerId,
+
+    /// The chains on which relaying was halted
+    halted_chains: BTreeSet<ChainId>,
+
+    /// The clients tracking a chain whose height went backwards,
+    /// until an operator acknowledges that they were checked
+    suspect_clients: BTreeSet<Client>,
+
+    /// The services of the profile the workers relay for
+    profile: Profile,
 }
 
 impl Default for WorkerMap {
     fn default() -> Self {
+        Self::new(Profile::default())
+    }
+}
+
+impl WorkerMap {
+    /// Create a new worker map, which will spawn workers relaying
+    /// for the profile with the given services.
+    pub fn new(profile: Profile) -> Self {
         Self {
             workers: HashMap::new(),
             latest_worker_id: WorkerId::new(0),
+            halted_chains: BTreeSet::new(),
+            suspect_clients: BTreeSet::new(),
+            profile,
         }
     }
-}
 
-impl WorkerMap {
-    /// Create a new worker map, which will spawn workers with
-    /// the given channel for sending messages back to the
-    /// [supervisor](crate::supervisor::SupervisorHandle).
-    pub fn new() -> Self {
-        Self::default()
+    /// The services of the profile the workers relay for.
+    pub fn profile(&self) -> &Profile {
+        &self.profile
     }
 
     /// Returns `true` if there is a spawned [`WorkerHandle`] associated with the given [`Object`].
@@ -132,7 +151,9 @@ impl WorkerMap {
         }
     }
 
-    /// Spawn a new [`WorkerHandle`], only if one does not exists already.
+    /// Spawn a new [`WorkerHandle`], only if one does not exists already,
+    /// relaying was not halted on any of the chains of the object, and
+    /// the object does not rely on a suspect client.
     ///
     /// Returns whether or not the worker was actually spawned.
     pub fn spawn<Chain: ChainHandle>(
@@ -142,6 +163,10 @@ impl WorkerMap {
         object: &Object,
         config: &Config,
     ) -> bool {
+        if self.is_blocked(object) {
+            return false;
+        }
+
         if !self.workers.contains_key(object) {
             let worker = self.spawn_worker(src, dst, object, config);
             self.workers.entry(object.clone()).or_insert(worker);
@@ -166,6 +191,7 @@ impl WorkerMap {
             self.next_worker_id(),
             object.clone(),
             config,
+            &self.profile,
         )
     }
 
@@ -209,6 +235,75 @@ impl WorkerMap {
         // Drop handle automatically handles the waiting for tasks to terminate.
     }
 
+    /// Shut down the workers relaying from or to the given chain, and mark the chain
+    /// as halted, so that no more workers are spawned for it until it is resumed.
+    pub fn halt_chain(&mut self, chain_id: &ChainId) {
+        for object in self.objects_for_chain(chain_id) {
+            self.shutdown_worker(&object);
+        }
+
+        self.halted_chains.insert(chain_id.clone());
+    }
+
+    /// Resume relaying from and to the given chain, so that its workers
+    /// can be spawned again.
+    pub fn resume_chain(&mut self, chain_id: &ChainId) {
+        self.halted_chains.remove(chain_id);
+    }
+
+    /// Returns `true` if relaying from or to the given chain was halted.
+    pub fn is_halted(&self, chain_id: &ChainId) -> bool {
+        self.halted_chains.contains(chain_id)
+    }
+
+    /// Mark the given client as suspect, so that no worker is spawned for it,
+    /// nor for the packets relayed between its chains, until it is acknowledged.
+    pub fn mark_suspect(&mut self, client: Client) {
+        self.suspect_clients.insert(client);
+    }
+
+    /// Clear the suspect state of the given client, once an operator checked it.
+    ///
+    /// Returns whether or not the client was suspect.
+    pub fn acknowledge_suspect(&mut self, chain_id: &ChainId, client_id: &ClientId) -> bool {
+        let len = self.suspect_clients.len();
+
+        self.suspect_clients.retain(|client| {
+            &client.dst_chain_id != chain_id || &client.dst_client_id != client_id
+        });
+
+        self.suspect_clients.len() < len
+    }
+
+    /// The clients tracking a chain whose height went backwards,
+    /// which have not been acknowledged yet.
+    pub fn suspect_clients(&self) -> impl Iterator<Item = &Client> {
+        self.suspect_clients.iter()
+    }
+
+    /// Returns `true` if the given object is a suspect client, or a packet relayed
+    /// between the chain hosting a suspect client and the chain it tracks.
+    pub fn is_suspect(&self, object: &Object) -> bool {
+        match object {
+            Object::Client(client) => self.suspect_clients.contains(client),
+            Object::Packet(packet) => self.suspect_clients.iter().any(|client| {
+                (client.src_chain_id == packet.src_chain_id
+                    && client.dst_chain_id == packet.dst_chain_id)
+                    || (client.src_chain_id == packet.dst_chain_id
+                        && client.dst_chain_id == packet.src_chain_id)
+            }),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if no worker must be spawned for the given object, as relaying
+    /// was halted on one of its chains, or as it relies on a suspect client.
+    pub fn is_blocked(&self, object: &Object) -> bool {
+        self.is_halted(object.src_chain_id())
+            || self.is_halted(object.dst_chain_id())
+            || self.is_suspect(object)
+    }
+
     /// Shut down all the workers, asynchronously.
     pub fn shutdown(&mut self) {
         let workers = mem::take(&mut self.workers);
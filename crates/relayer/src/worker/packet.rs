@@ -9,6 +9,7 @@ use itertools::Itertools;
 use moka::sync::Cache;
 use std::borrow::BorrowMut;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tracing::{debug, warn};
 
 use crossbeam_channel::Receiver;
@@ -23,6 +24,7 @@ use tracing::{error, error_span, trace};
 
 use ibc_relayer_types::Height;
 
+use crate::chain::endpoint::ChainFeatures;
 use crate::chain::handle::ChainHandle;
 use crate::config::filter::FeePolicy;
 use crate::event::source::EventBatch;
@@ -106,8 +108,27 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
     let mut idle_worker_timer = 0;
 
+    // The packet events of the channel are accumulated during its batch delay, if any
+    let batch_delay = {
+        let relay_path = &link.lock().unwrap().a_to_b;
+
+        relay_path.src_chain().config().ok().and_then(|config| {
+            config
+                .channel_batch(relay_path.src_port_id(), relay_path.src_channel_id())
+                .and_then(|channel_batch| channel_batch.batch_delay)
+        })
+    };
+
+    let mut delayed_batch = None;
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
-        if let Ok(cmd) = cmd_rx.try_recv() {
+        let mut cmd = cmd_rx.try_recv().ok();
+
+        if let Some(batch_delay) = batch_delay {
+            cmd = delay_event_batch(cmd, &mut delayed_batch, batch_delay);
+        }
+
+        if let Some(cmd) = cmd {
             let is_new_batch = cmd.is_ibc_events();
 
             // Try to clear pending packets. At different levels down in `handle_packet_cmd` there
@@ -142,6 +163,34 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     })
 }
 
+/// Accumulates the event batches received during the batch delay into a single batch,
+/// which is returned as a command once the delay since the first batch has elapsed.
+/// The other commands are returned as is.
+fn delay_event_batch(
+    cmd: Option<WorkerCmd>,
+    delayed_batch: &mut Option<(Instant, EventBatch)>,
+    batch_delay: Duration,
+) -> Option<WorkerCmd> {
+    match cmd {
+        Some(WorkerCmd::IbcEvents { batch }) => match delayed_batch {
+            Some((_, delayed)) => {
+                delayed.height = delayed.height.max(batch.height);
+                delayed.events.extend(batch.events);
+            }
+            None => *delayed_batch = Some((Instant::now(), batch)),
+        },
+        Some(cmd) => return Some(cmd),
+        None => {}
+    }
+
+    match delayed_batch {
+        Some((since, _)) if since.elapsed() >= batch_delay => delayed_batch
+            .take()
+            .map(|(_, batch)| WorkerCmd::IbcEvents { batch }),
+        _ => None,
+    }
+}
+
 pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     cmd_rx: Receiver<WorkerCmd>,
     // Mutex is used to prevent race condition between the packet workers
@@ -297,6 +346,11 @@ fn _get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: Chai
 ) {
     let dst_chain = link.a_to_b.dst_chain();
 
+    // No packet is incentivized on a chain which does not run the fee middleware
+    if !ChainFeatures::detected_or_all(&dst_chain.id(), dst_chain.query_features()).fee_middleware {
+        return;
+    }
+
     // Build PacketId required for the QueryIncentivizedPacketRequest
     let packet_id = PacketId {
         port_id: ack.packet.source_port.to_string(),
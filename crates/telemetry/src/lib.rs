@@ -1,5 +1,6 @@
 pub mod encoder;
 mod path_identifier;
+pub mod profile;
 pub mod server;
 pub mod state;
 
@@ -0,0 +1,64 @@
+//! The relaying profile whose metrics are recorded by the current thread, when a single
+//! Hermes process hosts several profiles, so that their metrics carry a `profile` label.
+
+use std::cell::RefCell;
+
+use opentelemetry::KeyValue;
+
+thread_local! {
+    static PROFILE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets the profile whose metrics are recorded by the current thread from now on,
+/// or `None` for the main configuration, whose metrics are not labeled.
+pub fn set_current(profile: Option<String>) {
+    PROFILE.with(|current| *current.borrow_mut() = profile);
+}
+
+/// The profile whose metrics are recorded by the current thread, if any.
+pub fn current() -> Option<String> {
+    PROFILE.with(|current| current.borrow().clone())
+}
+
+/// Appends the `profile` label to the given labels, if the current thread
+/// records the metrics of a profile.
+pub fn with_profile<const N: usize>(labels: [KeyValue; N]) -> Vec<KeyValue> {
+    let mut labels = Vec::from(labels);
+
+    PROFILE.with(|current| {
+        if let Some(profile) = current.borrow().as_ref() {
+            labels.push(KeyValue::new("profile", profile.clone()));
+        }
+    });
+
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_the_metrics_of_profiles() {
+        let labels = with_profile([KeyValue::new("chain", "ibc-0")]);
+        assert_eq!(labels, vec![KeyValue::new("chain", "ibc-0")]);
+
+        set_current(Some("customer-a".to_string()));
+
+        let labels = with_profile([KeyValue::new("chain", "ibc-0")]);
+        assert_eq!(
+            labels,
+            vec![
+                KeyValue::new("chain", "ibc-0"),
+                KeyValue::new("profile", "customer-a")
+            ]
+        );
+
+        // The profile is only set for the current thread
+        std::thread::spawn(|| assert_eq!(current(), None))
+            .join()
+            .unwrap();
+
+        set_current(None);
+    }
+}
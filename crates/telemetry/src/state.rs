@@ -1,8 +1,9 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use std::{
+    collections::BTreeMap,
     ops::Range,
     sync::Mutex,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 use dashmap::{DashMap, DashSet};
@@ -23,11 +24,36 @@ use ibc_relayer_types::{
 use tendermint::Time;
 
 use crate::path_identifier::PathIdentifier;
+use crate::profile::with_profile;
 
 const EMPTY_BACKLOG_SYMBOL: u64 = 0;
 const BACKLOG_CAPACITY: usize = 1000;
 const BACKLOG_RESET_THRESHOLD: usize = 900;
 
+/// The maximum number of packets whose time to relay is measured at the same time,
+/// beyond which the least recently sent ones are forgotten.
+const IN_FLIGHT_PACKETS_CAPACITY: u64 = 100_000;
+
+/// The buckets of the histogram of the ratios of the gas used by the transactions
+/// to the gas they requested. Ratios above 1 are those of transactions running out of gas.
+const GAS_USED_RATIO_BUCKETS: [f64; 11] = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.5];
+
+/// The buckets of the histogram of the latencies of the queries, in milliseconds.
+const QUERY_LATENCY_BUCKETS: [f64; 11] = [
+    5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0,
+];
+
+/// The buckets of the histogram of the sizes of the data of the packets, in bytes.
+const PACKET_DATA_SIZE_BUCKETS: [f64; 9] = [
+    64.0, 256.0, 1024.0, 4096.0, 16384.0, 65536.0, 262144.0, 1048576.0, 4194304.0,
+];
+
+/// The buckets of the histogram of the times to relay the packets, in milliseconds.
+const PACKET_RELAY_LATENCY_BUCKETS: [f64; 11] = [
+    1000.0, 2000.0, 5000.0, 10000.0, 20000.0, 30000.0, 60000.0, 120000.0, 300000.0, 600000.0,
+    1800000.0,
+];
+
 const QUERY_TYPES_CACHE: [&str; 4] = [
     "query_latest_height",
     "query_client_state",
@@ -35,7 +61,7 @@ const QUERY_TYPES_CACHE: [&str; 4] = [
     "query_channel",
 ];
 
-const QUERY_TYPES: [&str; 26] = [
+const QUERY_TYPES: [&str; 27] = [
     "query_latest_height",
     "query_block",
     "query_blocks",
@@ -45,6 +71,7 @@ const QUERY_TYPES: [&str; 26] = [
     "query_unreceived_acknowledgements",
     "query_packet_acknowledgements",
     "query_unreceived_packets",
+    "query_packet_commitment",
     "query_packet_commitments",
     "query_channel_client_state",
     "query_channel",
@@ -116,18 +143,38 @@ pub struct TelemetryState {
     /// Number of queries submitted by Hermes, per chain and query type
     queries: Counter<u64>,
 
+    /// The latency of the queries of the client states, consensus states and packet
+    /// commitments submitted by Hermes, per chain and query type
+    query_latency: ObservableGauge<u64>,
+
     /// Number of cache hits for queries submitted by Hermes, per chain and query type
     queries_cache_hits: Counter<u64>,
 
     /// Number of times Hermes reconnected to the websocket endpoint, per chain
     ws_reconnect: Counter<u64>,
 
+    /// Number of times the height of a chain went backwards, eg. after a restart from genesis, per chain
+    height_rollbacks: Counter<u64>,
+
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     ws_events: Counter<u64>,
 
     /// Number of messages submitted to a specific chain
     messages_submitted: Counter<u64>,
 
+    /// Number of transactions broadcast to a chain, including the failed broadcasts
+    tx_broadcast_attempts: Counter<u64>,
+
+    /// Number of transactions rejected by a chain during CheckTx, when broadcast
+    tx_check_tx_failures: Counter<u64>,
+
+    /// Number of transactions which failed during DeliverTx, when included in a block
+    tx_deliver_tx_failures: Counter<u64>,
+
+    /// The ratio of the gas used by each transaction included in a block of a chain
+    /// to the gas requested by the transaction
+    tx_gas_used_ratio: ObservableGauge<f64>,
+
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
@@ -148,9 +195,27 @@ pub struct TelemetryState {
     /// Number of SendPacket events received
     send_packet_events: Counter<u64>,
 
+    /// The sizes of the data of the packets sent on a specific channel. Bytes.
+    packet_data_size: ObservableGauge<u64>,
+
+    /// The time to relay the packets sent on a specific channel, i.e. the difference between
+    /// the time of the block in which a packet was sent and the moment when Hermes received
+    /// the WriteAcknowledgement event of the packet. Milliseconds.
+    packet_relay_latency: ObservableGauge<u64>,
+
+    /// Records the time of the block in which each pending packet was sent.
+    /// Used for computing the `packet_relay_latency` metric.
+    in_flight_packets: moka::sync::Cache<String, SystemTime>,
+
     /// Number of WriteAcknowledgement events received
     acknowledgement_events: Counter<u64>,
 
+    /// Number of ICS-20 acknowledgements received, by outcome (success or error)
+    ics20_acknowledgements: Counter<u64>,
+
+    /// Number of packets whose memo exceeds the maximum size, by policy (relay, skip or strip)
+    oversized_memo_packets: Counter<u64>,
+
     /// Number of Timeout events received
     timeout_events: Counter<u64>,
 
@@ -260,6 +325,13 @@ impl TelemetryState {
                 )
                 .init(),
 
+            query_latency: meter
+                .u64_observable_gauge("query_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The latency of the queries of the client states, consensus states \
+                    and packet commitments submitted by Hermes, per chain and query type. Milliseconds.")
+                .init(),
+
             queries_cache_hits: meter
                 .u64_counter("queries_cache_hits")
                 .with_description("Number of cache hits for queries submitted by Hermes")
@@ -270,6 +342,11 @@ impl TelemetryState {
                 .with_description("Number of times Hermes reconnected to the websocket endpoint")
                 .init(),
 
+            height_rollbacks: meter
+                .u64_counter("height_rollbacks")
+                .with_description("Number of times relaying was halted as the height of a chain went backwards, eg. after a restart from genesis")
+                .init(),
+
             ws_events: meter
                 .u64_counter("ws_events")
                 .with_description("How many IBC events did Hermes receive via the websocket subscription")
@@ -280,6 +357,27 @@ impl TelemetryState {
                 .with_description("Number of messages submitted to a specific chain")
                 .init(),
 
+            tx_broadcast_attempts: meter
+                .u64_counter("tx_broadcast_attempts")
+                .with_description("Number of transactions broadcast to a specific chain, including the failed broadcasts")
+                .init(),
+
+            tx_check_tx_failures: meter
+                .u64_counter("tx_check_tx_failures")
+                .with_description("Number of transactions rejected by a specific chain during CheckTx")
+                .init(),
+
+            tx_deliver_tx_failures: meter
+                .u64_counter("tx_deliver_tx_failures")
+                .with_description("Number of transactions which failed during DeliverTx on a specific chain. Available if relayer runs with Tx confirmation enabled")
+                .init(),
+
+            tx_gas_used_ratio: meter
+                .f64_observable_gauge("tx_gas_used_ratio")
+                .with_description("The ratio of the gas used by the transactions included in a block \
+                    to the gas they requested, per chain. Available if relayer runs with Tx confirmation enabled")
+                .init(),
+
             wallet_balance: meter
                 .f64_observable_gauge("wallet_balance")
                 .with_description("The balance of each wallet Hermes uses per chain. Please note that when converting the balance to f64 a loss in precision might be introduced in the displayed value")
@@ -295,6 +393,16 @@ impl TelemetryState {
                 .with_description("Number of WriteAcknowledgement events received")
                 .init(),
 
+            ics20_acknowledgements: meter
+                .u64_counter("ics20_acknowledgements")
+                .with_description("Number of ICS-20 acknowledgements received, by outcome (success or error)")
+                .init(),
+
+            oversized_memo_packets: meter
+                .u64_counter("oversized_memo_packets")
+                .with_description("Number of packets whose memo exceeds the maximum size, by policy (relay, skip or strip)")
+                .init(),
+
             timeout_events: meter
                 .u64_counter("timeout_events")
                 .with_description("Number of TimeoutPacket events received")
@@ -331,6 +439,25 @@ impl TelemetryState {
                 .time_to_idle(Duration::from_secs(30 * 60)) // Remove entries if they have been idle for 30 minutes
                 .build(),
 
+            packet_data_size: meter
+                .u64_observable_gauge("packet_data_size")
+                .with_unit(Unit::new("bytes"))
+                .with_description("The sizes of the data of the packets sent on a specific channel. Bytes.")
+                .init(),
+
+            packet_relay_latency: meter
+                .u64_observable_gauge("packet_relay_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The time to relay the packets sent on a specific channel, \
+                    i.e. the difference between the time of the block in which a packet was sent \
+                    and the moment when Hermes received its WriteAcknowledgement event. Milliseconds.")
+                .init(),
+
+            in_flight_packets: moka::sync::Cache::builder()
+                .max_capacity(IN_FLIGHT_PACKETS_CAPACITY)
+                .time_to_live(Duration::from_secs(24 * 60 * 60)) // Remove entries after 1 day
+                .build(),
+
             backlogs: DashMap::new(),
 
             backlog_oldest_sequence: meter
@@ -370,6 +497,24 @@ impl TelemetryState {
         self.exporter.registry().gather()
     }
 
+    /// The current values of the gauge with the given name, along with their labels.
+    pub fn gauge_values(&self, name: &str) -> Vec<(BTreeMap<String, String>, f64)> {
+        self.gather()
+            .iter()
+            .filter(|family| family.get_name() == name)
+            .flat_map(|family| family.get_metric())
+            .map(|metric| {
+                let labels = metric
+                    .get_label()
+                    .iter()
+                    .map(|label| (label.get_name().to_string(), label.get_value().to_string()))
+                    .collect();
+
+                (labels, metric.get_gauge().get_value())
+            })
+            .collect()
+    }
+
     pub fn init_worker_by_type(&self, worker_type: WorkerType) {
         self.worker(worker_type, 0);
     }
@@ -377,11 +522,15 @@ impl TelemetryState {
     pub fn init_per_chain(&self, chain_id: &ChainId) {
         let cx = Context::current();
 
-        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
 
         self.ws_reconnect.add(&cx, 0, labels);
+        self.height_rollbacks.add(&cx, 0, labels);
         self.ws_events.add(&cx, 0, labels);
         self.messages_submitted.add(&cx, 0, labels);
+        self.tx_broadcast_attempts.add(&cx, 0, labels);
+        self.tx_check_tx_failures.add(&cx, 0, labels);
+        self.tx_deliver_tx_failures.add(&cx, 0, labels);
 
         self.init_queries(chain_id);
     }
@@ -397,14 +546,14 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("src_chain", src_chain.to_string()),
             KeyValue::new("dst_chain", dst_chain.to_string()),
             KeyValue::new("src_channel", src_channel.to_string()),
             KeyValue::new("dst_channel", dst_channel.to_string()),
             KeyValue::new("src_port", src_port.to_string()),
             KeyValue::new("dst_port", dst_port.to_string()),
-        ];
+        ]);
 
         self.receive_packets_confirmed.add(&cx, 0, labels);
         self.acknowledgment_packets_confirmed.add(&cx, 0, labels);
@@ -421,12 +570,12 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain.to_string()),
             KeyValue::new("counterparty", counterparty.to_string()),
             KeyValue::new("channel", channel.to_string()),
             KeyValue::new("port", port.to_string()),
-        ];
+        ]);
 
         self.send_packet_events.add(&cx, 0, labels);
         self.acknowledgement_events.add(&cx, 0, labels);
@@ -451,11 +600,11 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("src_chain", src_chain.to_string()),
             KeyValue::new("dst_chain", dst_chain.to_string()),
             KeyValue::new("client", client.to_string()),
-        ];
+        ]);
 
         self.client_updates_submitted.add(&cx, 0, labels);
 
@@ -468,19 +617,19 @@ impl TelemetryState {
         let cx = Context::current();
 
         for query_type in QUERY_TYPES {
-            let labels = &[
+            let labels = &with_profile([
                 KeyValue::new("chain", chain_id.to_string()),
                 KeyValue::new("query_type", query_type),
-            ];
+            ]);
 
             self.queries.add(&cx, 0, labels);
         }
 
         for query_type in QUERY_TYPES_CACHE {
-            let labels = &[
+            let labels = &with_profile([
                 KeyValue::new("chain", chain_id.to_string()),
                 KeyValue::new("query_type", query_type),
-            ];
+            ]);
 
             self.queries_cache_hits.add(&cx, 0, labels);
         }
@@ -489,7 +638,7 @@ impl TelemetryState {
     /// Update the number of workers per object
     pub fn worker(&self, worker_type: WorkerType, count: i64) {
         let cx = Context::current();
-        let labels = &[KeyValue::new("type", worker_type.to_string())];
+        let labels = &with_profile([KeyValue::new("type", worker_type.to_string())]);
         self.workers.add(&cx, count, labels);
     }
 
@@ -503,11 +652,11 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("src_chain", src_chain.to_string()),
             KeyValue::new("dst_chain", dst_chain.to_string()),
             KeyValue::new("client", client.to_string()),
-        ];
+        ]);
 
         self.client_updates_submitted.add(&cx, count, labels);
     }
@@ -522,11 +671,11 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("src_chain", src_chain.to_string()),
             KeyValue::new("dst_chain", dst_chain.to_string()),
             KeyValue::new("client", client.to_string()),
-        ];
+        ]);
 
         self.client_misbehaviours_submitted.add(&cx, count, labels);
     }
@@ -546,14 +695,14 @@ impl TelemetryState {
         let cx = Context::current();
 
         if count > 0 {
-            let labels = &[
+            let labels = &with_profile([
                 KeyValue::new("src_chain", src_chain.to_string()),
                 KeyValue::new("dst_chain", dst_chain.to_string()),
                 KeyValue::new("src_channel", src_channel.to_string()),
                 KeyValue::new("dst_channel", dst_channel.to_string()),
                 KeyValue::new("src_port", src_port.to_string()),
                 KeyValue::new("dst_port", dst_port.to_string()),
-            ];
+            ]);
 
             self.receive_packets_confirmed.add(&cx, count, labels);
         }
@@ -574,14 +723,14 @@ impl TelemetryState {
         let cx = Context::current();
 
         if count > 0 {
-            let labels = &[
+            let labels = &with_profile([
                 KeyValue::new("src_chain", src_chain.to_string()),
                 KeyValue::new("dst_chain", dst_chain.to_string()),
                 KeyValue::new("src_channel", src_channel.to_string()),
                 KeyValue::new("dst_channel", dst_channel.to_string()),
                 KeyValue::new("src_port", src_port.to_string()),
                 KeyValue::new("dst_port", dst_port.to_string()),
-            ];
+            ]);
 
             self.acknowledgment_packets_confirmed
                 .add(&cx, count, labels);
@@ -603,14 +752,14 @@ impl TelemetryState {
         let cx = Context::current();
 
         if count > 0 {
-            let labels = &[
+            let labels = &with_profile([
                 KeyValue::new("src_chain", src_chain.to_string()),
                 KeyValue::new("dst_chain", dst_chain.to_string()),
                 KeyValue::new("src_channel", src_channel.to_string()),
                 KeyValue::new("dst_channel", dst_channel.to_string()),
                 KeyValue::new("src_port", src_port.to_string()),
                 KeyValue::new("dst_port", dst_port.to_string()),
-            ];
+            ]);
 
             self.timeout_packets_confirmed.add(&cx, count, labels);
         }
@@ -620,22 +769,35 @@ impl TelemetryState {
     pub fn query(&self, chain_id: &ChainId, query_type: &'static str) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("query_type", query_type),
-        ];
+        ]);
 
         self.queries.add(&cx, 1, labels);
     }
 
+    /// The latency of a query emitted by the relayer, per chain and query type
+    pub fn query_latency(&self, chain_id: &ChainId, query_type: &'static str, latency: Duration) {
+        let cx = Context::current();
+
+        let labels = &with_profile([
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("query_type", query_type),
+        ]);
+
+        self.query_latency
+            .observe(&cx, latency.as_millis() as u64, labels);
+    }
+
     /// Number of cache hits for queries emitted by the relayer, per chain and query type
     pub fn queries_cache_hits(&self, chain_id: &ChainId, query_type: &'static str) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("query_type", query_type),
-        ];
+        ]);
 
         self.queries_cache_hits.add(&cx, 1, labels);
     }
@@ -644,16 +806,25 @@ impl TelemetryState {
     pub fn ws_reconnect(&self, chain_id: &ChainId) {
         let cx = Context::current();
 
-        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
 
         self.ws_reconnect.add(&cx, 1, labels);
     }
 
+    /// Number of times the height of a chain went backwards, per chain
+    pub fn height_rollback(&self, chain_id: &ChainId) {
+        let cx = Context::current();
+
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
+
+        self.height_rollbacks.add(&cx, 1, labels);
+    }
+
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     pub fn ws_events(&self, chain_id: &ChainId, count: u64) {
         let cx = Context::current();
 
-        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
 
         self.ws_events.add(&cx, count, labels);
     }
@@ -662,21 +833,57 @@ impl TelemetryState {
     pub fn messages_submitted(&self, chain_id: &ChainId, count: u64) {
         let cx = Context::current();
 
-        let labels = &[KeyValue::new("chain", chain_id.to_string())];
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
 
         self.messages_submitted.add(&cx, count, labels);
     }
 
+    /// A transaction was broadcast to the chain, and was rejected during CheckTx if `check_tx_failed`
+    pub fn tx_broadcast(&self, chain_id: &ChainId, check_tx_failed: bool) {
+        let cx = Context::current();
+
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
+
+        self.tx_broadcast_attempts.add(&cx, 1, labels);
+
+        if check_tx_failed {
+            self.tx_check_tx_failures.add(&cx, 1, labels);
+        }
+    }
+
+    /// A transaction was included in a block of the chain, using `gas_used` out of
+    /// the `gas_wanted` it requested, and failed during DeliverTx if `deliver_tx_failed`
+    pub fn tx_delivered(
+        &self,
+        chain_id: &ChainId,
+        deliver_tx_failed: bool,
+        gas_used: i64,
+        gas_wanted: i64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &with_profile([KeyValue::new("chain", chain_id.to_string())]);
+
+        if deliver_tx_failed {
+            self.tx_deliver_tx_failures.add(&cx, 1, labels);
+        }
+
+        if gas_wanted > 0 {
+            let ratio = gas_used as f64 / gas_wanted as f64;
+            self.tx_gas_used_ratio.observe(&cx, ratio, labels);
+        }
+    }
+
     /// The balance in each wallet that Hermes is using, per account, denom and chain.
     /// The amount given is of unit: 10^6 * `denom`
     pub fn wallet_balance(&self, chain_id: &ChainId, account: &str, amount: f64, denom: &str) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("account", account.to_string()),
             KeyValue::new("denom", denom.to_string()),
-        ];
+        ]);
 
         self.wallet_balance.observe(&cx, amount, labels);
     }
@@ -702,13 +909,13 @@ impl TelemetryState {
         if let Some(start) = self.in_flight_events.get(&tracking_id) {
             let latency = start.elapsed().as_millis() as u64;
 
-            let labels = &[
+            let labels = &with_profile([
                 // KeyValue::new("tracking_id", tracking_id),
                 KeyValue::new("chain", chain_id.to_string()),
                 KeyValue::new("counterparty", counterparty_chain_id.to_string()),
                 KeyValue::new("channel", channel_id.to_string()),
                 KeyValue::new("port", port_id.to_string()),
-            ];
+            ]);
 
             for _ in 0..tx_count {
                 self.tx_latency_submitted.observe(&cx, latency, labels);
@@ -732,13 +939,13 @@ impl TelemetryState {
         if let Some(start) = self.in_flight_events.get(&tracking_id) {
             let latency = start.elapsed().as_millis() as u64;
 
-            let labels = &[
+            let labels = &with_profile([
                 // KeyValue::new("tracking_id", tracking_id),
                 KeyValue::new("chain", chain_id.to_string()),
                 KeyValue::new("counterparty", counterparty_chain_id.to_string()),
                 KeyValue::new("channel", channel_id.to_string()),
                 KeyValue::new("port", port_id.to_string()),
-            ];
+            ]);
 
             for _ in 0..tx_count {
                 self.tx_latency_confirmed.observe(&cx, latency, labels);
@@ -757,16 +964,99 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         self.send_packet_events.add(&cx, 1, labels);
     }
 
+    /// Record the size of the data of a packet sent on the given channel, and the time
+    /// of the block in which it was sent, to measure the time to relay it.
+    ///
+    /// Packets already recorded, eg. cleared after their SendPacket event was received,
+    /// are ignored.
+    #[allow(clippy::too_many_arguments)]
+    pub fn packet_sent(
+        &self,
+        seq_nr: u64,
+        data_size: usize,
+        sent_at: SystemTime,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let key = in_flight_packet_key(seq_nr, chain_id, channel_id, port_id);
+
+        if self.in_flight_packets.contains_key(&key) {
+            return;
+        }
+
+        let cx = Context::current();
+
+        let labels = &with_profile([
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ]);
+
+        self.packet_data_size.observe(&cx, data_size as u64, labels);
+
+        self.in_flight_packets.insert(key, sent_at);
+    }
+
+    /// Record the time it took to relay a packet sent on the given channel,
+    /// upon receiving its WriteAcknowledgement event.
+    ///
+    /// The acknowledgement is received on the counterparty chain, so the given
+    /// chain, channel and port are those the packet was sent on, ie. the
+    /// counterparty ones of the channel end it was acknowledged on.
+    pub fn packet_acknowledged(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let key = in_flight_packet_key(seq_nr, chain_id, channel_id, port_id);
+
+        if let Some(sent_at) = self.in_flight_packets.get(&key) {
+            self.in_flight_packets.invalidate(&key);
+
+            let latency = sent_at.elapsed().unwrap_or_default();
+
+            let labels = &with_profile([
+                KeyValue::new("chain", chain_id.to_string()),
+                KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+                KeyValue::new("channel", channel_id.to_string()),
+                KeyValue::new("port", port_id.to_string()),
+            ]);
+
+            self.packet_relay_latency
+                .observe(&cx, latency.as_millis() as u64, labels);
+        }
+    }
+
+    /// Stop measuring the time to relay a packet sent on the given channel, which timed out.
+    pub fn packet_timed_out(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) {
+        self.in_flight_packets
+            .invalidate(&in_flight_packet_key(seq_nr, chain_id, channel_id, port_id));
+    }
+
     pub fn acknowledgement_events(
         &self,
         _seq_nr: u64,
@@ -778,16 +1068,60 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         self.acknowledgement_events.add(&cx, 1, labels);
     }
 
+    pub fn ics20_acknowledgement(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        success: bool,
+    ) {
+        let cx = Context::current();
+
+        let outcome = if success { "success" } else { "error" };
+
+        let labels = &with_profile([
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("outcome", outcome),
+        ]);
+
+        self.ics20_acknowledgements.add(&cx, 1, labels);
+    }
+
+    pub fn oversized_memo_packet(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        policy: &'static str,
+    ) {
+        let cx = Context::current();
+
+        let labels = &with_profile([
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("policy", policy),
+        ]);
+
+        self.oversized_memo_packets.add(&cx, 1, labels);
+    }
+
     pub fn timeout_events(
         &self,
         chain_id: &ChainId,
@@ -797,12 +1131,12 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         self.timeout_events.add(&cx, 1, labels);
     }
@@ -818,12 +1152,12 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels: &[KeyValue; 4] = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         self.cleared_send_packet_events.add(&cx, 1, labels);
     }
@@ -839,12 +1173,12 @@ impl TelemetryState {
     ) {
         let cx = Context::current();
 
-        let labels: &[KeyValue; 4] = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         self.cleared_acknowledgment_events.add(&cx, 1, labels);
     }
@@ -868,12 +1202,12 @@ impl TelemetryState {
             port_id.to_string(),
         );
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         // Retrieve local timestamp when this SendPacket event was recorded.
         let now = Time::now();
@@ -950,12 +1284,12 @@ impl TelemetryState {
             port_id.to_string(),
         );
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("counterparty", counterparty_chain_id.to_string()),
             KeyValue::new("channel", channel_id.to_string()),
             KeyValue::new("port", port_id.to_string()),
-        ];
+        ]);
 
         if let Some(path_backlog) = self.backlogs.get(&path_uid) {
             if path_backlog.remove(&seq_nr).is_some() {
@@ -991,11 +1325,11 @@ impl TelemetryState {
         }
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("receiver", receiver.to_string()),
             KeyValue::new("denom", fee_amounts.denom.to_string()),
-        ];
+        ]);
 
         let fee_amount = fee_amounts.amount.0.as_u64();
 
@@ -1020,11 +1354,11 @@ impl TelemetryState {
     pub fn update_period_fees(&self, chain_id: &ChainId, receiver: &String, denom: &String) {
         let cx = Context::current();
 
-        let labels = &[
+        let labels = &with_profile([
             KeyValue::new("chain", chain_id.to_string()),
             KeyValue::new("receiver", receiver.to_string()),
             KeyValue::new("denom", denom.to_string()),
-        ];
+        ]);
 
         let key = format!("fee_amount:{chain_id}/{receiver}/{}", denom);
 
@@ -1097,6 +1431,16 @@ fn build_histogram_buckets(start: u64, end: u64, buckets: u64) -> Vec<f64> {
         .collect::<Vec<_>>()
 }
 
+/// The key of a packet in the in-flight packets, given the channel it was sent on.
+fn in_flight_packet_key(
+    seq_nr: u64,
+    chain_id: &ChainId,
+    channel_id: &ChannelId,
+    port_id: &PortId,
+) -> String {
+    format!("{chain_id}/{port_id}/{channel_id}/{seq_nr}")
+}
+
 impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
         match descriptor.name() {
@@ -1109,8 +1453,62 @@ impl AggregatorSelector for CustomAggregatorSelector {
             // TODO: Once quantile sketches are supported, replace histograms with that.
             "tx_latency_submitted" => Some(Arc::new(histogram(&self.get_submitted_range()))),
             "tx_latency_confirmed" => Some(Arc::new(histogram(&self.get_confirmed_range()))),
+            "tx_gas_used_ratio" => Some(Arc::new(histogram(&GAS_USED_RATIO_BUCKETS))),
+            "query_latency" => Some(Arc::new(histogram(&QUERY_LATENCY_BUCKETS))),
+            "packet_data_size" => Some(Arc::new(histogram(&PACKET_DATA_SIZE_BUCKETS))),
+            "packet_relay_latency" => Some(Arc::new(histogram(&PACKET_RELAY_LATENCY_BUCKETS))),
             "ics29_period_fees" => Some(Arc::new(last_value())),
             _ => Some(Arc::new(sum())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pair_the_acknowledgements_with_the_sent_packets() {
+        let state = TelemetryState::new(0..5000, 5, 0..5000, 5);
+
+        let (chain_a, chain_b) = (ChainId::new("ibc".into(), 0), ChainId::new("ibc".into(), 1));
+        let (channel_a, channel_b) = (ChannelId::new(0), ChannelId::new(1));
+        let port = PortId::transfer();
+
+        let key = |seq_nr| in_flight_packet_key(seq_nr, &chain_a, &channel_a, &port);
+
+        // The packet is sent from chain A, on the path from A to B
+        state.packet_sent(
+            1,
+            32,
+            SystemTime::now(),
+            &chain_a,
+            &channel_a,
+            &port,
+            &chain_b,
+        );
+        assert!(state.in_flight_packets.get(&key(1)).is_some());
+
+        // The channel end of chain B does not identify the packet
+        state.packet_acknowledged(1, &chain_b, &channel_b, &port, &chain_a);
+        assert!(state.in_flight_packets.get(&key(1)).is_some());
+
+        // Its acknowledgement is received on the path from B to A, whose
+        // destination channel end is the source channel end of the packet
+        state.packet_acknowledged(1, &chain_a, &channel_a, &port, &chain_b);
+        assert!(state.in_flight_packets.get(&key(1)).is_none());
+
+        // A packet which timed out is not measured anymore
+        state.packet_sent(
+            2,
+            32,
+            SystemTime::now(),
+            &chain_a,
+            &channel_a,
+            &port,
+            &chain_b,
+        );
+        state.packet_timed_out(2, &chain_a, &channel_a, &port);
+        assert!(state.in_flight_packets.get(&key(2)).is_none());
+    }
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use ibc_relayer::chain::{
-    cosmos::query::consensus_state::query_consensus_states,
+    cosmos::{query::consensus_state::query_consensus_states, types::grpc::GrpcAddress},
     requests::{PageRequest, QueryConsensusStateHeightsRequest, QueryConsensusStatesRequest},
 };
 
@@ -50,14 +50,17 @@ impl BinaryChainTest for ConsensusStateHeights {
             &CONSENSUS_STATES_COUNT,
         )?;
 
-        let grpc_address = chains
-            .node_b
-            .value()
-            .chain_driver
-            .grpc_address()
-            .as_str()
-            .parse()
-            .unwrap();
+        let grpc_address = GrpcAddress::new(
+            chains
+                .node_b
+                .value()
+                .chain_driver
+                .grpc_address()
+                .as_str()
+                .parse()
+                .unwrap(),
+            None,
+        );
 
         let states =
             chains
@@ -0,0 +1,61 @@
+//! Tests that the faucet of the test framework funds wallets created mid-test.
+
+use ibc_test_framework::chain::ext::bootstrap::ChainBootstrapMethodsExt;
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::util::random::{random_u128_range, random_u32};
+
+#[test]
+fn test_faucet() -> Result<(), Error> {
+    run_binary_chain_test(&FaucetTest)
+}
+
+pub struct FaucetTest;
+
+const EXTRA_DENOM: &str = "faucetcoin";
+
+impl TestOverrides for FaucetTest {
+    fn modify_test_config(&self, config: &mut TestConfig) {
+        config.faucet_denoms.push(EXTRA_DENOM.to_string());
+    }
+}
+
+impl BinaryChainTest for FaucetTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let chain_driver = chains.node_a.chain_driver();
+
+        let wallet: MonoTagged<ChainA, Wallet> = MonoTagged::new(
+            chain_driver
+                .value()
+                .add_wallet(&format!("funded-{:x}", random_u32()))?,
+        );
+
+        let stake_denom: MonoTagged<ChainA, Denom> = MonoTagged::new(Denom::base("stake"));
+        let extra_denom: MonoTagged<ChainA, Denom> = MonoTagged::new(Denom::base(EXTRA_DENOM));
+
+        for denom in [
+            chains.node_a.denom(),
+            stake_denom.as_ref(),
+            extra_denom.as_ref(),
+        ] {
+            let token = denom.with_amount(random_u128_range(1000, 5000));
+
+            info!("funding new wallet with {} from the faucet", token);
+
+            chain_driver.fund_from_faucet(
+                &chains.node_a.wallets().faucet(),
+                &wallet.as_ref().address(),
+                &token.as_ref(),
+            )?;
+
+            chain_driver
+                .assert_eventual_wallet_amount(&wallet.as_ref().address(), &token.as_ref())?;
+        }
+
+        Ok(())
+    }
+}
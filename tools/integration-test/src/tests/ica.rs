@@ -27,7 +27,9 @@ use ibc_relayer_types::{
 use ibc_test_framework::{
     ibc::denom::Denom,
     prelude::*,
-    relayer::channel::{assert_eventually_channel_established, query_channel_end},
+    relayer::channel::{
+        assert_eventually_channel_established, channel_diagnostics, query_channel_end,
+    },
 };
 
 #[test]
@@ -68,22 +70,7 @@ impl TestOverrides for IcaFilterTestAllow {
 
     // Allow MsgSend messages over ICA
     fn modify_genesis_file(&self, genesis: &mut serde_json::Value) -> Result<(), Error> {
-        use serde_json::Value;
-
-        let allow_messages = genesis
-            .get_mut("app_state")
-            .and_then(|app_state| app_state.get_mut("interchainaccounts"))
-            .and_then(|ica| ica.get_mut("host_genesis_state"))
-            .and_then(|state| state.get_mut("params"))
-            .and_then(|params| params.get_mut("allow_messages"))
-            .and_then(|allow_messages| allow_messages.as_array_mut());
-
-        if let Some(allow_messages) = allow_messages {
-            allow_messages.push(Value::String("/cosmos.bank.v1beta1.MsgSend".to_string()));
-            Ok(())
-        } else {
-            Err(Error::generic(eyre!("failed to update genesis file")))
-        }
+        allow_msg_send_over_ica(genesis)
     }
 }
 
@@ -136,24 +123,12 @@ impl BinaryConnectionTest for IcaFilterTestAllow {
 
         let amount = 12345;
 
-        let msg = MsgSend {
-            from_address: ica_address.to_string(),
-            to_address: chains.node_b.wallets().user2().address().to_string(),
-            amount: vec![Coin {
-                denom: stake_denom.to_string(),
-                amount: Amount(U256::from(amount)),
-            }],
-        };
-
-        let raw_msg = msg.to_any();
-
-        let cosmos_tx = CosmosTx {
-            messages: vec![raw_msg],
-        };
-
-        let raw_cosmos_tx = cosmos_tx.to_any();
-
-        let interchain_account_packet_data = InterchainAccountPacketData::new(raw_cosmos_tx.value);
+        let interchain_account_packet_data = ica_msg_send_packet_data(
+            ica_address.value(),
+            chains.node_b.wallets().user2().address().value(),
+            stake_denom.value(),
+            amount,
+        );
 
         let signer = Signer::from_str(&wallet.address().to_string()).unwrap();
 
@@ -176,6 +151,50 @@ impl BinaryConnectionTest for IcaFilterTestAllow {
     }
 }
 
+/// Allow MsgSend messages to be executed by interchain accounts on the host chain.
+fn allow_msg_send_over_ica(genesis: &mut serde_json::Value) -> Result<(), Error> {
+    use serde_json::Value;
+
+    let allow_messages = genesis
+        .get_mut("app_state")
+        .and_then(|app_state| app_state.get_mut("interchainaccounts"))
+        .and_then(|ica| ica.get_mut("host_genesis_state"))
+        .and_then(|state| state.get_mut("params"))
+        .and_then(|params| params.get_mut("allow_messages"))
+        .and_then(|allow_messages| allow_messages.as_array_mut());
+
+    if let Some(allow_messages) = allow_messages {
+        allow_messages.push(Value::String("/cosmos.bank.v1beta1.MsgSend".to_string()));
+        Ok(())
+    } else {
+        Err(Error::generic(eyre!("failed to update genesis file")))
+    }
+}
+
+/// Build the packet data of an ICA transaction sending `amount` of `denom`
+/// from the interchain account to the given recipient on the host chain.
+fn ica_msg_send_packet_data(
+    ica_address: &WalletAddress,
+    recipient: &WalletAddress,
+    denom: &Denom,
+    amount: u64,
+) -> InterchainAccountPacketData {
+    let msg = MsgSend {
+        from_address: ica_address.to_string(),
+        to_address: recipient.to_string(),
+        amount: vec![Coin {
+            denom: denom.to_string(),
+            amount: Amount(U256::from(amount)),
+        }],
+    };
+
+    let cosmos_tx = CosmosTx {
+        messages: vec![msg.to_any()],
+    };
+
+    InterchainAccountPacketData::new(cosmos_tx.to_any().value)
+}
+
 fn interchain_send_tx<ChainA: ChainHandle>(
     chain: &ChainA,
     from: &Signer,
@@ -248,6 +267,203 @@ impl BinaryConnectionTest for IcaFilterTestDeny {
     }
 }
 
+/// Test that a packet timing out on the ordered ICA channel closes the channel
+/// on both ends, and that the interchain account can be used again over a new
+/// channel opened by registering the account anew.
+#[test]
+fn test_ica_close_on_timeout_and_reopen() -> Result<(), Error> {
+    run_binary_connection_test(&IcaCloseOnTimeoutTest)
+}
+
+pub struct IcaCloseOnTimeoutTest;
+
+impl TestOverrides for IcaCloseOnTimeoutTest {
+    // Enable channel workers, so that the relayer completes the handshakes
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.mode.channels.enabled = true;
+    }
+
+    fn modify_genesis_file(&self, genesis: &mut serde_json::Value) -> Result<(), Error> {
+        allow_msg_send_over_ica(genesis)
+    }
+
+    // The relayer is stopped while the ICA packet times out
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryConnectionTest for IcaCloseOnTimeoutTest {
+    fn run<Controller: ChainHandle, Host: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<Controller, Host>,
+        connection: ConnectedConnection<Controller, Host>,
+    ) -> Result<(), Error> {
+        let stake_denom: MonoTagged<Host, Denom> = MonoTagged::new(Denom::base("stake"));
+        let recipient = chains.node_b.wallets().user2().address().cloned();
+        let ica_fund = 42000u64;
+        let amount = 12345u64;
+
+        let (wallet, channel_id, port_id, ica_address) = relayer.with_supervisor(|| {
+            let (wallet, channel_id, port_id) = register_interchain_account(&chains, &connection)?;
+
+            assert_eventually_channel_established(
+                chains.handle_a(),
+                chains.handle_b(),
+                &channel_id.as_ref(),
+                &port_id.as_ref(),
+            )?;
+
+            let channel_end =
+                query_channel_end(chains.handle_a(), &channel_id.as_ref(), &port_id.as_ref())?;
+
+            assert_eq(
+                "ICA channel should be ordered",
+                channel_end.value().ordering(),
+                &Ordering::Ordered,
+            )?;
+
+            let ica_address = chains.node_a.chain_driver().query_interchain_account(
+                &wallet.address(),
+                &connection.connection_id_a.as_ref(),
+            )?;
+
+            chains.node_b.chain_driver().local_transfer_token(
+                &chains.node_b.wallets().user1(),
+                &ica_address.as_ref(),
+                &stake_denom.with_amount(ica_fund).as_ref(),
+            )?;
+
+            chains.node_b.chain_driver().assert_eventual_wallet_amount(
+                &ica_address.as_ref(),
+                &stake_denom.with_amount(ica_fund).as_ref(),
+            )?;
+
+            Ok((wallet, channel_id, port_id, ica_address))
+        })?;
+
+        let signer = Signer::from_str(&wallet.address().to_string()).unwrap();
+
+        info!("sending an ICA transaction which times out while the relayer is stopped");
+
+        interchain_send_tx(
+            chains.handle_a(),
+            &signer,
+            &connection.connection_id_a.0,
+            ica_msg_send_packet_data(
+                ica_address.value(),
+                recipient.value(),
+                stake_denom.value(),
+                amount,
+            ),
+            Timestamp::from_nanoseconds(5_000_000_000).unwrap(),
+        )?;
+
+        sleep(Duration::from_secs(10));
+
+        relayer.with_supervisor(|| {
+            // The relayer times out the packet, which closes the ordered channel
+            // on the controller chain, and then closes the channel on the host chain.
+            assert_eventually_channel_closed(
+                chains.handle_a(),
+                chains.handle_b(),
+                &channel_id.as_ref(),
+                &port_id.as_ref(),
+            )?;
+
+            chains.node_b.chain_driver().assert_eventual_wallet_amount(
+                &ica_address.as_ref(),
+                &stake_denom.with_amount(ica_fund).as_ref(),
+            )?;
+
+            info!("registering the interchain account again to reopen a channel");
+
+            let (_, new_channel_id, new_port_id) =
+                register_interchain_account(&chains, &connection)?;
+
+            assert_eventually_channel_established(
+                chains.handle_a(),
+                chains.handle_b(),
+                &new_channel_id.as_ref(),
+                &new_port_id.as_ref(),
+            )?;
+
+            let new_ica_address = chains.node_a.chain_driver().query_interchain_account(
+                &wallet.address(),
+                &connection.connection_id_a.as_ref(),
+            )?;
+
+            assert_eq(
+                "the interchain account should be the same over the new channel",
+                &new_ica_address,
+                &ica_address,
+            )?;
+
+            interchain_send_tx(
+                chains.handle_a(),
+                &signer,
+                &connection.connection_id_a.0,
+                ica_msg_send_packet_data(
+                    ica_address.value(),
+                    recipient.value(),
+                    stake_denom.value(),
+                    amount,
+                ),
+                Timestamp::from_nanoseconds(120_000_000_000).unwrap(),
+            )?;
+
+            chains.node_b.chain_driver().assert_eventual_wallet_amount(
+                &ica_address.as_ref(),
+                &stake_denom.with_amount(ica_fund - amount).as_ref(),
+            )?;
+
+            Ok(())
+        })
+    }
+}
+
+/// Assert that both ends of the channel are eventually closed.
+fn assert_eventually_channel_closed<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<ChainA, ChainB>,
+) -> Result<(), Error> {
+    assert_eventually_succeed_with(
+        "channel should eventually be closed",
+        &PollConfig::new(Duration::from_secs(1), Duration::from_secs(60)),
+        || channel_diagnostics(handle_a, handle_b, channel_id_a, port_id_a),
+        || {
+            let channel_end_a = query_channel_end(handle_a, channel_id_a, port_id_a)?;
+
+            if !channel_end_a.value().state_matches(&State::Closed) {
+                return Err(Error::generic(eyre!(
+                    "expected channel end A to be in closed state"
+                )));
+            }
+
+            let channel_id_b = channel_end_a
+                .tagged_counterparty_channel_id()
+                .ok_or_else(|| eyre!("expected counterparty channel id to be present"))?;
+
+            let port_id_b = channel_end_a.tagged_counterparty_port_id();
+
+            let channel_end_b =
+                query_channel_end(handle_b, &channel_id_b.as_ref(), &port_id_b.as_ref())?;
+
+            if !channel_end_b.value().state_matches(&State::Closed) {
+                return Err(Error::generic(eyre!(
+                    "expected channel end B to be in closed state"
+                )));
+            }
+
+            Ok(())
+        },
+    )
+}
+
 #[allow(clippy::type_complexity)]
 fn register_interchain_account<ChainA: ChainHandle, ChainB: ChainHandle>(
     chains: &ConnectedChains<ChainA, ChainB>,
@@ -16,10 +16,12 @@ pub mod consensus_states;
 pub mod denom_trace;
 pub mod error_events;
 pub mod execute_schedule;
+pub mod faucet;
 pub mod handshake_on_start;
 pub mod memo;
 pub mod python;
 pub mod query_packet;
+pub mod reload_key;
 pub mod supervisor;
 pub mod tendermint;
 pub mod ternary_transfer;
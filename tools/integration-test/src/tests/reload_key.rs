@@ -0,0 +1,71 @@
+//! Tests that the key signing the transactions of a running chain runtime
+//! can be reloaded, and switched to another key of the keyring.
+
+use ibc_test_framework::prelude::*;
+
+#[test]
+fn test_reload_key() -> Result<(), Error> {
+    run_binary_chain_test(&ReloadKeyTest)
+}
+
+pub struct ReloadKeyTest;
+
+impl TestOverrides for ReloadKeyTest {}
+
+impl BinaryChainTest for ReloadKeyTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let chain = chains.handle_a();
+
+        let relayer = chains.node_a.wallets().relayer().cloned_value();
+        let user = chains.node_a.wallets().user1().cloned_value();
+
+        info!("switching the key of the chain to the key of {}", user.id);
+
+        let signer = chain.reload_key(Some(user.id.0.clone()))?;
+
+        assert_eq(
+            "the transactions should be signed by the reloaded key",
+            &signer.as_ref(),
+            &user.address.0.as_str(),
+        )?;
+
+        assert_eq(
+            "the configuration of the runtime should hold the reloaded key",
+            &chain.config()?.key_name,
+            &user.id.0,
+        )?;
+
+        // Reloading without a key name reloads the key in use
+        let signer = chain.reload_key(None)?;
+
+        assert_eq(
+            "the key in use should be reloaded",
+            &signer.as_ref(),
+            &user.address.0.as_str(),
+        )?;
+
+        // A key missing from the keyring is not switched to
+        assert!(chain.reload_key(Some("missing-key".to_owned())).is_err());
+
+        assert_eq(
+            "the key in use should be kept",
+            &chain.config()?.key_name,
+            &user.id.0,
+        )?;
+
+        let signer = chain.reload_key(Some(relayer.id.0.clone()))?;
+
+        assert_eq(
+            "the transactions should be signed by the relayer again",
+            &signer.as_ref(),
+            &relayer.address.0.as_str(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -238,6 +238,10 @@ pub fn add_keys_to_chain_handle<Chain: ChainHandle>(
     add_key_to_chain_handle(chain, &wallets.user1)?;
     add_key_to_chain_handle(chain, &wallets.user2)?;
 
+    for user in &wallets.extra_users {
+        add_key_to_chain_handle(chain, user)?;
+    }
+
     Ok(())
 }
 
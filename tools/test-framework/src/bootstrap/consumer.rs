@@ -8,6 +8,7 @@ use std::time::Duration;
 use toml;
 use tracing::info;
 
+use crate::bootstrap::single::add_faucet_account;
 use crate::chain::builder::ChainBuilder;
 use crate::chain::config;
 use crate::chain::ext::bootstrap::ChainBootstrapMethodsExt;
@@ -19,6 +20,7 @@ pub fn bootstrap_consumer_node(
     builder: &ChainBuilder,
     prefix: &str,
     node_a: &FullNode,
+    extra_user_wallets: usize,
     config_modifier: impl FnOnce(&mut toml::Value) -> Result<(), Error>,
     genesis_modifier: impl FnOnce(&mut serde_json::Value) -> Result<(), Error>,
     chain_number: usize,
@@ -47,12 +49,28 @@ pub fn bootstrap_consumer_node(
     let relayer = chain_driver.add_wallet("relayer")?;
     let user1 = chain_driver.add_wallet("user1")?;
     let user2 = chain_driver.add_wallet("user2")?;
+    let extra_users = (3..extra_user_wallets + 3)
+        .map(|i| chain_driver.add_wallet(&format!("user{i}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    let faucet = chain_driver.add_wallet("faucet")?;
 
     chain_driver.add_genesis_account(&validator.address, &[&additional_initial_stake])?;
     chain_driver.add_genesis_account(&relayer.address, &[&initial_stake, &initial_coin])?;
     chain_driver.add_genesis_account(&user1.address, &[&initial_stake, &initial_coin])?;
     chain_driver.add_genesis_account(&user2.address, &[&initial_stake, &initial_coin])?;
 
+    for user in &extra_users {
+        chain_driver.add_genesis_account(&user.address, &[&initial_stake, &initial_coin])?;
+    }
+
+    add_faucet_account(
+        builder,
+        &chain_driver,
+        &faucet,
+        initial_amount,
+        &[&initial_stake, &initial_coin],
+    )?;
+
     // Wait for the consumer chain to be initialized before querying the genesis
     thread::sleep(Duration::from_secs(10));
 
@@ -123,6 +141,8 @@ pub fn bootstrap_consumer_node(
         relayer,
         user1,
         user2,
+        extra_users,
+        faucet,
     };
 
     let node = FullNode {
@@ -7,6 +7,8 @@ use eyre::Report as Error;
 use ibc_relayer_cli::components::enable_ansi;
 use std::env;
 use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
 use std::sync::Once;
 use tracing_subscriber::{
     self as ts,
@@ -16,6 +18,7 @@ use tracing_subscriber::{
 };
 
 use crate::types::config::TestConfig;
+use crate::util::log::RecentLogsWriter;
 use crate::util::random::random_u32;
 
 static INIT: Once = Once::new();
@@ -48,26 +51,56 @@ pub fn init_test() -> Result<TestConfig, Error> {
 
     let account_prefixes = parse_chain_command_paths(account_prefix);
 
-    let chain_store_dir = format!("{}/test-{}", base_chain_store_dir, random_u32());
-
-    fs::create_dir_all(&chain_store_dir)?;
-
-    let chain_store_dir = fs::canonicalize(chain_store_dir)?;
+    let chain_store_dir = create_test_store_dir(&base_chain_store_dir)?;
 
     let hang_on_fail = env::var("HANG_ON_FAIL")
         .ok()
         .map(|val| val == "1")
         .unwrap_or(false);
 
+    let parallel = env::var("PARALLEL_TESTS")
+        .ok()
+        .map(|val| val == "1")
+        .unwrap_or(false);
+
+    let faucet_denoms = env::var("FAUCET_DENOMS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|denom| !denom.is_empty())
+        .map(String::from)
+        .collect();
+
     Ok(TestConfig {
         chain_command_paths,
         chain_store_dir,
         account_prefixes,
         hang_on_fail,
         bootstrap_with_random_ids: false,
+        extra_user_wallets: 0,
+        faucet_denoms,
+        parallel,
     })
 }
 
+/**
+   Create a new directory for the files of the test, which is not shared with
+   any other test, even with the tests running in other processes.
+*/
+fn create_test_store_dir(base_chain_store_dir: &str) -> Result<PathBuf, Error> {
+    fs::create_dir_all(base_chain_store_dir)?;
+
+    loop {
+        let chain_store_dir = format!("{}/test-{}", base_chain_store_dir, random_u32());
+
+        match fs::create_dir(&chain_store_dir) {
+            Ok(()) => return Ok(fs::canonicalize(chain_store_dir)?),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
 fn parse_chain_command_paths(chain_command_path: String) -> Vec<String> {
     let patterns: Vec<String> = chain_command_path
         .split(',')
@@ -88,5 +121,14 @@ pub fn install_logger(with_color: bool) {
 
     let layer = ts::fmt::layer().with_ansi(with_color);
 
-    ts::registry().with(env_filter).with(layer).init();
+    // Keep the most recent logs to include them in the errors of failed assertions
+    let recent_logs_layer = ts::fmt::layer()
+        .with_ansi(false)
+        .with_writer(RecentLogsWriter);
+
+    ts::registry()
+        .with(env_filter)
+        .with(layer)
+        .with(recent_logs_layer)
+        .init();
 }
@@ -31,6 +31,9 @@ use crate::util::random::{random_u128_range, random_u32};
    and wallets. This is to help ensure that the test is written to
    only work with specific hardcoded parameters.
 
+   Besides `user1` and `user2`, `extra_user_wallets` additional user
+   wallets are created and funded with the same tokens.
+
    TODO: Due to the limitation of the `gaiad` command, currently
    parameters such as the stake denomination (`stake`) and the wallet
    address prefix (`cosmos`) cannot be overridden. It would be
@@ -41,6 +44,7 @@ pub fn bootstrap_single_node(
     builder: &ChainBuilder,
     prefix: &str,
     use_random_id: bool,
+    extra_user_wallets: usize,
     config_modifier: impl FnOnce(&mut toml::Value) -> Result<(), Error>,
     genesis_modifier: impl FnOnce(&mut serde_json::Value) -> Result<(), Error>,
     chain_number: usize,
@@ -77,6 +81,10 @@ pub fn bootstrap_single_node(
     let relayer = add_wallet(&chain_driver, "relayer", use_random_id)?;
     let user1 = add_wallet(&chain_driver, "user1", use_random_id)?;
     let user2 = add_wallet(&chain_driver, "user2", use_random_id)?;
+    let extra_users = (3..extra_user_wallets + 3)
+        .map(|i| add_wallet(&chain_driver, &format!("user{i}"), use_random_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let faucet = add_wallet(&chain_driver, "faucet", use_random_id)?;
 
     // Validator is given more tokens as they are required to vote on upgrade chain
     chain_driver.add_genesis_account(&validator.address, &[&additional_initial_stake])?;
@@ -87,8 +95,20 @@ pub fn bootstrap_single_node(
 
     chain_driver.add_genesis_account(&user2.address, &[&initial_stake, &initial_coin])?;
 
+    for user in &extra_users {
+        chain_driver.add_genesis_account(&user.address, &[&initial_stake, &initial_coin])?;
+    }
+
     chain_driver.add_genesis_account(&relayer.address, &[&initial_stake, &initial_coin])?;
 
+    add_faucet_account(
+        builder,
+        &chain_driver,
+        &faucet,
+        initial_amount,
+        &[&initial_stake, &initial_coin],
+    )?;
+
     chain_driver.collect_gen_txs()?;
 
     let log_level = std::env::var("CHAIN_LOG_LEVEL").unwrap_or_else(|_| "info".to_string());
@@ -144,6 +164,8 @@ pub fn bootstrap_single_node(
         relayer,
         user1,
         user2,
+        extra_users,
+        faucet,
     };
 
     let node = FullNode {
@@ -156,6 +178,33 @@ pub fn bootstrap_single_node(
     Ok(node)
 }
 
+/**
+   Add the faucet wallet to the genesis accounts, with the given tokens along
+   with the given amount of each of the
+   [`faucet_denoms`](ChainBuilder::faucet_denoms) of the builder.
+*/
+pub fn add_faucet_account(
+    builder: &ChainBuilder,
+    driver: &ChainDriver,
+    faucet: &Wallet,
+    amount: u128,
+    tokens: &[&Token],
+) -> Result<(), Error> {
+    let faucet_tokens = builder
+        .faucet_denoms
+        .iter()
+        .map(|denom| Token::new(denom.clone(), amount))
+        .collect::<Vec<_>>();
+
+    let amounts = tokens
+        .iter()
+        .copied()
+        .chain(faucet_tokens.iter())
+        .collect::<Vec<_>>();
+
+    driver.add_genesis_account(&faucet.address, &amounts)
+}
+
 fn add_wallet(driver: &ChainDriver, prefix: &str, use_random_id: bool) -> Result<Wallet, Error> {
     if use_random_id {
         let num = random_u32();
@@ -9,6 +9,7 @@ use tokio::runtime::Runtime;
 
 use crate::chain::driver::ChainDriver;
 use crate::error::Error;
+use crate::ibc::denom::Denom;
 use crate::types::config::TestConfig;
 use crate::util::random::random_unused_tcp_port;
 
@@ -39,6 +40,18 @@ pub struct ChainBuilder {
 
     pub account_prefixes: Vec<String>,
 
+    /**
+       Whether to give the chains IDs with a random suffix, unique to the
+       test, even when they are not bootstrapped with random IDs.
+    */
+    pub unique_chain_ids: bool,
+
+    /**
+       The extra denominations given to the faucet wallet of the chains at
+       genesis, besides the stake denomination and the denomination of the chain.
+    */
+    pub faucet_denoms: Vec<Denom>,
+
     pub runtime: Arc<Runtime>,
 }
 
@@ -56,6 +69,8 @@ impl ChainBuilder {
             command_paths,
             base_store_dir: base_store_dir.to_string(),
             account_prefixes,
+            unique_chain_ids: false,
+            faucet_denoms: Vec::new(),
             runtime,
         }
     }
@@ -64,12 +79,20 @@ impl ChainBuilder {
        Create a `ChainBuilder` based on the provided [`TestConfig`].
     */
     pub fn new_with_config(config: &TestConfig, runtime: Arc<Runtime>) -> Self {
-        Self::new(
-            config.chain_command_paths.clone(),
-            &format!("{}", config.chain_store_dir.display()),
-            config.account_prefixes.clone(),
-            runtime,
-        )
+        Self {
+            unique_chain_ids: config.parallel,
+            faucet_denoms: config
+                .faucet_denoms
+                .iter()
+                .map(|denom| Denom::base(denom))
+                .collect(),
+            ..Self::new(
+                config.chain_command_paths.clone(),
+                &format!("{}", config.chain_store_dir.display()),
+                config.account_prefixes.clone(),
+                runtime,
+            )
+        }
     }
 
     /**
@@ -99,7 +122,7 @@ impl ChainBuilder {
 
         let chain_type = ChainType::from_str(&self.command_paths[chain_number])?;
 
-        let chain_id = chain_type.chain_id(prefix, use_random_id);
+        let chain_id = chain_type.chain_id(prefix, use_random_id || self.unique_chain_ids);
 
         let rpc_port = random_unused_tcp_port();
         let grpc_port = random_unused_tcp_port();
@@ -5,7 +5,7 @@ use std::process::{Command, Stdio};
 use std::str;
 use std::thread::sleep;
 
-use crate::chain::exec::simple_exec;
+use crate::chain::exec::{exec_with_input, simple_exec};
 use crate::error::Error;
 use crate::types::process::ChildProcess;
 use crate::util::file::pipe_to_file;
@@ -56,6 +56,43 @@ pub fn add_wallet(
     }
 }
 
+/**
+   Import the key derived from the given mnemonic into the keyring of
+   the full node, under the given wallet ID.
+*/
+pub fn import_wallet(
+    chain_id: &str,
+    command_path: &str,
+    home_path: &str,
+    wallet_id: &str,
+    mnemonic: &str,
+) -> Result<String, Error> {
+    let output = exec_with_input(
+        chain_id,
+        command_path,
+        &[
+            "--home",
+            home_path,
+            "keys",
+            "add",
+            wallet_id,
+            "--recover",
+            "--keyring-backend",
+            "test",
+            "--output",
+            "json",
+        ],
+        &format!("{mnemonic}\n"),
+    )?;
+
+    // gaia6 somehow displays result in stderr instead of stdout
+    if output.stdout.is_empty() {
+        Ok(output.stderr)
+    } else {
+        Ok(output.stdout)
+    }
+}
+
 pub fn add_genesis_account(
     chain_id: &str,
     command_path: &str,
@@ -20,7 +20,7 @@ use crate::ibc::token::Token;
 use crate::relayer::tx::new_tx_config_for_test;
 use crate::types::env::{EnvWriter, ExportEnv};
 use crate::types::wallet::WalletAddress;
-use crate::util::retry::assert_eventually_succeed;
+use crate::util::retry::{assert_eventually_succeed_with, PollConfig};
 
 /**
    Number of times (seconds) to try and query a wallet to reach the
@@ -203,10 +203,27 @@ impl ChainDriver {
         wallet: &WalletAddress,
         token: &Token,
     ) -> Result<(), Error> {
-        assert_eventually_succeed(
+        self.assert_eventual_wallet_amount_with(
+            wallet,
+            token,
+            &PollConfig::from_attempts(WAIT_WALLET_AMOUNT_ATTEMPTS, Duration::from_secs(1)),
+        )
+    }
+
+    /**
+       Assert that a wallet should eventually have the expected amount in the
+       given denomination, polling according to the given [`PollConfig`].
+    */
+    pub fn assert_eventual_wallet_amount_with(
+        &self,
+        wallet: &WalletAddress,
+        token: &Token,
+        poll: &PollConfig,
+    ) -> Result<(), Error> {
+        assert_eventually_succeed_with(
             &format!("wallet reach {wallet} amount {token}"),
-            WAIT_WALLET_AMOUNT_ATTEMPTS,
-            Duration::from_secs(1),
+            poll,
+            String::new,
             || {
                 let amount: Amount = self.query_balance(wallet, &token.denom)?;
 
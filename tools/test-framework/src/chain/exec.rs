@@ -1,5 +1,6 @@
 use eyre::eyre;
-use std::process::Command;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
 use std::str;
 use tracing::{debug, trace};
 
@@ -23,6 +24,46 @@ pub fn simple_exec(desc: &str, command_path: &str, args: &[&str]) -> Result<Exec
         .output()
         .map_err(handle_exec_error(command_path))?;
 
+    handle_output(output)
+}
+
+/**
+   Execute a command, writing the given input to its standard input,
+   eg. to provide a mnemonic to a command that prompts for it.
+*/
+pub fn exec_with_input(
+    desc: &str,
+    command_path: &str,
+    args: &[&str],
+    input: &str,
+) -> Result<ExecOutput, Error> {
+    debug!(
+        "Executing command with input for {}: {} {}",
+        desc,
+        command_path,
+        itertools::join(args, " ")
+    );
+
+    let mut child = Command::new(command_path)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(handle_exec_error(command_path))?;
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| eyre!("expected stdin to be present in child process"))?
+        .write_all(input.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+
+    handle_output(output)
+}
+
+fn handle_output(output: Output) -> Result<ExecOutput, Error> {
     if output.status.success() {
         let stdout = str::from_utf8(&output.stdout)
             .map_err(handle_generic_error)?
@@ -11,13 +11,14 @@ use tracing::debug;
 use ibc_relayer::keyring::{Secp256k1KeyPair, SigningKeyPair};
 
 use crate::chain::cli::bootstrap::{
-    add_genesis_account, add_genesis_validator, add_wallet, collect_gen_txs, initialize,
-    start_chain,
+    add_genesis_account, add_genesis_validator, add_wallet, collect_gen_txs, import_wallet,
+    initialize, start_chain,
 };
 use crate::chain::cli::provider::{
     copy_validator_key_pair, query_consumer_genesis, replace_genesis_state,
     submit_consumer_chain_proposal,
 };
+use crate::chain::cli::transfer::local_transfer_token;
 use crate::chain::driver::ChainDriver;
 use crate::chain::exec::simple_exec;
 use crate::error::{handle_generic_error, Error};
@@ -73,12 +74,34 @@ pub trait ChainBootstrapMethodsExt {
     */
     fn add_wallet(&self, wallet_id: &str) -> Result<Wallet, Error>;
 
+    /**
+       Import an externally provided mnemonic into the full node's keyring
+       under the given wallet ID, eg. to use a pre-existing account or a key
+       also held by a remote signer.
+
+       The wallet can then be funded at genesis with
+       [`add_genesis_account`](Self::add_genesis_account), or at runtime
+       with [`fund_wallet`](Self::fund_wallet).
+    */
+    fn import_wallet(&self, wallet_id: &str, mnemonic: &str) -> Result<Wallet, Error>;
+
     /**
        Add a wallet address to the genesis account list for an uninitialized
        full node.
     */
     fn add_genesis_account(&self, wallet: &WalletAddress, amounts: &[&Token]) -> Result<(), Error>;
 
+    /**
+       Fund a wallet on a running full node, by sending it the given tokens
+       from an already funded wallet.
+    */
+    fn fund_wallet(
+        &self,
+        funder: &Wallet,
+        wallet: &WalletAddress,
+        token: &Token,
+    ) -> Result<(), Error>;
+
     /**
        Add a wallet ID with the given stake amount to be the genesis validator
        for an uninitialized chain.
@@ -213,6 +236,46 @@ impl ChainBootstrapMethodsExt for ChainDriver {
         Ok(Wallet::new(wallet_id.to_string(), wallet_address, key))
     }
 
+    fn import_wallet(&self, wallet_id: &str, mnemonic: &str) -> Result<Wallet, Error> {
+        let output = import_wallet(
+            self.chain_id.as_str(),
+            &self.command_path,
+            &self.home_path,
+            wallet_id,
+            mnemonic,
+        )?;
+
+        let json_val: json::Value = json::from_str(&output).map_err(handle_generic_error)?;
+
+        let wallet_address = json_val
+            .get("address")
+            .ok_or_else(|| eyre!("expect address string field to be present in json result"))?
+            .as_str()
+            .ok_or_else(|| eyre!("expect address string field to be present in json result"))?
+            .to_string();
+
+        let hd_path = StandardHDPath::from_str(self.chain_type.hd_path())
+            .map_err(|e| eyre!("failed to create StandardHDPath: {:?}", e))?;
+
+        let key = Secp256k1KeyPair::from_mnemonic(
+            mnemonic,
+            &hd_path,
+            &self.chain_type.address_type(),
+            &self.account_prefix,
+        )
+        .map_err(handle_generic_error)?;
+
+        if key.account() != wallet_address {
+            return Err(Error::generic(eyre!(
+                "address {} of the key derived from the mnemonic does not match the address {} of the imported key",
+                key.account(),
+                wallet_address
+            )));
+        }
+
+        Ok(Wallet::new(wallet_id.to_string(), wallet_address, key))
+    }
+
     fn add_genesis_account(&self, wallet: &WalletAddress, amounts: &[&Token]) -> Result<(), Error> {
         let amounts_str = amounts.iter().map(|t| t.to_string()).collect::<Vec<_>>();
 
@@ -225,6 +288,23 @@ impl ChainBootstrapMethodsExt for ChainDriver {
         )
     }
 
+    fn fund_wallet(
+        &self,
+        funder: &Wallet,
+        wallet: &WalletAddress,
+        token: &Token,
+    ) -> Result<(), Error> {
+        local_transfer_token(
+            self.chain_id.as_str(),
+            &self.command_path,
+            &self.home_path,
+            &self.rpc_listen_address(),
+            funder.address.as_str(),
+            wallet.as_str(),
+            &token.to_string(),
+        )
+    }
+
     fn add_genesis_validator(&self, wallet_id: &WalletId, token: &Token) -> Result<(), Error> {
         add_genesis_validator(
             self.chain_id.as_str(),
@@ -0,0 +1,67 @@
+use std::sync::Mutex;
+
+use eyre::eyre;
+use once_cell::sync::Lazy;
+
+use crate::chain::driver::ChainDriver;
+use crate::chain::ext::bootstrap::ChainBootstrapMethodsExt;
+use crate::chain::tagged::TaggedChainDriverExt;
+use crate::error::Error;
+use crate::ibc::token::{TaggedTokenExt, TaggedTokenRef};
+use crate::types::tagged::*;
+use crate::types::wallet::{TaggedWallet, Wallet, WalletAddress};
+
+/**
+   Serializes the transfers from the faucets, as concurrent transfers from
+   the same wallet would be rejected for reusing the same account sequence.
+*/
+static FAUCET_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+pub trait ChainFaucetMethodsExt<Chain> {
+    /**
+       Fund a wallet with the given tokens from the faucet wallet of the chain,
+       and wait until the wallet received them.
+
+       The faucet holds a large balance of the stake denomination, of the
+       denomination of the chain, and of any denomination listed in the
+       [`faucet_denoms`](crate::types::config::TestConfig::faucet_denoms)
+       of the test config, which are allocated at genesis.
+    */
+    fn fund_from_faucet(
+        &self,
+        faucet: &MonoTagged<Chain, &Wallet>,
+        recipient: &MonoTagged<Chain, &WalletAddress>,
+        token: &TaggedTokenRef<Chain>,
+    ) -> Result<(), Error>;
+}
+
+impl<Chain: Send> ChainFaucetMethodsExt<Chain> for MonoTagged<Chain, &ChainDriver> {
+    fn fund_from_faucet(
+        &self,
+        faucet: &MonoTagged<Chain, &Wallet>,
+        recipient: &MonoTagged<Chain, &WalletAddress>,
+        token: &TaggedTokenRef<Chain>,
+    ) -> Result<(), Error> {
+        let _guard = FAUCET_LOCK
+            .lock()
+            .map_err(|_| Error::generic(eyre!("faucet lock poisoned")))?;
+
+        let available = self.query_balance(&faucet.address(), &token.denom())?;
+
+        if available.amount() < token.amount() {
+            return Err(Error::generic(eyre!(
+                "the faucet of chain {} holds {} but {} was requested, add the denomination to `faucet_denoms` in the test config to allocate it to the faucet at genesis",
+                self.value().chain_id,
+                available.value(),
+                token.value(),
+            )));
+        }
+
+        let balance = self.query_balance(recipient, &token.denom())?;
+
+        self.value()
+            .fund_wallet(faucet.value(), recipient.value(), token.value())?;
+
+        self.assert_eventual_wallet_amount(recipient, &(balance + token.amount()).as_ref())
+    }
+}
@@ -5,6 +5,7 @@
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer::chain::cosmos::tx::simple_send_tx;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::chain::cosmos::types::rpc::RpcClient;
 use ibc_relayer::event::IbcEventWithHeight;
 use serde_json as json;
 use tendermint_rpc::client::{Client, CompatMode, HttpClient};
@@ -37,7 +38,7 @@ pub trait TaggedChainDriverExt<Chain> {
     ///
     /// The RPC server must be running and be able to respond on the
     /// `/status` endpoint.
-    fn rpc_client(&self) -> Result<MonoTagged<Chain, HttpClient>, Error>;
+    fn rpc_client(&self) -> Result<MonoTagged<Chain, RpcClient>, Error>;
 
     fn send_tx(
         &self,
@@ -90,7 +91,7 @@ impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a Chai
         self.map_ref(|val| &val.tx_config)
     }
 
-    fn rpc_client(&self) -> Result<MonoTagged<Chain, HttpClient>, Error> {
+    fn rpc_client(&self) -> Result<MonoTagged<Chain, RpcClient>, Error> {
         let rpc_address = self.value().tx_config.rpc_address.clone();
         let rt = &self.value().runtime;
 
@@ -101,7 +102,7 @@ impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a Chai
             CompatMode::from_version(status.node_info.version).map_err(handle_generic_error)?;
         client.set_compat_mode(compat_mode);
 
-        Ok(MonoTagged::new(client))
+        Ok(MonoTagged::new(client.into()))
     }
 
     fn send_tx(
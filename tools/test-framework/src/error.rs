@@ -62,12 +62,16 @@ define_error! {
             {
                 task_name: String,
                 attempts: u16,
+                last_error: String,
+                diagnostics: String,
             }
             | e | {
                 format_args!(
-                    "Expected task to eventually succeeed, but failed after {} attempts: {}",
+                    "Expected task to eventually succeeed, but failed after {} attempts: {}\nlast error: {}\n{}",
                     e.attempts,
-                    e.task_name
+                    e.task_name,
+                    e.last_error,
+                    e.diagnostics
                 )
             },
 
@@ -11,6 +11,7 @@ use crate::bootstrap::init::init_test;
 use crate::chain::builder::ChainBuilder;
 use crate::error::Error;
 use crate::types::config::TestConfig;
+use crate::util::parallel::acquire_test_slot;
 
 /**
    Runs a primitive test case implementing [`PrimitiveTest`].
@@ -93,6 +94,9 @@ where
     fn run(&self) -> Result<(), Error> {
         let mut config = init_test()?;
 
+        // Held until the test ends, along with its chains and relayer
+        let _slot = acquire_test_slot();
+
         let runtime = Arc::new(Runtime::new()?);
 
         self.test.get_overrides().modify_test_config(&mut config);
@@ -61,6 +61,7 @@ where
             builder,
             "provider",
             false,
+            config.extra_user_wallets,
             |config| self.test.get_overrides().modify_node_config(config),
             |genesis| self.test.get_overrides().modify_genesis_file(genesis),
             0,
@@ -90,6 +91,7 @@ where
             builder,
             "consumer",
             &node_a,
+            config.extra_user_wallets,
             |config| self.test.get_overrides().modify_node_config(config),
             |genesis| self.test.get_overrides().modify_genesis_file(genesis),
             1,
@@ -114,6 +114,7 @@ where
             builder,
             "1",
             config.bootstrap_with_random_ids,
+            config.extra_user_wallets,
             |config| self.test.get_overrides().modify_node_config(config),
             |genesis| self.test.get_overrides().modify_genesis_file(genesis),
             0,
@@ -123,6 +124,7 @@ where
             builder,
             "2",
             config.bootstrap_with_random_ids,
+            config.extra_user_wallets,
             |config| self.test.get_overrides().modify_node_config(config),
             |genesis| self.test.get_overrides().modify_genesis_file(genesis),
             1,
@@ -148,6 +150,7 @@ where
             builder,
             "1",
             config.bootstrap_with_random_ids,
+            config.extra_user_wallets,
             |config| self.test.get_overrides().modify_node_config(config),
             |genesis| self.test.get_overrides().modify_genesis_file(genesis),
             0,
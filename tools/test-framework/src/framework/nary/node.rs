@@ -60,6 +60,7 @@ where
                 builder,
                 &format!("{}", i + 1),
                 config.bootstrap_with_random_ids,
+                config.extra_user_wallets,
                 |config| self.test.get_overrides().modify_node_config(config),
                 |genesis| self.test.get_overrides().modify_genesis_file(genesis),
                 i,
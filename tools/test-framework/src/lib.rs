@@ -68,6 +68,21 @@
 //! log, which looks something like `/path/to/ibc-rs/tools/integration-test/data/test-1094235493`.
 //! The sub-directory `test-1094235493` is randomly generated at the beginning of a test
 //! case such that all data related to that test lives in the same directory.
+//!
+//! ## Running Tests in Parallel
+//!
+//! Each test bootstraps its own chains, which listen on ports reserved for the test, even
+//! against the tests running in other processes, and store their data in the directory of
+//! the test. Setting `PARALLEL_TESTS=1` also gives the chains of each test a unique chain ID,
+//! so that the tests can safely run concurrently:
+//!
+//! ```bash
+//! PARALLEL_TESTS=1 MAX_PARALLEL_TESTS=4 \
+//!     cargo test -p ibc-integration-test -- --test-threads=8
+//! ```
+//!
+//! The optional `MAX_PARALLEL_TESTS` variable limits the number of tests running at the same
+//! time in the test process, whatever the number of test threads.
 
 extern crate alloc;
 
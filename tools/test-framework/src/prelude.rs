@@ -17,6 +17,7 @@ pub use std::thread::sleep;
 pub use tracing::{debug, error, info, warn};
 
 pub use crate::chain::driver::ChainDriver;
+pub use crate::chain::ext::faucet::ChainFaucetMethodsExt;
 pub use crate::chain::ext::fee::ChainFeeMethodsExt;
 pub use crate::chain::ext::ica::InterchainAccountMethodsExt;
 pub use crate::chain::ext::proposal::ChainProposalMethodsExt;
@@ -73,5 +74,7 @@ pub use crate::types::wallet::{
     TaggedTestWalletsExt, TaggedWallet, TestWallets, Wallet, WalletAddress, WalletId,
 };
 pub use crate::util::assert::*;
-pub use crate::util::retry::assert_eventually_succeed;
+pub use crate::util::retry::{
+    assert_eventually_succeed, assert_eventually_succeed_with, PollConfig,
+};
 pub use crate::util::suspend::suspend;
@@ -28,7 +28,7 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_relayer::account::Balance;
 use ibc_relayer::chain::client::ClientSettings;
-use ibc_relayer::chain::endpoint::{ChainStatus, HealthCheck};
+use ibc_relayer::chain::endpoint::{ChainFeatures, ChainStatus, HealthCheck};
 use ibc_relayer::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use ibc_relayer::chain::requests::*;
 use ibc_relayer::chain::tracking::TrackedMsgs;
@@ -42,6 +42,7 @@ use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::keyring::AnySigningKeyPair;
 use ibc_relayer::light_client::AnyHeader;
 use ibc_relayer::misbehaviour::MisbehaviourEvidence;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -105,6 +106,10 @@ where
         self.value().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn simulate_messages(&self, tracked_msgs: TrackedMsgs) -> Result<Option<u64>, Error> {
+        self.value().simulate_messages(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.value().get_signer()
     }
@@ -121,10 +126,18 @@ where
         self.value().add_key(key_name, key)
     }
 
+    fn reload_key(&self, key_name: Option<String>) -> Result<Signer, Error> {
+        self.value().reload_key(key_name)
+    }
+
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
         self.value().ibc_version()
     }
 
+    fn query_features(&self) -> Result<ChainFeatures, Error> {
+        self.value().query_features()
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.value().query_application_status()
     }
@@ -430,4 +443,27 @@ where
     ) -> Result<QueryIncentivizedPacketResponse, Error> {
         self.value().query_incentivized_packet(request)
     }
+
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.value().query_incentivized_packets(request)
+    }
+
+    fn query_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.value().query_payee(channel_id, relayer)
+    }
+
+    fn query_counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, Error> {
+        self.value().query_counterparty_payee(channel_id, relayer)
+    }
 }
@@ -1,5 +1,6 @@
 use core::time::Duration;
 use eyre::eyre;
+use ibc_relayer::chain::counterparty::pending_packet_summary;
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{IncludeProof, QueryChannelRequest, QueryHeight};
 use ibc_relayer::channel::{extract_channel_id, Channel, ChannelSide};
@@ -13,7 +14,7 @@ use crate::types::id::{
     TaggedPortIdRef,
 };
 use crate::types::tagged::DualTagged;
-use crate::util::retry::assert_eventually_succeed;
+use crate::util::retry::{assert_eventually_succeed_with, PollConfig};
 
 pub trait TaggedChannelEndExt<ChainA, ChainB> {
     fn tagged_counterparty_channel_id(&self) -> Option<TaggedChannelId<ChainB, ChainA>>;
@@ -173,10 +174,33 @@ pub fn assert_eventually_channel_established<ChainA: ChainHandle, ChainB: ChainH
     channel_id_a: &TaggedChannelIdRef<ChainA, ChainB>,
     port_id_a: &TaggedPortIdRef<ChainA, ChainB>,
 ) -> Result<TaggedChannelId<ChainB, ChainA>, Error> {
-    assert_eventually_succeed(
+    assert_eventually_channel_established_with(
+        handle_a,
+        handle_b,
+        channel_id_a,
+        port_id_a,
+        &PollConfig::from_attempts(20, Duration::from_secs(1)),
+    )
+}
+
+/**
+   Assert that the channel is eventually open on both chains, polling
+   according to the given [`PollConfig`].
+
+   On failure, the error contains the state of both channel ends and
+   of the packets pending on them.
+*/
+pub fn assert_eventually_channel_established_with<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<ChainA, ChainB>,
+    poll: &PollConfig,
+) -> Result<TaggedChannelId<ChainB, ChainA>, Error> {
+    assert_eventually_succeed_with(
         "channel should eventually established",
-        20,
-        Duration::from_secs(1),
+        poll,
+        || channel_diagnostics(handle_a, handle_b, channel_id_a, port_id_a),
         || {
             let channel_end_a = query_channel_end(handle_a, channel_id_a, port_id_a)?;
 
@@ -207,3 +231,67 @@ pub fn assert_eventually_channel_established<ChainA: ChainHandle, ChainB: ChainH
         },
     )
 }
+
+/**
+   Dump the state of both ends of the channel, and the sequences of the
+   packets pending on them, to help diagnose a failed assertion.
+*/
+pub fn channel_diagnostics<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<ChainA, ChainB>,
+) -> String {
+    let channel_end_a = match query_identified_channel_end(handle_a, *channel_id_a, *port_id_a) {
+        Ok(channel_end_a) => channel_end_a.into_value(),
+        Err(e) => {
+            return format!(
+                "failed to query channel end on chain {}: {e}",
+                handle_a.id()
+            )
+        }
+    };
+
+    let mut diagnostics = format!(
+        "channel end on chain {}: {:?}\n",
+        handle_a.id(),
+        channel_end_a
+    );
+
+    match pending_packet_summary(handle_a, handle_b, &channel_end_a) {
+        Ok(pending) => diagnostics.push_str(&format!(
+            "pending packets on chain {}: {:?}\n",
+            handle_a.id(),
+            pending
+        )),
+        Err(e) => diagnostics.push_str(&format!(
+            "failed to query pending packets on chain {}: {e}\n",
+            handle_a.id()
+        )),
+    }
+
+    let counterparty = channel_end_a.channel_end.counterparty();
+
+    if let Some(channel_id_b) = &counterparty.channel_id {
+        match handle_b.query_channel(
+            QueryChannelRequest {
+                port_id: counterparty.port_id.clone(),
+                channel_id: channel_id_b.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((channel_end_b, _)) => diagnostics.push_str(&format!(
+                "channel end on chain {}: {:?}\n",
+                handle_b.id(),
+                channel_end_b
+            )),
+            Err(e) => diagnostics.push_str(&format!(
+                "failed to query channel end on chain {}: {e}\n",
+                handle_b.id()
+            )),
+        }
+    }
+
+    diagnostics
+}
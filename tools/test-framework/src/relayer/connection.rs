@@ -16,7 +16,7 @@ use ibc_relayer_types::timestamp::ZERO_DURATION;
 use crate::error::Error;
 use crate::types::id::{TaggedClientIdRef, TaggedConnectionId, TaggedConnectionIdRef};
 use crate::types::tagged::DualTagged;
-use crate::util::retry::assert_eventually_succeed;
+use crate::util::retry::{assert_eventually_succeed_with, PollConfig};
 
 /**
    An extension trait that provide helper methods to get tagged identifiers
@@ -157,10 +157,30 @@ pub fn assert_eventually_connection_established<ChainA: ChainHandle, ChainB: Cha
     handle_b: &ChainB,
     connection_id_a: &TaggedConnectionIdRef<ChainA, ChainB>,
 ) -> Result<TaggedConnectionId<ChainB, ChainA>, Error> {
-    assert_eventually_succeed(
+    assert_eventually_connection_established_with(
+        handle_a,
+        handle_b,
+        connection_id_a,
+        &PollConfig::from_attempts(20, Duration::from_secs(1)),
+    )
+}
+
+/**
+   Assert that the connection is eventually open on both chains, polling
+   according to the given [`PollConfig`].
+
+   On failure, the error contains the state of both connection ends.
+*/
+pub fn assert_eventually_connection_established_with<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    connection_id_a: &TaggedConnectionIdRef<ChainA, ChainB>,
+    poll: &PollConfig,
+) -> Result<TaggedConnectionId<ChainB, ChainA>, Error> {
+    assert_eventually_succeed_with(
         "connection should eventually established",
-        20,
-        Duration::from_secs(1),
+        poll,
+        || connection_diagnostics(handle_a, handle_b, connection_id_a),
         || {
             let connection_end_a = query_connection_end(handle_a, connection_id_a)?;
 
@@ -194,3 +214,45 @@ pub fn assert_eventually_connection_established<ChainA: ChainHandle, ChainB: Cha
         },
     )
 }
+
+/**
+   Dump the state of both ends of the connection to help diagnose
+   a failed assertion.
+*/
+pub fn connection_diagnostics<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    connection_id_a: &TaggedConnectionIdRef<ChainA, ChainB>,
+) -> String {
+    let connection_end_a = match query_connection_end(handle_a, connection_id_a) {
+        Ok(connection_end_a) => connection_end_a,
+        Err(e) => {
+            return format!(
+                "failed to query connection end on chain {}: {e}",
+                handle_a.id()
+            )
+        }
+    };
+
+    let mut diagnostics = format!(
+        "connection end on chain {}: {:?}\n",
+        handle_a.id(),
+        connection_end_a.value()
+    );
+
+    if let Some(connection_id_b) = connection_end_a.tagged_counterparty_connection_id() {
+        match query_connection_end(handle_b, &connection_id_b.as_ref()) {
+            Ok(connection_end_b) => diagnostics.push_str(&format!(
+                "connection end on chain {}: {:?}\n",
+                handle_b.id(),
+                connection_end_b.value()
+            )),
+            Err(e) => diagnostics.push_str(&format!(
+                "failed to query connection end on chain {}: {e}\n",
+                handle_b.id()
+            )),
+        }
+    }
+
+    diagnostics
+}
@@ -6,6 +6,7 @@ use ibc_relayer::chain::handle::CountingAndCachingChainHandle;
 use ibc_relayer::config::Config;
 use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer::supervisor::{spawn_supervisor, SupervisorHandle, SupervisorOptions};
+use ibc_relayer::util::profile::Profile;
 use std::path::PathBuf;
 
 use crate::error::Error;
@@ -62,6 +63,7 @@ impl RelayerDriver {
             SupervisorOptions {
                 health_check: false,
                 force_full_scan: false,
+                profile: Profile::default(),
             },
         )
         .map_err(Error::supervisor)
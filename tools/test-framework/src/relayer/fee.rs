@@ -1,11 +1,12 @@
 use core::time::Duration;
-use http::uri::Uri;
 use ibc_relayer::chain::cosmos::query::fee::{
     query_counterparty_payee as raw_query_counterparty_payee,
     query_incentivized_packets as raw_query_incentivized_packets,
 };
 use ibc_relayer::chain::cosmos::tx::simple_send_tx;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::chain::cosmos::types::grpc::GrpcAddress;
+use ibc_relayer::chain::cosmos::types::rpc::RpcClient;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer_types::applications::ics29_fee::msgs::pay_packet::build_pay_packet_message;
 use ibc_relayer_types::applications::ics29_fee::msgs::pay_packet_async::build_pay_packet_fee_async_message;
@@ -14,7 +15,6 @@ use ibc_relayer_types::applications::ics29_fee::msgs::register_payee::{
 };
 use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
-use tendermint_rpc::HttpClient;
 
 use crate::error::{handle_generic_error, Error};
 use crate::ibc::token::{TaggedTokenExt, TaggedTokenRef};
@@ -24,7 +24,7 @@ use crate::types::tagged::{DualTagged, MonoTagged};
 use crate::types::wallet::{Wallet, WalletAddress};
 
 pub async fn ibc_token_transfer_with_fee<SrcChain, DstChain>(
-    rpc_client: MonoTagged<SrcChain, &HttpClient>,
+    rpc_client: MonoTagged<SrcChain, &RpcClient>,
     tx_config: &MonoTagged<SrcChain, &TxConfig>,
     port_id: &TaggedPortIdRef<'_, SrcChain, DstChain>,
     channel_id: &TaggedChannelIdRef<'_, SrcChain, DstChain>,
@@ -75,7 +75,7 @@ pub async fn ibc_token_transfer_with_fee<SrcChain, DstChain>(
 }
 
 pub async fn pay_packet_fee<Chain, Counterparty>(
-    rpc_client: MonoTagged<Chain, &HttpClient>,
+    rpc_client: MonoTagged<Chain, &RpcClient>,
     tx_config: &MonoTagged<Chain, &TxConfig>,
     port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
     channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
@@ -114,7 +114,7 @@ pub async fn pay_packet_fee<Chain, Counterparty>(
 }
 
 pub async fn register_counterparty_payee<Chain, Counterparty>(
-    rpc_client: MonoTagged<Chain, &HttpClient>,
+    rpc_client: MonoTagged<Chain, &RpcClient>,
     tx_config: &MonoTagged<Chain, &TxConfig>,
     wallet: &MonoTagged<Chain, &Wallet>,
     counterparty_payee: &MonoTagged<Counterparty, &WalletAddress>,
@@ -152,7 +152,7 @@ pub async fn register_counterparty_payee<Chain, Counterparty>(
 }
 
 pub async fn register_payee<Chain, Counterparty>(
-    rpc_client: MonoTagged<Chain, &HttpClient>,
+    rpc_client: MonoTagged<Chain, &RpcClient>,
     tx_config: &MonoTagged<Chain, &TxConfig>,
     wallet: &MonoTagged<Chain, &Wallet>,
     payee: &MonoTagged<Chain, &WalletAddress>,
@@ -186,7 +186,7 @@ pub async fn register_payee<Chain, Counterparty>(
 }
 
 pub async fn query_counterparty_payee<Chain, Counterparty>(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
     address: &MonoTagged<Chain, &WalletAddress>,
 ) -> Result<Option<MonoTagged<Counterparty, WalletAddress>>, Error> {
@@ -202,7 +202,7 @@ pub async fn query_counterparty_payee<Chain, Counterparty>(
 }
 
 pub async fn query_incentivized_packets<Chain, Counterparty>(
-    grpc_address: &Uri,
+    grpc_address: &GrpcAddress,
     channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
     port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
 ) -> Result<Vec<IdentifiedPacketFees>, Error> {
@@ -13,12 +13,12 @@ use ibc_proto::google::protobuf::Any;
 use ibc_relayer::chain::cosmos::tx::batched_send_tx;
 use ibc_relayer::chain::cosmos::tx::simple_send_tx;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::chain::cosmos::types::rpc::RpcClient;
 use ibc_relayer::transfer::build_transfer_message as raw_build_transfer_message;
 use ibc_relayer::transfer::TransferError;
 use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
 use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
 use ibc_relayer_types::timestamp::Timestamp;
-use tendermint_rpc::HttpClient;
 
 use crate::error::{handle_generic_error, Error};
 use crate::ibc::token::TaggedTokenRef;
@@ -83,7 +83,7 @@ pub fn build_transfer_message<SrcChain, DstChain>(
    transfer message and pass it to send_tx.
 */
 pub async fn ibc_token_transfer<SrcChain, DstChain>(
-    rpc_client: MonoTagged<SrcChain, &HttpClient>,
+    rpc_client: MonoTagged<SrcChain, &RpcClient>,
     tx_config: &MonoTagged<SrcChain, &TxConfig>,
     port_id: &TaggedPortIdRef<'_, SrcChain, DstChain>,
     channel_id: &TaggedChannelIdRef<'_, SrcChain, DstChain>,
@@ -123,7 +123,7 @@ pub async fn ibc_token_transfer<SrcChain, DstChain>(
 }
 
 pub async fn batched_ibc_token_transfer<SrcChain, DstChain>(
-    rpc_client: MonoTagged<SrcChain, &HttpClient>,
+    rpc_client: MonoTagged<SrcChain, &RpcClient>,
     tx_config: &MonoTagged<SrcChain, &TxConfig>,
     port_id: &TaggedPortIdRef<'_, SrcChain, DstChain>,
     channel_id: &TaggedChannelIdRef<'_, SrcChain, DstChain>,
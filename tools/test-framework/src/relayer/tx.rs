@@ -35,6 +35,7 @@ pub fn gas_config_for_test() -> GasConfig {
         gas_price,
         max_fee,
         fee_granter,
+        dynamic_gas_price: Default::default(),
     }
 }
 
@@ -45,7 +46,9 @@ pub fn new_tx_config_for_test(
     address_type: AddressType,
 ) -> Result<TxConfig, Error> {
     let rpc_address = Url::from_str(&raw_rpc_address).map_err(handle_generic_error)?;
-    let grpc_address = Uri::from_str(&raw_grpc_address).map_err(handle_generic_error)?;
+    let grpc_address = Uri::from_str(&raw_grpc_address)
+        .map_err(handle_generic_error)?
+        .into();
     let gas_config = gas_config_for_test();
     let rpc_timeout = Duration::from_secs(30);
     let max_msg_num = Default::default();
@@ -61,6 +64,7 @@ pub fn new_tx_config_for_test(
         address_type,
         max_msg_num,
         max_tx_size,
+        target_tx_size: None,
         extension_options,
     })
 }
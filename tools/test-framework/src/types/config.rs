@@ -58,4 +58,42 @@ pub struct TestConfig {
     pub hang_on_fail: bool,
 
     pub bootstrap_with_random_ids: bool,
+
+    /**
+       The number of user wallets to bootstrap on each chain besides `user1`
+       and `user2`, for the tests involving more parties. Defaults to `0`.
+       This can be overridden by the test cases with
+       [`TestOverrides::modify_test_config`](crate::framework::overrides::TestOverrides::modify_test_config).
+
+       The additional wallets are funded at genesis like the first two
+       user wallets, and retrieved with
+       [`TestWallets::user`](crate::types::wallet::TestWallets::user).
+    */
+    pub extra_user_wallets: usize,
+
+    /**
+       The extra denominations held by the faucet wallet of each chain, besides
+       the stake denomination and the denomination of the chain. Defaults to the
+       comma-separated denominations of the `$FAUCET_DENOMS` environment variable.
+       This can be overridden by the test cases with
+       [`TestOverrides::modify_test_config`](crate::framework::overrides::TestOverrides::modify_test_config),
+       to fund wallets with any denomination mid-test with
+       [`ChainFaucetMethodsExt`](crate::chain::ext::faucet::ChainFaucetMethodsExt).
+
+       The faucet is given a large amount of each of them at genesis.
+    */
+    pub faucet_denoms: Vec<String>,
+
+    /**
+       Whether the tests may run in parallel, in which case the chains are
+       bootstrapped with chain IDs unique to each test, so that the tests
+       never share any state keyed by chain ID in the test process.
+       Defaults to `false`. This can be overridden by setting `PARALLEL_TESTS=1`.
+
+       The chains of each test always listen on their own ports and store their
+       files in their own directory. The number of tests running at the same
+       time can be limited with the `$MAX_PARALLEL_TESTS` environment variable,
+       see [`acquire_test_slot`](crate::util::parallel::acquire_test_slot).
+    */
+    pub parallel: bool,
 }
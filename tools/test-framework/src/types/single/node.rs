@@ -162,15 +162,27 @@ impl FullNode {
             max_block_time: Duration::from_secs(30),
             clock_drift: Duration::from_secs(5),
             trusting_period: Some(Duration::from_secs(14 * 24 * 3600)),
+            trusting_period_margin: None,
             ccv_consumer_chain: false,
             trust_threshold: Default::default(),
             gas_price: config::GasPrice::new(0.003, "stake".to_string()),
             packet_filter: Default::default(),
+            proxy: None,
+            rate_limit: Default::default(),
+            retry: Default::default(),
+            packet_memo: Default::default(),
+            height_rollback: Default::default(),
+            cache: Default::default(),
+            dynamic_gas_price: Default::default(),
+            adaptive_batch: Default::default(),
+            channel_batch: Vec::new(),
+            submitter: None,
             address_type: chain_type.address_type(),
             memo_prefix: Default::default(),
             proof_specs: Default::default(),
             extension_options: Default::default(),
             sequential_batch_tx: false,
+            aggregation_window: None,
         })
     }
 
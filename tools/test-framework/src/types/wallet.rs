@@ -3,8 +3,10 @@
 */
 
 use core::fmt::{self, Display};
+use eyre::eyre;
 use ibc_relayer::keyring::Secp256k1KeyPair;
 
+use crate::error::Error;
 use crate::types::env::{prefix_writer, EnvWriter, ExportEnv};
 use crate::types::tagged::*;
 
@@ -42,16 +44,15 @@ pub struct Wallet {
 /**
    A collection of wallets used for testing. We use an explicit
    struct instead of a generic HashMap so that the retrieval
-   of a specific wallet can always succeed. We shouldn't need
-   more than the wallets listed here for testing purposes.
-
-   In case we do need more wallets for testing, there shouldn't
-   be much overhead for adding a few more wallets here globally.
-   Alternatively the particular test that needs more wallets
-   can add new wallets in the test itself, or we can add
-   a HashMap here together with a
-   [`TestOverrides`](crate::framework::overrides::TestOverrides)
-   trait to generate additional wallets during test setup.
+   of the wallets used by most tests can always succeed.
+
+   Tests that need more user wallets, such as the tests involving
+   many parties, can set
+   [`extra_user_wallets`](crate::types::config::TestConfig::extra_user_wallets)
+   in
+   [`TestOverrides::modify_test_config`](crate::framework::overrides::TestOverrides::modify_test_config),
+   so that the additional wallets are funded at genesis and retrieved
+   with [`TestWallets::user`].
 */
 #[derive(Debug, Clone)]
 pub struct TestWallets {
@@ -66,6 +67,14 @@ pub struct TestWallets {
 
     /// The second user wallet that can be used for testing.
     pub user2: Wallet,
+
+    /// The additional user wallets, starting from the third user wallet,
+    /// which are funded like the first two user wallets.
+    pub extra_users: Vec<Wallet>,
+
+    /// The faucet wallet, from which the tests can fund other wallets
+    /// mid-test with [`ChainFaucetMethodsExt`](crate::chain::ext::faucet::ChainFaucetMethodsExt).
+    pub faucet: Wallet,
 }
 
 /**
@@ -103,6 +112,13 @@ pub trait TaggedTestWalletsExt<Chain> {
 
     /// Get the second user [`Wallet`] tagged with the given `Chain`.
     fn user2(&self) -> MonoTagged<Chain, &Wallet>;
+
+    /// Get the `n`-th user [`Wallet`] tagged with the given `Chain`,
+    /// see [`TestWallets::user`].
+    fn user(&self, n: usize) -> Result<MonoTagged<Chain, &Wallet>, Error>;
+
+    /// Get the faucet [`Wallet`] tagged with the given `Chain`.
+    fn faucet(&self) -> MonoTagged<Chain, &Wallet>;
 }
 
 impl Wallet {
@@ -116,6 +132,46 @@ impl Wallet {
     }
 }
 
+impl TestWallets {
+    /**
+       Get the `n`-th user wallet, counting from 1, so that `user(1)` and
+       `user(2)` are the same as `user1` and `user2`.
+
+       Returns an error if there are less than `n` user wallets, in which case
+       the test should set
+       [`extra_user_wallets`](crate::types::config::TestConfig::extra_user_wallets)
+       to bootstrap more of them.
+    */
+    pub fn user(&self, n: usize) -> Result<&Wallet, Error> {
+        match n {
+            1 => Ok(&self.user1),
+            2 => Ok(&self.user2),
+            _ => n
+                .checked_sub(3)
+                .and_then(|i| self.extra_users.get(i))
+                .ok_or_else(|| {
+                    Error::generic(eyre!(
+                        "there is no user wallet {} out of the {} user wallets, set `extra_user_wallets` in the test config to bootstrap more of them",
+                        n,
+                        self.user_count(),
+                    ))
+                }),
+        }
+    }
+
+    /// The number of user wallets, including `user1` and `user2`.
+    pub fn user_count(&self) -> usize {
+        2 + self.extra_users.len()
+    }
+
+    /// All the user wallets, starting from `user1`.
+    pub fn users(&self) -> impl Iterator<Item = &Wallet> {
+        [&self.user1, &self.user2]
+            .into_iter()
+            .chain(self.extra_users.iter())
+    }
+}
+
 impl WalletAddress {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
@@ -166,6 +222,14 @@ impl<Chain> TaggedTestWalletsExt<Chain> for MonoTagged<Chain, TestWallets> {
     fn user2(&self) -> MonoTagged<Chain, &Wallet> {
         self.map_ref(|w| &w.user2)
     }
+
+    fn user(&self, n: usize) -> Result<MonoTagged<Chain, &Wallet>, Error> {
+        Ok(MonoTagged::new(self.value().user(n)?))
+    }
+
+    fn faucet(&self) -> MonoTagged<Chain, &Wallet> {
+        self.map_ref(|w| &w.faucet)
+    }
 }
 
 impl<'a, Chain> TaggedTestWalletsExt<Chain> for MonoTagged<Chain, &'a TestWallets> {
@@ -184,6 +248,14 @@ impl<'a, Chain> TaggedTestWalletsExt<Chain> for MonoTagged<Chain, &'a TestWallet
     fn user2(&self) -> MonoTagged<Chain, &Wallet> {
         self.map_ref(|w| &w.user2)
     }
+
+    fn user(&self, n: usize) -> Result<MonoTagged<Chain, &Wallet>, Error> {
+        Ok(MonoTagged::new(self.value().user(n)?))
+    }
+
+    fn faucet(&self) -> MonoTagged<Chain, &Wallet> {
+        self.map_ref(|w| &w.faucet)
+    }
 }
 
 impl ExportEnv for TestWallets {
@@ -194,6 +266,12 @@ impl ExportEnv for TestWallets {
             .export_env(&mut prefix_writer("RELAYER", writer));
         self.user1.export_env(&mut prefix_writer("USER1", writer));
         self.user2.export_env(&mut prefix_writer("USER2", writer));
+
+        for (i, user) in self.extra_users.iter().enumerate() {
+            user.export_env(&mut prefix_writer(&format!("USER{}", i + 3), writer));
+        }
+
+        self.faucet.export_env(&mut prefix_writer("FAUCET", writer));
     }
 }
 
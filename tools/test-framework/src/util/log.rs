@@ -0,0 +1,62 @@
+/*!
+   Capture of the most recent log lines, to include them in the
+   errors of failed assertions.
+*/
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use tracing_subscriber::fmt::MakeWriter;
+
+/**
+   The maximum number of log lines to keep.
+*/
+const MAX_RECENT_LOGS: usize = 100;
+
+static RECENT_LOGS: Lazy<Mutex<VecDeque<String>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(MAX_RECENT_LOGS)));
+
+/**
+   Returns the most recent log lines, oldest first.
+*/
+pub fn recent_logs() -> Vec<String> {
+    RECENT_LOGS
+        .lock()
+        .map(|logs| logs.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/**
+   A [`MakeWriter`] for a [`tracing_subscriber`] layer which keeps
+   the most recent log lines in memory.
+*/
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RecentLogsWriter;
+
+impl Write for RecentLogsWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Ok(mut logs) = RECENT_LOGS.lock() {
+            if logs.len() == MAX_RECENT_LOGS {
+                logs.pop_front();
+            }
+
+            logs.push_back(String::from_utf8_lossy(buf).into_owned());
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for RecentLogsWriter {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        *self
+    }
+}
@@ -5,6 +5,8 @@
 pub mod array;
 pub mod assert;
 pub mod file;
+pub mod log;
+pub mod parallel;
 pub mod random;
 pub mod retry;
 pub mod suspend;
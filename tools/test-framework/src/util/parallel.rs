@@ -0,0 +1,69 @@
+/*!
+   Utilities for running the tests in parallel, by limiting the number of
+   tests running at the same time in the test process.
+*/
+
+use std::env;
+use std::sync::{Condvar, Mutex};
+
+use once_cell::sync::Lazy;
+
+static RUNNING_TESTS: Lazy<(Mutex<usize>, Condvar)> = Lazy::new(|| (Mutex::new(0), Condvar::new()));
+
+/**
+   The maximum number of tests running at the same time, as set with the
+   `$MAX_PARALLEL_TESTS` environment variable, or `None` if unlimited.
+
+   As each test spawns its own chains and relayer, this allows running the
+   tests with more test threads than the machine can run chains at once.
+*/
+pub fn max_parallel_tests() -> Option<usize> {
+    env::var("MAX_PARALLEL_TESTS")
+        .ok()
+        .and_then(|max| max.parse().ok())
+        .filter(|max| *max > 0)
+}
+
+/**
+   A slot among the tests running at the same time, which is released when dropped.
+*/
+pub struct TestSlot {
+    limited: bool,
+}
+
+/**
+   Wait until less than [`max_parallel_tests`] tests are running, and
+   acquire a slot for the current test.
+*/
+pub fn acquire_test_slot() -> TestSlot {
+    let Some(max) = max_parallel_tests() else {
+        return TestSlot { limited: false };
+    };
+
+    let (running, available) = &*RUNNING_TESTS;
+
+    let mut running = running.lock().unwrap_or_else(|e| e.into_inner());
+
+    while *running >= max {
+        running = available.wait(running).unwrap_or_else(|e| e.into_inner());
+    }
+
+    *running += 1;
+
+    TestSlot { limited: true }
+}
+
+impl Drop for TestSlot {
+    fn drop(&mut self) {
+        if !self.limited {
+            return;
+        }
+
+        let (running, available) = &*RUNNING_TESTS;
+
+        let mut running = running.lock().unwrap_or_else(|e| e.into_inner());
+        *running = running.saturating_sub(1);
+
+        available.notify_one();
+    }
+}
@@ -7,8 +7,12 @@ use once_cell::sync::Lazy;
 use rand::Rng;
 use std::{
     collections::HashSet,
+    env,
+    fs::{self, OpenOptions},
+    io::ErrorKind,
     net::{Ipv4Addr, SocketAddrV4, TcpListener},
     sync::Mutex,
+    time::Duration,
 };
 
 /// Generates a random `u32` value.
@@ -56,7 +60,14 @@ fn random_port() -> u16 {
     rng.gen_range(1024..=u16::MAX)
 }
 
+/// How long a port stays reserved for the test process which allocated it.
+const PORT_RESERVATION_DURATION: Duration = Duration::from_secs(3600);
+
 /// Find a random unused non-privileged TCP port.
+///
+/// The port is also reserved against the other test processes running on the
+/// machine, eg. when running the tests with `cargo nextest`, so that the tests
+/// running in parallel never allocate the same port.
 pub fn random_unused_tcp_port() -> u16 {
     static ALLOCATED_PORTS: Lazy<Mutex<HashSet<u16>>> = Lazy::new(|| Mutex::new(HashSet::new()));
 
@@ -64,7 +75,41 @@ pub fn random_unused_tcp_port() -> u16 {
     let loopback = Ipv4Addr::new(127, 0, 0, 1);
     let address = SocketAddrV4::new(loopback, port);
     match TcpListener::bind(address) {
-        Ok(_) if ALLOCATED_PORTS.lock().unwrap().insert(port) => port,
+        Ok(_) if ALLOCATED_PORTS.lock().unwrap().insert(port) && reserve_port(port) => port,
         _ => random_unused_tcp_port(),
     }
 }
+
+/// Reserve the port by creating a file named after it in a directory shared by
+/// the test processes, unless another process reserved it less than
+/// [`PORT_RESERVATION_DURATION`] ago.
+///
+/// The reservation is best effort: the port is deemed reserved if the
+/// directory cannot be written to.
+fn reserve_port(port: u16) -> bool {
+    let dir = env::temp_dir().join("ibc-test-ports");
+
+    if fs::create_dir_all(&dir).is_err() {
+        return true;
+    }
+
+    let path = dir.join(port.to_string());
+
+    match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(_) => true,
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            // Take over the reservations left over by the earlier test runs
+            let expired = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .map(|modified| {
+                    modified
+                        .elapsed()
+                        .map_or(false, |elapsed| elapsed > PORT_RESERVATION_DURATION)
+                })
+                .unwrap_or(false);
+
+            expired && fs::write(&path, b"").is_ok()
+        }
+        Err(_) => true,
+    }
+}
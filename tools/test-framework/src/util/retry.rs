@@ -3,10 +3,71 @@
 */
 
 use core::time::Duration;
+use std::env;
 use std::thread::sleep;
 use tracing::{info, trace};
 
 use crate::error::Error;
+use crate::util::log::recent_logs;
+
+/**
+   How often, and for how long, to poll for a condition that should
+   eventually hold.
+
+   The timeout is scaled by the `POLL_TIMEOUT_MULTIPLIER` environment
+   variable if it is set, so that the tests can be given more time
+   on slow CI runners without changing the code.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PollConfig {
+    pub interval: Duration,
+    pub timeout: Duration,
+}
+
+impl PollConfig {
+    pub const fn new(interval: Duration, timeout: Duration) -> Self {
+        Self { interval, timeout }
+    }
+
+    /**
+       Poll the given number of times, waiting for the given interval
+       before each attempt.
+    */
+    pub fn from_attempts(attempts: u16, interval: Duration) -> Self {
+        Self::new(interval, interval * u32::from(attempts))
+    }
+
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /**
+       The number of attempts to make before giving up, taking into
+       account the `POLL_TIMEOUT_MULTIPLIER` environment variable.
+    */
+    pub fn attempts(&self) -> u16 {
+        let multiplier = env::var("POLL_TIMEOUT_MULTIPLIER")
+            .ok()
+            .and_then(|val| val.parse::<f64>().ok())
+            .filter(|val| *val > 0.0)
+            .unwrap_or(1.0);
+
+        let interval = self.interval.as_secs_f64().max(f64::EPSILON);
+        let attempts = (self.timeout.as_secs_f64() * multiplier / interval).ceil();
+
+        attempts.clamp(1.0, f64::from(u16::MAX)) as u16
+    }
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(20))
+    }
+}
 
 /**
    A simplified version of retry logic used for testing.
@@ -20,7 +81,33 @@ pub fn assert_eventually_succeed<R>(
     interval: Duration,
     task: impl Fn() -> Result<R, Error>,
 ) -> Result<R, Error> {
-    sleep(interval);
+    assert_eventually_succeed_with(
+        task_name,
+        &PollConfig::from_attempts(attempts, interval),
+        String::new,
+        task,
+    )
+}
+
+/**
+   Poll the given task according to the given [`PollConfig`] until it
+   succeeds.
+
+   If the task never succeeds, the returned error contains the error of the
+   last attempt, the diagnostics produced by the `diagnose` closure, eg. a
+   dump of the relevant on-chain state, and the most recent relayer logs,
+   so that the failure can be understood from the error alone.
+*/
+pub fn assert_eventually_succeed_with<R>(
+    task_name: &str,
+    poll: &PollConfig,
+    diagnose: impl Fn() -> String,
+    task: impl Fn() -> Result<R, Error>,
+) -> Result<R, Error> {
+    let attempts = poll.attempts();
+    let mut last_error = String::new();
+
+    sleep(poll.interval);
     for i in 0..attempts {
         match task() {
             Ok(res) => {
@@ -29,10 +116,22 @@ pub fn assert_eventually_succeed<R>(
             }
             Err(e) => {
                 trace!("retrying task {} that failed with error: {}", task_name, e);
-                sleep(interval)
+                last_error = e.to_string();
+                sleep(poll.interval)
             }
         }
     }
 
-    Err(Error::retry(task_name.to_string(), attempts))
+    let diagnostics = format!(
+        "{}\nrecent relayer logs:\n{}",
+        diagnose(),
+        recent_logs().join("")
+    );
+
+    Err(Error::retry(
+        task_name.to_string(),
+        attempts,
+        last_error,
+        diagnostics,
+    ))
 }